@@ -1123,6 +1123,10 @@ pub struct App {
     deferred_kitty_releases: VecDeque<DeferredKittyRelease>,
     kitty_ctrl_c_input_handoff: Option<KittyInputHandoff>,
     log_enabled: bool,
+    /// Whether input is being typed into a secret prompt and must stay out
+    /// of the log.
+    secret_input_active: bool,
+    pending_key_sequence: Option<PendingKeySequence>,
     lua_repl_session: Option<views::LuaReplSession>,
     last_stdin_update: Option<u128>,
     stabilization_profiles: HashMap<AccessibilityContext, StabilizationProfile>,
@@ -1152,6 +1156,9 @@ pub struct App {
     nested_tmux_gateways: BTreeMap<(u64, u64), NestedTmuxGatewayState>,
     tmux_hierarchy: ConnectionHierarchy,
     tmux_connections: Vec<TmuxConnectionState>,
+    /// Panes seen in each connection's inventory, so only panes that were
+    /// known and then closed lose their speech and notice state.
+    known_tmux_panes: BTreeMap<u64, BTreeSet<crate::tmux_model::PaneId>>,
     pending_tmux_commands: VecDeque<PendingTmuxCommand>,
     active_tmux_connection: Option<u64>,
     pending_tmux_confirmation: Option<PendingTmuxConfirmation>,
@@ -1167,6 +1174,9 @@ pub struct App {
     pending_tmux_background_bytes: usize,
     tmux_hidden_output_bytes_this_turn: usize,
     pending_presentation_batch: Option<PendingPresentationBatch>,
+    tmux_workspace_dir: Option<std::path::PathBuf>,
+    /// A transcript waiting for its target path to be entered.
+    pending_transcript: Option<(crate::review::export::Transcript, String)>,
     clock: Box<dyn Clock>,
 }
 
@@ -1211,6 +1221,10 @@ struct TmuxConnectionState {
     flow_control_warning_announced: bool,
     capture_line_flags_supported: Option<bool>,
     last_announced_location: Option<crate::tmux_model::TmuxLocation>,
+    /// Background windows of the attached session with output since their
+    /// last visit.
+    unseen_output_windows: BTreeSet<crate::tmux_model::WindowId>,
+    workspace_restore: Option<crate::tmux_workspace::TmuxWorkspaceRestore>,
 }
 
 struct PendingTmuxPaneCapture {
@@ -1280,6 +1294,23 @@ impl TmuxConnectionState {
         self.inventory_failed = false;
         self.inventory_failure_detail = None;
     }
+
+    fn note_background_output(&mut self, pane_id: crate::tmux_model::PaneId) {
+        let Some(window_id) = self.topology.pane(pane_id).map(|pane| pane.window_id) else {
+            return;
+        };
+        let is_background = self
+            .topology
+            .attached_session()
+            .and_then(|session_id| self.topology.session(session_id))
+            .is_some_and(|session| {
+                session.active_window != Some(window_id)
+                    && session.windows.values().any(|id| *id == window_id)
+            });
+        if is_background {
+            self.unseen_output_windows.insert(window_id);
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -1299,6 +1330,9 @@ enum ExpectedTmuxReply {
     PanePause(crate::tmux_model::PaneId),
     PaneContinue(crate::tmux_model::PaneId),
     Ignored,
+    ReviewHistory(crate::tmux_model::PaneId),
+    WorkspaceSave(String),
+    WorkspaceRestore,
     UserCommand {
         description: String,
         show_success: bool,
@@ -1354,6 +1388,14 @@ enum ForwardedInputTarget {
     },
 }
 
+/// Which part of the reviewed document a transcript saves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TranscriptScope {
+    Document,
+    Selection,
+    CommandOutput,
+}
+
 #[derive(Clone, Copy)]
 struct ForwardedKeyPress {
     target: ForwardedInputTarget,
@@ -1367,6 +1409,14 @@ struct DeferredKittyRelease {
     release_at_ms: u128,
 }
 
+/// The keys typed so far of a bound key sequence such as `M-s l`.
+#[derive(Default)]
+struct PendingKeySequence {
+    keys: String,
+    /// The presses the application gets if no sequence is completed.
+    presses: Vec<(KeyInput, Vec<u8>)>,
+}
+
 #[derive(Clone, Copy)]
 struct KittyInputHandoff {
     target: ForwardedInputTarget,
@@ -1410,6 +1460,8 @@ impl App {
             deferred_kitty_releases: VecDeque::new(),
             kitty_ctrl_c_input_handoff: None,
             log_enabled: false,
+            secret_input_active: false,
+            pending_key_sequence: None,
             lua_repl_session: None,
             last_stdin_update: None,
             stabilization_profiles: HashMap::new(),
@@ -1437,6 +1489,7 @@ impl App {
             nested_tmux_gateways: BTreeMap::new(),
             tmux_hierarchy: ConnectionHierarchy::new(),
             tmux_connections: Vec::new(),
+            known_tmux_panes: BTreeMap::new(),
             pending_tmux_commands: VecDeque::new(),
             active_tmux_connection: None,
             pending_tmux_confirmation: None,
@@ -1452,6 +1505,8 @@ impl App {
             pending_tmux_background_bytes: 0,
             tmux_hidden_output_bytes_this_turn: 0,
             pending_presentation_batch: None,
+            tmux_workspace_dir: crate::tmux_workspace::default_workspace_dir(),
+            pending_transcript: None,
             clock,
         };
         let now_ms = app.clock.now_ms();
@@ -1666,6 +1721,11 @@ impl App {
         }
     }
 
+    /// Overrides where named tmux workspaces are saved and loaded.
+    pub fn set_tmux_workspace_dir(&mut self, directory: std::path::PathBuf) {
+        self.tmux_workspace_dir = Some(directory);
+    }
+
     pub fn set_physical_profile(&mut self, profile: PhysicalTerminalProfile) {
         let capabilities = RenderCapabilities {
             synchronized_output: self.output_scheduler.is_none() && profile.synchronized_output,
@@ -2175,6 +2235,21 @@ impl App {
         }
     }
 
+    /// Logs input bytes, or only their count while a secret is typed.
+    fn log_input_bytes(&self, label: &str, bytes: &[u8]) {
+        if self.secret_input_active {
+            if self.log_enabled {
+                crate::diagnostics::event(
+                    "app",
+                    label,
+                    &format!("{} bytes hidden during secret entry", bytes.len()),
+                );
+            }
+            return;
+        }
+        self.log_bytes(label, bytes);
+    }
+
     fn log_event(&self, message: &str) {
         if self.log_enabled {
             crate::diagnostics::event("app", "event", message);
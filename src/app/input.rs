@@ -1,5 +1,5 @@
 use super::*;
-use crate::views::ViewController;
+use crate::{screen_reader::TypedKey, views::ViewController};
 
 impl App {
     pub fn handle_stdin(
@@ -9,7 +9,9 @@ impl App {
         pty_out: &mut dyn Write,
         term_out: &mut dyn Write,
     ) -> Result<()> {
-        self.log_bytes("stdin from terminal", input);
+        sr.refresh_line_discipline();
+        self.secret_input_active = sr.secret_entry_active();
+        self.log_input_bytes("stdin from terminal", input);
         self.log_latency_stage("input-received", || format!("bytes={}", input.len()));
         let input = if let Some(broker) = self.startup_probe_broker.as_mut() {
             broker.ingest(input, self.clock.now_ms())
@@ -31,6 +33,7 @@ impl App {
         pty_out: &mut dyn Write,
         term_out: &mut dyn Write,
     ) -> Result<()> {
+        self.sync_active_tmux_pane(sr)?;
         for &byte in input {
             self.pending_input_last_at = Some(self.clock.now_ms());
             self.pending_input.push_back(byte);
@@ -71,7 +74,7 @@ impl App {
                 SequenceStatus::Complete(osc_len) => {
                     let raw: Vec<u8> = self.pending_input.drain(..osc_len).collect();
                     self.pending_input_last_at = None;
-                    self.log_bytes("recognized OSC sequence", &raw);
+                    self.log_input_bytes("recognized OSC sequence", &raw);
                     self.handle_raw_bytes(sr, &raw, pty_out, term_out)?;
                     continue;
                 }
@@ -103,7 +106,7 @@ impl App {
                     if self.pending_input.is_empty() {
                         self.pending_input_last_at = None;
                     }
-                    self.log_bytes("parsed terminal event bytes", &raw);
+                    self.log_input_bytes("parsed terminal event bytes", &raw);
                     self.handle_event(sr, event, &raw, pty_out, term_out)?;
                     continue;
                 }
@@ -112,7 +115,7 @@ impl App {
                     if self.pending_input.is_empty() {
                         self.pending_input_last_at = None;
                     }
-                    self.log_bytes("recognized modifyOtherKeys sequence", &raw);
+                    self.log_input_bytes("recognized modifyOtherKeys sequence", &raw);
                     self.handle_raw_bytes(sr, &raw, pty_out, term_out)?;
                     continue;
                 }
@@ -125,7 +128,7 @@ impl App {
                 if self.pending_input.is_empty() {
                     self.pending_input_last_at = None;
                 }
-                self.log_bytes("reclassified partial SS3 as Alt-O", &raw);
+                self.log_input_bytes("reclassified partial SS3 as Alt-O", &raw);
                 let event =
                     Event::Key(KeyEvent::new(KeyCode::Char('O')).modifiers(KeyModifiers::ALT));
                 self.handle_event(sr, event, &raw, pty_out, term_out)?;
@@ -138,7 +141,7 @@ impl App {
                     let raw = buf.to_vec();
                     self.pending_input.clear();
                     self.pending_input_last_at = None;
-                    self.log_bytes("parsed terminal event bytes", &raw);
+                    self.log_input_bytes("parsed terminal event bytes", &raw);
                     self.handle_event(sr, event, &raw, pty_out, term_out)?;
                 }
                 Ok(None) => {
@@ -152,7 +155,7 @@ impl App {
                     if self.pending_input.is_empty() {
                         self.pending_input_last_at = None;
                     }
-                    self.log_bytes("forwarding undecodable byte", &[raw_byte]);
+                    self.log_input_bytes("forwarding undecodable byte", &[raw_byte]);
                     self.handle_raw_bytes(sr, &[raw_byte], pty_out, term_out)?;
                 }
             }
@@ -210,7 +213,7 @@ impl App {
 
         let raw: Vec<u8> = self.pending_input.drain(..).collect();
         self.pending_input_last_at = None;
-        self.log_bytes("flushing pending input after timeout", &raw);
+        self.log_input_bytes("flushing pending input after timeout", &raw);
 
         if let Some(event) = timed_out_event(&raw) {
             self.handle_event(sr, event, &raw, pty_out, term_out)
@@ -334,10 +337,73 @@ impl App {
             return self.dispatch_key_to_view(sr, &key, raw, pty_out, term_out);
         }
 
-        let binding_name = self.key_event_binding_name(key_event);
+        let mut binding_name = self.key_event_binding_name(key_event);
+        // An overlay opened from table or user mode, such as a column search
+        // or the bindings list, takes typed text, so mode bindings like `j`
+        // must not claim its keys.
+        let binding_mode = if self.view_stack.active_mut().kind().takes_text() {
+            crate::keymap::InputMode::Normal
+        } else {
+            sr.input_mode()
+        };
+        // After the first keys of a sequence such as `M-s l`, this key
+        // continues or completes it. Escape cancels; otherwise the keys typed
+        // so far go on to the application and this key is handled alone.
+        let mut completes_sequence = false;
+        let mut pending = None;
+        // Escape after the first corner of a screen region abandons it.
+        if self.pending_key_sequence.is_none()
+            && key_event.code == KeyCode::Esc
+            && key_event.modifiers.is_empty()
+            && sr.regions_mut().cancel_corner()
+        {
+            sr.speak("cancelled", false)?;
+            self.consumed_key_presses.insert(key_id);
+            return Ok(());
+        }
+        if let Some(sequence) = self.pending_key_sequence.take() {
+            if key_event.code == KeyCode::Esc && key_event.modifiers.is_empty() {
+                sr.speak("cancelled", false)?;
+                self.consumed_key_presses.insert(key_id);
+                return Ok(());
+            }
+            let keys = binding_name
+                .as_deref()
+                .map(|name| format!("{} {name}", sequence.keys))
+                .filter(|keys| {
+                    let bindings = sr.key_bindings();
+                    bindings.binding_for_mode(binding_mode, keys).is_some()
+                        || bindings.is_sequence_prefix(binding_mode, keys)
+                });
+            if keys.is_some() {
+                binding_name = keys;
+                completes_sequence = true;
+                pending = Some(sequence);
+            } else {
+                for (press, press_raw) in sequence.presses {
+                    let event = press.event();
+                    self.consumed_key_presses
+                        .remove(&(event.code, event.modifiers, event.state));
+                    self.dispatch_key_to_view(sr, &press, &press_raw, pty_out, term_out)?;
+                }
+            }
+        }
+        if let Some(keys) = binding_name.as_deref()
+            && sr.key_bindings().is_sequence_prefix(binding_mode, keys)
+        {
+            sr.speak(keys, false)?;
+            let mut pending = pending.unwrap_or_default();
+            keys.clone_into(&mut pending.keys);
+            if !captures_unbound_key(binding_mode, key_event) {
+                pending.presses.push((key.clone(), raw.to_vec()));
+            }
+            self.pending_key_sequence = Some(pending);
+            self.consumed_key_presses.insert(key_id);
+            return Ok(());
+        }
         let preempts_tmux_prefix = binding_name
             .as_deref()
-            .and_then(|name| sr.key_bindings().binding_for_mode(sr.input_mode(), name))
+            .and_then(|name| sr.key_bindings().binding_for_mode(binding_mode, name))
             .is_some_and(|binding| {
                 matches!(
                     binding,
@@ -348,14 +414,17 @@ impl App {
                     )
                 )
             });
-        if !preempts_tmux_prefix && self.handle_tmux_prefix_key(sr, &key, term_out)? {
+        if !preempts_tmux_prefix
+            && !completes_sequence
+            && self.handle_tmux_prefix_key(sr, &key, term_out)?
+        {
             self.consumed_key_presses.insert(key_id);
             return Ok(());
         }
 
         let binding = binding_name.as_ref().and_then(|name| {
             sr.key_bindings()
-                .binding_for_mode(sr.input_mode(), name.as_str())
+                .binding_for_mode(binding_mode, name.as_str())
         });
         if self.log_enabled && binding.is_some() {
             self.log_event(&format!(
@@ -369,188 +438,26 @@ impl App {
                 if matches!(binding, Binding::Builtin(commands::Action::ToggleHelp)) {
                     // Allow exiting help mode.
                 } else {
-                    let help = binding.help_text().to_owned();
+                    let help = match binding {
+                        Binding::Mode(mode) => sr.key_bindings().mode_help(*mode),
+                        _ => binding.help_text().to_owned(),
+                    };
                     sr.speak(&help, false)?;
                     self.consumed_key_presses.insert(key_id);
                     return Ok(());
                 }
             }
-            match binding {
-                Binding::Builtin(action) => {
-                    let action = *action;
-                    if matches!(action, commands::Action::OpenReview) {
-                        self.open_review(sr, false, term_out)?;
-                        self.consumed_key_presses.insert(key_id);
-                        return Ok(());
-                    }
-                    if matches!(action, commands::Action::OpenLuaRepl) {
-                        if self.view_stack.active_mut().kind() == views::ViewKind::LuaRepl {
-                            sr.speak("Lua REPL already open", false)?;
-                            self.consumed_key_presses.insert(key_id);
-                            return Ok(());
-                        }
-                        let (rows, cols) = self.view_stack.active_mut().model().live_size();
-                        let session = match &self.lua_repl_session {
-                            Some(session) => session.clone(),
-                            None => {
-                                let session = views::LuaReplSession::new(Vec::new())?;
-                                self.lua_repl_session = Some(session.clone());
-                                session
-                            }
-                        };
-                        let repl = views::LuaReplView::from_session(rows, cols, session);
-                        self.handle_view_action(
-                            sr,
-                            views::ViewAction::Push(Box::new(repl)),
-                            term_out,
-                        )?;
-                        self.consumed_key_presses.insert(key_id);
-                        return Ok(());
-                    }
-                    let tmux_overlay_opened = match action {
-                        commands::Action::OpenTmuxConnectionChooser => {
-                            Some(self.show_tmux_connection_chooser(sr, term_out)?)
-                        }
-                        commands::Action::RenameTmuxConnection => {
-                            Some(self.show_tmux_connection_rename(sr, term_out)?)
-                        }
-                        commands::Action::OpenTmuxSessionChooser => {
-                            Some(self.show_tmux_session_chooser(sr, term_out)?)
-                        }
-                        commands::Action::OpenTmuxWindowChooser => {
-                            Some(self.show_tmux_window_chooser(sr, term_out)?)
-                        }
-                        commands::Action::OpenTmuxPaneChooser => {
-                            Some(self.show_tmux_pane_chooser(sr, term_out)?)
-                        }
-                        commands::Action::OpenTmuxCommandPrompt => {
-                            Some(self.show_tmux_command_prompt(sr, term_out)?)
-                        }
-                        commands::Action::DetachTmuxConnection => {
-                            Some(self.request_tmux_gateway_action(
-                                sr,
-                                crate::tmux_lifecycle::GatewayControlAction::GracefulDetach,
-                                term_out,
-                            )?)
-                        }
-                        commands::Action::ForceAbandonTmuxGateway => {
-                            Some(self.request_tmux_gateway_action(
-                                sr,
-                                crate::tmux_lifecycle::GatewayControlAction::ForceAbandon,
-                                term_out,
-                            )?)
-                        }
-                        _ => None,
-                    };
-                    if tmux_overlay_opened.is_some() {
-                        if tmux_overlay_opened == Some(false) {
-                            if action == commands::Action::OpenTmuxConnectionChooser {
-                                sr.speak("no tmux connections active", false)?;
-                            } else {
-                                self.emit_physical_bells(term_out, 1)?;
-                            }
-                        }
-                        self.consumed_key_presses.insert(key_id);
-                        return Ok(());
-                    }
-                    if matches!(action, commands::Action::LeftClick)
-                        && let Some(view_action) = self
-                            .view_stack
-                            .active_mut()
-                            .place_application_cursor_at_review_cursor()
-                    {
-                        self.last_stdin_update = Some(self.clock.now_ms());
-                        self.handle_view_action(sr, view_action, term_out)?;
-                        self.consumed_key_presses.insert(key_id);
-                        return Ok(());
-                    }
-                    let mode_before = sr.input_mode();
-                    if matches!(action, commands::Action::RevLineRead) {
-                        let view = if action.uses_presented_view() {
-                            self.presented_accessibility_model_mut()
-                        } else {
-                            self.view_stack.active_mut().model()
-                        };
-                        synchronize_pending_review_cursor(sr, view)?;
-                    }
-                    let title = if matches!(action, commands::Action::SayOverlay)
-                        && self.output_scheduler.is_some()
-                    {
-                        self.presented_accessibility_label
-                            .clone()
-                            .unwrap_or_else(|| "terminal".to_owned())
-                    } else {
-                        let active = self.view_stack.active_mut();
-                        if let Some(tmux) =
-                            active.as_any().downcast_ref::<views::TmuxConnectionView>()
-                        {
-                            tmux.accessible_title()
-                        } else if active.kind() == views::ViewKind::Terminal {
-                            active
-                                .model()
-                                .screen()
-                                .title
-                                .as_deref()
-                                .filter(|title| !title.is_empty())
-                                .map_or_else(
-                                    || "terminal".to_string(),
-                                    |title| format!("terminal, {title}"),
-                                )
-                        } else {
-                            active.title().to_string()
-                        }
-                    };
-                    let command_result = if action.uses_presented_view() {
-                        commands::handle(
-                            sr,
-                            &title,
-                            self.presented_accessibility_model_mut(),
-                            action,
-                        )?
-                    } else {
-                        commands::handle(sr, &title, self.view_stack.active_mut().model(), action)?
-                    };
-                    let consumed = match command_result {
-                        commands::CommandResult::Handled => true,
-                        commands::CommandResult::ForwardInput => {
-                            self.dispatch_key_to_view(sr, &key, raw, pty_out, term_out)?;
-                            false
-                        }
-                        commands::CommandResult::Paste(contents) => {
-                            let view_action = self
-                                .view_stack
-                                .active_mut()
-                                .handle_paste(sr, &contents, pty_out)?;
-                            self.handle_view_action(sr, view_action, term_out)?;
-                            true
-                        }
-                        commands::CommandResult::PtyInput(input) => {
-                            self.dispatch_to_view(sr, &input, pty_out, term_out)?;
-                            true
-                        }
-                    };
-                    if consumed {
-                        self.consumed_key_presses.insert(key_id);
-                    } else {
-                        self.consumed_key_presses.remove(&key_id);
-                    }
-                    self.sync_table_setup_layer(mode_before, sr, term_out)?;
-                }
-                Binding::Lua(lua_binding) => {
-                    let mode_before = sr.input_mode();
-                    lua_binding.call()?;
-                    self.consumed_key_presses.insert(key_id);
-                    self.sync_table_setup_layer(mode_before, sr, term_out)?;
-                }
+            let name = binding_name.as_deref().unwrap_or_default();
+            if self.run_binding(sr, binding_mode, name, Some((&key, raw)), pty_out, term_out)? {
+                self.consumed_key_presses.insert(key_id);
+            } else {
+                self.consumed_key_presses.remove(&key_id);
             }
         } else if sr.help_mode() {
             sr.speak("this key is unmapped", false)?;
             self.consumed_key_presses.insert(key_id);
         } else {
-            if matches!(
-                sr.input_mode(),
-                crate::keymap::InputMode::Table | crate::keymap::InputMode::TableSetup
-            ) {
+            if captures_unbound_key(binding_mode, key_event) {
                 if sr.hook_on_key_unhandled(binding_name.as_deref(), sr.input_mode())? {
                     self.consumed_key_presses.insert(key_id);
                     return Ok(());
@@ -568,6 +475,252 @@ impl App {
         Ok(())
     }
 
+    /// Runs the binding of `name` in `mode`, returning whether its key press
+    /// was consumed. `key` is the press that triggered it, or `None` when the
+    /// binding is chosen from the bindings list.
+    pub(super) fn run_binding(
+        &mut self,
+        sr: &mut ScreenReader,
+        mode: crate::keymap::InputMode,
+        name: &str,
+        key: Option<(&KeyInput, &[u8])>,
+        pty_out: &mut dyn Write,
+        term_out: &mut dyn Write,
+    ) -> Result<bool> {
+        let Some(binding) = sr.key_bindings().binding_for_mode(mode, name) else {
+            return Ok(false);
+        };
+        match binding {
+            Binding::Builtin(action) => {
+                let action = *action;
+                if matches!(action, commands::Action::OpenReview) {
+                    self.open_review(sr, false, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::OpenLinksList) {
+                    self.open_links_list(sr, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::ListBindings) {
+                    self.open_bindings_list(sr, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::TableSearchColumn) {
+                    self.show_table_search_prompt(sr, term_out)?;
+                    return Ok(true);
+                }
+                if let Some(scope) = match action {
+                    commands::Action::SaveTranscript => Some(TranscriptScope::Document),
+                    commands::Action::SaveSelectionTranscript => Some(TranscriptScope::Selection),
+                    commands::Action::SaveCommandOutput => Some(TranscriptScope::CommandOutput),
+                    _ => None,
+                } {
+                    self.show_transcript_prompt(sr, scope, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::SaveTableLayout) {
+                    self.show_table_layout_prompt(sr, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::MarkRegionCorner) {
+                    self.mark_region_corner(sr, term_out)?;
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::OpenLuaRepl) {
+                    if self.view_stack.active_mut().kind() == views::ViewKind::LuaRepl {
+                        sr.speak("Lua REPL already open", false)?;
+                        return Ok(true);
+                    }
+                    let (rows, cols) = self.view_stack.active_mut().model().live_size();
+                    let session = match &self.lua_repl_session {
+                        Some(session) => session.clone(),
+                        None => {
+                            let session = match views::LuaReplSession::default_history_path() {
+                                Some(path) => views::LuaReplSession::with_history_file(path)?,
+                                None => views::LuaReplSession::new(Vec::new())?,
+                            };
+                            if let Some(path) = sr.repl_scratch_file() {
+                                session.load_file(path);
+                            }
+                            self.lua_repl_session = Some(session.clone());
+                            session
+                        }
+                    };
+                    let repl = views::LuaReplView::from_session(rows, cols, session);
+                    self.handle_view_action(sr, views::ViewAction::Push(Box::new(repl)), term_out)?;
+                    return Ok(true);
+                }
+                let tmux_overlay_opened = match action {
+                    commands::Action::OpenTmuxConnectionChooser => {
+                        Some(self.show_tmux_connection_chooser(sr, term_out)?)
+                    }
+                    commands::Action::RenameTmuxConnection => {
+                        Some(self.show_tmux_connection_rename(sr, term_out)?)
+                    }
+                    commands::Action::OpenTmuxSessionChooser => {
+                        Some(self.show_tmux_session_chooser(sr, term_out)?)
+                    }
+                    commands::Action::OpenTmuxWindowChooser => {
+                        Some(self.show_tmux_window_chooser(sr, term_out)?)
+                    }
+                    commands::Action::OpenTmuxPaneChooser => {
+                        Some(self.show_tmux_pane_chooser(sr, term_out)?)
+                    }
+                    commands::Action::OpenTmuxCommandPrompt => {
+                        Some(self.show_tmux_command_prompt(sr, term_out)?)
+                    }
+                    commands::Action::SummarizeTmuxActivity => {
+                        Some(self.show_tmux_activity_summary(sr, term_out)?)
+                    }
+                    commands::Action::SaveTmuxWorkspace => {
+                        Some(self.show_tmux_workspace_prompt(sr, true, term_out)?)
+                    }
+                    commands::Action::RestoreTmuxWorkspace => {
+                        Some(self.show_tmux_workspace_prompt(sr, false, term_out)?)
+                    }
+                    commands::Action::AttachTmuxSession => {
+                        Some(self.show_tmux_attach_prompt(sr, term_out)?)
+                    }
+                    commands::Action::DetachTmuxConnection => {
+                        Some(self.request_tmux_gateway_action(
+                            sr,
+                            crate::tmux_lifecycle::GatewayControlAction::GracefulDetach,
+                            term_out,
+                        )?)
+                    }
+                    commands::Action::ForceAbandonTmuxGateway => {
+                        Some(self.request_tmux_gateway_action(
+                            sr,
+                            crate::tmux_lifecycle::GatewayControlAction::ForceAbandon,
+                            term_out,
+                        )?)
+                    }
+                    _ => None,
+                };
+                if tmux_overlay_opened.is_some() {
+                    if tmux_overlay_opened == Some(false) {
+                        if action == commands::Action::OpenTmuxConnectionChooser {
+                            sr.speak("no tmux connections active", false)?;
+                        } else {
+                            self.emit_physical_bells(term_out, 1)?;
+                        }
+                    }
+                    return Ok(true);
+                }
+                if matches!(action, commands::Action::LeftClick)
+                    && let Some(view_action) = self
+                        .view_stack
+                        .active_mut()
+                        .place_application_cursor_at_review_cursor()
+                {
+                    self.last_stdin_update = Some(self.clock.now_ms());
+                    self.handle_view_action(sr, view_action, term_out)?;
+                    return Ok(true);
+                }
+                let mode_before = sr.input_mode();
+                if matches!(action, commands::Action::RevLineRead) {
+                    let view = if action.uses_presented_view() {
+                        self.presented_accessibility_model_mut()
+                    } else {
+                        self.view_stack.active_mut().model()
+                    };
+                    synchronize_pending_review_cursor(sr, view)?;
+                }
+                let title = if matches!(action, commands::Action::SayOverlay)
+                    && self.output_scheduler.is_some()
+                {
+                    self.presented_accessibility_label
+                        .clone()
+                        .unwrap_or_else(|| "terminal".to_owned())
+                } else {
+                    let active = self.view_stack.active_mut();
+                    if let Some(tmux) = active.as_any().downcast_ref::<views::TmuxConnectionView>()
+                    {
+                        tmux.accessible_title()
+                    } else if active.kind() == views::ViewKind::Terminal {
+                        active
+                            .model()
+                            .screen()
+                            .title
+                            .as_deref()
+                            .filter(|title| !title.is_empty())
+                            .map_or_else(
+                                || "terminal".to_string(),
+                                |title| format!("terminal, {title}"),
+                            )
+                    } else {
+                        active.title().to_string()
+                    }
+                };
+                let table_result = if action.navigates_table() {
+                    self.view_stack
+                        .active_mut()
+                        .run_table_command(sr, &mut |sr, view| {
+                            commands::handle(sr, &title, view, action)
+                        })
+                        .transpose()?
+                } else {
+                    None
+                };
+                if table_result.is_some() {
+                    self.handle_view_action(sr, views::ViewAction::RedrawSilently, term_out)?;
+                }
+                let command_result = if let Some(result) = table_result {
+                    result
+                } else if action.uses_presented_view() {
+                    commands::handle(sr, &title, self.presented_accessibility_model_mut(), action)?
+                } else {
+                    commands::handle(sr, &title, self.view_stack.active_mut().model(), action)?
+                };
+                let consumed = match command_result {
+                    commands::CommandResult::Handled => true,
+                    commands::CommandResult::ForwardInput => {
+                        // A binding run from the bindings list has no key to
+                        // forward.
+                        if let Some((key, raw)) = key {
+                            self.dispatch_key_to_view(sr, key, raw, pty_out, term_out)?;
+                            false
+                        } else {
+                            true
+                        }
+                    }
+                    commands::CommandResult::Paste(contents) => {
+                        let view_action = self
+                            .view_stack
+                            .active_mut()
+                            .handle_paste(sr, &contents, pty_out)?;
+                        self.handle_view_action(sr, view_action, term_out)?;
+                        true
+                    }
+                    commands::CommandResult::PtyInput(input) => {
+                        self.dispatch_to_view(sr, &input, pty_out, term_out)?;
+                        true
+                    }
+                    commands::CommandResult::Message { title, text } => {
+                        self.show_message(sr, &title, &text, term_out)?;
+                        true
+                    }
+                };
+                self.sync_table_setup_layer(mode_before, sr, term_out)?;
+                Ok(consumed)
+            }
+            Binding::Mode(mode) => {
+                let name = sr.key_bindings().mode_name(*mode).to_owned();
+                sr.enter_user_mode(&name)?;
+                Ok(true)
+            }
+            Binding::Lua(lua_binding) => {
+                let mode_before = sr.input_mode();
+                lua_binding.call()?;
+                if let Some(name) = sr.regions_mut().take_read_request() {
+                    commands::read_region(sr, self.presented_accessibility_model_mut(), &name)?;
+                }
+                self.sync_table_setup_layer(mode_before, sr, term_out)?;
+                Ok(true)
+            }
+        }
+    }
+
     fn dispatch_key_to_view(
         &mut self,
         sr: &mut ScreenReader,
@@ -603,12 +756,27 @@ impl App {
         } else {
             Cow::Borrowed(input)
         };
-        self.log_bytes("dispatching decoded key to active view", &input);
+        self.log_input_bytes("dispatching decoded key to active view", &input);
         self.last_stdin_update = Some(self.clock.now_ms());
         let action = self
             .view_stack
             .active_mut()
             .handle_key_input(sr, key, &input, pty_out)?;
+        if !key.is_release()
+            && matches!(
+                &action,
+                views::ViewAction::PtyInput | views::ViewAction::TmuxInput { .. }
+            )
+        {
+            let text = key.text();
+            let typed = match (text.as_deref(), event.code) {
+                (Some(text), _) => TypedKey::Text(text),
+                (None, KeyCode::Enter) => TypedKey::Enter,
+                (None, KeyCode::Backspace) => TypedKey::Backspace,
+                _ => TypedKey::Other,
+            };
+            sr.echo_forwarded_key(typed)?;
+        }
         if let Some(mode) = kitty_press_mode {
             let target = match &action {
                 views::ViewAction::PtyInput => Some(ForwardedInputTarget::RootPty),
@@ -637,10 +805,14 @@ impl App {
             views::ViewAction::Pop
                 | views::ViewAction::PopupResponse(_)
                 | views::ViewAction::ActivateTmuxConnection(_)
+                | views::ViewAction::RunBinding(_)
         ) {
             self.view_transition_key_presses
                 .insert((event.code, event.modifiers, event.state));
         }
+        if let views::ViewAction::RunBinding(target) = action {
+            return self.run_listed_binding(sr, target, pty_out, term_out);
+        }
         self.handle_view_action(sr, action, term_out)
     }
 
@@ -696,7 +868,7 @@ impl App {
                 self.deferred_kitty_releases.push_back(release);
                 continue;
             }
-            self.log_bytes("forwarding deferred Kitty release", &release.bytes);
+            self.log_input_bytes("forwarding deferred Kitty release", &release.bytes);
             match release.target {
                 ForwardedInputTarget::RootPty => {
                     pty_out.write_all(&release.bytes)?;
@@ -722,7 +894,7 @@ impl App {
         pty_out: &mut dyn Write,
         term_out: &mut dyn Write,
     ) -> Result<()> {
-        self.log_bytes("forwarding raw bytes to active view", raw);
+        self.log_input_bytes("forwarding raw bytes to active view", raw);
         self.update_last_key(sr, raw, false)?;
         let _ = sr.take_pass_through();
         self.dispatch_to_view(sr, raw, pty_out, term_out)
@@ -795,3 +967,17 @@ impl App {
         Some(binding)
     }
 }
+
+/// Whether `mode` keeps a key it has no binding for from the application.
+/// Table modes capture every key; user modes only keys without Control or
+/// Alt, so the application still gets its chords.
+fn captures_unbound_key(mode: crate::keymap::InputMode, key_event: KeyEvent) -> bool {
+    match mode {
+        crate::keymap::InputMode::Normal => false,
+        crate::keymap::InputMode::User(_) => key_event
+            .modifiers
+            .difference(KeyModifiers::SHIFT)
+            .is_empty(),
+        _ => true,
+    }
+}
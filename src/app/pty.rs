@@ -423,6 +423,15 @@ impl App {
         }
         let effect_time = self.clock.now_ms();
         for event in &terminal_update.effects.events {
+            // A failing Lua hook must not stop the output it came with, or
+            // the clipboard writes and effects after it.
+            if let Err(error) = sr.handle_terminal_notice(
+                crate::screen_reader::NoticeSource::Root,
+                event,
+                effect_time,
+            ) {
+                self.log_event(&format!("terminal notice hook failed: {error}"));
+            }
             match self.terminal_effect_policy.disposition(event) {
                 crate::terminal_protocol::EffectDisposition::LocalClipboard => {
                     let crate::terminal::TerminalEvent::ClipboardWrite { contents, .. } = event
@@ -451,6 +460,9 @@ impl App {
                 | crate::terminal_protocol::EffectDisposition::Drop => {}
             }
         }
+        if terminal_update.prompt_started {
+            sr.note_prompt_start(crate::screen_reader::NoticeSource::Root)?;
+        }
         let output_screen = (
             terminal_update.screen_after,
             terminal_update.screen_before != terminal_update.screen_after,
@@ -530,6 +542,8 @@ impl App {
             flow_control_warning_announced: false,
             capture_line_flags_supported: None,
             last_announced_location: None,
+            unseen_output_windows: BTreeSet::new(),
+            workspace_restore: None,
         });
         self.pending_tmux_commands.push_back(PendingTmuxCommand {
             connection_id,
@@ -689,6 +703,9 @@ impl App {
         let mut resume_after_pane_resync = None;
         let mut pane_output = None;
         let mut user_command_result = None;
+        let mut review_history_reply = None;
+        let mut workspace_save_reply = None;
+        let mut workspace_restore_reply = None;
         let mut notification_popup = None;
         let mut inventory_terminal_failure = None;
         let location_changed;
@@ -1029,6 +1046,26 @@ impl App {
                             }
                         }
                         Some(ExpectedTmuxReply::Ignored) => {}
+                        Some(ExpectedTmuxReply::ReviewHistory(pane_id)) => {
+                            review_history_reply = Some((
+                                pane_id,
+                                (status == crate::tmux_control::CommandStatus::Success)
+                                    .then_some(output),
+                            ));
+                        }
+                        Some(ExpectedTmuxReply::WorkspaceSave(name)) => {
+                            workspace_save_reply = Some((
+                                name,
+                                (status == crate::tmux_control::CommandStatus::Success)
+                                    .then_some(output),
+                            ));
+                        }
+                        Some(ExpectedTmuxReply::WorkspaceRestore) => {
+                            workspace_restore_reply = Some((
+                                status == crate::tmux_control::CommandStatus::Success,
+                                output,
+                            ));
+                        }
                         Some(ExpectedTmuxReply::UserCommand {
                             description,
                             show_success,
@@ -1058,6 +1095,7 @@ impl App {
                 crate::tmux_control::ControlEvent::Output { pane_id, bytes } => {
                     let pane_id = crate::tmux_model::PaneId(pane_id);
                     connection.pane_flow.entry(pane_id).or_default();
+                    connection.note_background_output(pane_id);
                     pane_output = Some((pane_id, bytes));
                 }
                 crate::tmux_control::ControlEvent::ExtendedOutput {
@@ -1069,6 +1107,7 @@ impl App {
                     let pane_id = crate::tmux_model::PaneId(pane_id);
                     let flow = connection.pane_flow.entry(pane_id).or_default();
                     flow.last_extended_output_age_ms = Some(age_ms);
+                    connection.note_background_output(pane_id);
                     pane_output = Some((pane_id, bytes));
                 }
                 crate::tmux_control::ControlEvent::Pause { pane_id } => {
@@ -1181,6 +1220,13 @@ impl App {
             // Leaving it again therefore rearms the first background bell.
             self.tmux_background_bell_windows
                 .remove(&(connection_id, window_id));
+            if let Some(connection) = self
+                .tmux_connections
+                .iter_mut()
+                .find(|connection| connection.id == connection_id)
+            {
+                connection.unseen_output_windows.remove(&window_id);
+            }
         }
         let mut announce_tmux_location = location_changed;
 
@@ -1576,6 +1622,19 @@ impl App {
                 None => {}
             }
         }
+        if let Some((pane_id, output)) = review_history_reply
+            && let Some(review) = self.view_stack.active_review_mut()
+            && review.tmux_history_pane() == Some((connection_id, pane_id))
+        {
+            let action = review.apply_tmux_history(sr, output.as_deref())?;
+            self.handle_view_action(sr, action, term_out)?;
+        }
+        if let Some((name, output)) = workspace_save_reply {
+            self.finish_tmux_workspace_save(sr, connection_id, &name, output, term_out)?;
+        }
+        if let Some((success, output)) = workspace_restore_reply {
+            self.advance_tmux_workspace_restore(sr, connection_id, success, &output, term_out)?;
+        }
         if let Some((status, description, output, show_success)) = user_command_result {
             let detail = output
                 .iter()
@@ -2165,6 +2224,24 @@ impl App {
                 });
             }
         }
+        if let Some(outcome) = &outcome {
+            let now_ms = self.clock.now_ms();
+            for event in &outcome.update.effects.events {
+                if let Err(error) = sr.handle_terminal_notice(
+                    crate::screen_reader::NoticeSource::TmuxPane(connection_id, pane_id),
+                    event,
+                    now_ms,
+                ) {
+                    self.log_event(&format!("terminal notice hook failed: {error}"));
+                }
+            }
+            if outcome.update.prompt_started {
+                sr.note_prompt_start(crate::screen_reader::NoticeSource::TmuxPane(
+                    connection_id,
+                    pane_id,
+                ))?;
+            }
+        }
         let bells = outcome.as_ref().map_or(0, |outcome| outcome.bells);
         let output_screen = outcome.as_ref().map(|outcome| {
             (
@@ -2403,6 +2480,15 @@ impl App {
         }) {
             self.last_tmux_bell_source = None;
         }
+        if let Some(connection) = self
+            .tmux_connections
+            .iter_mut()
+            .find(|connection| connection.id == connection_id)
+        {
+            connection
+                .unseen_output_windows
+                .retain(|window_id| topology.window(*window_id).is_some());
+        }
         let requests = self
             .view_stack
             .tmux_connection_mut(connection_id)
@@ -2659,6 +2745,7 @@ impl App {
         }
         self.flush_application_replies(pty_out)?;
         self.flush_pending_input(sr, pty_out, term_out)?;
+        self.flush_tmux_attach_request(sr, pty_out)?;
         let tick_action = self.view_stack.active_mut().tick(sr, pty_out)?;
         self.handle_view_action(sr, tick_action, term_out)?;
         self.drain_tmux_background_output(sr, term_out)?;
@@ -2931,7 +3018,7 @@ impl App {
             self.mark_tmux_flow_commands_in_flight(rejected_connection_id, &rejected_replies);
             debug_assert_eq!(root_connection_id, connection_id);
             for bytes in encoded {
-                self.log_bytes("tmux control command", &bytes);
+                self.log_input_bytes("tmux control command", &bytes);
                 output
                     .write_all(&bytes)
                     .context("write scoped tmux control command")?;
@@ -3251,6 +3338,8 @@ impl App {
         let recent_input = stabilization_input_is_recent(now_ms, self.last_stdin_update);
         let decision = stabilization_decision(now_ms, burst, update_status, accessibility_blocked);
         if let StabilizationDecision::Commit(commit_reason) = decision {
+            self.sync_active_tmux_pane(sr)?;
+            sr.refresh_line_discipline();
             self.log_latency_stage("accessibility-finalization-start", || {
                 format!(
                     "reason={} parser_continuation={} prompt_transaction_open={} diff_delay_ms={}",
@@ -3292,6 +3381,8 @@ impl App {
                         speak_application_cursor_line(sr, view)?;
                     }
                 } else if !overlay_active {
+                    sr.exclude_regions_from_auto_read(view);
+                    sr.track_watched_regions(view)?;
                     let mut read_text = sr.resolve_pending_delete(view)?;
                     let semantic_history_read = if sr.take_pending_history_navigation() {
                         if let Some(input) = view.active_semantic_input() {
@@ -3303,13 +3394,15 @@ impl App {
                     } else {
                         false
                     };
-                    if semantic_history_read {
+                    if semantic_history_read
+                        || (sr.selection_tracking_enabled() && sr.track_selection(view)?)
+                    {
                         read_text = true;
                     } else {
                         if sr.highlight_tracking_enabled() {
                             sr.track_highlighting(view)?;
                         }
-                        let auto_read_text = if sr.auto_read_enabled() {
+                        let auto_read_text = if sr.live_auto_read_enabled() {
                             if recent_input {
                                 sr.auto_read_after_input(view)?
                             } else {
@@ -3320,6 +3413,7 @@ impl App {
                         };
                         read_text |= auto_read_text;
                     }
+                    read_text |= sr.track_secret_entry(view)?;
                     if recent_input && !read_text {
                         sr.track_cursor(view)?;
                     }
@@ -356,6 +356,54 @@ impl App {
         true
     }
 
+    /// Tells the screen reader which tmux pane the active view reads, so pane
+    /// speech overrides apply to auto-read, pane commands, and Lua. Overrides
+    /// and notification state for panes that were seen and have since closed,
+    /// or whose connection ended, are dropped here. Panes not seen yet keep
+    /// theirs, so Lua can configure a pane before its inventory arrives.
+    pub(super) fn sync_active_tmux_pane(&mut self, sr: &mut ScreenReader) -> Result<()> {
+        let mut closed_connections = BTreeSet::new();
+        let mut closed_panes = BTreeSet::new();
+        self.known_tmux_panes.retain(|&connection_id, known| {
+            let Some(connection) = self
+                .tmux_connections
+                .iter()
+                .find(|connection| connection.id == connection_id)
+            else {
+                closed_connections.insert(connection_id);
+                return false;
+            };
+            if connection.has_inventory {
+                known.retain(|&pane_id| {
+                    let open = connection.topology.pane(pane_id).is_some();
+                    if !open {
+                        closed_panes.insert((connection_id, pane_id));
+                    }
+                    open
+                });
+            }
+            true
+        });
+        for connection in &self.tmux_connections {
+            let known = self.known_tmux_panes.entry(connection.id).or_default();
+            if connection.has_inventory {
+                known.extend(connection.topology.panes().keys().copied());
+            }
+        }
+        let keep = |connection_id, pane_id| {
+            !closed_connections.contains(&connection_id)
+                && !closed_panes.contains(&(connection_id, pane_id))
+        };
+        sr.retain_tmux_pane_speech(keep);
+        sr.retain_tmux_notices(keep);
+        let pane = self
+            .view_stack
+            .active_tmux_connection_mut()
+            .and_then(|view| Some((view.connection_id(), view.active_input_pane()?)));
+        sr.set_active_tmux_pane(pane)?;
+        Ok(())
+    }
+
     fn active_visible_tmux_snapshot(&mut self) -> Option<(u64, crate::tmux_model::TmuxTopology)> {
         let connection_id = self
             .view_stack
@@ -453,6 +501,354 @@ impl App {
         self.handle_view_action(sr, views::ViewAction::Pop, term_out)
     }
 
+    /// Prompts for `SESSION` or `SOCKET:SESSION` and starts or attaches that
+    /// tmux session in control mode from the focused shell.
+    pub fn show_tmux_attach_prompt(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<bool> {
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                views::TextInputPurpose::TmuxAttach,
+                "attach tmux session",
+                "session or socket:session",
+                "",
+            ))),
+            term_out,
+        )?;
+        Ok(true)
+    }
+
+    pub(super) fn handle_tmux_attach_submit(
+        &mut self,
+        sr: &mut ScreenReader,
+        target: &str,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        match crate::tmux_launch::TmuxAttachRequest::parse_target(target) {
+            Ok(request) => sr.request_tmux_attach(request),
+            Err(error) => {
+                self.show_popup_error(sr, "invalid tmux target", &error.to_string(), term_out)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Types a queued `tmux -CC` launch into the shell beneath any overlay:
+    /// the presented tmux pane, or otherwise the root PTY. The control-mode
+    /// marker that follows is routed like any other gateway.
+    pub(super) fn flush_tmux_attach_request(
+        &mut self,
+        sr: &mut ScreenReader,
+        pty_out: &mut dyn Write,
+    ) -> Result<()> {
+        let Some(request) = sr.take_tmux_attach_request() else {
+            return Ok(());
+        };
+        let input = request.shell_input();
+        if let Some(view) = self.view_stack.presented_tmux_connection_mut() {
+            let connection_id = view.connection_id();
+            let Some(pane_id) = view.active_input_pane() else {
+                return self.report_tmux_attach_failure(sr, &request, "no tmux pane accepts input");
+            };
+            self.queue_tmux_input(connection_id, pane_id, &input)?;
+        } else if self.tmux_gateway.active_connection().is_some() {
+            return self.report_tmux_attach_failure(
+                sr,
+                &request,
+                "the root terminal is already a tmux control client",
+            );
+        } else {
+            pty_out.write_all(&input)?;
+            pty_out.flush()?;
+        }
+        self.log_event(&format!(
+            "typed tmux attach command for {}",
+            request.description()
+        ));
+        self.last_stdin_update = Some(self.clock.now_ms());
+        sr.speak(&format!("attaching {}", request.description()), false)?;
+        Ok(())
+    }
+
+    fn report_tmux_attach_failure(
+        &mut self,
+        sr: &mut ScreenReader,
+        request: &crate::tmux_launch::TmuxAttachRequest,
+        reason: &str,
+    ) -> Result<()> {
+        let message = format!("cannot attach {}: {reason}", request.description());
+        self.log_event(&message);
+        sr.speak(&message, false)?;
+        sr.hook_on_error(&message, "tmux-attach")?;
+        Ok(())
+    }
+
+    /// Prompts for a workspace name bound to the active connection.
+    pub fn show_tmux_workspace_prompt(
+        &mut self,
+        sr: &mut ScreenReader,
+        save: bool,
+        term_out: &mut dyn Write,
+    ) -> Result<bool> {
+        let Some((connection_id, _)) = self.active_visible_tmux_snapshot() else {
+            return Ok(false);
+        };
+        let (purpose, title) = if save {
+            (
+                views::TextInputPurpose::SaveTmuxWorkspace { connection_id },
+                "save tmux workspace",
+            )
+        } else {
+            (
+                views::TextInputPurpose::RestoreTmuxWorkspace { connection_id },
+                "restore tmux workspace",
+            )
+        };
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                purpose,
+                title,
+                "workspace name",
+                "",
+            ))),
+            term_out,
+        )?;
+        Ok(true)
+    }
+
+    /// Queries pane working directories; the file is written when the reply
+    /// arrives so that it reflects one consistent topology.
+    pub(super) fn handle_tmux_workspace_save_submit(
+        &mut self,
+        sr: &mut ScreenReader,
+        connection_id: u64,
+        name: &str,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        let name = name.trim();
+        if let Err(error) = crate::tmux_workspace::validate_workspace_name(name) {
+            return self.show_popup_error(
+                sr,
+                "tmux workspace not saved",
+                &error.to_string(),
+                term_out,
+            );
+        }
+        if !self
+            .tmux_connections
+            .iter()
+            .any(|connection| connection.id == connection_id && connection.has_inventory)
+        {
+            self.emit_physical_bells(term_out, 1)?;
+            return Ok(());
+        }
+        self.pending_tmux_commands.push_back(PendingTmuxCommand {
+            connection_id,
+            bytes: crate::tmux_workspace::WORKSPACE_DIRECTORY_QUERY
+                .as_bytes()
+                .to_vec(),
+            expected_replies: vec![ExpectedTmuxReply::WorkspaceSave(name.to_owned())],
+            kind: PendingTmuxCommandKind::Ordinary,
+        });
+        Ok(())
+    }
+
+    pub(super) fn finish_tmux_workspace_save(
+        &mut self,
+        sr: &mut ScreenReader,
+        connection_id: u64,
+        name: &str,
+        output: Option<Vec<Vec<u8>>>,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        let Some(topology) = self
+            .tmux_connections
+            .iter()
+            .find(|connection| connection.id == connection_id)
+            .map(|connection| &connection.topology)
+        else {
+            return Ok(());
+        };
+        // Without directories the layout is still worth saving; panes then
+        // start in tmux's default directory on restore.
+        let directories = output
+            .iter()
+            .flatten()
+            .filter_map(|line| crate::tmux_workspace::parse_directory_record(line))
+            .collect();
+        let workspace = crate::tmux_workspace::TmuxWorkspace::capture(topology, &directories);
+        let result = match &self.tmux_workspace_dir {
+            Some(directory) => workspace.save(directory, name),
+            None => Err(crate::tmux_workspace::WorkspaceError::NoDirectory),
+        };
+        match result {
+            Ok(path) => {
+                self.log_event(&format!("saved tmux workspace to {}", path.display()));
+                sr.speak(&format!("saved workspace {name}"), false)?;
+                Ok(())
+            }
+            Err(error) => {
+                self.show_popup_error(sr, "tmux workspace not saved", &error.to_string(), term_out)
+            }
+        }
+    }
+
+    pub(super) fn handle_tmux_workspace_restore_submit(
+        &mut self,
+        sr: &mut ScreenReader,
+        connection_id: u64,
+        name: &str,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        let name = name.trim();
+        let loaded = match &self.tmux_workspace_dir {
+            Some(directory) => crate::tmux_workspace::TmuxWorkspace::load(directory, name),
+            None => Err(crate::tmux_workspace::WorkspaceError::NoDirectory),
+        };
+        let workspace = match loaded {
+            Ok(workspace) => workspace,
+            Err(error) => {
+                return self.show_popup_error(
+                    sr,
+                    "tmux workspace not restored",
+                    &error.to_string(),
+                    term_out,
+                );
+            }
+        };
+        let Some(connection) = self
+            .tmux_connections
+            .iter_mut()
+            .find(|connection| connection.id == connection_id && connection.has_inventory)
+        else {
+            self.emit_physical_bells(term_out, 1)?;
+            return Ok(());
+        };
+        if connection.workspace_restore.is_some() {
+            sr.speak("a workspace restore is already running", false)?;
+            return Ok(());
+        }
+        connection.workspace_restore = Some(crate::tmux_workspace::TmuxWorkspaceRestore::new(
+            name,
+            &workspace,
+            &connection.topology,
+        ));
+        sr.speak(&format!("restoring workspace {name}"), false)?;
+        self.queue_next_tmux_workspace_command(connection_id);
+        Ok(())
+    }
+
+    fn queue_next_tmux_workspace_command(&mut self, connection_id: u64) -> bool {
+        let Some(bytes) = self
+            .tmux_connections
+            .iter_mut()
+            .find(|connection| connection.id == connection_id)
+            .and_then(|connection| connection.workspace_restore.as_mut())
+            .and_then(crate::tmux_workspace::TmuxWorkspaceRestore::next_command)
+        else {
+            return false;
+        };
+        self.pending_tmux_commands.push_back(PendingTmuxCommand {
+            connection_id,
+            bytes,
+            expected_replies: vec![ExpectedTmuxReply::WorkspaceRestore],
+            kind: PendingTmuxCommandKind::Ordinary,
+        });
+        true
+    }
+
+    /// Applies one restore reply: queues the next command, finishes, or rolls
+    /// back everything the restore created after a failure.
+    pub(super) fn advance_tmux_workspace_restore(
+        &mut self,
+        sr: &mut ScreenReader,
+        connection_id: u64,
+        success: bool,
+        output: &[Vec<u8>],
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        let Some(connection) = self
+            .tmux_connections
+            .iter_mut()
+            .find(|connection| connection.id == connection_id)
+        else {
+            return Ok(());
+        };
+        let Some(restore) = connection.workspace_restore.as_mut() else {
+            return Ok(());
+        };
+        match restore.apply_reply(success, output) {
+            Ok(crate::tmux_workspace::RestoreProgress::Continue) => {}
+            Ok(crate::tmux_workspace::RestoreProgress::WindowRestored { name, done, total }) => {
+                sr.speak(&format!("window {name}, {done} of {total}"), false)?;
+            }
+            Err(error) => {
+                let restore = connection
+                    .workspace_restore
+                    .take()
+                    .expect("workspace restore checked above");
+                let rollback = restore.rollback_commands();
+                let created = rollback.len();
+                for bytes in rollback {
+                    self.pending_tmux_commands.push_back(PendingTmuxCommand {
+                        connection_id,
+                        bytes,
+                        expected_replies: vec![ExpectedTmuxReply::Ignored],
+                        kind: PendingTmuxCommandKind::Ordinary,
+                    });
+                }
+                let message = format!(
+                    "workspace {} failed at {error}; removing {created} created windows or sessions",
+                    restore.name()
+                );
+                self.log_event(&message);
+                return self.show_popup_error(
+                    sr,
+                    "tmux workspace not restored",
+                    &message,
+                    term_out,
+                );
+            }
+        }
+        if self.queue_next_tmux_workspace_command(connection_id) {
+            return Ok(());
+        }
+        let Some(connection) = self
+            .tmux_connections
+            .iter_mut()
+            .find(|connection| connection.id == connection_id)
+        else {
+            return Ok(());
+        };
+        let Some(restore) = connection
+            .workspace_restore
+            .take_if(|restore| restore.is_finished())
+        else {
+            return Ok(());
+        };
+        if let Some(label) = restore.label() {
+            // An over-long saved label keeps the current one rather than
+            // failing a restore whose windows already exist.
+            let _ = connection.topology.set_label(label);
+            self.sync_tmux_panes(connection_id)?;
+        }
+        sr.speak(&format!("workspace {} restored", restore.name()), false)?;
+        Ok(())
+    }
+
     pub fn show_tmux_session_chooser(
         &mut self,
         sr: &mut ScreenReader,
@@ -478,8 +874,19 @@ impl App {
         sr: &mut ScreenReader,
         term_out: &mut dyn Write,
     ) -> Result<bool> {
+        let profiles = sr
+            .tmux_pane_speech_profiles()
+            .map(|(key, profile)| (key, profile.describe()))
+            .collect::<Vec<_>>();
         self.show_tmux_chooser(sr, term_out, |rows, cols, connection_id, topology| {
-            views::TmuxChooserView::panes(rows, cols, connection_id, topology)
+            let notes = profiles
+                .into_iter()
+                .filter(|((connection, _), _)| *connection == connection_id)
+                .map(|((_, pane_id), note)| (pane_id, note))
+                .collect();
+            let mut chooser = views::TmuxChooserView::panes(rows, cols, connection_id, topology);
+            chooser.set_pane_notes(notes, topology);
+            chooser
         })
     }
 
@@ -526,6 +933,46 @@ impl App {
         Ok(true)
     }
 
+    /// Speaks and opens a summary of unseen output, pending bells, stale
+    /// panes, and exited panes in the active connection's attached session.
+    pub fn show_tmux_activity_summary(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<bool> {
+        let Some((connection_id, topology)) = self.active_visible_tmux_snapshot() else {
+            return Ok(false);
+        };
+        let Some(connection) = self
+            .tmux_connections
+            .iter()
+            .find(|connection| connection.id == connection_id)
+        else {
+            return Ok(false);
+        };
+        let bell_windows = self
+            .tmux_background_bell_windows
+            .iter()
+            .filter(|(source_connection, _)| *source_connection == connection_id)
+            .map(|(_, window_id)| *window_id)
+            .collect();
+        let stale_panes = connection
+            .pane_flow
+            .iter()
+            .filter(|(_, flow)| flow.is_paused || flow.status != TmuxFlowStatus::Running)
+            .map(|(pane_id, _)| *pane_id)
+            .collect();
+        let summary = crate::tmux_activity::TmuxActivitySummary::collect(
+            &topology,
+            &connection.unseen_output_windows,
+            &bell_windows,
+            &stale_panes,
+        );
+        sr.speak(&summary.spoken(), true)?;
+        self.show_popup_announcement(sr, "tmux activity", &summary.detail(), term_out)?;
+        Ok(true)
+    }
+
     pub(super) fn handle_tmux_chooser_selection(
         &mut self,
         sr: &mut ScreenReader,
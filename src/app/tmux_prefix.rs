@@ -150,7 +150,7 @@ impl App {
             .cloned()
     }
 
-    fn tmux_default_key_table(&self, connection_id: u64) -> String {
+    pub(super) fn tmux_default_key_table(&self, connection_id: u64) -> String {
         self.tmux_connections
             .iter()
             .find(|connection| connection.id == connection_id)
@@ -189,7 +189,7 @@ impl App {
             .min(60_000)
     }
 
-    fn execute_tmux_binding(
+    pub(super) fn execute_tmux_binding(
         &mut self,
         sr: &mut ScreenReader,
         connection_id: u64,
@@ -24,14 +24,376 @@ impl App {
             sr.speak("Review already open", false)?;
             return Ok(());
         }
-        let review = if page_up {
-            views::ReviewView::new_page_up(self.presented_accessibility_model_mut())
+        let tmux_history = self.review_tmux_history_source();
+        let source = self.presented_accessibility_model_mut();
+        let retained_history = u32::try_from(source.scrollback_len()).unwrap_or(u32::MAX);
+        let mut review = if page_up {
+            views::ReviewView::new_page_up(source)
         } else {
-            views::ReviewView::new(self.presented_accessibility_model_mut())
+            views::ReviewView::new(source)
         };
+        if let Some((connection_id, pane_id, history_size)) = tmux_history {
+            review = review.with_tmux_history(
+                connection_id,
+                pane_id,
+                retained_history,
+                history_size.saturating_sub(retained_history),
+            );
+        }
         self.handle_view_action(sr, views::ViewAction::Push(Box::new(review)), term_out)
     }
 
+    pub(super) fn open_links_list(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        if self.view_stack.active_mut().kind() == views::ViewKind::Links {
+            sr.speak("links list already open", false)?;
+            return Ok(());
+        }
+        let links = views::LinksView::new(self.presented_accessibility_model_mut());
+        if links.is_empty() {
+            sr.speak("no links", false)?;
+            return Ok(());
+        }
+        self.handle_view_action(sr, views::ViewAction::Push(Box::new(links)), term_out)
+    }
+
+    pub(super) fn open_bindings_list(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        if self.view_stack.active_mut().kind() == views::ViewKind::Bindings {
+            sr.speak("bindings list already open", false)?;
+            return Ok(());
+        }
+        let mode = sr.input_mode();
+        let bindings = sr.key_bindings();
+        let mut items: Vec<_> = bindings
+            .effective_bindings(mode)
+            .into_iter()
+            .map(|(key, binding)| views::BindingItem {
+                key: key.to_owned(),
+                help: bindings.binding_help(binding),
+                target: views::BindingTarget::Lector {
+                    mode,
+                    key: key.to_owned(),
+                },
+            })
+            .collect();
+        if self.view_stack.active_mut().kind() == views::ViewKind::Review {
+            items.extend(
+                crate::review::parser::review_keys()
+                    .into_iter()
+                    .map(|(keys, help)| views::BindingItem {
+                        key: format!("review {keys}"),
+                        help: help.to_owned(),
+                        target: views::BindingTarget::Review(keys),
+                    }),
+            );
+        }
+        if let Some(connection_id) = self
+            .view_stack
+            .active_tmux_connection_mut()
+            .map(|view| view.connection_id())
+            && let Some(connection) = self
+                .tmux_connections
+                .iter()
+                .find(|connection| connection.id == connection_id)
+        {
+            let default_table = self.tmux_default_key_table(connection_id);
+            for table in ["prefix", default_table.as_str()] {
+                items.extend(connection.topology.table_bindings(table).map(|binding| {
+                    views::BindingItem {
+                        key: format!("tmux {table} {}", binding.key),
+                        help: binding.command.clone(),
+                        target: views::BindingTarget::Tmux {
+                            connection_id,
+                            command: binding.command.clone(),
+                        },
+                    }
+                }));
+            }
+        }
+        let (rows, cols) = self.view_stack.active_mut().model().live_size();
+        let list = views::BindingsView::new(rows, cols, items);
+        self.handle_view_action(sr, views::ViewAction::Push(Box::new(list)), term_out)
+    }
+
+    /// Closes the bindings list and runs the binding chosen from it in the
+    /// view underneath.
+    pub(super) fn run_listed_binding(
+        &mut self,
+        sr: &mut ScreenReader,
+        target: views::BindingTarget,
+        pty_out: &mut dyn Write,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        match target {
+            views::BindingTarget::Lector { mode, key } => {
+                self.run_binding(sr, mode, &key, None, pty_out, term_out)?;
+            }
+            views::BindingTarget::Review(keys) => {
+                let input = crate::review::parser::review_key_bytes(&keys);
+                self.dispatch_to_view(sr, &input, pty_out, term_out)?;
+            }
+            views::BindingTarget::Tmux {
+                connection_id,
+                command,
+            } => self.execute_tmux_binding(sr, connection_id, &command, term_out)?,
+        }
+        Ok(())
+    }
+
+    pub(super) fn show_table_search_prompt(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        if sr.table_session().navigation().is_none() {
+            sr.speak("no table found", false)?;
+            return Ok(());
+        }
+        let initial = sr
+            .table_session()
+            .last_search()
+            .unwrap_or_default()
+            .to_owned();
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                views::TextInputPurpose::TableColumnSearch,
+                "search table column",
+                "search",
+                &initial,
+            ))),
+            term_out,
+        )
+    }
+
+    pub(super) fn show_table_layout_prompt(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        match sr.table_session().setup() {
+            None => {
+                sr.speak("table setup not active", false)?;
+                return Ok(());
+            }
+            Some(setup) if setup.tabstops().is_empty() => {
+                sr.speak("no tabstops set", false)?;
+                return Ok(());
+            }
+            Some(_) => {}
+        }
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                views::TextInputPurpose::TableLayoutName,
+                "save table layout",
+                "layout name",
+                "",
+            ))),
+            term_out,
+        )
+    }
+
+    /// Marks a corner of a screen region at the review cursor. The second
+    /// corner prompts for the region's name and mode.
+    pub(super) fn mark_region_corner(
+        &mut self,
+        sr: &mut ScreenReader,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        let corner = self
+            .presented_accessibility_model_mut()
+            .review_cursor_position();
+        let Some(rect) = sr.regions_mut().mark_corner(corner) else {
+            sr.speak("first corner", false)?;
+            return Ok(());
+        };
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                views::TextInputPurpose::RegionName { rect },
+                "define screen region",
+                "region name, then silence, watch or read",
+                "",
+            ))),
+            term_out,
+        )
+    }
+
+    pub(super) fn show_transcript_prompt(
+        &mut self,
+        sr: &mut ScreenReader,
+        scope: TranscriptScope,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        let title = self.view_stack.active_mut().title().to_string();
+        let captured;
+        let (document, selection) = match self.view_stack.active_review_mut() {
+            Some(review) => (review.document(), review.selection()),
+            None => {
+                captured = crate::review::document::ReviewDocument::capture(
+                    self.presented_accessibility_model_mut(),
+                )
+                .0;
+                (&captured, None)
+            }
+        };
+        let range = match scope {
+            TranscriptScope::Document => {
+                let last_row = document.row_count().saturating_sub(1);
+                Some((
+                    crate::terminal::HistoryPosition { row: 0, col: 0 },
+                    crate::terminal::HistoryPosition {
+                        row: last_row,
+                        col: document.line_last_col(last_row),
+                    },
+                    true,
+                ))
+            }
+            TranscriptScope::Selection => selection,
+            TranscriptScope::CommandOutput => document
+                .last_command_output()
+                .map(|(first, last)| (first, last, false)),
+        };
+        let transcript =
+            range.map(|(first, last, linewise)| document.transcript(first, last, linewise));
+        let Some(transcript) = transcript.filter(|transcript| !transcript.is_blank()) else {
+            sr.speak(
+                match scope {
+                    TranscriptScope::Document => "nothing to save",
+                    TranscriptScope::Selection => "no selection",
+                    TranscriptScope::CommandOutput => "no command output",
+                },
+                false,
+            )?;
+            return Ok(());
+        };
+        self.pending_transcript = Some((transcript, title));
+
+        let initial = crate::review::export::default_transcript_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        let (rows, cols) = self.view_stack.root_mut().model().live_size();
+        self.handle_view_action(
+            sr,
+            views::ViewAction::Push(Box::new(views::TextInputView::new(
+                rows,
+                cols,
+                views::TextInputPurpose::SaveTranscript,
+                "save transcript",
+                "file",
+                &initial,
+            ))),
+            term_out,
+        )
+    }
+
+    fn handle_transcript_submit(
+        &mut self,
+        sr: &mut ScreenReader,
+        text: &str,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        let Some((transcript, title)) = self.pending_transcript.take() else {
+            return Ok(());
+        };
+        let text = text.trim();
+        if text.is_empty() {
+            sr.speak("no file name", false)?;
+            return Ok(());
+        }
+        let path = match (text.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => std::path::PathBuf::from(text),
+        };
+        match crate::review::export::save(&transcript, &path, &title) {
+            Ok(()) => {
+                let lines = transcript.line_count();
+                let name = path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into_owned(),
+                );
+                sr.speak(
+                    &format!(
+                        "saved {lines} {} to {name}",
+                        if lines == 1 { "line" } else { "lines" }
+                    ),
+                    false,
+                )?;
+            }
+            Err(error) => sr.speak(&format!("save failed: {error}"), false)?,
+        }
+        Ok(())
+    }
+
+    /// Runs the search once its prompt is gone, so the table command sees
+    /// the view the table was found in.
+    fn handle_table_search_submit(
+        &mut self,
+        sr: &mut ScreenReader,
+        query: String,
+        term_out: &mut dyn Write,
+    ) -> Result<()> {
+        self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+        if query.is_empty() {
+            return Ok(());
+        }
+        sr.table_session_mut().set_last_search(query);
+
+        let action = commands::Action::TableSearchColumnNext;
+        let title = self.view_stack.active_mut().title().to_string();
+        let table_result = self
+            .view_stack
+            .active_mut()
+            .run_table_command(sr, &mut |sr, view| {
+                commands::handle(sr, &title, view, action)
+            })
+            .transpose()?;
+        if table_result.is_some() {
+            self.handle_view_action(sr, views::ViewAction::RedrawSilently, term_out)?;
+        } else {
+            commands::handle(sr, &title, self.presented_accessibility_model_mut(), action)?;
+        }
+        Ok(())
+    }
+
+    /// The presented tmux pane whose server-side history can extend Review.
+    /// Alternate screens and tmux modes have no addressable primary history.
+    fn review_tmux_history_source(&mut self) -> Option<(u64, crate::tmux_model::PaneId, u32)> {
+        let view = self.view_stack.presented_tmux_connection_mut()?;
+        let connection_id = view.connection_id();
+        let pane_id = view.active_input_pane()?;
+        let pane = self
+            .tmux_connections
+            .iter()
+            .find(|connection| connection.id == connection_id)?
+            .topology
+            .pane(pane_id)?;
+        (!pane.alternate_on && pane.pane_in_mode == 0).then_some((
+            connection_id,
+            pane_id,
+            pane.history_size,
+        ))
+    }
+
     pub(super) fn sync_table_setup_layer(
         &mut self,
         mode_before: crate::keymap::InputMode,
@@ -75,13 +437,16 @@ impl App {
         pty_out: &mut dyn Write,
         term_out: &mut dyn Write,
     ) -> Result<()> {
-        self.log_bytes("dispatching bytes to active view", input);
+        self.log_input_bytes("dispatching bytes to active view", input);
         self.last_stdin_update = Some(self.clock.now_ms());
         let action = self
             .view_stack
             .active_mut()
             .handle_input(sr, input, pty_out)?;
         self.log_latency_stage("input-dispatched", || format!("bytes={}", input.len()));
+        if let views::ViewAction::RunBinding(target) = action {
+            return self.run_listed_binding(sr, target, pty_out, term_out);
+        }
         self.handle_view_action(sr, action, term_out)
     }
 
@@ -104,6 +469,19 @@ impl App {
                 self.announce_view_change(sr)?;
             }
             views::ViewAction::Pop => {
+                // Table rows found in Review's document do not describe the
+                // view underneath it.
+                if self.view_stack.active_mut().kind() == views::ViewKind::Review
+                    && sr.input_mode() == crate::keymap::InputMode::Table
+                {
+                    let title = self.view_stack.active_mut().title().to_owned();
+                    crate::commands::handle(
+                        sr,
+                        &title,
+                        self.view_stack.active_mut().model(),
+                        crate::commands::Action::ExitTableMode,
+                    )?;
+                }
                 if self.view_stack.pop() {
                     self.render_active_view(term_out)?;
                     self.announce_view_change(sr)?;
@@ -208,6 +586,47 @@ impl App {
                 self.queue_tmux_input(connection_id, pane_id, &bytes)?;
                 self.last_stdin_update = Some(self.clock.now_ms());
             }
+            views::ViewAction::TmuxHistoryRequest {
+                connection_id,
+                pane_id,
+                first_line,
+                last_line,
+            } => {
+                self.pending_tmux_commands.push_back(PendingTmuxCommand {
+                    connection_id,
+                    bytes: crate::tmux_panes::history_capture_command(
+                        pane_id, first_line, last_line,
+                    ),
+                    expected_replies: vec![ExpectedTmuxReply::ReviewHistory(pane_id)],
+                    kind: PendingTmuxCommandKind::Ordinary,
+                });
+                sr.speak("loading tmux history", false)?;
+            }
+            views::ViewAction::TextInputSubmit { purpose, text } => match purpose {
+                views::TextInputPurpose::TmuxAttach => {
+                    self.handle_tmux_attach_submit(sr, &text, term_out)?;
+                }
+                views::TextInputPurpose::SaveTmuxWorkspace { connection_id } => {
+                    self.handle_tmux_workspace_save_submit(sr, connection_id, &text, term_out)?;
+                }
+                views::TextInputPurpose::RestoreTmuxWorkspace { connection_id } => {
+                    self.handle_tmux_workspace_restore_submit(sr, connection_id, &text, term_out)?;
+                }
+                views::TextInputPurpose::TableColumnSearch => {
+                    self.handle_table_search_submit(sr, text, term_out)?;
+                }
+                views::TextInputPurpose::SaveTranscript => {
+                    self.handle_transcript_submit(sr, &text, term_out)?;
+                }
+                views::TextInputPurpose::TableLayoutName => {
+                    self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+                    commands::save_table_layout(sr, text.trim())?;
+                }
+                views::TextInputPurpose::RegionName { rect } => {
+                    self.handle_view_action(sr, views::ViewAction::Pop, term_out)?;
+                    commands::define_region(sr, rect, &text)?;
+                }
+            },
             views::ViewAction::Redraw => {
                 self.render_active_view(term_out)?;
                 self.read_active_view_changes(sr)?;
@@ -221,7 +640,9 @@ impl App {
                 sr.set_auto_read_enabled(auto_read_enabled);
                 result?;
             }
-            views::ViewAction::None => {}
+            // Bindings can write to the PTY, so the dispatch that received
+            // this action runs it with `run_listed_binding`.
+            views::ViewAction::RunBinding(_) | views::ViewAction::None => {}
         }
         Ok(())
     }
@@ -698,6 +1119,8 @@ impl App {
     }
 
     pub(super) fn announce_view_change(&mut self, sr: &mut ScreenReader) -> Result<()> {
+        // A corner marked in one view does not describe the next one.
+        sr.regions_mut().cancel_corner();
         if !self.accessibility_announcement_ready() {
             self.pending_view_announcement = true;
             return Ok(());
@@ -768,6 +1191,7 @@ impl App {
             return Ok(());
         }
         self.pending_active_view_read = None;
+        self.sync_active_tmux_pane(sr)?;
         let now_ms = self.clock.now_ms();
         let overlay_active = self.view_stack.has_overlay();
         let recent_input = self
@@ -800,7 +1224,7 @@ impl App {
                 }
             } else {
                 let mut read_text = sr.resolve_pending_delete(view)?;
-                let auto_read_text = if sr.auto_read_enabled() {
+                let auto_read_text = if sr.live_auto_read_enabled() {
                     if recent_input {
                         sr.auto_read_after_input(view)?
                     } else {
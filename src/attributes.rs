@@ -1,4 +1,5 @@
 use phf::phf_map;
+use std::collections::HashSet;
 
 static COLORS: phf::Map<u8, &'static str> = phf_map! {
             0u8 => "Black",
@@ -530,10 +531,103 @@ pub fn describe_color(color: crate::terminal::Color) -> String {
     }
 }
 
+/// Names for the eight ANSI colors, which the xterm names above call Maroon,
+/// Olive, Navy and so on.
+const ANSI_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// The colors a color word names: an ANSI name such as `red` or
+/// `brightred`, part of an xterm name such as `orange` for `DarkOrange3`, a
+/// `#rrggbb` value, or `default`. Case is ignored and `gray` is `grey`. The
+/// word is resolved once, so checking a cell is a lookup.
+#[derive(Clone, Debug)]
+pub(crate) struct ColorSet {
+    default: bool,
+    indexed: [bool; 256],
+    rgb: HashSet<(u8, u8, u8)>,
+}
+
+impl ColorSet {
+    /// The colors `word` names, or `None` when it names none.
+    pub(crate) fn parse(word: &str) -> Option<Self> {
+        let word = word.to_ascii_lowercase().replace("gray", "grey");
+        if word.is_empty() {
+            return None;
+        }
+        let named = |index: u8| {
+            COLORS
+                .get(&index)
+                .is_some_and(|name| name.to_ascii_lowercase().contains(&word))
+        };
+        let mut indexed = [false; 256];
+        for index in 0..=255u8 {
+            let ansi = index < 16 && {
+                let name = ANSI_NAMES[usize::from(index % 8)];
+                word == name || (index >= 8 && word.strip_prefix("bright") == Some(name))
+            };
+            indexed[usize::from(index)] = ansi || named(index);
+        }
+        let mut rgb = RGB_INDEX
+            .entries()
+            .filter(|(_, index)| named(**index))
+            .filter_map(|(hex, _)| parse_hex(hex))
+            .collect::<HashSet<_>>();
+        rgb.extend(parse_hex(&word));
+        let set = Self {
+            default: "default".contains(&word),
+            indexed,
+            rgb,
+        };
+        (set.default || set.indexed.contains(&true) || !set.rgb.is_empty()).then_some(set)
+    }
+
+    pub(crate) fn contains(&self, color: crate::terminal::Color) -> bool {
+        use crate::terminal::Color::*;
+        match color {
+            Default => self.default,
+            Indexed(index) => self.indexed[usize::from(index)],
+            Rgb(r, g, b) => self.rgb.contains(&(r, g, b)),
+        }
+    }
+}
+
+fn parse_hex(word: &str) -> Option<(u8, u8, u8)> {
+    let digits = word.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |start: usize| u8::from_str_radix(&digits[start..start + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Speaks a cell style the way the attribute-reading command does: the
+/// foreground, then the background when it is set, then each attribute.
+pub(crate) fn describe_style(style: &crate::terminal::Style) -> String {
+    use crate::terminal::{Color, UnderlineStyle};
+    let mut words = vec![describe_color(style.foreground)];
+    if style.background != Color::Default {
+        words.push(format!("on {}", describe_color(style.background)));
+    }
+    for (set, name) in [
+        (style.bold, "bold"),
+        (style.dim, "dim"),
+        (style.italic, "italic"),
+        (style.underline != UnderlineStyle::None, "underline"),
+        (style.inverse, "inverse"),
+        (style.strikethrough, "strikethrough"),
+    ] {
+        if set {
+            words.push(name.to_owned());
+        }
+    }
+    words.join(" ")
+}
+
 #[cfg(test)]
 mod tests {
-    use super::describe_color;
-    use crate::terminal::Color;
+    use super::{ColorSet, describe_color, describe_style};
+    use crate::terminal::{Color, Style};
 
     #[test]
     fn describes_default_indexed_and_rgb_colors() {
@@ -548,4 +642,34 @@ mod tests {
     fn unknown_rgb_colors_use_fixed_width_uppercase_hex() {
         assert_eq!(describe_color(Color::Rgb(1, 2, 10)), "#01020A");
     }
+
+    #[test]
+    fn color_words_match_ansi_and_xterm_names() {
+        let color_matches =
+            |color, word| ColorSet::parse(word).is_some_and(|set| set.contains(color));
+        assert!(color_matches(Color::Indexed(1), "red"));
+        assert!(color_matches(Color::Indexed(9), "BrightRed"));
+        assert!(!color_matches(Color::Indexed(1), "brightred"));
+        assert!(color_matches(Color::Indexed(196), "red"));
+        assert!(color_matches(Color::Rgb(255, 0, 0), "red"));
+        assert!(color_matches(Color::Indexed(8), "gray"));
+        assert!(color_matches(Color::Rgb(1, 2, 10), "#01020a"));
+        assert!(!color_matches(Color::Indexed(2), "red"));
+        assert!(color_matches(Color::Default, "default"));
+        assert!(ColorSet::parse("magenta").is_some() && ColorSet::parse("#a0b0c0").is_some());
+        assert!(ColorSet::parse("bold").is_none());
+    }
+
+    #[test]
+    fn styles_are_described_with_color_names_and_attributes() {
+        let style = Style {
+            foreground: Color::Indexed(9),
+            background: Color::Indexed(4),
+            bold: true,
+            inverse: true,
+            ..Style::default()
+        };
+        assert_eq!(describe_style(&style), "Red on Navy bold inverse");
+        assert_eq!(describe_style(&Style::default()), "default");
+    }
 }
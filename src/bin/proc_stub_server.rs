@@ -213,6 +213,14 @@ fn handle_request(request: Request, state: &mut State) -> Result<Value, RpcError
                 Ok(json!({ "rate": state.rate }))
             }
         }
+        "set_voice" => match request
+            .params
+            .as_ref()
+            .and_then(|params| params.get("voice"))
+        {
+            Some(Value::String(_) | Value::Null) => Ok(Value::Null),
+            _ => Err(RpcError::invalid_params("voice must be a string or null")),
+        },
         _ => Err(RpcError::method_not_found(request.method)),
     }
 }
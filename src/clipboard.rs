@@ -9,14 +9,17 @@ pub(crate) enum ClipboardRegister {
     #[default]
     Internal,
     System,
+    /// One of the vi registers `a` to `z`, kept for the session.
+    Named(char),
 }
 
 impl fmt::Display for ClipboardRegister {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str(match self {
-            Self::Internal => "\"",
-            Self::System => "+",
-        })
+        match self {
+            Self::Internal => formatter.write_str("\""),
+            Self::System => formatter.write_str("+"),
+            Self::Named(name) => write!(formatter, "{name}"),
+        }
     }
 }
 
@@ -27,7 +30,10 @@ impl FromStr for ClipboardRegister {
         match value {
             "\"" => Ok(Self::Internal),
             "+" => Ok(Self::System),
-            _ => anyhow::bail!("clipboard register must be \" or +"),
+            name if name.len() == 1 && name.bytes().all(|byte| byte.is_ascii_lowercase()) => {
+                Ok(Self::Named(char::from(name.as_bytes()[0])))
+            }
+            _ => anyhow::bail!("clipboard register must be \", +, or a letter from a to z"),
         }
     }
 }
@@ -293,6 +299,12 @@ mod tests {
             "+".parse::<ClipboardRegister>().unwrap(),
             ClipboardRegister::System
         );
+        assert_eq!(
+            "q".parse::<ClipboardRegister>().unwrap(),
+            ClipboardRegister::Named('q')
+        );
+        assert_eq!(ClipboardRegister::Named('q').to_string(), "q");
+        assert!("Q".parse::<ClipboardRegister>().is_err());
         assert_eq!(
             "native".parse::<SystemClipboardProvider>().unwrap(),
             SystemClipboardProvider::Native
@@ -1,7 +1,10 @@
-use super::{screen_reader::ScreenReader, view::View};
+use super::{screen_reader::ScreenReader, terminal::ScreenRect, view::View};
+use mouse::Gesture;
+use terminput::{MouseButton, ScrollDirection};
 
 mod clipboard;
 mod mouse;
+mod regions;
 mod review;
 mod system;
 mod table;
@@ -77,7 +80,17 @@ macro_rules! define_actions {
                         | Action::RevFirst
                         | Action::RevLast
                         | Action::RevReadAttributes
+                        | Action::RevLinkPrev
+                        | Action::RevLinkNext
+                        | Action::RevLinkRead
+                        | Action::RevLinkCopy
+                        | Action::RevLinkOpen
+                        | Action::OpenLinksList
+                        | Action::ListImages
+                        | Action::SayTitle
+                        | Action::SayWorkingDirectory
                         | Action::SetMark
+                        | Action::DragFromMark
                         | Action::Copy
                         | Action::ToggleTableMode
                         | Action::StartTableSetupMode
@@ -99,6 +112,11 @@ macro_rules! define_actions {
                         | Action::TableCharPrev
                         | Action::TableCharNext
                         | Action::TableCharRead
+                        | Action::TableCopyRow
+                        | Action::TableCopyColumn
+                        | Action::TableCopyTable
+                        | Action::TableSortByColumn
+                        | Action::TableSearchColumnNext
                 )
             }
         }
@@ -114,12 +132,16 @@ macro_rules! define_actions {
 
 define_actions! {
     ToggleHelp => ("toggle help", "toggle_help"),
+    ListBindings => ("list key bindings", "list_bindings"),
     ToggleAutoRead => ("toggle auto read", "toggle_auto_read"),
     ToggleReviewCursorFollowsScreenCursor => (
         "toggle whether review cursor follows screen cursor",
         "toggle_review_cursor_follows_screen_cursor"
     ),
     ToggleSymbolLevel => ("toggle symbol level", "toggle_symbol_level"),
+    TogglePaneAutoRead => ("toggle auto read for the active tmux pane", "toggle_pane_auto_read"),
+    CyclePaneSymbolLevel => ("cycle symbol level for the active tmux pane", "cycle_pane_symbol_level"),
+    ClearPaneSpeech => ("clear speech overrides for the active tmux pane", "clear_pane_speech"),
     SayOverlay => ("say current overlay", "say_overlay"),
     OpenLuaRepl => ("open Lua REPL", "open_lua_repl"),
     OpenReview => ("enter review mode", "open_review"),
@@ -132,10 +154,15 @@ define_actions! {
     OpenTmuxWindowChooser => ("open tmux window chooser", "open_tmux_window_chooser"),
     OpenTmuxPaneChooser => ("open tmux pane chooser", "open_tmux_pane_chooser"),
     OpenTmuxCommandPrompt => ("open tmux command prompt", "open_tmux_command_prompt"),
+    SummarizeTmuxActivity => ("summarize activity in the active tmux connection", "summarize_tmux_activity"),
+    SaveTmuxWorkspace => ("save the active tmux connection as a named workspace", "save_tmux_workspace"),
+    RestoreTmuxWorkspace => ("restore a named tmux workspace into the active connection", "restore_tmux_workspace"),
+    AttachTmuxSession => ("start or attach a tmux control-mode session", "attach_tmux_session"),
     DetachTmuxConnection => ("gracefully detach the active tmux connection", "detach_tmux_connection"),
     ForceAbandonTmuxGateway => ("expose a stuck active tmux gateway as raw terminal input", "force_abandon_tmux_gateway"),
     PassNextKey => ("forward next key press", "pass_next_key"),
     StopSpeaking => ("stop speaking", "stop_speaking"),
+    ExitMode => ("leave the current key mode", "exit_mode"),
     RevLinePrev => ("previous line", "review_line_prev"),
     RevLineNext => ("next line", "review_line_next"),
     RevLinePrevNonBlank => ("previous non blank line", "review_line_prev_non_blank"),
@@ -153,11 +180,29 @@ define_actions! {
     RevFirst => ("beginning of line", "review_first"),
     RevLast => ("end of line", "review_last"),
     RevReadAttributes => ("read attributes", "review_read_attributes"),
+    RevLinkPrev => ("previous link", "review_link_prev"),
+    RevLinkNext => ("next link", "review_link_next"),
+    RevLinkRead => ("link target", "review_link_read"),
+    RevLinkCopy => ("copy link target", "review_link_copy"),
+    RevLinkOpen => ("open link", "review_link_open"),
+    OpenLinksList => ("list links", "open_links_list"),
+    ListImages => ("list images on screen", "list_images"),
     LeftClick => ("left click at review cursor", "left_click"),
     RightClick => ("right click at review cursor", "right_click"),
+    MiddleClick => ("middle click at review cursor", "middle_click"),
+    DoubleClick => ("double click at review cursor", "double_click"),
+    ScrollWheelUp => ("scroll mouse wheel up at review cursor", "scroll_wheel_up"),
+    ScrollWheelDown => ("scroll mouse wheel down at review cursor", "scroll_wheel_down"),
+    DragFromMark => ("drag mouse from mark to review cursor", "drag_from_mark"),
     Backspace => ("backspace", "backspace"),
     Delete => ("delete", "delete"),
     SayTime => ("say the time", "say_time"),
+    SayTitle => ("say the terminal title", "say_title"),
+    SayWorkingDirectory => ("say the working directory", "say_working_directory"),
+    MarkRegionCorner => (
+        "mark a corner of a screen region at the review cursor",
+        "mark_region_corner"
+    ),
     SetMark => ("set mark", "set_mark"),
     Copy => ("copy", "copy"),
     Paste => ("paste", "paste"),
@@ -198,6 +243,17 @@ define_actions! {
     TableCharPrev => ("previous character in cell", "table_char_prev"),
     TableCharNext => ("next character in cell", "table_char_next"),
     TableCharRead => ("current character in cell", "table_char_read"),
+    TableCopyRow => ("copy table row", "table_copy_row"),
+    TableCopyColumn => ("copy table column", "table_copy_column"),
+    TableCopyTable => ("copy whole table", "table_copy_table"),
+    CycleTableExportFormat => ("cycle table copy format", "cycle_table_export_format"),
+    TableSortByColumn => ("show table sorted by current column", "table_sort_by_column"),
+    TableSearchColumn => ("search current table column", "table_search_column"),
+    TableSearchColumnNext => ("next match in current table column", "table_search_column_next"),
+    SaveTableLayout => ("save tabstops as a named layout", "save_table_layout"),
+    SaveTranscript => ("save screen and scrollback to a file", "save_transcript"),
+    SaveSelectionTranscript => ("save review selection to a file", "save_selection_transcript"),
+    SaveCommandOutput => ("save last command output to a file", "save_command_output"),
 }
 
 pub enum CommandResult {
@@ -205,12 +261,66 @@ pub enum CommandResult {
     ForwardInput,
     Paste(String),
     PtyInput(Vec<u8>),
+    /// Show `text` in a message overlay.
+    Message {
+        title: String,
+        text: String,
+    },
+}
+
+/// Saves the tabstops of the current table setup as layout `name`.
+pub fn save_table_layout(sr: &mut ScreenReader, name: &str) -> Result<()> {
+    table::save_setup_layout(sr, name)
+}
+
+/// Defines the screen region between two marked corners from a prompt
+/// answer.
+pub fn define_region(sr: &mut ScreenReader, rect: ScreenRect, text: &str) -> Result<()> {
+    regions::define(sr, rect, text)
+}
+
+/// Speaks the text of region `name` on `view`.
+pub fn read_region(sr: &mut ScreenReader, view: &View, name: &str) -> Result<()> {
+    regions::read(sr, view, name)
 }
 
 pub fn builtin_action_name(action: Action) -> &'static str {
     action.metadata().name
 }
 
+impl Action {
+    /// Table commands that detect, read, or move through a table. Views with
+    /// a scrollable document run these over the whole document.
+    pub(crate) fn navigates_table(self) -> bool {
+        matches!(
+            self,
+            Action::ToggleTableMode
+                | Action::ExitTableMode
+                | Action::TableRowPrev
+                | Action::TableRowNext
+                | Action::TableRowTop
+                | Action::TableRowBottom
+                | Action::TableColPrev
+                | Action::TableColNext
+                | Action::TableColFirst
+                | Action::TableColLast
+                | Action::TableCellRead
+                | Action::TableHeaderRead
+                | Action::TableWordPrev
+                | Action::TableWordNext
+                | Action::TableWordRead
+                | Action::TableCharPrev
+                | Action::TableCharNext
+                | Action::TableCharRead
+                | Action::TableCopyRow
+                | Action::TableCopyColumn
+                | Action::TableCopyTable
+                | Action::TableSortByColumn
+                | Action::TableSearchColumnNext
+        )
+    }
+}
+
 pub fn handle(
     sr: &mut ScreenReader,
     title: &str,
@@ -225,12 +335,32 @@ pub fn handle(
         return Ok(CommandResult::Handled);
     }
 
+    let review_cursor = view.review_cursor_position();
+    let result = run(sr, title, view, action)?;
+    if matches!(result, CommandResult::Handled)
+        && view.review_cursor_position() != review_cursor
+        && let Some(input) = mouse::hover(sr, view)
+    {
+        return Ok(CommandResult::PtyInput(input));
+    }
+    Ok(result)
+}
+
+fn run(
+    sr: &mut ScreenReader,
+    title: &str,
+    view: &mut View,
+    action: Action,
+) -> Result<CommandResult> {
     match action {
         Action::ToggleAutoRead => system::toggle_auto_read(sr),
         Action::ToggleReviewCursorFollowsScreenCursor => {
             system::toggle_review_follows_screen_cursor(sr, view)
         }
         Action::ToggleSymbolLevel => system::toggle_symbol_level(sr),
+        Action::TogglePaneAutoRead => system::toggle_pane_auto_read(sr),
+        Action::CyclePaneSymbolLevel => system::cycle_pane_symbol_level(sr),
+        Action::ClearPaneSpeech => system::clear_pane_speech(sr),
         Action::SayOverlay => system::say_overlay(sr, title),
         Action::PassNextKey => system::pass_next_key(sr),
         Action::StopSpeaking => system::stop(sr),
@@ -251,11 +381,24 @@ pub fn handle(
         Action::RevFirst => review::first(sr, view),
         Action::RevLast => review::last(sr, view),
         Action::RevReadAttributes => review::read_attributes(sr, view),
-        Action::LeftClick => mouse::click(sr, view, mouse::Button::Left),
-        Action::RightClick => mouse::click(sr, view, mouse::Button::Right),
+        Action::RevLinkPrev => review::link_previous(sr, view),
+        Action::RevLinkNext => review::link_next(sr, view),
+        Action::RevLinkRead => review::link_read(sr, view),
+        Action::RevLinkCopy => review::link_copy(sr, view),
+        Action::RevLinkOpen => review::link_open(sr, view),
+        Action::ListImages => review::list_images(sr, view),
+        Action::LeftClick => mouse::perform(sr, view, Gesture::Click(MouseButton::Left)),
+        Action::RightClick => mouse::perform(sr, view, Gesture::Click(MouseButton::Right)),
+        Action::MiddleClick => mouse::perform(sr, view, Gesture::Click(MouseButton::Middle)),
+        Action::DoubleClick => mouse::perform(sr, view, Gesture::DoubleClick),
+        Action::ScrollWheelUp => mouse::perform(sr, view, Gesture::Scroll(ScrollDirection::Up)),
+        Action::ScrollWheelDown => mouse::perform(sr, view, Gesture::Scroll(ScrollDirection::Down)),
+        Action::DragFromMark => mouse::perform(sr, view, Gesture::DragFromMark),
         Action::Backspace => system::backspace(sr, view),
         Action::Delete => system::delete(sr, view),
         Action::SayTime => system::say_time(sr),
+        Action::SayTitle => system::say_title(sr, view),
+        Action::SayWorkingDirectory => system::say_working_directory(sr, view),
         Action::SetMark => clipboard::set_mark(sr, view),
         Action::Copy => clipboard::copy(sr, view),
         Action::Paste => clipboard::paste(sr),
@@ -272,6 +415,7 @@ pub fn handle(
         Action::CancelTableSetupMode => table::cancel_setup(sr),
         Action::CommitTableSetupMode => table::commit_setup(sr, view),
         Action::ToggleTableSetupTabstop => table::toggle_setup_tabstop(sr, view),
+        Action::ExitMode => system::exit_mode(sr),
         Action::ExitTableMode => table::exit_mode(sr),
         Action::TableRowPrev => table::row_move(sr, view, table::RowMove::Previous),
         Action::TableRowNext => table::row_move(sr, view, table::RowMove::Next),
@@ -290,15 +434,33 @@ pub fn handle(
         Action::TableCharPrev => table::character_previous(sr, view),
         Action::TableCharNext => table::character_next(sr, view),
         Action::TableCharRead => table::character_read(sr, view),
+        Action::TableCopyRow => table::copy(sr, view, table::CopyScope::Row),
+        Action::TableCopyColumn => table::copy(sr, view, table::CopyScope::Column),
+        Action::TableCopyTable => table::copy(sr, view, table::CopyScope::Table),
+        Action::CycleTableExportFormat => table::cycle_export_format(sr),
+        Action::TableSortByColumn => table::sort_by_column(sr, view),
+        Action::TableSearchColumnNext => table::search_column_next(sr, view),
         Action::ToggleHelp
+        | Action::ListBindings
         | Action::OpenLuaRepl
         | Action::OpenReview
+        | Action::OpenLinksList
+        | Action::TableSearchColumn
+        | Action::SaveTableLayout
+        | Action::MarkRegionCorner
+        | Action::SaveTranscript
+        | Action::SaveSelectionTranscript
+        | Action::SaveCommandOutput
         | Action::OpenTmuxConnectionChooser
         | Action::RenameTmuxConnection
         | Action::OpenTmuxSessionChooser
         | Action::OpenTmuxWindowChooser
         | Action::OpenTmuxPaneChooser
         | Action::OpenTmuxCommandPrompt
+        | Action::SummarizeTmuxActivity
+        | Action::SaveTmuxWorkspace
+        | Action::RestoreTmuxWorkspace
+        | Action::AttachTmuxSession
         | Action::DetachTmuxConnection
         | Action::ForceAbandonTmuxGateway => {
             sr.speak("not implemented", false)?;
@@ -331,6 +493,7 @@ mod tests {
     #[test]
     fn tmux_gateway_actions_have_stable_configuration_names() {
         for (name, action) in [
+            ("attach_tmux_session", Action::AttachTmuxSession),
             ("detach_tmux_connection", Action::DetachTmuxConnection),
             (
                 "force_abandon_tmux_gateway",
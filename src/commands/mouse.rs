@@ -2,152 +2,199 @@ use super::{CommandResult, Result};
 use crate::{
     screen_reader::ScreenReader,
     terminal::{MouseEncoding, MouseProtocol},
+    tmux_input::{encode_mouse, protocol_reports},
     view::View,
 };
+use terminput::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind, ScrollDirection};
 
+/// A mouse action performed at the review cursor.
 #[derive(Copy, Clone)]
-pub(super) enum Button {
-    Left,
-    Right,
+pub(super) enum Gesture {
+    Click(MouseButton),
+    DoubleClick,
+    Scroll(ScrollDirection),
+    /// Press at the mark, drag to the review cursor, and release there, as
+    /// when selecting text with the mouse.
+    DragFromMark,
 }
 
-pub(super) fn click(sr: &mut ScreenReader, view: &View, button: Button) -> Result<CommandResult> {
+pub(super) fn perform(
+    sr: &mut ScreenReader,
+    view: &View,
+    gesture: Gesture,
+) -> Result<CommandResult> {
     // The coordinate comes from the user's presented review cursor, but the
     // protocol belongs to the mutable application endpoint. A mode change
     // immediately before the click has the same ordinary UI race as an
     // element moving immediately before a physical mouse press.
     let screen = view.live_screen();
-    if screen.mouse_protocol_mode() == MouseProtocol::None {
+    let mode = screen.mouse_protocol_mode();
+    if mode == MouseProtocol::None {
         sr.speak("mouse input unavailable", false)?;
         return Ok(CommandResult::Handled);
     }
 
-    let Some(input) = encode_click(
-        screen.mouse_protocol_mode(),
-        screen.mouse_protocol_encoding(),
-        button,
-        view.review_cursor_position(),
-    ) else {
+    let cursor = view.review_cursor_position();
+    let events = match gesture {
+        Gesture::Click(button) => click_events(button, cursor),
+        Gesture::DoubleClick => {
+            let mut events = click_events(MouseButton::Left, cursor);
+            events.extend(click_events(MouseButton::Left, cursor));
+            events
+        }
+        Gesture::Scroll(direction) => vec![(MouseEventKind::Scroll(direction), cursor)],
+        Gesture::DragFromMark => {
+            // Without release reports a drag is only a press at the mark.
+            if mode == MouseProtocol::Press {
+                sr.speak("mouse drag unavailable", false)?;
+                return Ok(CommandResult::Handled);
+            }
+            let Some(mark) = mark_position(view) else {
+                sr.speak("no mark on screen", false)?;
+                return Ok(CommandResult::Handled);
+            };
+            drag_events(mark, cursor)
+        }
+    };
+
+    let Some(input) = encode_events(mode, screen.mouse_protocol_encoding(), &events) else {
         sr.speak("mouse position unavailable", false)?;
         return Ok(CommandResult::Handled);
     };
-
     Ok(CommandResult::PtyInput(input))
 }
 
-fn encode_click(
+/// The motion report that lets an application show hover tooltips for the
+/// cell under the review cursor, when hover is enabled and the application
+/// tracks all motion.
+pub(super) fn hover(sr: &ScreenReader, view: &View) -> Option<Vec<u8>> {
+    if !sr.mouse_hover_enabled() {
+        return None;
+    }
+    let screen = view.live_screen();
+    if screen.mouse_protocol_mode() != MouseProtocol::AnyMotion {
+        return None;
+    }
+    encode_events(
+        MouseProtocol::AnyMotion,
+        screen.mouse_protocol_encoding(),
+        &[(MouseEventKind::Moved, view.review_cursor_position())],
+    )
+}
+
+/// The mark as a cell of the screen the review cursor is on, if it is
+/// visible there.
+fn mark_position(view: &View) -> Option<(u16, u16)> {
+    let mark = view.review_mark_position()?;
+    let top = view.scrollback_len().saturating_sub(view.scrollback());
+    let row = u16::try_from(mark.row.checked_sub(top)?).ok()?;
+    (row < view.size().0).then_some((row, mark.col))
+}
+
+fn click_events(button: MouseButton, at: (u16, u16)) -> Vec<(MouseEventKind, (u16, u16))> {
+    vec![
+        (MouseEventKind::Down(button), at),
+        (MouseEventKind::Up(button), at),
+    ]
+}
+
+fn drag_events(from: (u16, u16), to: (u16, u16)) -> Vec<(MouseEventKind, (u16, u16))> {
+    vec![
+        (MouseEventKind::Down(MouseButton::Left), from),
+        (MouseEventKind::Drag(MouseButton::Left), to),
+        (MouseEventKind::Up(MouseButton::Left), to),
+    ]
+}
+
+/// Encodes the events `mode` reports, skipping the rest, such as releases
+/// under X10 press-only reporting. `None` means a reported position cannot be
+/// represented in `encoding`.
+fn encode_events(
     mode: MouseProtocol,
     encoding: MouseEncoding,
-    button: Button,
-    (row, col): (u16, u16),
+    events: &[(MouseEventKind, (u16, u16))],
 ) -> Option<Vec<u8>> {
-    let button_code = match button {
-        Button::Left => 0,
-        Button::Right => 2,
-    };
-    let include_release = mode != MouseProtocol::Press;
     let mut input = Vec::new();
-
-    match encoding {
-        MouseEncoding::Sgr => {
-            input.extend_from_slice(
-                format!(
-                    "\x1B[<{button_code};{};{}M",
-                    u32::from(col) + 1,
-                    u32::from(row) + 1
-                )
-                .as_bytes(),
-            );
-            if include_release {
-                input.extend_from_slice(
-                    format!(
-                        "\x1B[<{button_code};{};{}m",
-                        u32::from(col) + 1,
-                        u32::from(row) + 1
-                    )
-                    .as_bytes(),
-                );
-            }
-        }
-        MouseEncoding::Default => {
-            let col = u8::try_from(u32::from(col) + 33).ok()?;
-            let row = u8::try_from(u32::from(row) + 33).ok()?;
-            input.extend_from_slice(&[0x1B, b'[', b'M', button_code + 32, col, row]);
-            if include_release {
-                input.extend_from_slice(&[0x1B, b'[', b'M', 35, col, row]);
-            }
-        }
-        MouseEncoding::Utf8 => {
-            let col = char::from_u32(u32::from(col) + 33)?;
-            let row = char::from_u32(u32::from(row) + 33)?;
-            let mut col_buf = [0; 4];
-            let mut row_buf = [0; 4];
-            let col = col.encode_utf8(&mut col_buf).as_bytes();
-            let row = row.encode_utf8(&mut row_buf).as_bytes();
-            input.extend_from_slice(b"\x1B[M");
-            input.push(button_code + 32);
-            input.extend_from_slice(col);
-            input.extend_from_slice(row);
-            if include_release {
-                input.extend_from_slice(b"\x1B[M#");
-                input.extend_from_slice(col);
-                input.extend_from_slice(row);
-            }
+    for &(kind, (row, column)) in events {
+        if !protocol_reports(mode, kind) {
+            continue;
         }
+        let event = MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::empty(),
+        };
+        input.extend(encode_mouse(
+            event,
+            u32::from(row),
+            u32::from(column),
+            encoding,
+        )?);
     }
-
     Some(input)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Button, encode_click};
+    use super::{click_events, drag_events, encode_events};
     use crate::terminal::{MouseEncoding, MouseProtocol};
+    use terminput::{MouseButton, MouseEventKind, ScrollDirection};
 
     #[test]
     fn encodes_sgr_left_click_with_one_based_coordinates() {
-        let input = encode_click(
+        let input = encode_events(
             MouseProtocol::PressRelease,
             MouseEncoding::Sgr,
-            Button::Left,
-            (4, 7),
+            &click_events(MouseButton::Left, (4, 7)),
         )
         .unwrap();
         assert_eq!(input, b"\x1B[<0;8;5M\x1B[<0;8;5m");
     }
 
     #[test]
-    fn encodes_sgr_right_click() {
-        let input = encode_click(
+    fn encodes_sgr_right_and_middle_clicks() {
+        let right = encode_events(
             MouseProtocol::ButtonMotion,
             MouseEncoding::Sgr,
-            Button::Right,
-            (0, 0),
+            &click_events(MouseButton::Right, (0, 0)),
         )
         .unwrap();
-        assert_eq!(input, b"\x1B[<2;1;1M\x1B[<2;1;1m");
+        assert_eq!(right, b"\x1B[<2;1;1M\x1B[<2;1;1m");
+        let middle = encode_events(
+            MouseProtocol::PressRelease,
+            MouseEncoding::Default,
+            &click_events(MouseButton::Middle, (0, 0)),
+        )
+        .unwrap();
+        assert_eq!(middle, b"\x1B[M!!!\x1B[M#!!");
     }
 
     #[test]
-    fn x10_mode_sends_only_button_press() {
-        let input = encode_click(
+    fn x10_mode_sends_only_button_presses() {
+        let input = encode_events(
             MouseProtocol::Press,
             MouseEncoding::Default,
-            Button::Left,
-            (1, 2),
+            &click_events(MouseButton::Left, (1, 2)),
         )
         .unwrap();
         assert_eq!(input, b"\x1B[M #\"");
+        let wheel = encode_events(
+            MouseProtocol::Press,
+            MouseEncoding::Default,
+            &[(MouseEventKind::Scroll(ScrollDirection::Down), (1, 2))],
+        )
+        .unwrap();
+        assert_eq!(wheel, b"\x1B[Ma#\"");
     }
 
     #[test]
     fn default_encoding_rejects_coordinates_it_cannot_represent() {
         assert!(
-            encode_click(
+            encode_events(
                 MouseProtocol::PressRelease,
                 MouseEncoding::Default,
-                Button::Left,
-                (0, 223),
+                &click_events(MouseButton::Left, (0, 223)),
             )
             .is_none()
         );
@@ -155,13 +202,52 @@ mod tests {
 
     #[test]
     fn encodes_utf8_mouse_click() {
-        let input = encode_click(
+        let input = encode_events(
             MouseProtocol::PressRelease,
             MouseEncoding::Utf8,
-            Button::Right,
-            (95, 95),
+            &click_events(MouseButton::Right, (95, 95)),
         )
         .unwrap();
         assert_eq!(input, b"\x1B[M\"\xC2\x80\xC2\x80\x1B[M#\xC2\x80\xC2\x80");
     }
+
+    #[test]
+    fn drags_report_motion_only_when_the_protocol_tracks_it() {
+        let drag = drag_events((0, 1), (2, 5));
+        assert_eq!(
+            encode_events(MouseProtocol::ButtonMotion, MouseEncoding::Sgr, &drag).unwrap(),
+            b"\x1B[<0;2;1M\x1B[<32;6;3M\x1B[<0;6;3m"
+        );
+        assert_eq!(
+            encode_events(MouseProtocol::PressRelease, MouseEncoding::Sgr, &drag).unwrap(),
+            b"\x1B[<0;2;1M\x1B[<0;6;3m"
+        );
+        assert_eq!(
+            encode_events(MouseProtocol::AnyMotion, MouseEncoding::Utf8, &drag).unwrap(),
+            b"\x1B[M \"!\x1B[M@&#\x1B[M#&#"
+        );
+    }
+
+    #[test]
+    fn encodes_wheel_scrolls_and_hover_motion() {
+        assert_eq!(
+            encode_events(
+                MouseProtocol::PressRelease,
+                MouseEncoding::Sgr,
+                &[(MouseEventKind::Scroll(ScrollDirection::Up), (3, 4))],
+            )
+            .unwrap(),
+            b"\x1B[<64;5;4M"
+        );
+        let hover = [(MouseEventKind::Moved, (3, 4))];
+        assert_eq!(
+            encode_events(MouseProtocol::AnyMotion, MouseEncoding::Sgr, &hover).unwrap(),
+            b"\x1B[<35;5;4M"
+        );
+        assert!(
+            encode_events(MouseProtocol::ButtonMotion, MouseEncoding::Sgr, &hover)
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
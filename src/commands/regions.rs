@@ -0,0 +1,45 @@
+use super::Result;
+use crate::{
+    regions::{Region, RegionMode, region_text},
+    screen_reader::ScreenReader,
+    terminal::ScreenRect,
+    view::View,
+};
+
+/// Defines the region marked with the review cursor from a prompt answer:
+/// a name, optionally followed by `silence`, `watch` or `read`.
+pub(super) fn define(sr: &mut ScreenReader, rect: ScreenRect, text: &str) -> Result<()> {
+    let text = text.trim();
+    let (name, mode) = match text.rsplit_once(char::is_whitespace) {
+        Some((name, mode)) => match mode.parse() {
+            Ok(mode) => (name.trim_end(), mode),
+            Err(_) => (text, RegionMode::default()),
+        },
+        None => (text, RegionMode::default()),
+    };
+    let message = match sr.regions_mut().define(name, Region::from_rect(rect, mode)) {
+        Ok(()) => match mode {
+            RegionMode::Silence => format!("region {name} silenced"),
+            RegionMode::Watch => format!("region {name} watched"),
+            RegionMode::Read => format!("region {name} defined"),
+        },
+        Err(error) => format!("{error:#}"),
+    };
+    sr.speak(&message, false)?;
+    Ok(())
+}
+
+pub(super) fn read(sr: &mut ScreenReader, view: &View, name: &str) -> Result<()> {
+    let (rows, cols) = view.screen().size();
+    let Some(rect) = sr
+        .regions()
+        .get(name)
+        .and_then(|region| region.resolve(rows, cols))
+    else {
+        sr.speak(&format!("no region {name}"), false)?;
+        return Ok(());
+    };
+    let text = region_text(view.screen(), rect);
+    sr.speak(if text.is_empty() { "blank" } else { &text }, true)?;
+    Ok(())
+}
@@ -2,8 +2,10 @@ use super::{CommandResult, Error, Result};
 use crate::{
     attributes,
     ext::{CellExt, ScreenExt},
-    screen_reader::ScreenReader,
-    view::View,
+    review::document::ReviewDocument,
+    screen_reader::{ScreenReader, image_description},
+    terminal::HistoryPosition,
+    view::{ReviewImage, View},
 };
 
 pub(super) fn report_move(
@@ -11,10 +13,21 @@ pub(super) fn report_move(
     view: &mut View,
     old_position: (u16, u16),
 ) -> Result<()> {
-    if old_position != view.review_cursor_position() {
+    let new_position = view.review_cursor_position();
+    if old_position != new_position {
         view.cancel_pending_screen_transition_follow();
     }
-    sr.hook_on_review_cursor_move(old_position, view.review_cursor_position())?;
+    sr.hook_on_review_cursor_move(old_position, new_position)?;
+    if old_position == new_position {
+        return Ok(());
+    }
+    let images = view.review_images();
+    if let Some(image) = ReviewImage::topmost_at(images, new_position)
+        && !ReviewImage::topmost_at(images, old_position)
+            .is_some_and(|old| old.same_placement(image))
+    {
+        sr.announce_image(image)?;
+    }
     Ok(())
 }
 
@@ -253,12 +266,112 @@ pub(super) fn read_attributes(sr: &mut ScreenReader, view: &View) -> Result<Comm
     Ok(CommandResult::Handled)
 }
 
+pub(super) fn link_previous(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
+    link_move(sr, view, false)
+}
+
+pub(super) fn link_next(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
+    link_move(sr, view, true)
+}
+
+fn link_move(sr: &mut ScreenReader, view: &mut View, forward: bool) -> Result<CommandResult> {
+    let document = ReviewDocument::visible(view);
+    let old_position = view.review_cursor_position();
+    let Some(target) = document.link(review_position(view), forward, 1) else {
+        sr.speak(
+            if forward {
+                "no next link"
+            } else {
+                "no previous link"
+            },
+            false,
+        )?;
+        return Ok(CommandResult::Handled);
+    };
+    view.set_review_cursor_position((target.row as u16, target.col));
+    report_move(sr, view, old_position)?;
+    if let Some(link) = document.link_containing(target) {
+        sr.speak(&format!("link {}", link.text), false)?;
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn link_read(sr: &mut ScreenReader, view: &View) -> Result<CommandResult> {
+    match link_under_review_cursor(view) {
+        Some(uri) => sr.speak(&uri, false)?,
+        None => sr.speak("not a link", false)?,
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn link_copy(sr: &mut ScreenReader, view: &View) -> Result<CommandResult> {
+    match link_under_review_cursor(view) {
+        Some(uri) => sr.copy_link(&uri)?,
+        None => sr.speak("not a link", false)?,
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn link_open(sr: &mut ScreenReader, view: &View) -> Result<CommandResult> {
+    match link_under_review_cursor(view) {
+        Some(uri) => sr.open_link(&uri)?,
+        None => sr.speak("not a link", false)?,
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn list_images(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
+    let images = view.review_images();
+    if images.is_empty() {
+        sr.speak("no images", false)?;
+        return Ok(CommandResult::Handled);
+    }
+    let text = images
+        .iter()
+        .map(|image| {
+            format!(
+                "{}, row {}, column {}",
+                image_description(image),
+                image.row + 1,
+                image.col + 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    Ok(CommandResult::Message {
+        title: match images.len() {
+            1 => "1 image".to_owned(),
+            count => format!("{count} images"),
+        },
+        text,
+    })
+}
+
+fn review_position(view: &View) -> HistoryPosition {
+    let (row, col) = view.review_cursor_position();
+    HistoryPosition {
+        row: usize::from(row),
+        col,
+    }
+}
+
+fn link_under_review_cursor(view: &View) -> Option<String> {
+    let (row, col) = view.review_cursor_position();
+    view.screen()
+        .rows
+        .get(usize::from(row))?
+        .cells
+        .get(usize::from(col))?
+        .hyperlink
+        .clone()
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         bottom, character_next, character_previous, character_read, character_read_phonetic, first,
-        last, line_next, line_previous, line_read, phonetic_name, read_attributes, top, word_next,
-        word_previous,
+        last, line_next, line_previous, line_read, link_copy, link_next, link_previous, link_read,
+        phonetic_name, read_attributes, top, word_next, word_previous,
     };
     use crate::{
         commands::Error,
@@ -407,6 +520,40 @@ mod tests {
         assert_eq!(view.review_cursor_position().1, 4);
     }
 
+    #[test]
+    fn link_navigation_reads_and_copies_targets_on_the_visible_screen() {
+        let (mut sr, output) = screen_reader();
+        let mut view = View::new(2, 20);
+        view.process_changes(
+            b"see \x1b]8;;https://a.example\x1b\\docs\x1b]8;;\x1b\\\r\n\x1b]8;;file:///tmp/x\x1b\\x\x1b]8;;\x1b\\",
+        );
+        view.set_review_cursor_position((0, 0));
+
+        link_read(&mut sr, &view).unwrap();
+        link_previous(&mut sr, &mut view).unwrap();
+        link_next(&mut sr, &mut view).unwrap();
+        assert_eq!(view.review_cursor_position(), (0, 4));
+        link_read(&mut sr, &view).unwrap();
+        link_next(&mut sr, &mut view).unwrap();
+        assert_eq!(view.review_cursor_position(), (1, 0));
+        link_next(&mut sr, &mut view).unwrap();
+        link_copy(&mut sr, &view).unwrap();
+
+        assert_eq!(
+            output.borrow().as_slice(),
+            [
+                "not a link",
+                "no previous link",
+                "link docs",
+                "https://a.example",
+                "link x",
+                "no next link",
+                "copied link",
+            ]
+        );
+        assert_eq!(sr.clipboard_text(), Some("file:///tmp/x"));
+    }
+
     #[test]
     fn attribute_reading_reports_styles_and_invalid_coordinates() {
         let (mut sr, output) = screen_reader();
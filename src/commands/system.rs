@@ -1,5 +1,9 @@
 use super::{CommandResult, Result};
-use crate::{screen_reader::ScreenReader, view::View};
+use crate::{
+    screen_reader::{PaneSpeechProfile, ScreenReader, directory_from_osc7},
+    tmux_model::PaneId,
+    view::View,
+};
 
 pub(super) fn stop(sr: &mut ScreenReader) -> Result<CommandResult> {
     sr.stop_speaking()?;
@@ -65,6 +69,17 @@ pub(super) fn toggle_help(sr: &mut ScreenReader) -> Result<CommandResult> {
         },
         false,
     )?;
+    if enabled && let Some(mode) = sr.user_mode() {
+        let help = sr.key_bindings().mode_help(mode);
+        sr.speak(&help, false)?;
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn exit_mode(sr: &mut ScreenReader) -> Result<CommandResult> {
+    if !sr.exit_user_mode()? {
+        sr.speak("no key mode active", false)?;
+    }
     Ok(CommandResult::Handled)
 }
 
@@ -85,23 +100,111 @@ pub(super) fn say_time(sr: &mut ScreenReader) -> Result<CommandResult> {
     Ok(CommandResult::Handled)
 }
 
+pub(super) fn say_title(sr: &mut ScreenReader, view: &View) -> Result<CommandResult> {
+    let title = sr
+        .active_terminal_title()
+        .or(view.screen().title.as_deref())
+        .filter(|title| !title.trim().is_empty())
+        .map_or_else(|| "no title".to_owned(), str::to_owned);
+    sr.speak(&title, false)?;
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn say_working_directory(sr: &mut ScreenReader, view: &View) -> Result<CommandResult> {
+    let directory = sr
+        .active_working_directory()
+        .or_else(|| {
+            view.screen()
+                .working_directory
+                .as_deref()
+                .map(directory_from_osc7)
+        })
+        .filter(|directory| !directory.is_empty())
+        .unwrap_or_else(|| "no working directory".to_owned());
+    sr.speak(&directory, false)?;
+    Ok(CommandResult::Handled)
+}
+
 pub(super) fn toggle_symbol_level(sr: &mut ScreenReader) -> Result<CommandResult> {
     let level = sr.speech_mut().cycle_symbol_level();
     sr.speak(&level.to_string(), false)?;
     Ok(CommandResult::Handled)
 }
 
+/// The active tmux pane and its current overrides, or `None` after telling the
+/// user there is no pane to configure.
+fn active_pane_speech(sr: &mut ScreenReader) -> Result<Option<(u64, PaneId, PaneSpeechProfile)>> {
+    let Some((connection_id, pane_id)) = sr.active_tmux_pane() else {
+        sr.speak("no active tmux pane", false)?;
+        return Ok(None);
+    };
+    let profile = sr
+        .tmux_pane_speech(connection_id, pane_id)
+        .cloned()
+        .unwrap_or_default();
+    Ok(Some((connection_id, pane_id, profile)))
+}
+
+pub(super) fn toggle_pane_auto_read(sr: &mut ScreenReader) -> Result<CommandResult> {
+    let Some((connection_id, pane_id, mut profile)) = active_pane_speech(sr)? else {
+        return Ok(CommandResult::Handled);
+    };
+    let enabled = !sr.live_auto_read_enabled();
+    // Landing back on the global setting drops the override, so the pane
+    // follows later changes to the global option again.
+    profile.auto_read = (enabled != sr.auto_read_enabled()).then_some(enabled);
+    sr.set_tmux_pane_speech(connection_id, pane_id, profile)?;
+    sr.speak(
+        if enabled {
+            "pane auto read enabled"
+        } else {
+            "pane auto read disabled"
+        },
+        false,
+    )?;
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn cycle_pane_symbol_level(sr: &mut ScreenReader) -> Result<CommandResult> {
+    let Some((connection_id, pane_id, mut profile)) = active_pane_speech(sr)? else {
+        return Ok(CommandResult::Handled);
+    };
+    let global = sr.speech().symbol_level();
+    let level = profile.symbol_level.unwrap_or(global).next();
+    profile.symbol_level = (level != global).then_some(level);
+    sr.set_tmux_pane_speech(connection_id, pane_id, profile)?;
+    sr.speak(&format!("pane symbols {level}"), false)?;
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn clear_pane_speech(sr: &mut ScreenReader) -> Result<CommandResult> {
+    let Some((connection_id, pane_id, profile)) = active_pane_speech(sr)? else {
+        return Ok(CommandResult::Handled);
+    };
+    sr.set_tmux_pane_speech(connection_id, pane_id, PaneSpeechProfile::default())?;
+    sr.speak(
+        if profile.is_empty() {
+            "pane uses global speech settings"
+        } else {
+            "pane speech overrides cleared"
+        },
+        false,
+    )?;
+    Ok(CommandResult::Handled)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        backspace, delete, pass_next_key, say_overlay, say_time, stop, toggle_auto_read,
-        toggle_help, toggle_review_follows_screen_cursor, toggle_stop_speech_on_focus_loss,
-        toggle_symbol_level,
+        backspace, clear_pane_speech, cycle_pane_symbol_level, delete, pass_next_key, say_overlay,
+        say_time, stop, toggle_auto_read, toggle_help, toggle_pane_auto_read,
+        toggle_review_follows_screen_cursor, toggle_stop_speech_on_focus_loss, toggle_symbol_level,
     };
     use crate::{
         commands::CommandResult,
         screen_reader::ScreenReader,
         speech::{self, symbols::Level},
+        tmux_model::PaneId,
         view::View,
     };
     use std::{cell::RefCell, rc::Rc};
@@ -167,6 +270,48 @@ mod tests {
         assert_eq!(output.borrow().as_slice(), ["most", "all", "none", "some"]);
     }
 
+    #[test]
+    fn pane_speech_commands_override_only_the_active_tmux_pane() {
+        let (mut sr, output) = screen_reader();
+
+        toggle_pane_auto_read(&mut sr).unwrap();
+        sr.set_active_tmux_pane(Some((1, PaneId(20)))).unwrap();
+        toggle_pane_auto_read(&mut sr).unwrap();
+        cycle_pane_symbol_level(&mut sr).unwrap();
+        assert!(!sr.live_auto_read_enabled());
+        assert!(sr.auto_read_enabled());
+        assert_eq!(
+            sr.tmux_pane_speech(1, PaneId(20)).unwrap().describe(),
+            "muted, symbols most"
+        );
+
+        sr.set_active_tmux_pane(Some((1, PaneId(21)))).unwrap();
+        assert!(sr.live_auto_read_enabled());
+        assert!(sr.tmux_pane_speech(1, PaneId(21)).is_none());
+
+        sr.set_active_tmux_pane(Some((1, PaneId(20)))).unwrap();
+        toggle_pane_auto_read(&mut sr).unwrap();
+        assert_eq!(
+            sr.tmux_pane_speech(1, PaneId(20)).unwrap().describe(),
+            "symbols most"
+        );
+        clear_pane_speech(&mut sr).unwrap();
+        clear_pane_speech(&mut sr).unwrap();
+        assert!(sr.tmux_pane_speech(1, PaneId(20)).is_none());
+
+        assert_eq!(
+            output.borrow().as_slice(),
+            [
+                "no active tmux pane",
+                "pane auto read disabled",
+                "pane symbols most",
+                "pane auto read enabled",
+                "pane speech overrides cleared",
+                "pane uses global speech settings",
+            ]
+        );
+    }
+
     #[test]
     fn system_commands_update_flags_delegate_stop_and_announce_state() {
         let (mut sr, output) = screen_reader();
@@ -12,9 +12,26 @@ pub(super) fn toggle_mode(sr: &mut ScreenReader, view: &mut View) -> Result<Comm
     }
 
     let row = view.review_cursor_position().0;
-    let Some(model) = table::detect(view, row) else {
-        sr.speak("no table found", false)?;
-        return Ok(CommandResult::Handled);
+    let title = sr.active_terminal_title().map(str::to_owned);
+    let layout = match sr.table_layouts_mut().find(view, row, title.as_deref()) {
+        Ok(layout) => layout,
+        Err(error) => {
+            sr.speak(&format!("{error:#}"), false)?;
+            None
+        }
+    };
+    let model = match layout {
+        Some((name, model)) => {
+            sr.speak(&format!("{name} layout"), false)?;
+            model
+        }
+        None => {
+            let Some(model) = table::detect(view, row) else {
+                sr.speak("no table found", false)?;
+                return Ok(CommandResult::Handled);
+            };
+            model
+        }
     };
     enter_mode_with_model(sr, view, model)?;
     Ok(CommandResult::Handled)
@@ -31,12 +48,14 @@ pub(super) fn start_setup(sr: &mut ScreenReader, view: &mut View) -> Result<Comm
     }
 
     let row = view.review_cursor_position().0;
-    if view.line(row).trim().is_empty() {
+    let header_text = view.line(row);
+    if header_text.trim().is_empty() {
         sr.speak("header row is blank", false)?;
         return Ok(CommandResult::Handled);
     }
 
-    let old_mode = sr.table_session_mut().enter_setup(row);
+    let old_mode = sr.input_mode();
+    sr.table_session_mut().enter_setup(row, header_text);
     sr.hook_on_mode_change(old_mode, sr.input_mode())?;
     sr.speak("table setup on", false)?;
     Ok(CommandResult::Handled)
@@ -76,6 +95,21 @@ pub(super) fn toggle_setup_tabstop(sr: &mut ScreenReader, view: &View) -> Result
     Ok(CommandResult::Handled)
 }
 
+/// Saves the tabstops being set up as layout `name`, matched by the setup's
+/// header line.
+pub(super) fn save_setup_layout(sr: &mut ScreenReader, name: &str) -> Result<()> {
+    let Some(setup) = sr.table_session().setup() else {
+        sr.speak("table setup not active", false)?;
+        return Ok(());
+    };
+    let layout = table::TableLayout::for_header(setup.header_text(), setup.tabstops());
+    match sr.table_layouts_mut().save(name, layout) {
+        Ok(()) => sr.speak(&format!("layout {name} saved"), false)?,
+        Err(error) => sr.speak(&format!("{error:#}"), false)?,
+    }
+    Ok(())
+}
+
 pub(super) fn commit_setup(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
     if !matches!(sr.input_mode(), InputMode::TableSetup) {
         return Ok(CommandResult::Handled);
@@ -105,15 +139,17 @@ fn enter_mode_with_model(
     let entry_row = model
         .nearest_data_row(view, anchor_row)
         .unwrap_or(anchor_row);
-    let preferred_column = model.column_for_col(view.review_cursor_position().1);
+    let preferred_column = model.column_at(view, anchor_row, old_position.1);
     let current_col = model.nearest_non_empty_col(view, entry_row, preferred_column);
     let state = TableState::new(model, current_col);
-    let column = state
-        .current_column()
+    let (start, _) = state
+        .model()
+        .cell_bounds(view, entry_row, current_col)
         .expect("detected tables always contain a current column");
-    view.set_review_cursor_position((entry_row, column.start()));
+    view.set_review_cursor_position((entry_row, start));
 
-    let old_mode = sr.table_session_mut().enter_table(state.clone());
+    let old_mode = sr.input_mode();
+    sr.table_session_mut().enter_table(state.clone());
     sr.hook_on_mode_change(old_mode, sr.input_mode())?;
     sr.hook_on_table_mode_enter(&state)?;
     review::report_move(sr, view, old_position)?;
@@ -441,6 +477,133 @@ pub(super) fn character_read(sr: &mut ScreenReader, view: &mut View) -> Result<C
     Ok(CommandResult::Handled)
 }
 
+#[derive(Copy, Clone)]
+pub(super) enum CopyScope {
+    Row,
+    Column,
+    Table,
+}
+
+pub(super) fn copy(
+    sr: &mut ScreenReader,
+    view: &mut View,
+    scope: CopyScope,
+) -> Result<CommandResult> {
+    if !ensure_state(sr, view) {
+        sr.speak("no table found", false)?;
+        return Ok(CommandResult::Handled);
+    }
+    let state = sr.table_session().navigation().unwrap().clone();
+    let model = state.model();
+    let col = state.current_col();
+    let column_cells = |row| vec![model.cell_text(view, row, col)];
+    // A copied row stands alone; columns and tables keep their header.
+    let (header, rows, alignments, what) = match scope {
+        CopyScope::Row => (
+            None,
+            vec![model.row_text(view, view.review_cursor_position().0)],
+            model.alignments().to_vec(),
+            "row",
+        ),
+        CopyScope::Column => (
+            model.header_row().map(column_cells),
+            model
+                .data_rows(view)
+                .into_iter()
+                .map(column_cells)
+                .collect(),
+            model.alignments().get(col).copied().into_iter().collect(),
+            "column",
+        ),
+        CopyScope::Table => (
+            model.header_row().map(|row| model.row_text(view, row)),
+            model
+                .data_rows(view)
+                .into_iter()
+                .map(|row| model.row_text(view, row))
+                .collect(),
+            model.alignments().to_vec(),
+            "table",
+        ),
+    };
+    let format = sr.table_export_format();
+    let text = table::render_export(format, header.as_deref(), &rows, &alignments);
+
+    let register = sr.clipboard_default_register();
+    if let Err(error) = sr.write_clipboard(register, text) {
+        sr.speak(&error.to_string(), false)?;
+    } else {
+        sr.speak(&format!("{what} copied as {format}"), false)?;
+    }
+    Ok(CommandResult::Handled)
+}
+
+pub(super) fn cycle_export_format(sr: &mut ScreenReader) -> Result<CommandResult> {
+    let format = sr.table_export_format().next();
+    sr.set_table_export_format(format);
+    sr.speak(&format!("copy tables as {format}"), false)?;
+    Ok(CommandResult::Handled)
+}
+
+/// Shows the table's data rows sorted by the current column. The copy is
+/// frozen text, so table mode ends here and can be entered again on the copy.
+pub(super) fn sort_by_column(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
+    if !ensure_state(sr, view) {
+        sr.speak("no table found", false)?;
+        return Ok(CommandResult::Handled);
+    }
+    let state = sr.table_session().navigation().unwrap().clone();
+    let model = state.model();
+    let col = state.current_col();
+    let header = model.header_row().map(|row| model.row_text(view, row));
+    let mut rows: Vec<Vec<String>> = model
+        .data_rows(view)
+        .into_iter()
+        .map(|row| model.row_text(view, row))
+        .collect();
+    table::sort_rows(&mut rows, col);
+
+    let column_name = model
+        .header_text(view, col)
+        .unwrap_or_else(|| format!("column {}", col + 1));
+    let old_mode = sr.table_session_mut().exit();
+    sr.hook_on_mode_change(old_mode, sr.input_mode())?;
+    sr.hook_on_table_mode_exit()?;
+    Ok(CommandResult::Message {
+        title: format!("table sorted by {column_name}"),
+        text: table::aligned_text(header.as_deref(), &rows),
+    })
+}
+
+pub(super) fn search_column_next(sr: &mut ScreenReader, view: &mut View) -> Result<CommandResult> {
+    if !ensure_state(sr, view) {
+        sr.speak("no table found", false)?;
+        return Ok(CommandResult::Handled);
+    }
+    let Some(query) = sr.table_session().last_search().map(str::to_owned) else {
+        sr.speak("no search", false)?;
+        return Ok(CommandResult::Handled);
+    };
+    let state = sr.table_session().navigation().unwrap().clone();
+    let old_position = view.review_cursor_position();
+    let Some((row, wrapped)) =
+        state
+            .model()
+            .find_in_column(view, state.current_col(), old_position.0, &query)
+    else {
+        sr.speak("not found", false)?;
+        return Ok(CommandResult::Handled);
+    };
+
+    move_to_cell(view, &state, row);
+    review::report_move(sr, view, old_position)?;
+    if wrapped {
+        sr.speak("wrapped", false)?;
+    }
+    speak_cell(sr, view, &state, false)?;
+    Ok(CommandResult::Handled)
+}
+
 fn ensure_state(sr: &mut ScreenReader, view: &mut View) -> bool {
     let row = view.review_cursor_position().0;
     let needs_refresh = match sr.table_session().navigation() {
@@ -452,7 +615,7 @@ fn ensure_state(sr: &mut ScreenReader, view: &mut View) -> bool {
             sr.table_session_mut().set_navigation(None);
             return false;
         };
-        let current_col = model.column_for_col(view.review_cursor_position().1);
+        let current_col = model.column_at(view, row, view.review_cursor_position().1);
         sr.table_session_mut()
             .set_navigation(Some(TableState::new(model, current_col)));
     }
@@ -463,9 +626,8 @@ fn ensure_state(sr: &mut ScreenReader, view: &mut View) -> bool {
         {
             move_to_cell(view, state, target_row);
         }
-        let current_col = state
-            .model()
-            .column_for_col(view.review_cursor_position().1);
+        let (row, col) = view.review_cursor_position();
+        let current_col = state.model().column_at(view, row, col);
         state.set_current_col(if current_col < state.model().column_count() {
             current_col
         } else {
@@ -477,9 +639,8 @@ fn ensure_state(sr: &mut ScreenReader, view: &mut View) -> bool {
 
 fn move_to_cell(view: &mut View, state: &TableState, row: u16) {
     let row = state.model().clamp_row(row);
-    if let Some(column) = state.current_column() {
-        let target_col =
-            first_text_col(view, row, column.start(), column.end()).unwrap_or(column.start());
+    if let Some((start, end)) = state.model().cell_bounds(view, row, state.current_col()) {
+        let target_col = first_text_col(view, row, start, end).unwrap_or(start);
         view.set_review_cursor_position((row, target_col));
     }
 }
@@ -487,9 +648,9 @@ fn move_to_cell(view: &mut View, state: &TableState, row: u16) {
 fn current_cell_text_bounds(sr: &ScreenReader, view: &View) -> Option<(u16, u16)> {
     let state = sr.table_session().navigation()?;
     let row = view.review_cursor_position().0;
-    let column = state.current_column()?;
-    let start = first_text_col(view, row, column.start(), column.end())?;
-    let end = last_text_col(view, row, column.start(), column.end())?;
+    let (start, end) = state.model().cell_bounds(view, row, state.current_col())?;
+    let start = first_text_col(view, row, start, end)?;
+    let end = last_text_col(view, row, start, end)?;
     Some((start, end))
 }
 
@@ -1,6 +1,7 @@
 use crate::{
     app::{self, App, Clock},
     output_scheduler::{DrainReport, OutputSchedulerConfig},
+    regions::Region,
     screen_reader::ScreenReader,
     speech,
     terminal::TerminalGeometry,
@@ -278,6 +279,58 @@ impl Harness {
                         self.sr.set_suppress_key_echo(parse_switch(payload)?);
                         Ok(())
                     }
+                    "key-echo" => {
+                        self.sr.set_key_echo_mode(payload.trim().parse()?);
+                        Ok(())
+                    }
+                    "terminal-echo" => {
+                        self.sr
+                            .set_line_discipline(Some(crate::pty::LineDiscipline {
+                                echo: parse_switch(payload)?,
+                                canonical: true,
+                            }));
+                        Ok(())
+                    }
+                    "bind" => {
+                        let Some((action, keys)) = payload.trim().split_once(' ') else {
+                            bail!("line {}: bind expects an action and keys", line_no + 1);
+                        };
+                        let action = crate::keymap::KeyBindings::builtin_action_from_value(action)?;
+                        self.sr.key_bindings_mut().set_builtin_binding_for_mode(
+                            crate::keymap::InputMode::Normal,
+                            String::from_utf8(parse_bytes(keys)?)?,
+                            action,
+                        );
+                        Ok(())
+                    }
+                    "selection-tracking" => {
+                        self.sr
+                            .set_selection_tracking_enabled(parse_switch(payload)?);
+                        Ok(())
+                    }
+                    "region" => {
+                        let parts: Vec<&str> = payload.split_whitespace().collect();
+                        let [name, mode, top, left, bottom, right] = parts.as_slice() else {
+                            bail!(
+                                "line {}: region expects name mode top left bottom right",
+                                line_no + 1
+                            );
+                        };
+                        let coordinate = |value: &str| {
+                            value.parse::<i32>().map_err(|_| {
+                                anyhow!("line {}: invalid region coordinate", line_no + 1)
+                            })
+                        };
+                        let region = Region {
+                            top: coordinate(top)?,
+                            left: coordinate(left)?,
+                            bottom: coordinate(bottom)?,
+                            right: coordinate(right)?,
+                            mode: mode.parse()?,
+                            title: None,
+                        };
+                        self.sr.regions_mut().define(name, region)
+                    }
                     "clear-speech" => {
                         self.speak_log.inner.borrow_mut().speaks.clear();
                         self.speak_cursor = 0;
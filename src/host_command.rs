@@ -3,11 +3,14 @@
 //! Lector treats tools such as `infocmp` and `tic` as hints, never as trusted
 //! startup dependencies. Capturing through files avoids pipe backpressure and
 //! helper threads, which is important when the virtual terminfo cache is built
-//! before the application PTY's fork boundary.
+//! before the application PTY's fork boundary. Only tools run from Lua after
+//! that boundary take input, which a writer thread pipes to them so it never
+//! reaches the disk.
 
 use std::{
     fs::{File, OpenOptions},
-    io::{self, ErrorKind, Read},
+    io::{self, ErrorKind, Read, Write},
+    os::unix::fs::OpenOptionsExt,
     path::{Path, PathBuf},
     process::{Command, ExitStatus, Stdio},
     sync::atomic::{AtomicU64, Ordering},
@@ -37,6 +40,19 @@ pub(crate) fn run_bounded_output(
     run_bounded_output_with_timeout(command, capture_directory, label, HOST_TOOL_TIMEOUT)
 }
 
+/// Like [`run_bounded_output`], with `input` on the command's standard input
+/// and a caller-chosen `timeout`.
+pub(crate) fn run_bounded_output_with_input(
+    command: &mut Command,
+    capture_directory: &Path,
+    label: &str,
+    input: &[u8],
+    timeout: Duration,
+) -> io::Result<BoundedOutput> {
+    let capture = CaptureFiles::create(capture_directory, label)?;
+    run_captured(command, capture, Some(input), label, timeout)
+}
+
 fn run_bounded_output_with_timeout(
     command: &mut Command,
     capture_directory: &Path,
@@ -44,11 +60,25 @@ fn run_bounded_output_with_timeout(
     timeout: Duration,
 ) -> io::Result<BoundedOutput> {
     let capture = CaptureFiles::create(capture_directory, label)?;
+    run_captured(command, capture, None, label, timeout)
+}
+
+fn run_captured(
+    command: &mut Command,
+    capture: CaptureFiles,
+    input: Option<&[u8]>,
+    label: &str,
+    timeout: Duration,
+) -> io::Result<BoundedOutput> {
     command
-        .stdin(Stdio::null())
+        .stdin(if input.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
         .stdout(Stdio::from(capture.stdout.try_clone()?))
         .stderr(Stdio::from(capture.stderr.try_clone()?));
-    let status = run_bounded(command, label, timeout)?;
+    let status = run_bounded(command, input, label, timeout)?;
     let stdout = read_bounded_file(&capture.stdout_path, HOST_TOOL_STDOUT_LIMIT);
     let stderr = read_bounded_file(&capture.stderr_path, HOST_TOOL_STDERR_LIMIT);
     let _ = std::fs::remove_file(&capture.stdout_path);
@@ -60,8 +90,21 @@ fn run_bounded_output_with_timeout(
     })
 }
 
-fn run_bounded(command: &mut Command, label: &str, timeout: Duration) -> io::Result<ExitStatus> {
+fn run_bounded(
+    command: &mut Command,
+    input: Option<&[u8]>,
+    label: &str,
+    timeout: Duration,
+) -> io::Result<ExitStatus> {
     let mut child = command.spawn()?;
+    if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+        // A tool that stops reading only blocks this thread, which ends with
+        // a broken pipe once the tool exits or is killed at the deadline.
+        let input = input.to_vec();
+        thread::spawn(move || {
+            let _ = stdin.write_all(&input);
+        });
+    }
     let deadline = Instant::now() + timeout;
     loop {
         match child.try_wait() {
@@ -119,6 +162,7 @@ impl CaptureFiles {
             let stdout = match OpenOptions::new()
                 .write(true)
                 .create_new(true)
+                .mode(0o600)
                 .open(&stdout_path)
             {
                 Ok(file) => file,
@@ -128,6 +172,7 @@ impl CaptureFiles {
             match OpenOptions::new()
                 .write(true)
                 .create_new(true)
+                .mode(0o600)
                 .open(&stderr_path)
             {
                 Ok(stderr) => {
@@ -163,9 +208,50 @@ impl Drop for CaptureFiles {
 
 #[cfg(test)]
 mod tests {
-    use super::run_bounded_output_with_timeout;
+    use super::{run_bounded_output_with_input, run_bounded_output_with_timeout};
     use std::{process::Command, time::Duration};
 
+    #[test]
+    fn input_reaches_the_host_tool_on_standard_input() {
+        let directory = tempfile::tempdir().expect("create capture directory");
+        let mut command = Command::new("sh");
+        command.args(["-c", "wc -c"]);
+        let output = run_bounded_output_with_input(
+            &mut command,
+            directory.path(),
+            "test-tool",
+            b"\0\xffpixels",
+            Duration::from_secs(5),
+        )
+        .expect("run the tool");
+
+        assert!(output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "8");
+        assert_eq!(
+            std::fs::read_dir(directory.path()).unwrap().count(),
+            0,
+            "capture files must be removed"
+        );
+    }
+
+    #[test]
+    fn host_tool_ignoring_its_input_still_finishes() {
+        let directory = tempfile::tempdir().expect("create capture directory");
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo done"]);
+        let output = run_bounded_output_with_input(
+            &mut command,
+            directory.path(),
+            "test-tool",
+            &vec![0; 4 * 1024 * 1024],
+            Duration::from_secs(5),
+        )
+        .expect("run the tool");
+
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"done\n");
+    }
+
     #[test]
     fn wedged_host_tool_is_killed_at_its_deadline() {
         let directory = tempfile::tempdir().expect("create capture directory");
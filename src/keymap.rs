@@ -13,6 +13,10 @@ pub enum Error {
     InvalidBuiltinPrefix,
     #[error("unknown action {0}")]
     UnknownAction(String),
+    #[error("mode names must be lowercase letters, digits and underscores")]
+    InvalidModeName,
+    #[error("{0} is a built-in mode")]
+    BuiltinMode(String),
 }
 
 fn lua_error(error: mlua::Error) -> Error {
@@ -20,14 +24,23 @@ fn lua_error(error: mlua::Error) -> Error {
 }
 
 pub const BUILTIN_PREFIX: &str = "lector.";
+/// Binding values that enter a user mode, such as `lector.modes.nav`.
+pub const MODE_PREFIX: &str = "lector.modes.";
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum InputMode {
     Normal,
     Table,
     TableSetup,
+    /// A modal layer declared from Lua with `lector.modes`.
+    User(UserModeId),
 }
 
+/// Index of a user-declared mode in [`KeyBindings`]. A mode keeps its index
+/// when it is removed and declared again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UserModeId(usize);
+
 impl InputMode {
     pub fn from_prefix(prefix: &str) -> Option<Self> {
         match prefix {
@@ -38,11 +51,22 @@ impl InputMode {
         }
     }
 
+    /// The name of a built-in mode. User modes all read `user`; use
+    /// [`InputMode::name`] for the name they were declared with.
     pub fn as_str(&self) -> &'static str {
         match self {
             InputMode::Normal => "normal",
             InputMode::Table => "table",
             InputMode::TableSetup => "table_setup",
+            InputMode::User(_) => "user",
+        }
+    }
+
+    /// The mode's name, looking user modes up in `bindings`.
+    pub fn name<'a>(&self, bindings: &'a KeyBindings) -> &'a str {
+        match self {
+            InputMode::User(UserModeId(index)) => &bindings.user_modes[*index].name,
+            mode => mode.as_str(),
         }
     }
 }
@@ -51,6 +75,8 @@ impl InputMode {
 pub enum Binding {
     Builtin(Action),
     Lua(LuaBinding),
+    /// Enters a user mode.
+    Mode(InputMode),
 }
 
 impl Binding {
@@ -58,6 +84,7 @@ impl Binding {
         match self {
             Binding::Builtin(action) => action.help_text(),
             Binding::Lua(binding) => &binding.help,
+            Binding::Mode(_) => "enter a key mode",
         }
     }
 
@@ -84,6 +111,7 @@ impl LuaBinding {
 
 const NORMAL_BINDINGS: &[(&str, Action)] = &[
     ("F1", Action::ToggleHelp),
+    ("M-?", Action::ListBindings),
     ("M-'", Action::ToggleAutoRead),
     ("M-\"", Action::ToggleReviewCursorFollowsScreenCursor),
     ("M-s", Action::ToggleSymbolLevel),
@@ -125,6 +153,9 @@ const NORMAL_BINDINGS: &[(&str, Action)] = &[
     ("M-}", Action::RightClick),
     ("M-T", Action::StartTableSetupMode),
     ("M-g", Action::ToggleStopSpeechOnFocusLoss),
+    ("M-S", Action::SaveTranscript),
+    ("M-V", Action::SaveSelectionTranscript),
+    ("M-E", Action::SaveCommandOutput),
 ];
 
 const TABLE_BINDINGS: &[(&str, Action)] = &[
@@ -150,12 +181,20 @@ const TABLE_BINDINGS: &[(&str, Action)] = &[
     ("H", Action::TableHeaderRead),
     ("M-h", Action::ToggleTableHeaderRead),
     ("M-H", Action::ToggleTableHeaderRead),
+    ("y", Action::TableCopyRow),
+    ("c", Action::TableCopyColumn),
+    ("Y", Action::TableCopyTable),
+    ("f", Action::CycleTableExportFormat),
+    ("s", Action::TableSortByColumn),
+    ("/", Action::TableSearchColumn),
+    ("n", Action::TableSearchColumnNext),
 ];
 
 const TABLE_SETUP_BINDINGS: &[(&str, Action)] = &[
     ("Esc", Action::CancelTableSetupMode),
     ("Enter", Action::CommitTableSetupMode),
     ("t", Action::ToggleTableSetupTabstop),
+    ("s", Action::SaveTableLayout),
     ("h", Action::RevCharPrev),
     ("l", Action::RevCharNext),
     ("i", Action::RevCharRead),
@@ -165,10 +204,17 @@ const TABLE_SETUP_BINDINGS: &[(&str, Action)] = &[
     ("b", Action::RevWordPrev),
 ];
 
+struct UserMode {
+    name: String,
+    declared: bool,
+    bindings: HashMap<String, Binding>,
+}
+
 pub struct KeyBindings {
     normal: HashMap<String, Binding>,
     table: HashMap<String, Binding>,
     table_setup: HashMap<String, Binding>,
+    user_modes: Vec<UserMode>,
 }
 
 impl KeyBindings {
@@ -177,6 +223,147 @@ impl KeyBindings {
             normal: Self::default_map(NORMAL_BINDINGS),
             table: Self::default_map(TABLE_BINDINGS),
             table_setup: Self::default_map(TABLE_SETUP_BINDINGS),
+            user_modes: Vec::new(),
+        }
+    }
+
+    /// Declares the user mode `name` with no bindings of its own, replacing
+    /// any earlier declaration, and binds `exit_key` in it to leave it.
+    pub fn declare_mode(&mut self, name: &str, exit_key: &str) -> Result<InputMode> {
+        if InputMode::from_prefix(name).is_some() {
+            return Err(Error::BuiltinMode(name.to_owned()));
+        }
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(Error::InvalidModeName);
+        }
+        let index = match self.user_modes.iter().position(|mode| mode.name == name) {
+            Some(index) => {
+                self.clear_user_mode(index);
+                index
+            }
+            None => {
+                self.user_modes.push(UserMode {
+                    name: name.to_owned(),
+                    declared: false,
+                    bindings: HashMap::new(),
+                });
+                self.user_modes.len() - 1
+            }
+        };
+        self.user_modes[index].declared = true;
+        let mode = InputMode::User(UserModeId(index));
+        self.set_builtin_binding_for_mode(mode, exit_key.to_owned(), Action::ExitMode);
+        Ok(mode)
+    }
+
+    /// Forgets the user mode `name`, its bindings and the keys that enter it.
+    pub fn remove_mode(&mut self, name: &str) {
+        if let Some(index) = self.user_mode_index(name) {
+            self.clear_user_mode(index);
+        }
+    }
+
+    /// The built-in or declared user mode called `name`.
+    pub fn mode(&self, name: &str) -> Option<InputMode> {
+        InputMode::from_prefix(name).or_else(|| {
+            self.user_mode_index(name)
+                .map(|index| InputMode::User(UserModeId(index)))
+        })
+    }
+
+    pub fn mode_name(&self, mode: InputMode) -> &str {
+        mode.name(self)
+    }
+
+    /// The bindings a mode adds over normal mode, sorted by key.
+    pub fn mode_bindings(&self, mode: InputMode) -> Vec<(&str, &Binding)> {
+        let mut bindings: Vec<_> = self
+            .bindings(mode)
+            .iter()
+            .map(|(key, binding)| (key.as_str(), binding))
+            .collect();
+        bindings.sort_by_key(|(key, _)| *key);
+        bindings
+    }
+
+    /// Every binding in effect in `mode`: its own and the normal bindings it
+    /// leaves in place, sorted by key.
+    pub fn effective_bindings(&self, mode: InputMode) -> Vec<(&str, &Binding)> {
+        let mut bindings = self.mode_bindings(mode);
+        if mode != InputMode::Normal {
+            let own = self.bindings(mode);
+            bindings.extend(
+                self.normal
+                    .iter()
+                    .filter(|(key, _)| !own.contains_key(*key))
+                    .map(|(key, binding)| (key.as_str(), binding)),
+            );
+            bindings.sort_by_key(|(key, _)| *key);
+        }
+        bindings
+    }
+
+    /// What `binding` does, naming the mode that a mode binding enters.
+    pub fn binding_help(&self, binding: &Binding) -> String {
+        match binding {
+            Binding::Mode(mode) => format!("enter {} mode", self.mode_name(*mode)),
+            _ => binding.help_text().to_owned(),
+        }
+    }
+
+    /// A spoken list of the keys of `mode` and what they do.
+    pub fn mode_help(&self, mode: InputMode) -> String {
+        let keys = self
+            .mode_bindings(mode)
+            .into_iter()
+            .map(|(key, binding)| format!("{key}, {}", self.binding_help(binding)))
+            .collect::<Vec<_>>();
+        format!("{} mode keys: {}", self.mode_name(mode), keys.join("; "))
+    }
+
+    /// The normal-mode key that enters `mode`, if it has one.
+    pub fn mode_entry_key(&self, mode: InputMode) -> Option<&str> {
+        self.normal
+            .iter()
+            .filter(|(_, binding)| matches!(binding, Binding::Mode(target) if *target == mode))
+            .map(|(key, _)| key.as_str())
+            .min()
+    }
+
+    /// The user mode a binding value such as `lector.modes.nav` enters.
+    pub fn mode_from_value(&self, value: &str) -> Option<InputMode> {
+        let index = self.user_mode_index(value.strip_prefix(MODE_PREFIX)?)?;
+        Some(InputMode::User(UserModeId(index)))
+    }
+
+    pub fn set_mode_entry_binding(&mut self, key: String, mode: InputMode) {
+        self.replace_binding(InputMode::Normal, key, Binding::Mode(mode));
+    }
+
+    fn user_mode_index(&self, name: &str) -> Option<usize> {
+        self.user_modes
+            .iter()
+            .position(|mode| mode.declared && mode.name == name)
+    }
+
+    fn clear_user_mode(&mut self, index: usize) {
+        let mode = InputMode::User(UserModeId(index));
+        self.user_modes[index].declared = false;
+        for (_, binding) in self.user_modes[index].bindings.drain() {
+            binding.cleanup();
+        }
+        let entry_keys: Vec<String> = self
+            .normal
+            .iter()
+            .filter(|(_, binding)| matches!(binding, Binding::Mode(target) if *target == mode))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in entry_keys {
+            self.clear_binding_for_mode(InputMode::Normal, &key);
         }
     }
 
@@ -188,6 +375,20 @@ impl KeyBindings {
         binding
     }
 
+    /// Whether `keys` starts a longer key sequence bound in `mode`, so the
+    /// next key completes the sequence instead of `keys` running alone.
+    pub fn is_sequence_prefix(&self, mode: InputMode, keys: &str) -> bool {
+        let prefix = format!("{keys} ");
+        let starts_sequence = |bindings: &HashMap<String, Binding>| {
+            bindings.keys().any(|key| key.starts_with(&prefix))
+        };
+        let own = self.bindings(mode);
+        starts_sequence(own)
+            || (mode != InputMode::Normal
+                && !own.contains_key(keys)
+                && starts_sequence(&self.normal))
+    }
+
     pub fn set_builtin_binding_for_mode(&mut self, mode: InputMode, key: String, action: Action) {
         self.replace_binding(mode, key, Binding::Builtin(action));
     }
@@ -236,6 +437,9 @@ impl KeyBindings {
                 BUILTIN_PREFIX,
                 commands::builtin_action_name(*action)
             ))?)),
+            Binding::Mode(mode) => Ok(Value::String(
+                lua.create_string(format!("{MODE_PREFIX}{}", self.mode_name(*mode)))?,
+            )),
             Binding::Lua(binding) => {
                 let tbl = lua.create_table()?;
                 tbl.set(1, binding.help.as_str())?;
@@ -261,7 +465,7 @@ impl KeyBindings {
         let mut parts = key.splitn(2, ':');
         let prefix = parts.next().unwrap_or("");
         let rest = parts.next();
-        if let Some(mode) = InputMode::from_prefix(prefix)
+        if let Some(mode) = self.mode(prefix)
             && let Some(rest) = rest
             && !rest.is_empty()
         {
@@ -270,8 +474,31 @@ impl KeyBindings {
         (InputMode::Normal, key)
     }
 
+    /// Binds `key` in `mode`. A key cannot both run a binding and start a
+    /// sequence, so bindings that `key` would shadow or be shadowed by, such
+    /// as `M-s` for `M-s l`, are removed.
     fn replace_binding(&mut self, mode: InputMode, key: String, binding: Binding) {
-        if let Some(prev) = self.bindings_mut(mode).insert(key, binding) {
+        let bindings = self.bindings_mut(mode);
+        let conflicting: Vec<String> = bindings
+            .keys()
+            .filter(|bound| {
+                let (shorter, longer) = if bound.len() < key.len() {
+                    (bound.as_str(), key.as_str())
+                } else {
+                    (key.as_str(), bound.as_str())
+                };
+                longer
+                    .strip_prefix(shorter)
+                    .is_some_and(|rest| rest.starts_with(' '))
+            })
+            .cloned()
+            .collect();
+        for bound in conflicting {
+            if let Some(prev) = bindings.remove(&bound) {
+                prev.cleanup();
+            }
+        }
+        if let Some(prev) = bindings.insert(key, binding) {
             prev.cleanup();
         }
     }
@@ -288,6 +515,7 @@ impl KeyBindings {
             InputMode::Normal => &self.normal,
             InputMode::Table => &self.table,
             InputMode::TableSetup => &self.table_setup,
+            InputMode::User(UserModeId(index)) => &self.user_modes[index].bindings,
         }
     }
 
@@ -296,6 +524,7 @@ impl KeyBindings {
             InputMode::Normal => &mut self.normal,
             InputMode::Table => &mut self.table,
             InputMode::TableSetup => &mut self.table_setup,
+            InputMode::User(UserModeId(index)) => &mut self.user_modes[index].bindings,
         }
     }
 }
@@ -330,7 +559,7 @@ mod tests {
         let binding = bindings.binding_for_mode(InputMode::Normal, "M-f").unwrap();
         match binding {
             Binding::Lua(binding) => binding.call().unwrap(),
-            Binding::Builtin(_) => panic!("expected lua binding"),
+            Binding::Builtin(_) | Binding::Mode(_) => panic!("expected lua binding"),
         }
 
         let count: i32 = lua.globals().get("count").unwrap();
@@ -415,18 +644,139 @@ mod tests {
 
     #[test]
     fn input_modes_round_trip_through_configuration_names() {
+        let bindings = KeyBindings::new();
         for (name, mode) in [
             ("normal", InputMode::Normal),
             ("table", InputMode::Table),
             ("table_setup", InputMode::TableSetup),
         ] {
             assert_eq!(InputMode::from_prefix(name), Some(mode));
-            assert_eq!(mode.as_str(), name);
+            assert_eq!(bindings.mode_name(mode), name);
         }
         assert_eq!(InputMode::from_prefix("TABLE"), None);
         assert_eq!(InputMode::from_prefix(""), None);
     }
 
+    #[test]
+    fn key_sequences_replace_the_bindings_they_shadow() {
+        let mut bindings = KeyBindings::new();
+        bindings.set_builtin_binding_for_mode(
+            InputMode::Normal,
+            "M-s l".to_string(),
+            Action::RevLineRead,
+        );
+        assert!(
+            bindings
+                .binding_for_mode(InputMode::Normal, "M-s")
+                .is_none()
+        );
+        assert!(bindings.is_sequence_prefix(InputMode::Normal, "M-s"));
+        assert!(bindings.is_sequence_prefix(InputMode::Table, "M-s"));
+        assert!(!bindings.is_sequence_prefix(InputMode::Normal, "M-s l"));
+        assert!(matches!(
+            bindings.binding_for_mode(InputMode::Table, "M-s l"),
+            Some(Binding::Builtin(Action::RevLineRead))
+        ));
+
+        bindings.set_builtin_binding_for_mode(
+            InputMode::Table,
+            "M-s".to_string(),
+            Action::TableCellRead,
+        );
+        assert!(!bindings.is_sequence_prefix(InputMode::Table, "M-s"));
+
+        bindings.set_builtin_binding_for_mode(
+            InputMode::Normal,
+            "M-s".to_string(),
+            Action::ToggleSymbolLevel,
+        );
+        assert!(
+            bindings
+                .binding_for_mode(InputMode::Normal, "M-s l")
+                .is_none()
+        );
+        assert!(!bindings.is_sequence_prefix(InputMode::Normal, "M-s"));
+    }
+
+    #[test]
+    fn key_sequences_can_end_in_space() {
+        let mut bindings = KeyBindings::new();
+        bindings.set_builtin_binding_for_mode(
+            InputMode::Normal,
+            "M-s  ".to_string(),
+            Action::RevLineRead,
+        );
+        assert!(bindings.is_sequence_prefix(InputMode::Normal, "M-s"));
+        assert!(!bindings.is_sequence_prefix(InputMode::Normal, "M-s  "));
+        assert!(matches!(
+            bindings.binding_for_mode(InputMode::Normal, "M-s  "),
+            Some(Binding::Builtin(Action::RevLineRead))
+        ));
+        assert!(
+            bindings
+                .binding_for_mode(InputMode::Normal, "M-s")
+                .is_none()
+        );
+
+        bindings.set_builtin_binding_for_mode(
+            InputMode::Normal,
+            " ".to_string(),
+            Action::RevCharRead,
+        );
+        assert!(
+            bindings
+                .binding_for_mode(InputMode::Normal, "M-s  ")
+                .is_some()
+        );
+        assert!(!bindings.is_sequence_prefix(InputMode::Normal, " "));
+    }
+
+    #[test]
+    fn user_modes_layer_over_normal_bindings_until_removed() {
+        let mut bindings = KeyBindings::new();
+        assert!(matches!(
+            bindings.declare_mode("table", "Esc"),
+            Err(Error::BuiltinMode(_))
+        ));
+        assert!(matches!(
+            bindings.declare_mode("Nav", "Esc"),
+            Err(Error::InvalidModeName)
+        ));
+
+        let nav = bindings.declare_mode("nav", "q").unwrap();
+        bindings.set_builtin_binding_for_mode(nav, "j".to_string(), Action::RevLineNext);
+        bindings.set_mode_entry_binding("M-N".to_string(), nav);
+        assert_eq!(bindings.split_mode_key("nav:j"), (nav, "j"));
+        assert_eq!(bindings.mode_from_value("lector.modes.nav"), Some(nav));
+        assert_eq!(bindings.mode_entry_key(nav), Some("M-N"));
+        assert!(matches!(
+            bindings.binding_for_mode(nav, "F1"),
+            Some(Binding::Builtin(Action::ToggleHelp))
+        ));
+        assert_eq!(
+            bindings.mode_help(nav),
+            "nav mode keys: j, next line; q, leave the current key mode"
+        );
+
+        let effective = bindings.effective_bindings(nav);
+        assert!(effective.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(effective.iter().any(|(key, _)| *key == "F1"));
+        assert_eq!(
+            bindings.binding_help(bindings.binding_for_mode(nav, "M-N").unwrap()),
+            "enter nav mode"
+        );
+
+        bindings.remove_mode("nav");
+        assert_eq!(bindings.mode("nav"), None);
+        assert!(
+            bindings
+                .binding_for_mode(InputMode::Normal, "M-N")
+                .is_none()
+        );
+        assert_eq!(bindings.declare_mode("nav", "Esc").unwrap(), nav);
+        assert!(bindings.binding_for_mode(nav, "j").is_none());
+    }
+
     #[test]
     fn builtin_bindings_can_be_replaced_and_cleared_per_mode() {
         let mut bindings = KeyBindings::new();
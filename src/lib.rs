@@ -15,19 +15,24 @@ pub mod platform;
 pub mod presentation;
 pub mod proc_server_common;
 pub mod pty;
+mod regions;
 mod review;
 pub mod screen_reader;
+mod selection;
 pub mod speech;
 mod table;
 pub mod terminal;
 pub mod terminal_input;
 pub mod terminal_protocol;
+pub mod tmux_activity;
 pub mod tmux_control;
 pub mod tmux_gateway;
 pub mod tmux_input;
+pub mod tmux_launch;
 pub mod tmux_lifecycle;
 pub mod tmux_model;
 pub mod tmux_panes;
 pub mod tmux_prefix;
+pub mod tmux_workspace;
 pub mod view;
 pub mod views;
@@ -106,6 +106,8 @@ local tbl_lector_clipboard = setmetatable({}, {
             return tbl_lector_clipboard_internal
         elseif k == 'system' then
             return tbl_lector_clipboard_system
+        elseif type(k) == 'string' and k:match('^%l$') then
+            return clipboard_namespace(k, false)
         end
         error("unknown clipboard namespace: " .. tostring(k), 2)
     end,
@@ -151,6 +153,103 @@ local tbl_lector_o_mt = {
 }
 local tbl_lector_o = setmetatable({}, tbl_lector_o_mt)
 
+local tbl_lector_table_layouts = setmetatable({}, {
+    __index = function(_, k)
+        if type(k) ~= "string" then
+            error("table layout name must be a string for indexing", 2)
+        end
+        return callbacks.get_table_layout(k)
+    end,
+    __newindex = function(_, k, v)
+        if type(k) ~= "string" then
+            error("table layout name must be a string", 2)
+        end
+        if v ~= nil and type(v) ~= "table" then
+            error("table layout must be a table {header, title, tabstops} or nil", 2)
+        end
+        callbacks.set_table_layout(k, v)
+    end,
+})
+
+local tbl_lector_selection_hints = setmetatable({}, {
+    __index = function(_, k)
+        if type(k) ~= "string" then
+            error("selection hint name must be a string for indexing", 2)
+        end
+        return callbacks.get_selection_hint(k)
+    end,
+    __newindex = function(_, k, v)
+        if type(k) ~= "string" then
+            error("selection hint name must be a string", 2)
+        end
+        if v ~= nil and type(v) ~= "table" then
+            error("selection hint must be a table {title, style, marker} or nil", 2)
+        end
+        callbacks.set_selection_hint(k, v)
+    end,
+})
+
+local tbl_lector_regions = setmetatable({}, {
+    __index = function(_, k)
+        if type(k) ~= "string" then
+            error("region name must be a string for indexing", 2)
+        end
+        return callbacks.get_region(k)
+    end,
+    __newindex = function(_, k, v)
+        if type(k) ~= "string" then
+            error("region name must be a string", 2)
+        end
+        if v ~= nil and type(v) ~= "table" then
+            error("region must be a table {top, left, bottom, right, mode, title, key} or nil", 2)
+        end
+        if v ~= nil and v.key ~= nil and type(v.key) ~= "string" then
+            error("region key must be a string", 2)
+        end
+        callbacks.set_region(k, v)
+        if v ~= nil and v.key ~= nil then
+            set_binding(v.key, { "read region " .. k, function() lector.api.read_region(k) end })
+        end
+    end,
+})
+
+local tbl_lector_modes = setmetatable({}, {
+    __index = function(_, k)
+        if type(k) ~= "string" then
+            error("mode name must be a string for indexing", 2)
+        end
+        return callbacks.get_mode(k)
+    end,
+    __newindex = function(_, k, v)
+        if type(k) ~= "string" then
+            error("mode name must be a string", 2)
+        end
+        if v ~= nil and type(v) ~= "table" then
+            error("mode must be a table {key, exit, bindings} or nil", 2)
+        end
+        if v ~= nil then
+            if v.key ~= nil and type(v.key) ~= "string" then
+                error("mode key must be a string", 2)
+            end
+            if v.exit ~= nil and type(v.exit) ~= "string" then
+                error("mode exit key must be a string", 2)
+            end
+            if v.bindings ~= nil and type(v.bindings) ~= "table" then
+                error("mode bindings must be a table", 2)
+            end
+        end
+        callbacks.set_mode(k, v and (v.exit or "Esc"))
+        if v ~= nil then
+            for key, binding in pairs(v.bindings or {}) do
+                set_binding(k .. ":" .. key, binding)
+            end
+            if v.key ~= nil then
+                set_binding(v.key, "lector.modes." .. k)
+            end
+        end
+    end,
+})
+
 local function set_hook(name, fn)
     if type(name) ~= "string" then
         error("hook name must be a string", 2)
@@ -186,6 +285,14 @@ local tbl_lector_mt = {
             return tbl_lector_hooks
         elseif k == 'clipboard' then
             return tbl_lector_clipboard
+        elseif k == 'table_layouts' then
+            return tbl_lector_table_layouts
+        elseif k == 'selection_hints' then
+            return tbl_lector_selection_hints
+        elseif k == 'regions' then
+            return tbl_lector_regions
+        elseif k == 'modes' then
+            return tbl_lector_modes
         else
             return rawget(t, k)
         end
@@ -220,6 +327,14 @@ local tbl_lector_mt = {
             error("assign individual hooks via lector.hooks[name] = value", 2)
         elseif k == "clipboard" then
             error("assign clipboard contents through the internal or system namespace", 2)
+        elseif k == "table_layouts" then
+            error("assign individual layouts via lector.table_layouts[name] = value", 2)
+        elseif k == "selection_hints" then
+            error("assign individual hints via lector.selection_hints[name] = value", 2)
+        elseif k == "regions" then
+            error("assign individual regions via lector.regions[name] = value", 2)
+        elseif k == "modes" then
+            error("assign individual modes via lector.modes[name] = value", 2)
         else
             error("cannot assign to arbitrary keys on the lector table", 2)
         end
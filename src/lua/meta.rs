@@ -1,9 +1,14 @@
 use super::ext::LuaResultExt;
 use crate::{
     clipboard::{ClipboardRegister, SystemClipboardProvider},
-    keymap::KeyBindings,
-    screen_reader::ScreenReader,
+    keymap::{InputMode, KeyBindings},
+    regions::{Region, RegionMode},
+    screen_reader::{LinkOpener, PaneSpeechProfile, ScreenReader},
+    selection::SelectionHint,
     speech::{SpeechServerSpec, symbols},
+    table::TableLayout,
+    tmux_launch::{TmuxAttachRequest, TmuxSocket},
+    tmux_model::PaneId,
 };
 use anyhow::{Context as AnyhowContext, anyhow};
 use mlua::{Error, Function, IntoLua, Lua, Result, Table, Value};
@@ -25,7 +30,15 @@ macro_rules! add_callbacks_common {
         clear_clipboard = $clear_clipboard:expr,
         get_clipboard_entries = $get_clipboard_entries:expr,
         get_clipboard_index = $get_clipboard_index:expr,
-        set_clipboard_index = $set_clipboard_index:expr $(,)?
+        set_clipboard_index = $set_clipboard_index:expr,
+        set_table_layout = $set_table_layout:expr,
+        get_table_layout = $get_table_layout:expr,
+        set_selection_hint = $set_selection_hint:expr,
+        get_selection_hint = $get_selection_hint:expr,
+        set_region = $set_region:expr,
+        get_region = $get_region:expr,
+        set_mode = $set_mode:expr,
+        get_mode = $get_mode:expr $(,)?
     ) => {{
         $tbl.set("set_option", $set_option)?;
         $tbl.set("get_option", $get_option)?;
@@ -42,6 +55,14 @@ macro_rules! add_callbacks_common {
         $tbl.set("get_clipboard_entries", $get_clipboard_entries)?;
         $tbl.set("get_clipboard_index", $get_clipboard_index)?;
         $tbl.set("set_clipboard_index", $set_clipboard_index)?;
+        $tbl.set("set_table_layout", $set_table_layout)?;
+        $tbl.set("get_table_layout", $get_table_layout)?;
+        $tbl.set("set_selection_hint", $set_selection_hint)?;
+        $tbl.set("get_selection_hint", $get_selection_hint)?;
+        $tbl.set("set_region", $set_region)?;
+        $tbl.set("get_region", $get_region)?;
+        $tbl.set("set_mode", $set_mode)?;
+        $tbl.set("get_mode", $get_mode)?;
         Ok(())
     }};
 }
@@ -216,6 +237,113 @@ fn add_callbacks_static(
             })
         }
     })?;
+    let set_table_layout = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, (name, value): (String, Value)| {
+            with_screen_reader_mut(&sr_ptr, |sr| {
+                let layouts = sr.table_layouts_mut();
+                match value {
+                    Value::Nil => layouts.remove(&name),
+                    value => table_layout_from_lua(value)
+                        .and_then(|layout| layouts.define(&name, layout)),
+                }
+                .map_err(Error::external)
+            })
+        }
+    })?;
+    let get_table_layout = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |lua, name: String| {
+            with_screen_reader_mut(&sr_ptr, |sr| {
+                match sr.table_layouts_mut().get(&name).map_err(Error::external)? {
+                    Some(layout) => table_layout_to_lua(lua, &layout),
+                    None => Ok(Value::Nil),
+                }
+            })
+        }
+    })?;
+    let set_selection_hint = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, (name, value): (String, Value)| {
+            with_screen_reader_mut(&sr_ptr, |sr| {
+                let hints = sr.selection_hints_mut();
+                match value {
+                    Value::Nil => {
+                        hints.remove(&name);
+                        Ok(())
+                    }
+                    value => {
+                        selection_hint_from_lua(value).and_then(|hint| hints.define(&name, hint))
+                    }
+                }
+                .map_err(Error::external)
+            })
+        }
+    })?;
+    let get_selection_hint = lua.create_function({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |lua, name: String| {
+            with_screen_reader(&sr_ptr, |sr| match sr.selection_hints().get(&name) {
+                Some(hint) => selection_hint_to_lua(lua, hint),
+                None => Ok(Value::Nil),
+            })
+        }
+    })?;
+    let set_region = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, (name, value): (String, Value)| {
+            with_screen_reader_mut(&sr_ptr, |sr| {
+                let regions = sr.regions_mut();
+                match value {
+                    Value::Nil => {
+                        regions.remove(&name);
+                        Ok(())
+                    }
+                    value => {
+                        region_from_lua(value).and_then(|region| regions.define(&name, region))
+                    }
+                }
+                .map_err(Error::external)
+            })
+        }
+    })?;
+    let get_region = lua.create_function({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |lua, name: String| {
+            with_screen_reader(&sr_ptr, |sr| match sr.regions().get(&name) {
+                Some(region) => region_to_lua(lua, region),
+                None => Ok(Value::Nil),
+            })
+        }
+    })?;
+    let set_mode = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, (name, exit_key): (String, Option<String>)| {
+            with_screen_reader_mut(&sr_ptr, |sr| {
+                if sr
+                    .user_mode()
+                    .is_some_and(|mode| sr.key_bindings().mode_name(mode) == name)
+                {
+                    sr.exit_user_mode().map_err(Error::external)?;
+                }
+                let bindings = sr.key_bindings_mut();
+                match exit_key {
+                    Some(exit_key) => bindings
+                        .declare_mode(&name, &exit_key)
+                        .map(|_| ())
+                        .map_err(Error::external),
+                    None => {
+                        bindings.remove_mode(&name);
+                        Ok(())
+                    }
+                }
+            })
+        }
+    })?;
+    let get_mode = lua.create_function({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |lua, name: String| with_screen_reader(&sr_ptr, |sr| mode_to_lua(lua, sr, &name))
+    })?;
 
     add_callbacks_common!(
         tbl_callbacks,
@@ -234,14 +362,204 @@ fn add_callbacks_static(
         get_clipboard_entries = get_clipboard_entries,
         get_clipboard_index = get_clipboard_index,
         set_clipboard_index = set_clipboard_index,
+        set_table_layout = set_table_layout,
+        get_table_layout = get_table_layout,
+        set_selection_hint = set_selection_hint,
+        get_selection_hint = get_selection_hint,
+        set_region = set_region,
+        get_region = get_region,
+        set_mode = set_mode,
+        get_mode = get_mode,
     )
 }
 
+/// `{key, bindings}` for a user mode, or nil when none is declared.
+fn mode_to_lua(lua: &Lua, sr: &ScreenReader, name: &str) -> Result<Value> {
+    let Some(mode) = sr
+        .key_bindings()
+        .mode(name)
+        .filter(|mode| matches!(mode, InputMode::User(_)))
+    else {
+        return Ok(Value::Nil);
+    };
+    let allow_function = sr.owns_lua_context(lua);
+    let table = lua.create_table()?;
+    table.set("key", sr.key_bindings().mode_entry_key(mode))?;
+    let bindings = lua.create_table()?;
+    for (key, _) in sr.key_bindings().mode_bindings(mode) {
+        let value = sr
+            .key_bindings()
+            .binding_value_for_lua_mode(mode, key, lua, allow_function)?;
+        bindings.set(key, value)?;
+    }
+    table.set("bindings", bindings)?;
+    Ok(Value::Table(table))
+}
+
+fn table_layout_from_lua(value: Value) -> anyhow::Result<TableLayout> {
+    let Value::Table(table) = value else {
+        return Err(anyhow!("table layout must be a table or nil"));
+    };
+    let pattern = |key: &str| -> anyhow::Result<Option<String>> {
+        table
+            .get::<Option<String>>(key)
+            .map_err(|_| anyhow!("table layout {key} must be a string"))
+    };
+    let tabstops: Vec<u16> = table
+        .get("tabstops")
+        .map_err(|_| anyhow!("table layout tabstops must be a list of column numbers"))?;
+    Ok(TableLayout {
+        header: pattern("header")?,
+        title: pattern("title")?,
+        tabstops,
+    })
+}
+
+fn table_layout_to_lua(lua: &Lua, layout: &TableLayout) -> Result<Value> {
+    let table = lua.create_table()?;
+    table.set("header", layout.header.as_deref())?;
+    table.set("title", layout.title.as_deref())?;
+    table.set(
+        "tabstops",
+        lua.create_sequence_from(layout.tabstops.iter().copied())?,
+    )?;
+    Ok(Value::Table(table))
+}
+
+fn selection_hint_from_lua(value: Value) -> anyhow::Result<SelectionHint> {
+    let Value::Table(table) = value else {
+        return Err(anyhow!("selection hint must be a table or nil"));
+    };
+    let field = |key: &str| -> anyhow::Result<Option<String>> {
+        table
+            .get::<Option<String>>(key)
+            .map_err(|_| anyhow!("selection hint {key} must be a string"))
+    };
+    let Some(title) = field("title")? else {
+        return Err(anyhow!("selection hint needs a title pattern"));
+    };
+    let style = match field("style")?.as_deref() {
+        None | Some("none") => None,
+        Some(style) => Some(style.parse()?),
+    };
+    Ok(SelectionHint {
+        title,
+        style,
+        marker: field("marker")?,
+    })
+}
+
+fn selection_hint_to_lua(lua: &Lua, hint: &SelectionHint) -> Result<Value> {
+    let table = lua.create_table()?;
+    table.set("title", hint.title.as_str())?;
+    let style = hint
+        .style
+        .map_or_else(|| "none".to_owned(), |style| style.to_string());
+    table.set("style", style)?;
+    table.set("marker", hint.marker.as_deref())?;
+    Ok(Value::Table(table))
+}
+
+fn region_from_lua(value: Value) -> anyhow::Result<Region> {
+    let Value::Table(table) = value else {
+        return Err(anyhow!("region must be a table or nil"));
+    };
+    let coordinate = |key: &str| -> anyhow::Result<Option<i32>> {
+        table
+            .get::<Option<i32>>(key)
+            .map_err(|_| anyhow!("region {key} must be a row or column number"))
+    };
+    let Some(top) = coordinate("top")? else {
+        return Err(anyhow!("region needs a top row"));
+    };
+    let mode = match table
+        .get::<Option<String>>("mode")
+        .map_err(|_| anyhow!("region mode must be a string"))?
+    {
+        Some(mode) => mode.parse()?,
+        None => RegionMode::default(),
+    };
+    Ok(Region {
+        top,
+        left: coordinate("left")?.unwrap_or(0),
+        bottom: coordinate("bottom")?.unwrap_or(top),
+        right: coordinate("right")?.unwrap_or(-1),
+        mode,
+        title: table
+            .get::<Option<String>>("title")
+            .map_err(|_| anyhow!("region title must be a string"))?,
+    })
+}
+
+fn region_to_lua(lua: &Lua, region: &Region) -> Result<Value> {
+    let table = lua.create_table()?;
+    table.set("top", region.top)?;
+    table.set("left", region.left)?;
+    table.set("bottom", region.bottom)?;
+    table.set("right", region.right)?;
+    table.set("mode", region.mode.to_string())?;
+    table.set("title", region.title.as_deref())?;
+    Ok(Value::Table(table))
+}
+
 fn clipboard_register(name: &str) -> anyhow::Result<ClipboardRegister> {
     match name {
         "internal" => Ok(ClipboardRegister::Internal),
         "system" => Ok(ClipboardRegister::System),
-        _ => Err(anyhow!("clipboard namespace must be internal or system")),
+        name => name
+            .parse()
+            .ok()
+            .filter(|register| matches!(register, ClipboardRegister::Named(_)))
+            .ok_or_else(|| {
+                anyhow!("clipboard namespace must be internal, system, or a letter from a to z")
+            }),
+    }
+}
+
+/// Options `get_option` answers, for REPL completion of `lector.o`.
+const OPTION_NAMES: &[&str] = &[
+    "announce_cwd",
+    "auto_read",
+    "clipboard",
+    "help_mode",
+    "highlight_tracking",
+    "key_echo",
+    "link_opener",
+    "mouse_hover",
+    "notifications",
+    "progress_step",
+    "repl_scratch_file",
+    "report_indentation",
+    "review_follows_screen_cursor",
+    "selection_tracking",
+    "speech",
+    "speech_rate",
+    "stop_speech_on_focus_loss",
+    "suppress_key_echo",
+    "symbol_level",
+    "table_export_format",
+    "tmux_bells",
+];
+
+/// Names the `lector` tables in meta.lua answer through `__index`
+/// functions, which leave them no keys of their own to complete.
+pub(crate) fn proxy_names(path: &str) -> &'static [&'static str] {
+    match path {
+        "lector" => &[
+            "bindings",
+            "clipboard",
+            "hooks",
+            "modes",
+            "o",
+            "regions",
+            "selection_hints",
+            "symbols",
+            "table_layouts",
+        ],
+        "lector.o" => OPTION_NAMES,
+        "lector.o.clipboard" => &["default_register", "system_provider"],
+        "lector.hooks" => crate::screen_reader::HOOK_NAMES,
+        _ => &[],
     }
 }
 
@@ -253,15 +571,24 @@ fn get_option(lua: &Lua, sr: &ScreenReader, option: &str) -> anyhow::Result<mlua
         "help_mode" => sr.help_mode().into_lua(lua),
         "auto_read" => sr.auto_read_enabled().into_lua(lua),
         "suppress_key_echo" => sr.suppress_key_echo().into_lua(lua),
+        "key_echo" => sr.key_echo_mode().to_string().into_lua(lua),
         "report_indentation" => sr.indentation_reporting_enabled().into_lua(lua),
         "review_follows_screen_cursor" | "rev_follows" => {
             sr.review_follows_screen_cursor().into_lua(lua)
         }
         "highlight_tracking" => sr.highlight_tracking_enabled().into_lua(lua),
+        "selection_tracking" => sr.selection_tracking_enabled().into_lua(lua),
         "stop_speech_on_focus_loss" => sr.stop_speech_on_focus_loss().into_lua(lua),
+        "table_export_format" => sr.table_export_format().to_string().into_lua(lua),
         "tmux_bells" => sr.tmux_bell_mode().to_string().into_lua(lua),
         "clipboard.default_register" => sr.clipboard_default_register().to_string().into_lua(lua),
         "clipboard.system_provider" => sr.system_clipboard_provider().to_string().into_lua(lua),
+        "link_opener" => link_opener_to_lua(lua, sr.link_opener()),
+        "notifications" => sr.notifications_enabled().into_lua(lua),
+        "progress_step" => sr.progress_step().into_lua(lua),
+        "announce_cwd" => sr.announce_cwd_enabled().into_lua(lua),
+        "mouse_hover" => sr.mouse_hover_enabled().into_lua(lua),
+        "repl_scratch_file" => sr.repl_scratch_file().into_lua(lua),
         _ => Err(Error::external(anyhow!("unknown option"))),
     }
     .map_err(|e| anyhow!("{}", e))
@@ -277,6 +604,11 @@ fn set_binding(lua: &Lua, sr: &mut ScreenReader, key: &str, value: Value) -> any
         }
         Value::String(name) => {
             let name = name.to_str().map_err(|err| anyhow!(err.to_string()))?;
+            if let Some(target) = sr.key_bindings().mode_from_value(name.as_ref()) {
+                sr.key_bindings_mut()
+                    .set_mode_entry_binding(key.to_string(), target);
+                return Ok(());
+            }
             let action = KeyBindings::builtin_action_from_value(name.as_ref())?;
             sr.key_bindings_mut()
                 .set_builtin_binding_for_mode(mode, key.to_string(), action);
@@ -367,6 +699,17 @@ fn set_option(sr: &mut ScreenReader, option: &str, value: mlua::Value) -> anyhow
             }
             _ => Err(anyhow!("value must be a boolean")),
         },
+        "key_echo" => match value {
+            String(v) => {
+                let mode = v
+                    .to_str()
+                    .map_err(|e| anyhow!(e.to_string()))?
+                    .parse::<crate::screen_reader::KeyEchoMode>()?;
+                sr.set_key_echo_mode(mode);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a string")),
+        },
         "report_indentation" => match value {
             Boolean(v) => {
                 sr.set_indentation_reporting_enabled(v);
@@ -388,6 +731,13 @@ fn set_option(sr: &mut ScreenReader, option: &str, value: mlua::Value) -> anyhow
             }
             _ => Err(anyhow!("value must be a boolean")),
         },
+        "selection_tracking" => match value {
+            Boolean(v) => {
+                sr.set_selection_tracking_enabled(v);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a boolean")),
+        },
         "stop_speech_on_focus_loss" => match value {
             Boolean(v) => {
                 sr.set_stop_speech_on_focus_loss(v);
@@ -395,6 +745,17 @@ fn set_option(sr: &mut ScreenReader, option: &str, value: mlua::Value) -> anyhow
             }
             _ => Err(anyhow!("value must be a boolean")),
         },
+        "table_export_format" => match value {
+            String(v) => {
+                let format = v
+                    .to_str()
+                    .map_err(|e| anyhow!(e.to_string()))?
+                    .parse::<crate::table::ExportFormat>()?;
+                sr.set_table_export_format(format);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a string")),
+        },
         "tmux_bells" => match value {
             String(v) => {
                 let mode = v
@@ -428,6 +789,49 @@ fn set_option(sr: &mut ScreenReader, option: &str, value: mlua::Value) -> anyhow
             }
             _ => Err(anyhow!("value must be a string")),
         },
+        "link_opener" => {
+            sr.set_link_opener(link_opener_from_lua(value)?);
+            Ok(())
+        }
+        "notifications" => match value {
+            Boolean(v) => {
+                sr.set_notifications_enabled(v);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a boolean")),
+        },
+        "progress_step" => match value {
+            Integer(v) if (0..=100).contains(&v) => {
+                sr.set_progress_step(v as u8);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be an integer from 0 to 100")),
+        },
+        "announce_cwd" => match value {
+            Boolean(v) => {
+                sr.set_announce_cwd_enabled(v);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a boolean")),
+        },
+        "mouse_hover" => match value {
+            Boolean(v) => {
+                sr.set_mouse_hover_enabled(v);
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a boolean")),
+        },
+        "repl_scratch_file" => match value {
+            Nil => {
+                sr.set_repl_scratch_file(None);
+                Ok(())
+            }
+            String(path) => {
+                sr.set_repl_scratch_file(Some(lua_utf8(&path, "scratch file")?));
+                Ok(())
+            }
+            _ => Err(anyhow!("value must be a path string or nil")),
+        },
         _ => Err(anyhow!("unknown option")),
     })
     .map_err(|e| anyhow!("set option: {}: {:?}", option, e))
@@ -468,14 +872,46 @@ pub(super) fn speech_server_spec_to_lua(lua: &Lua, spec: &SpeechServerSpec) -> m
 }
 
 fn parse_process_speech_server(table: Table) -> anyhow::Result<SpeechServerSpec> {
+    let (program, args) = parse_process_table(table, "speech server")?;
+    Ok(SpeechServerSpec::Process { program, args })
+}
+
+/// Accepts `nil` or `{program = ..., args = {...}}`.
+fn link_opener_from_lua(value: Value) -> anyhow::Result<Option<LinkOpener>> {
+    match value {
+        Value::Nil => Ok(None),
+        Value::Table(table) => {
+            let (program, args) = parse_process_table(table, "link opener")?;
+            Ok(Some(LinkOpener { program, args }))
+        }
+        _ => Err(anyhow!(
+            "link opener must be nil or a table with program and args"
+        )),
+    }
+}
+
+fn link_opener_to_lua(lua: &Lua, opener: Option<&LinkOpener>) -> mlua::Result<Value> {
+    let Some(opener) = opener else {
+        return Ok(Value::Nil);
+    };
+    let table = lua.create_table()?;
+    table.set("program", opener.program.as_str())?;
+    table.set(
+        "args",
+        lua.create_sequence_from(opener.args.iter().map(String::as_str))?,
+    )?;
+    Ok(Value::Table(table))
+}
+
+fn parse_process_table(table: Table, label: &str) -> anyhow::Result<(String, Vec<String>)> {
     for pair in table.clone().pairs::<Value, Value>() {
         let (key, _) = pair.map_err(|error| anyhow!(error.to_string()))?;
         let Value::String(key) = key else {
-            return Err(anyhow!("speech server table keys must be strings"));
+            return Err(anyhow!("{label} table keys must be strings"));
         };
-        match lua_utf8(&key, "speech server table key")?.as_str() {
+        match lua_utf8(&key, &format!("{label} table key"))?.as_str() {
             "program" | "args" => {}
-            key => return Err(anyhow!("unknown speech server field: {key}")),
+            key => return Err(anyhow!("unknown {label} field: {key}")),
         }
     }
 
@@ -483,46 +919,46 @@ fn parse_process_speech_server(table: Table) -> anyhow::Result<SpeechServerSpec>
         .get::<Value>("program")
         .map_err(|error| anyhow!(error.to_string()))?;
     let Value::String(program_value) = program_value else {
-        return Err(anyhow!("speech server program must be a string"));
+        return Err(anyhow!("{label} program must be a string"));
     };
-    let program = lua_utf8(&program_value, "speech server program")?;
+    let program = lua_utf8(&program_value, &format!("{label} program"))?;
     if program.is_empty() {
-        return Err(anyhow!("speech server program must not be empty"));
+        return Err(anyhow!("{label} program must not be empty"));
     }
-    reject_nul(&program, "speech server program")?;
+    reject_nul(&program, &format!("{label} program"))?;
 
     let args = match table
         .get::<Value>("args")
         .map_err(|error| anyhow!(error.to_string()))?
     {
         Value::Nil => Vec::new(),
-        Value::Table(args) => parse_speech_server_args(args)?,
-        _ => return Err(anyhow!("speech server args must be an array of strings")),
+        Value::Table(args) => parse_process_args(args, label)?,
+        _ => return Err(anyhow!("{label} args must be an array of strings")),
     };
 
-    Ok(SpeechServerSpec::Process { program, args })
+    Ok((program, args))
 }
 
-fn parse_speech_server_args(table: Table) -> anyhow::Result<Vec<String>> {
+fn parse_process_args(table: Table, label: &str) -> anyhow::Result<Vec<String>> {
     let mut indexed = BTreeMap::new();
     for pair in table.pairs::<Value, Value>() {
         let (key, value) = pair.map_err(|error| anyhow!(error.to_string()))?;
         let Value::Integer(index) = key else {
             return Err(anyhow!(
-                "speech server args must have consecutive integer indexes starting at 1"
+                "{label} args must have consecutive integer indexes starting at 1"
             ));
         };
         let index = usize::try_from(index)
             .ok()
             .filter(|index| *index > 0)
             .ok_or_else(|| {
-                anyhow!("speech server args must have consecutive integer indexes starting at 1")
+                anyhow!("{label} args must have consecutive integer indexes starting at 1")
             })?;
         let Value::String(value) = value else {
-            return Err(anyhow!("speech server argument {index} must be a string"));
+            return Err(anyhow!("{label} argument {index} must be a string"));
         };
-        let value = lua_utf8(&value, &format!("speech server argument {index}"))?;
-        reject_nul(&value, &format!("speech server argument {index}"))?;
+        let value = lua_utf8(&value, &format!("{label} argument {index}"))?;
+        reject_nul(&value, &format!("{label} argument {index}"))?;
         indexed.insert(index, value);
     }
 
@@ -530,7 +966,7 @@ fn parse_speech_server_args(table: Table) -> anyhow::Result<Vec<String>> {
     for expected in 1..=indexed.len() {
         let Some(value) = indexed.remove(&expected) else {
             return Err(anyhow!(
-                "speech server args must have consecutive integer indexes starting at 1"
+                "{label} args must have consecutive integer indexes starting at 1"
             ));
         };
         args.push(value);
@@ -538,6 +974,145 @@ fn parse_speech_server_args(table: Table) -> anyhow::Result<Vec<String>> {
     Ok(args)
 }
 
+/// Accepts `"SESSION"`, `"SOCKET:SESSION"`, or `{session = ..., socket = ...}`.
+pub(super) fn tmux_attach_request_from_lua(value: Value) -> anyhow::Result<TmuxAttachRequest> {
+    match value {
+        Value::String(value) => {
+            let target = lua_utf8(&value, "tmux target")?;
+            Ok(TmuxAttachRequest::parse_target(&target)?)
+        }
+        Value::Table(table) => parse_tmux_attach_table(table),
+        _ => Err(anyhow!(
+            "tmux target must be a string or a table with session and socket"
+        )),
+    }
+}
+
+fn parse_tmux_attach_table(table: Table) -> anyhow::Result<TmuxAttachRequest> {
+    for pair in table.clone().pairs::<Value, Value>() {
+        let (key, _) = pair.map_err(|error| anyhow!(error.to_string()))?;
+        let Value::String(key) = key else {
+            return Err(anyhow!("tmux target table keys must be strings"));
+        };
+        match lua_utf8(&key, "tmux target table key")?.as_str() {
+            "session" | "socket" => {}
+            key => return Err(anyhow!("unknown tmux target field: {key}")),
+        }
+    }
+
+    let Value::String(session) = table
+        .get::<Value>("session")
+        .map_err(|error| anyhow!(error.to_string()))?
+    else {
+        return Err(anyhow!("tmux session must be a string"));
+    };
+    let session = lua_utf8(&session, "tmux session")?;
+    let socket = match table
+        .get::<Value>("socket")
+        .map_err(|error| anyhow!(error.to_string()))?
+    {
+        Value::Nil => TmuxSocket::Default,
+        Value::String(socket) => TmuxSocket::parse(&lua_utf8(&socket, "tmux socket")?)?,
+        _ => return Err(anyhow!("tmux socket must be a string")),
+    };
+    Ok(TmuxAttachRequest::new(socket, &session)?)
+}
+
+/// A `set_tmux_pane_speech` request. Without an explicit target it applies
+/// to the active tmux pane.
+pub(super) struct PaneSpeechRequest {
+    pub(super) target: Option<(u64, PaneId)>,
+    pub(super) profile: PaneSpeechProfile,
+}
+
+pub(super) fn pane_speech_request_from_lua(value: Value) -> anyhow::Result<PaneSpeechRequest> {
+    let Value::Table(table) = value else {
+        return Err(anyhow!("pane speech must be a table"));
+    };
+    for pair in table.clone().pairs::<Value, Value>() {
+        let (key, _) = pair.map_err(|error| anyhow!(error.to_string()))?;
+        let Value::String(key) = key else {
+            return Err(anyhow!("pane speech table keys must be strings"));
+        };
+        match lua_utf8(&key, "pane speech table key")?.as_str() {
+            "connection" | "pane" | "auto_read" | "symbol_level" | "voice" => {}
+            key => return Err(anyhow!("unknown pane speech field: {key}")),
+        }
+    }
+    let get = |field: &str| {
+        table
+            .get::<Value>(field)
+            .map_err(|error| anyhow!(error.to_string()))
+    };
+    let target = match (get("connection")?, get("pane")?) {
+        (Value::Nil, Value::Nil) => None,
+        (Value::Integer(connection), Value::Integer(pane)) if connection > 0 && pane >= 0 => {
+            Some((connection as u64, PaneId(pane as u64)))
+        }
+        _ => {
+            return Err(anyhow!(
+                "pane speech connection and pane must both be given as numeric IDs"
+            ));
+        }
+    };
+    let auto_read = match get("auto_read")? {
+        Value::Nil => None,
+        Value::Boolean(value) => Some(value),
+        _ => return Err(anyhow!("pane auto_read must be a boolean")),
+    };
+    let symbol_level = match get("symbol_level")? {
+        Value::Nil => None,
+        Value::String(level) => Some(
+            lua_utf8(&level, "pane symbol_level")?
+                .parse::<symbols::Level>()
+                .map_err(|_| {
+                    anyhow!("pane symbol_level must be none, some, most, all, or character")
+                })?,
+        ),
+        _ => return Err(anyhow!("pane symbol_level must be a string")),
+    };
+    let voice = match get("voice")? {
+        Value::Nil => None,
+        Value::String(voice) => {
+            let voice = lua_utf8(&voice, "pane voice")?;
+            if voice.is_empty() {
+                return Err(anyhow!("pane voice must not be empty"));
+            }
+            reject_nul(&voice, "pane voice")?;
+            Some(voice)
+        }
+        _ => return Err(anyhow!("pane voice must be a string")),
+    };
+    Ok(PaneSpeechRequest {
+        target,
+        profile: PaneSpeechProfile {
+            auto_read,
+            symbol_level,
+            voice,
+        },
+    })
+}
+
+pub(super) fn pane_speech_to_lua(
+    lua: &Lua,
+    (connection_id, pane_id): (u64, PaneId),
+    profile: &PaneSpeechProfile,
+) -> mlua::Result<Value> {
+    let table = lua.create_table()?;
+    table.set("connection", connection_id)?;
+    table.set("pane", pane_id.0)?;
+    if let Some(auto_read) = profile.auto_read {
+        table.set("auto_read", auto_read)?;
+    }
+    if let Some(level) = profile.symbol_level {
+        table.set("symbol_level", level.to_string())?;
+    }
+    if let Some(voice) = &profile.voice {
+        table.set("voice", voice.as_str())?;
+    }
+    Ok(Value::Table(table))
+}
+
 fn lua_utf8(value: &mlua::String, field: &str) -> anyhow::Result<String> {
     value
         .to_str()
@@ -1,12 +1,20 @@
 use self::ext::LuaResultExt;
-use crate::screen_reader::ScreenReader;
+use crate::{host_command::run_bounded_output_with_input, screen_reader::ScreenReader};
 use anyhow::{Context as AnyhowContext, anyhow};
 use mlua::{Error, Function, Lua, LuaOptions, Result, StdLib, Value};
-use std::{cell::RefCell, fs::File, io::Read, path::PathBuf, rc::Rc};
+use std::{
+    cell::RefCell, fs::File, io::Read, path::PathBuf, process::Command, rc::Rc, time::Duration,
+};
+
+/// Long enough for a local OCR or captioning program. Lector reads no output
+/// and handles no keys while it waits, so the bound stays short.
+const LUA_HOST_COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
 
 mod ext;
 mod meta;
 
+pub(crate) use meta::proxy_names;
+
 pub fn setup<F>(
     init_lua_file: PathBuf,
     load_init_file: bool,
@@ -89,19 +97,153 @@ fn install_api_static(lua: &Lua, sr_ptr: Rc<RefCell<*mut ScreenReader>>) -> Resu
             sr.speak(&text, interrupt).to_lua_result()
         }
     })?;
-    let set_speech_fn = lua.create_function_mut(move |_, value: Value| {
+    let set_speech_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, value: Value| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            let spec = meta::speech_server_spec_from_lua(value).map_err(Error::external)?;
+            sr.request_speech_reconfiguration(spec);
+            Ok(())
+        }
+    })?;
+    let tmux_attach_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, value: Value| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            let request = meta::tmux_attach_request_from_lua(value).map_err(Error::external)?;
+            sr.request_tmux_attach(request);
+            Ok(())
+        }
+    })?;
+    let set_tmux_pane_speech_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, value: Value| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            let request = meta::pane_speech_request_from_lua(value).map_err(Error::external)?;
+            let Some((connection_id, pane_id)) = request.target.or(sr.active_tmux_pane()) else {
+                return Err(Error::external(anyhow!("no active tmux pane")));
+            };
+            sr.set_tmux_pane_speech(connection_id, pane_id, request.profile)
+                .map_err(Error::external)
+        }
+    })?;
+    let read_region_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, name: String| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            if sr.regions().get(&name).is_none() {
+                return Err(Error::external(anyhow!("no region named {name}")));
+            }
+            sr.regions_mut().request_read(&name);
+            Ok(())
+        }
+    })?;
+    let enter_mode_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, name: String| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            sr.enter_user_mode(&name).map_err(Error::external)
+        }
+    })?;
+    let exit_mode_fn = lua.create_function_mut({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, ()| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &mut *ptr };
+            sr.exit_user_mode().map_err(Error::external)
+        }
+    })?;
+    let mode_fn = lua.create_function({
+        let sr_ptr = Rc::clone(&sr_ptr);
+        move |_, ()| {
+            let ptr = *sr_ptr.borrow();
+            if ptr.is_null() {
+                return Err(Error::external(anyhow!("screen reader unavailable")));
+            }
+            // Safety: pointer is set by the main thread before any Lua call.
+            let sr = unsafe { &*ptr };
+            Ok(sr.input_mode_name().to_owned())
+        }
+    })?;
+    let tmux_pane_speech_fn = lua.create_function(move |lua, ()| {
         let ptr = *sr_ptr.borrow();
         if ptr.is_null() {
             return Err(Error::external(anyhow!("screen reader unavailable")));
         }
         // Safety: pointer is set by the main thread before any Lua call.
-        let sr = unsafe { &mut *ptr };
-        let spec = meta::speech_server_spec_from_lua(value).map_err(Error::external)?;
-        sr.request_speech_reconfiguration(spec);
-        Ok(())
+        let sr = unsafe { &*ptr };
+        let Some(target) = sr.active_tmux_pane() else {
+            return Ok(Value::Nil);
+        };
+        let profile = sr
+            .tmux_pane_speech(target.0, target.1)
+            .cloned()
+            .unwrap_or_default();
+        meta::pane_speech_to_lua(lua, target, &profile)
     })?;
+    let host_command_fn = lua.create_function(
+        |lua, (program, args, input): (String, Option<Vec<String>>, Option<mlua::String>)| {
+            let mut command = Command::new(&program);
+            command.args(args.unwrap_or_default());
+            let input = input.map(|input| input.as_bytes().to_vec());
+            let output = run_bounded_output_with_input(
+                &mut command,
+                &std::env::temp_dir(),
+                "lua-host-command",
+                input.as_deref().unwrap_or_default(),
+                LUA_HOST_COMMAND_TIMEOUT,
+            )
+            .map_err(|error| Error::external(anyhow!("{program}: {error}")))?;
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                let detail = stderr
+                    .lines()
+                    .find(|line| !line.trim().is_empty())
+                    .map_or_else(|| output.status.to_string(), |line| line.trim().to_owned());
+                return Err(Error::external(anyhow!("{program} failed: {detail}")));
+            }
+            lua.create_string(&output.stdout)
+        },
+    )?;
     tbl_api.set("speak", speak_fn)?;
     tbl_api.set("set_speech", set_speech_fn)?;
+    tbl_api.set("tmux_attach", tmux_attach_fn)?;
+    tbl_api.set("set_tmux_pane_speech", set_tmux_pane_speech_fn)?;
+    tbl_api.set("tmux_pane_speech", tmux_pane_speech_fn)?;
+    tbl_api.set("read_region", read_region_fn)?;
+    tbl_api.set("enter_mode", enter_mode_fn)?;
+    tbl_api.set("exit_mode", exit_mode_fn)?;
+    tbl_api.set("mode", mode_fn)?;
+    tbl_api.set("host_command", host_command_fn)?;
     tbl_lector.set("api", tbl_api)?;
     lua.globals().set("lector", tbl_lector)?;
     Ok(())
@@ -109,12 +251,14 @@ fn install_api_static(lua: &Lua, sr_ptr: Rc<RefCell<*mut ScreenReader>>) -> Resu
 
 #[cfg(test)]
 mod tests {
-    use super::{setup, setup_repl};
+    use super::{proxy_names, setup, setup_repl};
     use crate::{
         keymap::{Binding, InputMode},
-        screen_reader::ScreenReader,
+        screen_reader::{NoticeSource, ScreenReader},
         speech::{self, SpeechServerSpec, symbols::Level},
-        table::{Column, TableModel, TableState},
+        table::{Column, ExportFormat, TableFormat, TableModel, TableState},
+        terminal::{ProgressState, TerminalEvent},
+        tmux_model::PaneId,
         view::View,
     };
     use mlua::Lua;
@@ -220,6 +364,198 @@ mod tests {
         fs::remove_file(path).unwrap();
     }
 
+    #[test]
+    fn link_opener_option_round_trips_and_rejects_malformed_tables() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                assert(lector.o.link_opener == nil)
+                lector.o.link_opener = {program = "xdg-open"}
+                assert(lector.o.link_opener.program == "xdg-open")
+                assert(#lector.o.link_opener.args == 0)
+                for _, opener in ipairs({
+                    "xdg-open",
+                    {program = ""},
+                    {program = "open", args = {1}},
+                    {program = "open", url = "x"},
+                }) do
+                    local ok, message = pcall(function()
+                        lector.o.link_opener = opener
+                    end)
+                    assert(not ok)
+                    assert(string.find(tostring(message), "link opener") ~= nil)
+                end
+                lector.o.link_opener = {program = "open", args = {"-g"}}
+            "#,
+        )
+        .exec()
+        .unwrap();
+
+        assert_eq!(
+            screen_reader.link_opener(),
+            Some(&crate::screen_reader::LinkOpener {
+                program: "open".to_owned(),
+                args: vec!["-g".to_owned()],
+            })
+        );
+        lua.load("lector.o.link_opener = nil").exec().unwrap();
+        assert_eq!(screen_reader.link_opener(), None);
+    }
+
+    #[test]
+    fn completion_names_for_lector_proxies_are_readable() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        for path in ["lector", "lector.o", "lector.o.clipboard", "lector.hooks"] {
+            assert!(!proxy_names(path).is_empty());
+            for name in proxy_names(path) {
+                lua.load(format!("local _ = {path}.{name}"))
+                    .exec()
+                    .unwrap_or_else(|err| panic!("{path}.{name}: {err}"));
+            }
+        }
+        assert!(proxy_names("lector.api").is_empty());
+    }
+
+    #[test]
+    fn table_layouts_are_defined_read_and_removed_from_lua() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                assert(lector.table_layouts.ps == nil)
+                lector.table_layouts.ps = {header = "^ *PID +USER", tabstops = {12, 6}}
+                local layout = lector.table_layouts.ps
+                assert(layout.header == "^ *PID +USER")
+                assert(layout.title == nil)
+                assert(layout.tabstops[1] == 6 and layout.tabstops[2] == 12)
+                for _, bad in ipairs({
+                    {tabstops = {4}},
+                    {header = "(", tabstops = {4}},
+                    {header = "x", tabstops = {}},
+                    {header = "x", tabstops = {0}},
+                    "x",
+                }) do
+                    assert(not pcall(function() lector.table_layouts.bad = bad end))
+                end
+                lector.table_layouts.ps = nil
+                assert(lector.table_layouts.ps == nil)
+            "#,
+        )
+        .exec()
+        .unwrap();
+    }
+
+    #[test]
+    fn selection_hints_and_tracking_are_configured_from_lua() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                assert(lector.o.selection_tracking == false)
+                lector.o.selection_tracking = true
+                lector.selection_hints.fzf = {title = "^fzf", marker = "▌"}
+                local hint = lector.selection_hints.fzf
+                assert(hint.title == "^fzf" and hint.style == "none" and hint.marker == "▌")
+                lector.selection_hints.vim = {title = "VIM", style = "none"}
+                for _, bad in ipairs({
+                    {style = "bold"},
+                    {title = "(", style = "bold"},
+                    {title = "x", style = "italic"},
+                    {title = "x", marker = ""},
+                    "x",
+                }) do
+                    assert(not pcall(function() lector.selection_hints.bad = bad end))
+                end
+                lector.selection_hints.vim = nil
+                assert(lector.selection_hints.vim == nil)
+            "#,
+        )
+        .exec()
+        .unwrap();
+        assert!(screen_reader.selection_tracking_enabled());
+        assert_eq!(
+            screen_reader
+                .selection_hints()
+                .for_title(Some("fzf - picker"))
+                .and_then(|hint| hint.marker.as_deref()),
+            Some("▌")
+        );
+    }
+
+    #[test]
+    fn user_modes_are_declared_entered_and_removed_from_lua() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                lector.modes.nav = {
+                    key = "M-N",
+                    bindings = {j = "lector.review_line_next", k = "lector.review_line_prev"},
+                }
+                local nav = lector.modes.nav
+                assert(nav.key == "M-N")
+                assert(nav.bindings.j == "lector.review_line_next")
+                assert(nav.bindings.Esc == "lector.exit_mode")
+                assert(lector.bindings["M-N"] == "lector.modes.nav")
+                assert(lector.bindings["nav:k"] == "lector.review_line_prev")
+                assert(lector.api.mode() == "normal")
+                lector.api.enter_mode("nav")
+                assert(lector.api.mode() == "nav")
+                assert(lector.api.exit_mode() == true)
+                assert(lector.api.exit_mode() == false)
+                for _, bad in ipairs({"table", "Bad"}) do
+                    assert(not pcall(function() lector.modes[bad] = {} end))
+                end
+                assert(not pcall(function() lector.modes.x = {exit = 1} end))
+                assert(not pcall(function() lector.api.enter_mode("missing") end))
+                lector.api.enter_mode("nav")
+                lector.modes.nav = nil
+                assert(lector.modes.nav == nil)
+                assert(lector.bindings["M-N"] == nil)
+            "#,
+        )
+        .exec()
+        .unwrap();
+        assert_eq!(screen_reader.input_mode(), InputMode::Normal);
+    }
+
+    #[test]
+    fn host_commands_receive_input_and_report_failures() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                local output = lector.api.host_command("sh", {"-c", "wc -c"}, "pix\0els")
+                assert(tonumber(output) == 7)
+                assert(lector.api.host_command("echo", {"a b"}) == "a b\n")
+                local ok, message = pcall(lector.api.host_command, "sh", {"-c", "echo nope >&2; exit 1"})
+                assert(not ok and tostring(message):find("sh failed: nope", 1, true))
+            "#,
+        )
+        .exec()
+        .unwrap();
+    }
+
     #[test]
     fn repl_requires_explicit_nonblocking_speech_reconfiguration() {
         let mut screen_reader = screen_reader();
@@ -299,6 +635,95 @@ mod tests {
         assert_eq!(screen_reader.take_speech_reconfiguration(), None);
     }
 
+    #[test]
+    fn tmux_attach_requests_are_validated_before_queueing() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                local invalid = {
+                    42,
+                    "",
+                    "bad.session",
+                    {},
+                    {session = "work", unknown = true},
+                    {session = "work", socket = 1},
+                    {session = "work", socket = ""},
+                }
+                for _, target in ipairs(invalid) do
+                    assert(pcall(lector.api.tmux_attach, target) == false)
+                end
+                lector.api.tmux_attach("first")
+                lector.api.tmux_attach({session = "work", socket = "/tmp/tmux/sock"})
+            "#,
+        )
+        .exec()
+        .unwrap();
+
+        let request = screen_reader.take_tmux_attach_request().unwrap();
+        assert_eq!(
+            request.description(),
+            "tmux session work on socket /tmp/tmux/sock"
+        );
+        assert_eq!(screen_reader.take_tmux_attach_request(), None);
+    }
+
+    #[test]
+    fn tmux_pane_speech_targets_the_active_pane_and_validates_fields() {
+        let mut screen_reader = screen_reader();
+        let lua = Lua::new();
+        let screen_reader_ptr = Rc::new(RefCell::new(&mut screen_reader as *mut ScreenReader));
+        setup_repl(&lua, screen_reader_ptr).unwrap();
+
+        lua.load(
+            r#"
+                assert(lector.api.tmux_pane_speech() == nil)
+                assert(pcall(lector.api.set_tmux_pane_speech, {auto_read = false}) == false)
+                local invalid = {
+                    42,
+                    {auto_read = "no"},
+                    {symbol_level = "loud"},
+                    {voice = ""},
+                    {connection = 1},
+                    {connection = 1, pane = -1},
+                    {volume = 1},
+                }
+                for _, profile in ipairs(invalid) do
+                    assert(pcall(lector.api.set_tmux_pane_speech, profile) == false)
+                end
+                lector.api.set_tmux_pane_speech({connection = 2, pane = 5, voice = "Alex"})
+            "#,
+        )
+        .exec()
+        .unwrap();
+        screen_reader
+            .set_active_tmux_pane(Some((1, crate::tmux_model::PaneId(20))))
+            .unwrap();
+        lua.load(
+            r#"
+                lector.api.set_tmux_pane_speech({auto_read = false, symbol_level = "none"})
+                local profile = lector.api.tmux_pane_speech()
+                assert(profile.connection == 1 and profile.pane == 20)
+                assert(profile.auto_read == false and profile.symbol_level == "none")
+                assert(profile.voice == nil)
+            "#,
+        )
+        .exec()
+        .unwrap();
+
+        assert_eq!(
+            screen_reader
+                .tmux_pane_speech(2, crate::tmux_model::PaneId(5))
+                .unwrap()
+                .describe(),
+            "voice Alex"
+        );
+        assert!(!screen_reader.live_auto_read_enabled());
+    }
+
     #[test]
     fn configuration_and_hooks_round_trip_through_the_lua_api() {
         let output = Rc::new(RefCell::new(Vec::new()));
@@ -313,19 +738,37 @@ mod tests {
             &path,
             r#"
                 assert(lector.o.suppress_key_echo == false)
+                assert(lector.o.key_echo == "none")
+                assert(pcall(function() lector.o.key_echo = "all" end) == false)
                 assert(lector.o.report_indentation == true)
                 assert(lector.o.tmux_bells == "audible")
                 assert(lector.o.clipboard.default_register == '"')
                 assert(lector.o.clipboard.system_provider == "native")
+                assert(lector.o.notifications == false)
+                assert(lector.o.progress_step == 0)
+                assert(lector.o.announce_cwd == false)
+                assert(lector.o.mouse_hover == false)
+                assert(lector.o.repl_scratch_file == nil)
+                assert(pcall(function() lector.o.repl_scratch_file = 1 end) == false)
+                assert(lector.o.table_export_format == "tsv")
+                assert(pcall(function() lector.o.table_export_format = "xml" end) == false)
+                assert(pcall(function() lector.o.progress_step = 101 end) == false)
                 lector.o.auto_read = false
                 lector.o.suppress_key_echo = true
+                lector.o.key_echo = "words"
+                lector.o.mouse_hover = true
+                lector.o.repl_scratch_file = "~/scratch.lua"
                 lector.o.report_indentation = false
                 lector.o.tmux_bells = "spoken"
+                lector.o.table_export_format = "markdown"
                 lector.o.clipboard.default_register = "+"
                 lector.o.clipboard.system_provider = "osc52"
                 lector.o.symbol_level = "all"
                 lector.symbols = { ["?"] = {"query", "all", "never", false} }
                 lector.bindings["M-z"] = "lector.stop_speaking"
+                lector.bindings["M-s  l"] = "lector.review_line_read"
+                assert(lector.bindings["M-s l"] == "lector.review_line_read")
+                assert(lector.bindings["M-s"] == nil)
                 lector.bindings["M-v"] = {
                     "custom binding",
                     function() lector.o.review_follows_screen_cursor = false end,
@@ -344,6 +787,20 @@ mod tests {
                 lector.hooks.on_key_unhandled = function(key, mode)
                     return key == "q" and mode == "table"
                 end
+                lector.hooks.on_notification = function(n)
+                    if n.source == "tmux" and n.connection == 1 and n.pane == 2 and n.body == "done" then
+                        lector.o.progress_step = 10
+                    end
+                    return true
+                end
+                lector.hooks.on_cwd_change = function(c)
+                    lector.o.announce_cwd = c.source == "root" and c.directory == "/my dir"
+                    return true
+                end
+                lector.hooks.on_progress = function(p)
+                    lector.o.notifications = p.source == "root" and p.state == "error" and p.progress == 40
+                    return true
+                end
                 lector.clipboard.internal.text = "older"
                 lector.clipboard.internal.text = "newer"
                 assert(lector.clipboard.internal.text == "newer")
@@ -356,6 +813,21 @@ mod tests {
                 local readable = pcall(function() return lector.clipboard.system.text end)
                 assert(readable == false)
                 lector.clipboard.system.text = nil
+                lector.clipboard.q.text = "named"
+                assert(lector.clipboard.q.text == "named" and lector.clipboard.r.text == nil)
+                lector.clipboard.q.text = nil
+                assert(lector.clipboard.q.text == nil)
+                lector.regions.status = {top = -1}
+                lector.regions.clock = {top = 0, left = -5, mode = "watch", title = "^vim", key = "M-1"}
+                local clock = lector.regions.clock
+                assert(clock.bottom == 0 and clock.right == -1 and clock.mode == "watch")
+                assert(lector.regions.status.mode == "silence" and lector.regions.status.title == nil)
+                assert(pcall(function() lector.regions.bad = {top = 2, bottom = 1} end) == false)
+                assert(pcall(function() lector.regions.bad = {left = 1} end) == false)
+                assert(pcall(function() lector.regions.bad = {top = 1, mode = "loud"} end) == false)
+                assert(pcall(lector.api.read_region, "missing") == false)
+                lector.regions.status = nil
+                assert(lector.regions.status == nil)
                 lector.o.auto_read = false
             "#,
         )
@@ -371,8 +843,12 @@ mod tests {
             assert!(sr.help_mode());
             assert!(!sr.auto_read_enabled());
             assert!(sr.suppress_key_echo());
+            assert_eq!(sr.key_echo_mode().to_string(), "words");
+            assert!(sr.mouse_hover_enabled());
+            assert_eq!(sr.repl_scratch_file(), Some("~/scratch.lua"));
             assert!(!sr.indentation_reporting_enabled());
             assert_eq!(sr.tmux_bell_mode().to_string(), "spoken");
+            assert_eq!(sr.table_export_format(), ExportFormat::Markdown);
             assert_eq!(sr.clipboard_default_register().to_string(), "+");
             assert_eq!(sr.system_clipboard_provider().to_string(), "osc52");
             assert_eq!(sr.clipboard_text(), Some("older"));
@@ -399,6 +875,18 @@ mod tests {
             binding.call()?;
             assert!(!sr.review_follows_screen_cursor());
 
+            let Some(Binding::Lua(binding)) =
+                sr.key_bindings().binding_for_mode(InputMode::Normal, "M-1")
+            else {
+                panic!("expected region binding");
+            };
+            assert_eq!(binding.help, "read region clock");
+            binding.call()?;
+            assert_eq!(
+                sr.regions_mut().take_read_request().as_deref(),
+                Some("clock")
+            );
+
             sr.speak("?", false)?;
             assert!(!sr.help_mode());
             assert!(!sr.auto_read_enabled());
@@ -408,6 +896,30 @@ mod tests {
             );
             assert!(sr.hook_on_key_unhandled(Some("q"), InputMode::Table)?);
             assert!(!sr.hook_on_key_unhandled(Some("x"), InputMode::Normal)?);
+            sr.handle_terminal_notice(
+                NoticeSource::TmuxPane(1, PaneId(2)),
+                &TerminalEvent::DesktopNotification {
+                    title: "build".to_owned(),
+                    body: "done".to_owned(),
+                },
+                0,
+            )?;
+            assert_eq!(sr.progress_step(), 10);
+            sr.handle_terminal_notice(
+                NoticeSource::Root,
+                &TerminalEvent::ProgressReport {
+                    state: ProgressState::Error,
+                    progress: Some(40),
+                },
+                0,
+            )?;
+            assert!(sr.notifications_enabled());
+            sr.handle_terminal_notice(
+                NoticeSource::Root,
+                &TerminalEvent::WorkingDirectoryChanged("file://host/my%20dir".to_owned()),
+                0,
+            )?;
+            assert!(sr.announce_cwd_enabled());
 
             sr.hook_on_review_cursor_move((0, 0), (0, 1))?;
             assert!(sr.highlight_tracking_enabled());
@@ -420,7 +932,7 @@ mod tests {
                     1,
                     vec![Column::new(0, 1), Column::new(2, 3)],
                     Some(0),
-                    None,
+                    TableFormat::FixedWidth,
                 ),
                 0,
             );
@@ -9,6 +9,7 @@ use lector::{
     terminal_protocol::{
         CapabilityOverrides, PhysicalTerminalProfile, ShutdownFenceBroker, TerminfoCapabilities,
     },
+    tmux_launch::{TMUX_PROGRAM, TmuxAttachRequest, TmuxSocket},
     views,
 };
 use nix::fcntl::{FcntlArg, OFlag, fcntl};
@@ -837,9 +838,9 @@ mod tests {
         SetupFailureAction, ShutdownFenceBroker, ShutdownFenceOutcome, TerminalSignalAction,
         drain_available_input, drain_available_pty, drain_shutdown_fence_input,
         emergency_terminal_cleanup_bytes, parse_focus_mode_report, requested_config_path,
-        resolved_default_config_path, runtime_setup_error, separate_focus_mode_report_input,
-        setup_failure_action, startup_deadline_poll_timeout, stdout_retry_poll_timeout,
-        terminal_signal_action, wait_for_shutdown_fence,
+        resolved_default_config_path, root_tmux_attach, runtime_setup_error,
+        separate_focus_mode_report_input, setup_failure_action, startup_deadline_poll_timeout,
+        stdout_retry_poll_timeout, terminal_signal_action, wait_for_shutdown_fence,
     };
     use clap::{CommandFactory, Parser};
     use nix::fcntl::{FcntlArg, OFlag, fcntl};
@@ -1134,6 +1135,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tmux_attach_replaces_the_shell_as_root_child() {
+        let cli = Cli::try_parse_from([
+            "lector",
+            "--tmux-attach",
+            "work",
+            "--tmux-socket",
+            "/tmp/tmux-test/sock",
+        ])
+        .expect("parse tmux attach");
+        let request = root_tmux_attach(&cli)
+            .unwrap()
+            .expect("tmux attach request");
+        assert_eq!(
+            request.args(),
+            [
+                "-S",
+                "/tmp/tmux-test/sock",
+                "-CC",
+                "new-session",
+                "-A",
+                "-s",
+                "work"
+            ]
+            .map(std::ffi::OsString::from)
+        );
+
+        let cli = Cli::try_parse_from(["lector", "--tmux-attach", "bad:name"]).unwrap();
+        assert!(root_tmux_attach(&cli).is_err());
+        assert!(
+            Cli::try_parse_from(["lector", "--shell", "/bin/sh", "--tmux-socket", "alt"]).is_err()
+        );
+    }
+
     #[test]
     fn lua_config_flags_are_explicit_and_mutually_exclusive() {
         let configured = Cli::try_parse_from([
@@ -1561,9 +1596,15 @@ struct Cli {
         long,
         short = 's',
         env,
-        required_unless_present = "native_speech_server"
+        required_unless_present_any = ["native_speech_server", "tmux_attach"]
     )]
     shell: Option<std::path::PathBuf>,
+    /// Start or attach this tmux session in control mode instead of the shell
+    #[clap(long, value_name = "SESSION")]
+    tmux_attach: Option<String>,
+    /// tmux socket for --tmux-attach: a name for -L, or a path (containing /) for -S
+    #[clap(long, value_name = "SOCKET", requires = "tmux_attach")]
+    tmux_socket: Option<String>,
     /// Load Lua configuration from this file
     #[clap(long, conflicts_with = "no_config")]
     config: Option<PathBuf>,
@@ -1584,6 +1625,19 @@ struct Cli {
     native_speech_parent_pid: Option<u32>,
 }
 
+/// With `--tmux-attach`, tmux itself is the root child; its control-mode
+/// marker is detected exactly as if a shell had run the same command.
+fn root_tmux_attach(cli: &Cli) -> Result<Option<TmuxAttachRequest>> {
+    let Some(session) = cli.tmux_attach.as_deref() else {
+        return Ok(None);
+    };
+    let socket = match cli.tmux_socket.as_deref() {
+        Some(socket) => TmuxSocket::parse(socket)?,
+        None => TmuxSocket::Default,
+    };
+    Ok(Some(TmuxAttachRequest::new(socket, session)?))
+}
+
 struct DiagnosticsShutdownGuard;
 
 impl Drop for DiagnosticsShutdownGuard {
@@ -1597,10 +1651,16 @@ fn main() -> Result<()> {
     if cli.native_speech_server {
         return lector::native_tts_server::run(cli.native_speech_parent_pid);
     }
-    let shell = cli
-        .shell
-        .as_ref()
-        .ok_or_else(|| anyhow!("--shell is required"))?;
+    let (program, args) = match root_tmux_attach(&cli)? {
+        Some(request) => (PathBuf::from(TMUX_PROGRAM), request.args()),
+        None => {
+            let shell = cli
+                .shell
+                .clone()
+                .ok_or_else(|| anyhow!("--shell is required"))?;
+            (shell, Vec::new())
+        }
+    };
     // Resolve the environment fallback ourselves so an explicit --no-config
     // can recover even when LECTOR_CONFIG points at a broken file. Clap would
     // otherwise treat an env-provided value as conflicting CLI input.
@@ -1630,8 +1690,8 @@ fn main() -> Result<()> {
         termios::tcgetattr(std::io::stdin().as_fd()).context("read terminal settings")?;
     let virtual_environment = pty::compatible_terminal_environment();
     let mut process = pty::Process::spawn_with_geometry_and_environment(
-        shell,
-        std::iter::empty::<&str>(),
+        &program,
+        &args,
         terminal_geometry,
         &init_term_attrs,
         Some(&virtual_environment),
@@ -1642,7 +1702,7 @@ fn main() -> Result<()> {
     // diagnostics worker until that fork boundary has completed.
     if logging_enabled {
         diagnostics::initialize(cli.log_file.as_deref())?;
-        diagnostics::event("main", "startup", &format!("shell={}", shell.display()));
+        diagnostics::event("main", "startup", &format!("shell={}", program.display()));
     }
     let diagnostics_shutdown = DiagnosticsShutdownGuard;
 
@@ -1672,6 +1732,7 @@ fn main() -> Result<()> {
     );
     let speech = speech::Speech::new(speech_driver);
     let mut screen_reader = ScreenReader::new(speech);
+    screen_reader.use_default_table_layouts_path();
     let view_stack = views::ViewStack::new(Box::new(views::PtyView::new_with_geometry(
         terminal_geometry,
     )));
@@ -1803,6 +1864,7 @@ fn do_events(
     // unwind restores the descriptor flags before this writes its final reset.
     let mut emergency_terminal = EmergencyTerminalGuard::new(initial_term_attrs);
     let mut pty_stream = process.stream().context("get PTY stream")?;
+    sr.set_line_discipline_probe(Some(process.line_discipline_probe()?));
     let _nonblocking_pty =
         NonblockingFdGuard::enable(pty_stream.as_raw_fd()).context("make child PTY nonblocking")?;
     // Set stdin to raw, so that input is read character by character,
@@ -18,9 +18,9 @@ use std::{
     sync::{Arc, Mutex},
     time::{SystemTime, UNIX_EPOCH},
 };
-use tts::Tts;
 #[cfg(target_os = "macos")]
 use tts::UtteranceId;
+use tts::{Tts, Voice};
 
 #[cfg(target_os = "macos")]
 const MAX_PENDING_UTTERANCES: usize = 32;
@@ -32,6 +32,9 @@ struct State {
     rate: f32,
     min_rate: f32,
     max_rate: f32,
+    /// Voice the backend started with, restored by `set_voice` with `null`.
+    default_voice: Option<Voice>,
+    voice: Option<Voice>,
     initialized: bool,
     rpc_log: Option<File>,
     #[cfg(target_os = "macos")]
@@ -54,6 +57,7 @@ pub fn run(expected_parent_pid: Option<u32>) -> Result<()> {
     let max_rate = tts.max_rate().map_err(|error| anyhow::anyhow!(error))?;
     let rate = tts.normal_rate().map_err(|error| anyhow::anyhow!(error))?;
     tts.set_rate(rate).map_err(|error| anyhow::anyhow!(error))?;
+    let default_voice = tts.voice().ok().flatten();
     let rpc_log = std::env::var_os("LECTOR_SPEECH_RPC_LOG")
         .map(|path| OpenOptions::new().create(true).append(true).open(path))
         .transpose()?;
@@ -68,6 +72,8 @@ pub fn run(expected_parent_pid: Option<u32>) -> Result<()> {
         rate,
         min_rate,
         max_rate,
+        voice: default_voice.clone(),
+        default_voice,
         initialized: false,
         rpc_log,
         #[cfg(target_os = "macos")]
@@ -212,6 +218,10 @@ impl State {
             .map_err(|error| RpcError::internal_error(error.to_string()))?;
         tts.set_rate(self.rate)
             .map_err(|error| RpcError::internal_error(error.to_string()))?;
+        if let Some(voice) = &self.voice {
+            tts.set_voice(voice)
+                .map_err(|error| RpcError::internal_error(error.to_string()))?;
+        }
         install_lifecycle_callbacks(&tts, &self.completed_tx)
             .map_err(|error| RpcError::internal_error(error.to_string()))?;
         self.tts = tts;
@@ -291,7 +301,11 @@ fn handle_request(request: Request, state: &mut State) -> Result<Value, RpcError
         }
         return result;
     }
-    if matches!(request.method.as_str(), "speak" | "stop" | "set_rate") && !state.initialized {
+    if matches!(
+        request.method.as_str(),
+        "speak" | "stop" | "set_rate" | "set_voice"
+    ) && !state.initialized
+    {
         return Err(RpcError::invalid_request(
             "speech server is not initialized",
         ));
@@ -344,6 +358,41 @@ fn handle_request(request: Request, state: &mut State) -> Result<Value, RpcError
             state.rate = clamped;
             Ok(json!({ "rate": state.rate }))
         }
+        "set_voice" => {
+            let params = request
+                .params
+                .ok_or_else(|| RpcError::invalid_params("missing params"))?;
+            let voice = match params.get("voice") {
+                Some(Value::String(requested)) => Some(find_voice(&state.tts, requested)?),
+                Some(Value::Null) => state.default_voice.clone(),
+                _ => return Err(RpcError::invalid_params("voice must be a string or null")),
+            };
+            if let Some(voice) = &voice {
+                state
+                    .tts
+                    .set_voice(voice)
+                    .map_err(|error| RpcError::internal_error(error.to_string()))?;
+            }
+            state.voice = voice;
+            Ok(Value::Null)
+        }
         _ => Err(RpcError::method_not_found(request.method)),
     }
 }
+
+/// Matches a voice by exact ID first, then by case-insensitive name.
+fn find_voice(tts: &Tts, requested: &str) -> Result<Voice, RpcError> {
+    let voices = tts
+        .voices()
+        .map_err(|error| RpcError::internal_error(error.to_string()))?;
+    voices
+        .iter()
+        .find(|voice| voice.id() == requested)
+        .or_else(|| {
+            voices
+                .iter()
+                .find(|voice| voice.name().eq_ignore_ascii_case(requested))
+        })
+        .cloned()
+        .ok_or_else(|| RpcError::invalid_params(format!("unknown voice {requested:?}")))
+}
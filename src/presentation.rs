@@ -539,6 +539,15 @@ impl PaneMediaStore {
     pub fn placement_count(&self) -> usize {
         self.placements.len()
     }
+
+    pub fn placements(&self) -> &[PresentedImage] {
+        &self.placements
+    }
+
+    /// The decoded pixels of `image_id`, in its placements' `format`.
+    pub fn image_data(&self, image_id: u32) -> Option<&Arc<[u8]>> {
+        self.uploads.get(&image_id).map(|upload| &upload.data)
+    }
 }
 
 fn pane_upload_matches(left: &PaneImageUpload, right: &PaneImageUpload) -> bool {
@@ -27,6 +27,9 @@ pub struct SpeechCapabilities {
     pub stop: bool,
     pub set_rate: bool,
     pub rpc_discover: bool,
+    /// Optional. Servers that omit it keep their default voice.
+    #[serde(default)]
+    pub set_voice: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -83,6 +86,7 @@ fn initialize(
             stop: true,
             set_rate: true,
             rpc_discover: true,
+            set_voice: true,
         },
     })
     .map_err(|error| RpcError::internal_error(error.to_string()))
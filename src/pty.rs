@@ -14,7 +14,7 @@ use std::collections::VecDeque;
 use std::ffi::OsStr;
 use std::fs::{DirBuilder, File, OpenOptions};
 use std::io::{self, ErrorKind, Read, Write};
-use std::os::fd::{AsRawFd, BorrowedFd, FromRawFd, RawFd};
+use std::os::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
 use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
 use std::path::{Path, PathBuf};
 use std::process::Command;
@@ -523,6 +523,33 @@ fn host_terminfo_tool_failure(name: &str, output: &BoundedOutput) -> io::Error {
     }
 }
 
+/// The termios flags of the child PTY that tell whether typed input is
+/// echoed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LineDiscipline {
+    pub echo: bool,
+    pub canonical: bool,
+}
+
+/// A handle on the PTY master for reading the child's line discipline when
+/// it matters, rather than on every turn of the event loop.
+#[derive(Debug)]
+pub struct LineDisciplineProbe {
+    master: OwnedFd,
+}
+
+impl LineDisciplineProbe {
+    /// The child's current line discipline. A program reading a password
+    /// turns echo off while keeping canonical line input.
+    pub fn read(&self) -> Option<LineDiscipline> {
+        let attrs = termios::tcgetattr(&self.master).ok()?;
+        Some(LineDiscipline {
+            echo: attrs.local_flags.contains(termios::LocalFlags::ECHO),
+            canonical: attrs.local_flags.contains(termios::LocalFlags::ICANON),
+        })
+    }
+}
+
 pub struct Process {
     master: Box<dyn MasterPty + Send>,
     child: Option<Box<dyn Child + Send + Sync>>,
@@ -623,6 +650,18 @@ impl Process {
         })
     }
 
+    pub fn line_discipline_probe(&self) -> Result<LineDisciplineProbe> {
+        let master_fd = self
+            .master
+            .as_raw_fd()
+            .ok_or_else(|| anyhow!("PTY master does not expose a file descriptor"))?;
+        let probe_fd = dup(master_fd).context("duplicate PTY master")?;
+        Ok(LineDisciplineProbe {
+            // SAFETY: `dup` returned a new descriptor that nothing else owns.
+            master: unsafe { OwnedFd::from_raw_fd(probe_fd) },
+        })
+    }
+
     pub fn resize(&self, rows: u16, cols: u16) -> Result<()> {
         self.resize_with_geometry(TerminalGeometry::from_cells(rows, cols))
     }
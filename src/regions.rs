@@ -0,0 +1,367 @@
+//! Named rectangles of the screen with their own reading rules.
+//!
+//! A silenced region is left out of the auto-read diff, so a clock or status
+//! line can change without being spoken. A watched region is also left out
+//! of the diff but is spoken whenever its text changes, even with auto-read
+//! off. Any region can be read on demand. Rows and columns count from 0;
+//! negative ones count back from the bottom row or right column, so `-1` is
+//! always the last one.
+
+use crate::terminal::{ScreenRect, TerminalSnapshot};
+use anyhow::{Result, bail};
+use regex::Regex;
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum RegionMode {
+    #[default]
+    Silence,
+    Watch,
+    /// Read only on demand.
+    Read,
+}
+
+impl fmt::Display for RegionMode {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Silence => "silence",
+            Self::Watch => "watch",
+            Self::Read => "read",
+        })
+    }
+}
+
+impl FromStr for RegionMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "silence" => Ok(Self::Silence),
+            "watch" => Ok(Self::Watch),
+            "read" => Ok(Self::Read),
+            _ => bail!("region mode must be silence, watch, or read"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Region {
+    pub(crate) top: i32,
+    pub(crate) left: i32,
+    pub(crate) bottom: i32,
+    pub(crate) right: i32,
+    pub(crate) mode: RegionMode,
+    /// Regex matched against the window title; without one the region
+    /// applies to every window.
+    pub(crate) title: Option<String>,
+}
+
+impl Region {
+    /// A region covering exactly `rect`, as marked with the review cursor.
+    pub(crate) fn from_rect(rect: ScreenRect, mode: RegionMode) -> Self {
+        Self {
+            top: i32::from(rect.top),
+            left: i32::from(rect.left),
+            bottom: i32::from(rect.bottom),
+            right: i32::from(rect.right),
+            mode,
+            title: None,
+        }
+    }
+
+    fn validated(self) -> Result<DefinedRegion> {
+        let title = self
+            .title
+            .as_deref()
+            .map(Regex::new)
+            .transpose()
+            .map_err(|error| anyhow::anyhow!("invalid region title pattern: {error}"))?;
+        // Corners on the same side of the screen must be in order; mixed
+        // anchors can only be checked against a screen size.
+        let ordered = |start: i32, end: i32| (start < 0) != (end < 0) || start <= end;
+        if !ordered(self.top, self.bottom) || !ordered(self.left, self.right) {
+            bail!("region top and left must come before bottom and right");
+        }
+        Ok(DefinedRegion {
+            region: self,
+            title,
+        })
+    }
+
+    /// The cells this region covers on a screen of `rows` by `cols`, or
+    /// `None` when it falls outside it.
+    pub(crate) fn resolve(&self, rows: u16, cols: u16) -> Option<ScreenRect> {
+        let (top, bottom) = (anchor(self.top, rows)?, anchor(self.bottom, rows)?);
+        let (left, right) = (anchor(self.left, cols)?, anchor(self.right, cols)?);
+        (top <= bottom && left <= right).then_some(ScreenRect {
+            top,
+            left,
+            bottom,
+            right,
+        })
+    }
+}
+
+fn anchor(coordinate: i32, size: u16) -> Option<u16> {
+    if size == 0 {
+        return None;
+    }
+    let size = i32::from(size);
+    let resolved = if coordinate < 0 {
+        size + coordinate
+    } else {
+        coordinate
+    };
+    if !(0..size).contains(&resolved) {
+        return None;
+    }
+    u16::try_from(resolved).ok()
+}
+
+/// A defined region with its title pattern compiled.
+#[derive(Debug)]
+struct DefinedRegion {
+    region: Region,
+    title: Option<Regex>,
+}
+
+impl DefinedRegion {
+    fn applies_to(&self, title: Option<&str>) -> bool {
+        match &self.title {
+            Some(pattern) => title.is_some_and(|title| pattern.is_match(title)),
+            None => true,
+        }
+    }
+}
+
+/// Regions defined from Lua or with the review cursor, by name.
+#[derive(Debug, Default)]
+pub(crate) struct Regions {
+    defined: BTreeMap<String, DefinedRegion>,
+    /// The last text of each watched region, to tell when it changes.
+    watched_text: BTreeMap<String, String>,
+    first_corner: Option<(u16, u16)>,
+    read_request: Option<String>,
+}
+
+impl Regions {
+    pub(crate) fn get(&self, name: &str) -> Option<&Region> {
+        self.defined.get(name).map(|defined| &defined.region)
+    }
+
+    pub(crate) fn define(&mut self, name: &str, region: Region) -> Result<()> {
+        if name.trim().is_empty() || name.chars().any(char::is_control) {
+            bail!("region names must be non-empty text");
+        }
+        self.defined.insert(name.to_owned(), region.validated()?);
+        self.watched_text.remove(name);
+        Ok(())
+    }
+
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.defined.remove(name);
+        self.watched_text.remove(name);
+    }
+
+    /// Records one corner marked with the review cursor. The second corner
+    /// returns the rectangle between them.
+    pub(crate) fn mark_corner(&mut self, corner: (u16, u16)) -> Option<ScreenRect> {
+        let Some(first) = self.first_corner.take() else {
+            self.first_corner = Some(corner);
+            return None;
+        };
+        Some(ScreenRect {
+            top: first.0.min(corner.0),
+            left: first.1.min(corner.1),
+            bottom: first.0.max(corner.0),
+            right: first.1.max(corner.1),
+        })
+    }
+
+    /// Forgets a marked first corner, returning whether there was one.
+    pub(crate) fn cancel_corner(&mut self) -> bool {
+        self.first_corner.take().is_some()
+    }
+
+    /// Queues `name` to be read once the screen is available, as Lua
+    /// bindings run without it.
+    pub(crate) fn request_read(&mut self, name: &str) {
+        self.read_request = Some(name.to_owned());
+    }
+
+    pub(crate) fn take_read_request(&mut self) -> Option<String> {
+        self.read_request.take()
+    }
+
+    /// Cells that auto-read leaves out: silenced and watched regions that
+    /// apply to the window titled `title`.
+    pub(crate) fn excluded(
+        &self,
+        screen: &TerminalSnapshot,
+        title: Option<&str>,
+    ) -> Vec<ScreenRect> {
+        let (rows, cols) = screen.size();
+        self.defined
+            .values()
+            .filter(|defined| defined.region.mode != RegionMode::Read && defined.applies_to(title))
+            .filter_map(|defined| defined.region.resolve(rows, cols))
+            .collect()
+    }
+
+    /// The text of each watched region that changed since the last call.
+    /// A region seen for the first time only records its text.
+    pub(crate) fn watched_changes(
+        &mut self,
+        screen: &TerminalSnapshot,
+        title: Option<&str>,
+    ) -> Vec<String> {
+        let (rows, cols) = screen.size();
+        let mut changes = Vec::new();
+        let mut seen = BTreeMap::new();
+        for (name, defined) in &self.defined {
+            if defined.region.mode != RegionMode::Watch || !defined.applies_to(title) {
+                continue;
+            }
+            let Some(rect) = defined.region.resolve(rows, cols) else {
+                continue;
+            };
+            let text = region_text(screen, rect);
+            if self
+                .watched_text
+                .get(name)
+                .is_some_and(|previous| *previous != text)
+                && !text.is_empty()
+            {
+                changes.push(text.clone());
+            }
+            seen.insert(name.clone(), text);
+        }
+        // Regions that stop applying start afresh when they apply again.
+        self.watched_text = seen;
+        changes
+    }
+}
+
+/// The nonblank lines of `rect`, each trimmed.
+pub(crate) fn region_text(screen: &TerminalSnapshot, rect: ScreenRect) -> String {
+    (rect.top..=rect.bottom)
+        .map(|row| screen.contents_between(row, rect.left, row, rect.right.saturating_add(1)))
+        .map(|line| line.trim().to_owned())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Region, RegionMode, Regions, region_text};
+    use crate::terminal::{GhosttyEngine, ScreenRect, TerminalEngine};
+
+    fn region(top: i32, left: i32, bottom: i32, right: i32, mode: RegionMode) -> Region {
+        Region {
+            top,
+            left,
+            bottom,
+            right,
+            mode,
+            title: None,
+        }
+    }
+
+    #[test]
+    fn negative_coordinates_anchor_to_the_bottom_and_right() {
+        let status = region(-1, 0, -1, -1, RegionMode::Silence);
+        assert_eq!(
+            status.resolve(24, 80),
+            Some(ScreenRect {
+                top: 23,
+                left: 0,
+                bottom: 23,
+                right: 79,
+            })
+        );
+        let clock = region(0, -5, 0, -1, RegionMode::Watch);
+        assert_eq!(
+            clock.resolve(10, 40),
+            Some(ScreenRect {
+                top: 0,
+                left: 35,
+                bottom: 0,
+                right: 39,
+            })
+        );
+        assert_eq!(region(3, 0, -8, -1, RegionMode::Read).resolve(10, 40), None);
+        assert_eq!(region(0, 0, 12, 5, RegionMode::Read).resolve(10, 40), None);
+        assert_eq!(
+            region(0, -50, 0, -1, RegionMode::Read).resolve(10, 40),
+            None
+        );
+
+        let mut regions = Regions::default();
+        assert!(
+            regions
+                .define("bad", region(5, 0, 2, -1, RegionMode::Read))
+                .is_err()
+        );
+        let titled = Region {
+            title: Some("(".to_owned()),
+            ..region(0, 0, 0, 0, RegionMode::Read)
+        };
+        assert!(regions.define("bad", titled).is_err());
+    }
+
+    #[test]
+    fn corners_and_watched_regions_follow_the_screen() {
+        let mut regions = Regions::default();
+        assert_eq!(regions.mark_corner((7, 7)), None);
+        assert!(regions.cancel_corner());
+        assert!(!regions.cancel_corner());
+        assert_eq!(regions.mark_corner((4, 9)), None);
+        assert_eq!(
+            regions.mark_corner((2, 3)),
+            Some(ScreenRect {
+                top: 2,
+                left: 3,
+                bottom: 4,
+                right: 9,
+            })
+        );
+
+        let mut engine = GhosttyEngine::new(4, 20).expect("create Ghostty engine");
+        engine
+            .advance(b"build 10%\x1b[4;1HNORMAL  main.rs")
+            .expect("draw regions fixture");
+        let clock = Region {
+            title: Some("^vim".to_owned()),
+            ..region(0, 6, 0, -1, RegionMode::Watch)
+        };
+        regions.define("progress", clock).unwrap();
+        regions
+            .define("status", region(-1, 0, -1, -1, RegionMode::Silence))
+            .unwrap();
+
+        assert_eq!(regions.excluded(engine.snapshot(), None).len(), 1);
+        assert_eq!(regions.excluded(engine.snapshot(), Some("vim")).len(), 2);
+        assert!(
+            regions
+                .watched_changes(engine.snapshot(), Some("vim"))
+                .is_empty()
+        );
+        engine.advance(b"\x1b[1;7H55%").expect("update progress");
+        assert_eq!(
+            regions.watched_changes(engine.snapshot(), Some("vim")),
+            vec!["55%".to_owned()]
+        );
+        assert!(
+            regions
+                .watched_changes(engine.snapshot(), Some("vim"))
+                .is_empty()
+        );
+
+        let status = regions
+            .get("status")
+            .and_then(|status| status.resolve(4, 20))
+            .unwrap();
+        assert_eq!(region_text(engine.snapshot(), status), "NORMAL  main.rs");
+    }
+}
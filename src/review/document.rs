@@ -1,3 +1,7 @@
+use super::{
+    export::{Transcript, TranscriptSpan},
+    styles::{StyleQuery, style_key},
+};
 use crate::{
     terminal::{
         Color, HistoryPosition, SemanticKind as Osc133Kind, SemanticMark as Osc133Mark, Style,
@@ -5,7 +9,7 @@ use crate::{
     },
     view::View,
 };
-use regex::RegexBuilder;
+use regex::{Regex, RegexBuilder};
 use std::ops::Range;
 
 #[derive(Clone, Debug)]
@@ -13,6 +17,7 @@ struct Cell {
     text: String,
     wide_continuation: bool,
     style: Style,
+    hyperlink: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -22,6 +27,14 @@ struct Row {
     end: u16,
 }
 
+/// A run of consecutive cells on one logical line that share an OSC 8 target.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Hyperlink {
+    pub(crate) start: HistoryPosition,
+    pub(crate) text: String,
+    pub(crate) uri: String,
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum WordStyle {
     Word,
@@ -60,6 +73,7 @@ pub(crate) struct ReviewDocument {
     flat_text: String,
     flat_positions: Vec<HistoryPosition>,
     positions: Vec<HistoryPosition>,
+    links: Vec<Hyperlink>,
 }
 
 impl ReviewDocument {
@@ -93,6 +107,7 @@ impl ReviewDocument {
                     text,
                     wide_continuation,
                     style: cell.map_or_else(Style::default, |cell| cell.style.clone()),
+                    hyperlink: cell.and_then(|cell| cell.hyperlink.clone()),
                 });
             }
             let wrapped = source_row.wrapped;
@@ -113,11 +128,55 @@ impl ReviewDocument {
             flat_text: String::new(),
             flat_positions: Vec::new(),
             positions: Vec::new(),
+            links: Vec::new(),
         };
         document.build_search_text();
         document
     }
 
+    /// Freezes only the visible screen of `view`, so document positions are
+    /// screen coordinates. Live review commands use this for link lookups.
+    pub(crate) fn visible(view: &View) -> Self {
+        let mut screen = view.screen().clone();
+        screen.scrollback.clear();
+        Self::from_snapshot(screen, view.size().1)
+    }
+
+    /// Reconstructs `capture-pane -p -e -J` output at the document width, so
+    /// server-side history can be stitched above the frozen capture.
+    pub(crate) fn from_capture_lines(lines: &[Vec<u8>], capture_cols: u16) -> Self {
+        let mut bytes = Vec::new();
+        for (index, line) in lines.iter().enumerate() {
+            if index > 0 {
+                bytes.extend_from_slice(b"\x1b[0m\r\n");
+            }
+            bytes.extend_from_slice(line);
+        }
+        let mut view = View::new(1, capture_cols);
+        view.process_changes(&bytes);
+        Self::from_snapshot(view.snapshot_with_history(), capture_cols)
+    }
+
+    /// Places older rows above the document and shifts every existing
+    /// position down. Returns the number of rows added.
+    pub(crate) fn prepend_history(&mut self, older: Self) -> usize {
+        let shift = older.rows.len();
+        if shift == 0 {
+            return 0;
+        }
+        let mut rows = older.rows;
+        rows.append(&mut self.rows);
+        self.rows = rows;
+        let mut marks = older.marks;
+        marks.extend(self.marks.drain(..).map(|mut mark| {
+            mark.position.row = mark.position.row.saturating_add(shift);
+            mark
+        }));
+        self.marks = marks;
+        self.build_search_text();
+        shift
+    }
+
     #[cfg(test)]
     pub(crate) fn from_text(rows: u16, cols: u16, text: &[u8]) -> Self {
         let mut view = View::new(rows, cols);
@@ -125,6 +184,10 @@ impl ReviewDocument {
         Self::capture(&mut view).0
     }
 
+    pub(crate) fn capture_cols(&self) -> u16 {
+        self.capture_cols
+    }
+
     pub(crate) fn row_count(&self) -> usize {
         self.rows.len()
     }
@@ -519,6 +582,103 @@ impl ReviewDocument {
             .map(|p| self.clamp(p))
     }
 
+    /// The OSC 8 target of the cell at `position`.
+    pub(crate) fn link_at(&self, position: HistoryPosition) -> Option<&str> {
+        self.cell(position.row, position.col)?.hyperlink.as_deref()
+    }
+
+    /// The link containing `position`, if any.
+    pub(crate) fn link_containing(&self, position: HistoryPosition) -> Option<Hyperlink> {
+        self.link_at(position)?;
+        self.links
+            .iter()
+            .take_while(|link| link.start <= position)
+            .last()
+            .cloned()
+    }
+
+    /// Every link in reading order.
+    pub(crate) fn links(&self) -> &[Hyperlink] {
+        &self.links
+    }
+
+    /// Collects the links once the document's positions are known. A target
+    /// that continues onto a soft-wrapped row stays one link; repeated
+    /// targets on separate lines are listed separately.
+    fn find_links(&self) -> Vec<Hyperlink> {
+        let mut links: Vec<Hyperlink> = Vec::new();
+        let mut previous: Option<(HistoryPosition, &str)> = None;
+        for &position in &self.positions {
+            let Some(uri) = self.link_at(position) else {
+                previous = None;
+                continue;
+            };
+            let text = match self.cell_text(position) {
+                "" => " ",
+                text => text,
+            };
+            let continues = previous.is_some_and(|(previous, previous_uri)| {
+                previous_uri == uri && self.same_logical_line(previous, position)
+            });
+            match links.last_mut() {
+                Some(link) if continues => link.text.push_str(text),
+                _ => links.push(Hyperlink {
+                    start: position,
+                    text: text.to_owned(),
+                    uri: uri.to_owned(),
+                }),
+            }
+            previous = Some((position, uri));
+        }
+        for link in &mut links {
+            link.text = link.text.trim().to_owned();
+        }
+        links
+    }
+
+    /// The start of the `count`th link after `position`, or before the link
+    /// containing `position` when moving backwards.
+    pub(crate) fn link(
+        &self,
+        position: HistoryPosition,
+        forward: bool,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        let links = &self.links;
+        let current = links
+            .iter()
+            .take_while(|link| link.start <= position)
+            .last()
+            .filter(|_| self.link_at(position).is_some())
+            .map_or(position, |link| link.start);
+        let mut starts = links
+            .iter()
+            .map(|link| link.start)
+            .filter(|start| {
+                if forward {
+                    *start > position
+                } else {
+                    *start < current
+                }
+            })
+            .collect::<Vec<_>>();
+        if !forward {
+            starts.reverse();
+        }
+        starts.get(count.saturating_sub(1)).copied()
+    }
+
+    /// Checks a regular expression search before it is committed.
+    pub(crate) fn valid_search_query(query: &str) -> bool {
+        RegexBuilder::new(query).multi_line(true).build().is_ok()
+    }
+
+    /// Checks a style search, such as `red` or `bold on blue:error`, before
+    /// it is committed.
+    pub(crate) fn valid_style_query(query: &str) -> bool {
+        StyleQuery::parse(query).is_ok()
+    }
+
     pub(crate) fn search(
         &self,
         query: &str,
@@ -527,41 +687,106 @@ impl ReviewDocument {
         count: usize,
     ) -> Option<HistoryPosition> {
         let regex = RegexBuilder::new(query).multi_line(true).build().ok()?;
-        let matches = regex
+        nearest_match(&self.pattern_matches(&regex), position, direction, count)
+    }
+
+    /// Like [`Self::search`], but finds text by its style.
+    pub(crate) fn search_style(
+        &self,
+        query: &str,
+        position: HistoryPosition,
+        direction: SearchDirection,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        let query = StyleQuery::parse(query).ok()?;
+        nearest_match(&self.style_matches(&query), position, direction, count)
+    }
+
+    fn pattern_matches(&self, regex: &Regex) -> Vec<HistoryPosition> {
+        regex
             .find_iter(&self.flat_text)
             .filter_map(|found| self.position_for_offset(found.start()))
+            .collect()
+    }
+
+    /// Where styled text matching `query` starts. With a pattern, these are
+    /// pattern matches that start on a matching cell; without one, they are
+    /// the starts of matching runs, which blank cells do not interrupt.
+    fn style_matches(&self, query: &StyleQuery) -> Vec<HistoryPosition> {
+        if let Some(regex) = query.text() {
+            return self
+                .pattern_matches(regex)
+                .into_iter()
+                .filter(|position| self.style_at(*position).is_some_and(|s| query.matches(s)))
+                .collect();
+        }
+        let mut starts = Vec::new();
+        let mut previous: Option<(HistoryPosition, bool)> = None;
+        for &position in &self.positions {
+            let Some(style) = self.significant_style(position) else {
+                continue;
+            };
+            let matching = query.matches(style);
+            let continues = previous.is_some_and(|(previous, previous_matching)| {
+                previous_matching && self.same_logical_line(previous, position)
+            });
+            if matching && !continues {
+                starts.push(position);
+            }
+            previous = Some((position, matching));
+        }
+        starts
+    }
+
+    pub(crate) fn style_at(&self, position: HistoryPosition) -> Option<&Style> {
+        self.cell(position.row, position.col)
+            .map(|cell| &cell.style)
+    }
+
+    /// The style of a cell that shows something: text, a background color,
+    /// or inverse video. Plain blanks have no style worth comparing.
+    fn significant_style(&self, position: HistoryPosition) -> Option<&Style> {
+        let cell = self.cell(position.row, position.col)?;
+        let visible = !cell.text.trim().is_empty()
+            || cell.style.background != Color::Default
+            || cell.style.inverse;
+        visible.then_some(&cell.style)
+    }
+
+    /// The `count`th cell after `position`, or before it, whose color, bold,
+    /// inverse or underline differs from the shown cell before it.
+    pub(crate) fn style_change(
+        &self,
+        position: HistoryPosition,
+        forward: bool,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        let mut changes = Vec::new();
+        let mut previous = None;
+        for &candidate in &self.positions {
+            let Some(style) = self.significant_style(candidate) else {
+                continue;
+            };
+            let key = style_key(style);
+            if previous.is_some_and(|previous| previous != key) {
+                changes.push(candidate);
+            }
+            previous = Some(key);
+        }
+        let mut candidates = changes
+            .into_iter()
+            .filter(|candidate| {
+                if forward {
+                    *candidate > position
+                } else {
+                    *candidate < position
+                }
+            })
             .collect::<Vec<_>>();
-        if matches.is_empty() {
-            return None;
+        if !forward {
+            candidates.reverse();
         }
-        let ordered = match direction {
-            SearchDirection::Forward => matches
-                .iter()
-                .copied()
-                .filter(|candidate| *candidate > position)
-                .chain(
-                    matches
-                        .iter()
-                        .copied()
-                        .filter(|candidate| *candidate <= position),
-                )
-                .collect::<Vec<_>>(),
-            SearchDirection::Backward => matches
-                .iter()
-                .copied()
-                .rev()
-                .filter(|candidate| *candidate < position)
-                .chain(
-                    matches
-                        .iter()
-                        .copied()
-                        .rev()
-                        .filter(|candidate| *candidate >= position),
-                )
-                .collect::<Vec<_>>(),
-        };
-        let target = *ordered.get(count.saturating_sub(1) % ordered.len())?;
-        (target != position).then_some(target)
+        candidates.get(count.saturating_sub(1)).copied()
     }
 
     fn build_search_text(&mut self) {
@@ -605,6 +830,7 @@ impl ReviewDocument {
                 });
             }
         }
+        self.links = self.find_links();
     }
 
     fn position_for_offset(&self, offset: usize) -> Option<HistoryPosition> {
@@ -665,6 +891,120 @@ impl ReviewDocument {
         (!text.is_empty()).then_some(text)
     }
 
+    /// The range between `first` and `last` with styles and link targets,
+    /// as logical lines. Trailing unstyled blanks are dropped, like a yank.
+    pub(crate) fn transcript(
+        &self,
+        first: HistoryPosition,
+        last: HistoryPosition,
+        linewise: bool,
+    ) -> Transcript {
+        let (mut first, mut last) = if first <= last {
+            (first, last)
+        } else {
+            (last, first)
+        };
+        if linewise {
+            first.col = 0;
+            last.col = self.line_last_col(last.row);
+        }
+        let mut transcript = Transcript::default();
+        let mut line: Vec<TranscriptSpan> = Vec::new();
+        let last_row = last.row.min(self.row_count().saturating_sub(1));
+        for row_index in first.row..=last_row {
+            let row = &self.rows[row_index];
+            let start = if row_index == first.row { first.col } else { 0 };
+            let end = if row_index == last.row {
+                last.col.saturating_add(1)
+            } else {
+                row.end
+            };
+            for cell in row
+                .cells
+                .iter()
+                .take(usize::from(end.min(row.end)))
+                .skip(usize::from(start))
+            {
+                if cell.wide_continuation {
+                    continue;
+                }
+                let text = if cell.text.is_empty() {
+                    " "
+                } else {
+                    cell.text.as_str()
+                };
+                match line.last_mut() {
+                    Some(span) if span.style == cell.style && span.hyperlink == cell.hyperlink => {
+                        span.text.push_str(text);
+                    }
+                    _ => line.push(TranscriptSpan {
+                        text: text.to_owned(),
+                        style: cell.style.clone(),
+                        hyperlink: cell.hyperlink.clone(),
+                    }),
+                }
+            }
+            if !row.wrapped || row_index == last_row {
+                trim_trailing_blanks(&mut line);
+                transcript.lines.push(std::mem::take(&mut line));
+            }
+        }
+        transcript
+    }
+
+    /// The output of the most recent command on the current screen: from its
+    /// OSC 133 C mark to the next prompt or finish mark, or to the end of the
+    /// document while it is still running.
+    pub(crate) fn last_command_output(&self) -> Option<(HistoryPosition, HistoryPosition)> {
+        let marks: Vec<&Osc133Mark> = self
+            .marks
+            .iter()
+            .filter(|mark| mark.alternate_screen == self.alternate_screen)
+            .collect();
+        let start_index = marks
+            .iter()
+            .rposition(|mark| matches!(mark.kind, Osc133Kind::CommandStart))?;
+        let mut start = marks[start_index].position;
+        // Shells that emit C before the newline leave it after the command
+        // line's text; the output starts on the next row.
+        if self
+            .rows
+            .get(start.row)
+            .is_some_and(|row| start.col >= row.end && !row.wrapped)
+        {
+            start = HistoryPosition {
+                row: start.row.saturating_add(1),
+                col: 0,
+            };
+        }
+        if start.row >= self.row_count() {
+            return None;
+        }
+        let end = marks[start_index + 1..]
+            .iter()
+            .find(|mark| {
+                matches!(
+                    mark.kind,
+                    Osc133Kind::PromptStart | Osc133Kind::CommandFinished { .. }
+                )
+            })
+            .map(|mark| mark.position);
+        let last_row = match end {
+            Some(end) if end.col == 0 => end.row.checked_sub(1)?,
+            Some(end) => end.row,
+            None => self.row_count().checked_sub(1)?,
+        };
+        (last_row >= start.row).then(|| {
+            (
+                start,
+                HistoryPosition {
+                    row: last_row,
+                    col: self.line_last_col(last_row),
+                },
+            )
+        })
+    }
+
     pub(crate) fn inner_word_range(
         &self,
         position: HistoryPosition,
@@ -742,6 +1082,7 @@ impl ReviewDocument {
         };
         let mut bytes = Vec::new();
         let mut style = Style::default();
+        let mut link = None;
         for (index, cell) in row
             .cells
             .iter()
@@ -763,17 +1104,88 @@ impl ReviewDocument {
                 write_style(&mut bytes, &cell.style);
                 style = cell.style.clone();
             }
+            // Keep OSC 8 targets so link commands work inside the overlay.
+            if cell.hyperlink.as_deref() != link {
+                link = cell.hyperlink.as_deref();
+                write_hyperlink(&mut bytes, link);
+            }
             if cell.text.is_empty() {
                 bytes.push(b' ');
             } else {
                 bytes.extend_from_slice(cell.text.as_bytes());
             }
         }
+        if link.is_some() {
+            write_hyperlink(&mut bytes, None);
+        }
         bytes
     }
 }
 
-fn write_style(bytes: &mut Vec<u8>, style: &Style) {
+/// The `count`th of `matches` from `position` in `direction`, wrapping at the
+/// ends of the document.
+fn nearest_match(
+    matches: &[HistoryPosition],
+    position: HistoryPosition,
+    direction: SearchDirection,
+    count: usize,
+) -> Option<HistoryPosition> {
+    if matches.is_empty() {
+        return None;
+    }
+    let ordered = match direction {
+        SearchDirection::Forward => matches
+            .iter()
+            .copied()
+            .filter(|candidate| *candidate > position)
+            .chain(
+                matches
+                    .iter()
+                    .copied()
+                    .filter(|candidate| *candidate <= position),
+            )
+            .collect::<Vec<_>>(),
+        SearchDirection::Backward => matches
+            .iter()
+            .copied()
+            .rev()
+            .filter(|candidate| *candidate < position)
+            .chain(
+                matches
+                    .iter()
+                    .copied()
+                    .rev()
+                    .filter(|candidate| *candidate >= position),
+            )
+            .collect::<Vec<_>>(),
+    };
+    let target = *ordered.get(count.saturating_sub(1) % ordered.len())?;
+    (target != position).then_some(target)
+}
+
+fn trim_trailing_blanks(line: &mut Vec<TranscriptSpan>) {
+    while let Some(span) = line.last_mut() {
+        if span.style != Style::default() {
+            return;
+        }
+        let trimmed = span.text.trim_end_matches(' ').len();
+        span.text.truncate(trimmed);
+        if !span.text.is_empty() {
+            return;
+        }
+        line.pop();
+    }
+}
+
+fn write_hyperlink(bytes: &mut Vec<u8>, uri: Option<&str>) {
+    bytes.extend_from_slice(b"\x1b]8;;");
+    if let Some(uri) = uri {
+        bytes.extend(uri.bytes().filter(|byte| *byte >= b' ' && *byte != 0x7f));
+    }
+    bytes.extend_from_slice(b"\x1b\\");
+}
+
+pub(super) fn write_style(bytes: &mut Vec<u8>, style: &Style) {
     bytes.extend_from_slice(b"\x1B[0");
     if style.bold {
         bytes.extend_from_slice(b";1");
@@ -832,6 +1244,25 @@ mod tests {
         assert_eq!(document.line_text(2), "three");
     }
 
+    #[test]
+    fn captured_history_is_stitched_above_existing_rows() {
+        let mut document = ReviewDocument::from_text(2, 10, b"three\r\nfour");
+        let older = ReviewDocument::from_capture_lines(
+            &[b"\x1b[1mone\x1b[0m".to_vec(), b"two two two two".to_vec()],
+            10,
+        );
+
+        assert_eq!(document.prepend_history(older), 3);
+        assert_eq!(document.row_count(), 5);
+        assert_eq!(document.line_text(0), "one");
+        assert_eq!(document.line_text(1), "two two tw");
+        assert_eq!(document.line_text(3), "three");
+        assert_eq!(
+            document.search("o two", pos(3, 0), SearchDirection::Backward, 1),
+            Some(pos(1, 2))
+        );
+    }
+
     #[test]
     fn word_motions_distinguish_words_and_big_words() {
         let document = ReviewDocument::from_text(2, 30, b"one.two  three");
@@ -928,6 +1359,68 @@ mod tests {
         assert_eq!(document.move_horizontal(pos(0, 1), true, 1), None);
     }
 
+    #[test]
+    fn hyperlinks_form_runs_that_follow_soft_wraps() {
+        let document = ReviewDocument::from_text(
+            4,
+            8,
+            b"ab \x1b]8;;https://a.example\x1b\\wrapped\x1b]8;;\x1b\\ \x1b]8;;https://b.example\x1b\\docs\x1b]8;;\x1b\\\r\n\x1b]8;;https://a.example\x1b\\again\x1b]8;;\x1b\\",
+        );
+        assert_eq!(
+            document
+                .links()
+                .iter()
+                .map(|link| (link.start, link.text.as_str(), link.uri.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (pos(0, 3), "wrapped", "https://a.example"),
+                (pos(1, 3), "docs", "https://b.example"),
+                (pos(2, 0), "again", "https://a.example"),
+            ]
+        );
+        assert_eq!(document.link_at(pos(0, 4)), Some("https://a.example"));
+        assert_eq!(document.link_at(pos(0, 0)), None);
+        assert_eq!(
+            document.link_containing(pos(1, 1)).map(|link| link.text),
+            Some("wrapped".into())
+        );
+
+        assert_eq!(document.link(pos(0, 0), true, 1), Some(pos(0, 3)));
+        assert_eq!(document.link(pos(0, 4), true, 2), Some(pos(2, 0)));
+        assert_eq!(document.link(pos(2, 0), true, 1), None);
+        assert_eq!(document.link(pos(1, 1), false, 1), None);
+        assert_eq!(document.link(pos(1, 5), false, 1), Some(pos(0, 3)));
+        assert_eq!(document.link(pos(1, 2), false, 1), Some(pos(0, 3)));
+    }
+
+    #[test]
+    fn style_changes_and_style_searches_follow_cell_attributes() {
+        let document = ReviewDocument::from_text(
+            3,
+            30,
+            b"plain \x1b[31mred text\x1b[0m and \x1b[1mbold\x1b[0m\r\n\x1b[31merror\x1b[0m: \x1b[7msel\x1b[0m",
+        );
+
+        assert_eq!(document.style_change(pos(0, 0), true, 1), Some(pos(0, 6)));
+        assert_eq!(document.style_change(pos(0, 0), true, 2), Some(pos(0, 15)));
+        assert_eq!(document.style_change(pos(0, 15), false, 1), Some(pos(0, 6)));
+        assert_eq!(document.style_change(pos(0, 0), false, 1), None);
+
+        let search =
+            |query: &str, from| document.search_style(query, from, SearchDirection::Forward, 1);
+        assert_eq!(search("red", pos(0, 0)), Some(pos(0, 6)));
+        assert_eq!(search("red", pos(0, 6)), Some(pos(1, 0)));
+        assert_eq!(search("red:text", pos(0, 0)), Some(pos(0, 10)));
+        assert_eq!(search("inverse", pos(0, 0)), Some(pos(1, 7)));
+        assert_eq!(search("bold on blue", pos(0, 0)), None);
+        assert!(ReviewDocument::valid_style_query("bold"));
+        assert!(!ReviewDocument::valid_style_query("nonsense"));
+        assert_eq!(
+            document.search("@red", pos(0, 0), SearchDirection::Forward, 1),
+            None
+        );
+    }
+
     #[test]
     fn ghostty_backed_document_preserves_history_unicode_styles_and_copying() {
         use crate::terminal::GhosttyEngine;
@@ -986,4 +1479,28 @@ mod tests {
         assert_eq!(document.prompt(pos(0, 1), true, 1), Some(pos(2, 0)));
         assert_eq!(document.prompt(pos(2, 0), false, 1), Some(pos(0, 0)));
     }
+
+    #[test]
+    fn last_command_output_spans_from_command_start_to_finish() {
+        use crate::terminal::GhosttyEngine;
+
+        let mut engine = GhosttyEngine::new_with_scrollback(5, 16, 20).unwrap();
+        engine
+            .advance(
+                b"\x1b]133;A\x07$ \x1b]133;B\x07ls\x1b]133;C\x07\r\na b\r\nc\x1b]133;D;0\x07\r\n\x1b]133;A\x07$ ",
+            )
+            .unwrap();
+        let document =
+            ReviewDocument::from_snapshot(engine.normalized_snapshot_with_history().unwrap(), 16);
+
+        assert_eq!(document.last_command_output(), Some((pos(1, 0), pos(2, 0))));
+        let transcript = document.transcript(pos(1, 0), pos(2, 0), false);
+        assert_eq!(
+            transcript.render(crate::review::export::TranscriptFormat::Text, ""),
+            "a b\nc\n"
+        );
+
+        let document = ReviewDocument::from_text(2, 10, b"$ ls");
+        assert_eq!(document.last_command_output(), None);
+    }
 }
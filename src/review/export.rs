@@ -0,0 +1,358 @@
+//! Saves part of a Review document as a transcript: plain text, text with
+//! SGR attributes, or HTML.
+
+use super::document::write_style;
+use crate::terminal::{Color, Style, UnderlineStyle};
+use std::{
+    fmt::Write as _,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// How a transcript file is written, chosen by its extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TranscriptFormat {
+    Text,
+    /// Text with SGR escape sequences, for `less -R` or `cat`.
+    Sgr,
+    Html,
+}
+
+impl TranscriptFormat {
+    /// `.html` and `.htm` files get HTML and `.ans` and `.ansi` files keep
+    /// SGR attributes; anything else is plain text.
+    pub(crate) fn from_path(path: &Path) -> Self {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("html" | "htm") => Self::Html,
+            Some("ans" | "ansi") => Self::Sgr,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// A run of text on one line that shares a style and link target.
+#[derive(Clone, Debug)]
+pub(crate) struct TranscriptSpan {
+    pub(crate) text: String,
+    pub(crate) style: Style,
+    pub(crate) hyperlink: Option<String>,
+}
+
+/// Logical lines copied out of a document, so the document can change or
+/// close while the target path is prompted for.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Transcript {
+    pub(crate) lines: Vec<Vec<TranscriptSpan>>,
+}
+
+impl Transcript {
+    pub(crate) fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub(crate) fn is_blank(&self) -> bool {
+        self.lines
+            .iter()
+            .flatten()
+            .all(|span| span.text.trim().is_empty())
+    }
+
+    pub(crate) fn render(&self, format: TranscriptFormat, title: &str) -> String {
+        match format {
+            TranscriptFormat::Text => self.render_text(),
+            TranscriptFormat::Sgr => self.render_sgr(),
+            TranscriptFormat::Html => self.render_html(title),
+        }
+    }
+
+    fn render_text(&self) -> String {
+        let mut text = String::new();
+        for line in &self.lines {
+            for span in line {
+                text.push_str(&span.text);
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    fn render_sgr(&self) -> String {
+        let mut bytes = Vec::new();
+        for line in &self.lines {
+            let mut style = Style::default();
+            for span in line {
+                if span.style != style {
+                    write_style(&mut bytes, &span.style);
+                    style = span.style.clone();
+                }
+                bytes.extend_from_slice(span.text.as_bytes());
+            }
+            if style != Style::default() {
+                bytes.extend_from_slice(b"\x1b[0m");
+            }
+            bytes.push(b'\n');
+        }
+        String::from_utf8(bytes).expect("transcript spans and SGR sequences are UTF-8")
+    }
+
+    fn render_html(&self, title: &str) -> String {
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+             <style>body {{ background: {DEFAULT_BACKGROUND}; color: {DEFAULT_FOREGROUND}; }}</style>\n\
+             </head>\n<body>\n<pre>\n",
+            escape_html(title)
+        );
+        for line in &self.lines {
+            for span in line {
+                let text = escape_html(&span.text);
+                let css = span_css(&span.style);
+                let styled = if css.is_empty() {
+                    text
+                } else {
+                    format!("<span style=\"{css}\">{text}</span>")
+                };
+                match &span.hyperlink {
+                    Some(uri) => {
+                        let _ = write!(html, "<a href=\"{}\">{styled}</a>", escape_html(uri));
+                    }
+                    None => html.push_str(&styled),
+                }
+            }
+            html.push('\n');
+        }
+        html.push_str("</pre>\n</body>\n</html>\n");
+        html
+    }
+}
+
+const DEFAULT_FOREGROUND: &str = "#d0d0d0";
+const DEFAULT_BACKGROUND: &str = "#000000";
+
+/// `$XDG_STATE_HOME/lector/transcripts/<time>.txt`, or the platform local
+/// data directory.
+pub(crate) fn default_transcript_path() -> Option<PathBuf> {
+    let directory = dirs::state_dir().or_else(dirs::data_local_dir)?;
+    let name = chrono::Local::now()
+        .format("%Y-%m-%d-%H%M%S.txt")
+        .to_string();
+    Some(directory.join("lector").join("transcripts").join(name))
+}
+
+/// Writes `transcript` to `path` in the format its extension selects,
+/// failing if `path` already exists.
+pub(crate) fn save(transcript: &Transcript, path: &Path, title: &str) -> io::Result<()> {
+    write_new_file(
+        path,
+        transcript
+            .render(TranscriptFormat::from_path(path), title)
+            .as_bytes(),
+    )
+}
+
+/// Creates `path` holding `contents`, refusing to replace an existing file.
+///
+/// The contents are written beside the destination first so an interrupted
+/// save never leaves a truncated file. A rename would silently replace
+/// whatever is at `path`, so the finished file is hard-linked into place
+/// instead, which fails when the name is taken.
+pub(crate) fn write_new_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let Some(name) = path.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "not a file name",
+        ));
+    };
+    if let Some(directory) = path.parent()
+        && !directory.as_os_str().is_empty()
+    {
+        fs::create_dir_all(directory)?;
+    }
+    let mut partial_name = name.to_owned();
+    partial_name.push(".partial");
+    let partial = path.with_file_name(partial_name);
+    fs::write(&partial, contents)?;
+    let linked = fs::hard_link(&partial, path);
+    let removed = fs::remove_file(&partial);
+    match linked {
+        Err(error) if error.kind() == io::ErrorKind::AlreadyExists => Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "file already exists",
+        )),
+        Err(error) => Err(error),
+        Ok(()) => removed,
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+fn span_css(style: &Style) -> String {
+    let (mut foreground, mut background) =
+        (css_color(style.foreground), css_color(style.background));
+    if style.inverse {
+        let swapped_foreground = background.unwrap_or_else(|| DEFAULT_BACKGROUND.to_owned());
+        let swapped_background = foreground.unwrap_or_else(|| DEFAULT_FOREGROUND.to_owned());
+        foreground = Some(swapped_foreground);
+        background = Some(swapped_background);
+    }
+    let mut css = Vec::new();
+    if let Some(color) = foreground {
+        css.push(format!("color: {color}"));
+    }
+    if let Some(color) = background {
+        css.push(format!("background: {color}"));
+    }
+    if style.bold {
+        css.push("font-weight: bold".to_owned());
+    }
+    if style.dim {
+        css.push("opacity: 0.6".to_owned());
+    }
+    if style.italic {
+        css.push("font-style: italic".to_owned());
+    }
+    let mut decorations = Vec::new();
+    if style.underline != UnderlineStyle::None {
+        decorations.push("underline");
+    }
+    if style.strikethrough {
+        decorations.push("line-through");
+    }
+    if style.overline {
+        decorations.push("overline");
+    }
+    if !decorations.is_empty() {
+        css.push(format!("text-decoration: {}", decorations.join(" ")));
+    }
+    if style.invisible {
+        css.push("visibility: hidden".to_owned());
+    }
+    css.join("; ")
+}
+
+fn css_color(color: Color) -> Option<String> {
+    let (red, green, blue) = match color {
+        Color::Default => return None,
+        Color::Rgb(red, green, blue) => (red, green, blue),
+        Color::Indexed(index) => indexed_rgb(index),
+    };
+    Some(format!("#{red:02x}{green:02x}{blue:02x}"))
+}
+
+/// The xterm 256-color palette.
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    const BASE: [(u8, u8, u8); 16] = [
+        (0x00, 0x00, 0x00),
+        (0xcd, 0x00, 0x00),
+        (0x00, 0xcd, 0x00),
+        (0xcd, 0xcd, 0x00),
+        (0x00, 0x00, 0xee),
+        (0xcd, 0x00, 0xcd),
+        (0x00, 0xcd, 0xcd),
+        (0xe5, 0xe5, 0xe5),
+        (0x7f, 0x7f, 0x7f),
+        (0xff, 0x00, 0x00),
+        (0x00, 0xff, 0x00),
+        (0xff, 0xff, 0x00),
+        (0x5c, 0x5c, 0xff),
+        (0xff, 0x00, 0xff),
+        (0x00, 0xff, 0xff),
+        (0xff, 0xff, 0xff),
+    ];
+    match index {
+        0..=15 => BASE[usize::from(index)],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let index = index - 16;
+            (level(index / 36), level(index / 6 % 6), level(index % 6))
+        }
+        232..=255 => {
+            let grey = 8 + (index - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TranscriptFormat, indexed_rgb, write_new_file};
+    use crate::{review::document::ReviewDocument, terminal::HistoryPosition};
+    use std::{fs, io, path::Path};
+
+    #[test]
+    fn transcripts_render_text_sgr_and_html() {
+        let document = ReviewDocument::from_text(
+            3,
+            20,
+            b"\x1b[1;31mred\x1b[0m <b>\r\n\x1b]8;;https://x.test\x1b\\link\x1b]8;;\x1b\\   ",
+        );
+        let transcript = document.transcript(
+            HistoryPosition { row: 0, col: 0 },
+            HistoryPosition { row: 1, col: 0 },
+            true,
+        );
+
+        assert_eq!(transcript.line_count(), 2);
+        assert_eq!(
+            transcript.render(TranscriptFormat::Text, "t"),
+            "red <b>\nlink\n"
+        );
+        assert_eq!(
+            transcript.render(TranscriptFormat::Sgr, "t"),
+            "\x1b[0;1;38;5;1mred\x1b[0m <b>\nlink\n"
+        );
+        let html = transcript.render(TranscriptFormat::Html, "a & b");
+        assert!(html.contains("<title>a &amp; b</title>"));
+        assert!(
+            html.contains(
+                "<span style=\"color: #cd0000; font-weight: bold\">red</span> &lt;b&gt;\n"
+            )
+        );
+        assert!(html.contains("<a href=\"https://x.test\">link</a>\n"));
+    }
+
+    #[test]
+    fn new_files_never_replace_existing_ones() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("notes").join("out.txt");
+        write_new_file(&path, b"first").unwrap();
+
+        let error = write_new_file(&path, b"second").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(&path).unwrap(), b"first");
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn formats_follow_the_extension_and_palette_matches_xterm() {
+        assert_eq!(
+            TranscriptFormat::from_path(Path::new("out.HTML")),
+            TranscriptFormat::Html
+        );
+        assert_eq!(
+            TranscriptFormat::from_path(Path::new("out.ans")),
+            TranscriptFormat::Sgr
+        );
+        assert_eq!(
+            TranscriptFormat::from_path(Path::new("out")),
+            TranscriptFormat::Text
+        );
+        assert_eq!(indexed_rgb(196), (0xff, 0, 0));
+        assert_eq!(indexed_rgb(244), (0x80, 0x80, 0x80));
+    }
+}
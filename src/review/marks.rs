@@ -0,0 +1,141 @@
+//! Marks and the jump list of a Review document.
+//!
+//! Both hold absolute document positions rather than viewport offsets, so
+//! they stay on the same text while Review scrolls. Prepending older tmux
+//! history shifts them with the rest of the document.
+
+use crate::terminal::HistoryPosition;
+use std::collections::BTreeMap;
+
+/// The mark named by `''` and ``` `` ```: where the cursor was before the
+/// latest jump.
+pub(crate) const PREVIOUS_CONTEXT: char = '\'';
+
+/// Jumps kept before the oldest are forgotten, as in vi.
+const MAX_JUMPS: usize = 100;
+
+#[derive(Debug, Default)]
+pub(crate) struct Marks {
+    named: BTreeMap<char, HistoryPosition>,
+    previous_context: Option<HistoryPosition>,
+    jumps: Vec<HistoryPosition>,
+    /// The entry `C-o` and `C-i` last moved to, or `jumps.len()` when the
+    /// cursor has not walked back into the list.
+    index: usize,
+}
+
+impl Marks {
+    pub(crate) fn set(&mut self, name: char, position: HistoryPosition) {
+        self.named.insert(name, position);
+    }
+
+    pub(crate) fn get(&self, name: char) -> Option<HistoryPosition> {
+        if name == PREVIOUS_CONTEXT {
+            return self.previous_context;
+        }
+        self.named.get(&name).copied()
+    }
+
+    /// Remembers `from` before a jump moves the cursor away from it. Like vi,
+    /// the list keeps one entry per line and a new jump ends any walk back
+    /// through it.
+    pub(crate) fn record_jump(&mut self, from: HistoryPosition) {
+        self.previous_context = Some(from);
+        self.push_jump(from);
+        self.index = self.jumps.len();
+    }
+
+    /// Moves `count` entries back through the jump list. Walking back from
+    /// the newest end first records `current`, so `C-i` can return to it.
+    pub(crate) fn older(
+        &mut self,
+        current: HistoryPosition,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        if self.index >= self.jumps.len() {
+            self.push_jump(current);
+            self.index = self.jumps.len().saturating_sub(1);
+        }
+        let index = self.index.checked_sub(count)?;
+        self.walk_to(current, index)
+    }
+
+    /// Moves `count` entries forward through the jump list.
+    pub(crate) fn newer(
+        &mut self,
+        current: HistoryPosition,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        let index = self.index.saturating_add(count);
+        if index >= self.jumps.len() {
+            return None;
+        }
+        self.walk_to(current, index)
+    }
+
+    /// Moves every position down by `rows` after rows are added above them.
+    pub(crate) fn shift(&mut self, rows: usize) {
+        let shift = |position: &mut HistoryPosition| {
+            position.row = position.row.saturating_add(rows);
+        };
+        self.named.values_mut().for_each(shift);
+        self.previous_context.iter_mut().for_each(shift);
+        self.jumps.iter_mut().for_each(shift);
+    }
+
+    fn walk_to(&mut self, current: HistoryPosition, index: usize) -> Option<HistoryPosition> {
+        let target = *self.jumps.get(index)?;
+        self.index = index;
+        self.previous_context = Some(current);
+        Some(target)
+    }
+
+    fn push_jump(&mut self, position: HistoryPosition) {
+        self.jumps.retain(|jump| jump.row != position.row);
+        self.jumps.push(position);
+        if self.jumps.len() > MAX_JUMPS {
+            self.jumps.remove(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Marks, PREVIOUS_CONTEXT};
+    use crate::terminal::HistoryPosition;
+
+    fn at(row: usize, col: u16) -> HistoryPosition {
+        HistoryPosition { row, col }
+    }
+
+    #[test]
+    fn marks_and_previous_context_follow_prepended_history() {
+        let mut marks = Marks::default();
+        marks.set('a', at(3, 4));
+        marks.record_jump(at(7, 0));
+        marks.shift(10);
+
+        assert_eq!(marks.get('a'), Some(at(13, 4)));
+        assert_eq!(marks.get(PREVIOUS_CONTEXT), Some(at(17, 0)));
+        assert_eq!(marks.get('b'), None);
+    }
+
+    #[test]
+    fn jump_list_walks_back_and_forward_with_one_entry_per_line() {
+        let mut marks = Marks::default();
+        marks.record_jump(at(1, 0));
+        marks.record_jump(at(5, 2));
+        marks.record_jump(at(1, 6));
+
+        assert_eq!(marks.older(at(9, 0), 1), Some(at(1, 6)));
+        assert_eq!(marks.older(at(1, 6), 1), Some(at(5, 2)));
+        assert_eq!(marks.older(at(5, 2), 1), None);
+        assert_eq!(marks.newer(at(5, 2), 2), Some(at(9, 0)));
+        assert_eq!(marks.newer(at(9, 0), 1), None);
+
+        marks.older(at(9, 0), 2);
+        marks.record_jump(at(5, 2));
+        assert_eq!(marks.newer(at(0, 0), 1), None);
+        assert_eq!(marks.older(at(0, 0), 1), Some(at(5, 2)));
+    }
+}
@@ -1,2 +1,5 @@
 pub(crate) mod document;
+pub(crate) mod export;
+pub(crate) mod marks;
 pub(crate) mod parser;
+pub(crate) mod styles;
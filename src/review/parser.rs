@@ -1,8 +1,53 @@
-use super::document::{SearchDirection, WordMove, WordStyle};
+use super::{
+    document::{SearchDirection, WordMove, WordStyle},
+    marks::PREVIOUS_CONTEXT,
+};
 use crate::clipboard::ClipboardRegister;
 
 const MAX_COUNT: usize = 10_000;
 
+/// Review's keys and what they do, as the bindings list shows them. They
+/// are found by feeding the parser every printable and Control key, then
+/// every key after those that wait for more, so the list follows the parser.
+/// `C-` marks a Control key; keys such as `f` and `m` wait for one more key
+/// and are listed alone. Counts are left out.
+pub(crate) fn review_keys() -> Vec<(String, &'static str)> {
+    let keys = || (' '..='~').map(Key::Char).chain(('a'..='z').map(Key::Ctrl));
+    let mut listed = Vec::new();
+    for first in keys().filter(|key| !matches!(key, Key::Char('1'..='9'))) {
+        let mut parser = Parser::default();
+        let command = parser.feed(first);
+        if let Some(help) = command.help().or_else(|| parser.pending_help()) {
+            listed.push((key_name(first), help));
+        }
+        if command != Command::None {
+            continue;
+        }
+        for second in keys() {
+            if let Some(help) = parser.clone().feed(second).help() {
+                listed.push((format!("{}{}", key_name(first), key_name(second)), help));
+            }
+        }
+    }
+    listed
+}
+
+fn key_name(key: Key) -> String {
+    match key {
+        Key::Ctrl(key) => format!("C-{key}"),
+        Key::Char(key) => key.to_string(),
+        key => format!("{key:?}"),
+    }
+}
+
+/// The input bytes that type `keys`, written as in [`review_keys`].
+pub(crate) fn review_key_bytes(keys: &str) -> Vec<u8> {
+    match keys.strip_prefix("C-") {
+        Some(key) if key.len() == 1 => vec![key.as_bytes()[0] & 0x1F],
+        _ => keys.as_bytes().to_vec(),
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub(crate) enum FindDirection {
     Forward,
@@ -33,6 +78,68 @@ pub(crate) enum Motion {
     Prompt {
         forward: bool,
     },
+    Link {
+        forward: bool,
+    },
+    /// `[a` and `]a`: the previous or next change of color, bold, inverse or
+    /// underline.
+    StyleChange {
+        forward: bool,
+    },
+    /// `'a` moves to the first nonblank of the mark's line; `` `a `` moves to
+    /// the exact position.
+    Mark {
+        name: char,
+        exact: bool,
+    },
+}
+
+impl Motion {
+    /// Motions that vi records in the jump list before moving.
+    pub(crate) fn is_jump(self) -> bool {
+        matches!(
+            self,
+            Self::DocumentStart
+                | Self::DocumentEnd
+                | Self::MatchingBrace
+                | Self::Prompt { .. }
+                | Self::Link { .. }
+                | Self::Mark { .. }
+        )
+    }
+
+    /// What the motion does, as the bindings list describes it.
+    fn help(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Left => "left",
+            Self::Down => "down",
+            Self::Up => "up",
+            Self::Right => "right",
+            Self::LineStart => "start of line",
+            Self::FirstNonblank => "first nonblank character",
+            Self::LineEnd => "end of line",
+            Self::Word(WordMove::ForwardStart, WordStyle::Word) => "next word",
+            Self::Word(WordMove::ForwardStart, WordStyle::BigWord) => "next blank-separated word",
+            Self::Word(WordMove::BackwardStart, WordStyle::Word) => "previous word",
+            Self::Word(WordMove::BackwardStart, WordStyle::BigWord) => {
+                "previous blank-separated word"
+            }
+            Self::Word(WordMove::ForwardEnd, WordStyle::Word) => "end of word",
+            Self::Word(WordMove::ForwardEnd, WordStyle::BigWord) => "end of blank-separated word",
+            Self::DocumentStart => "top of document",
+            Self::DocumentEnd => "bottom of document",
+            Self::MatchingBrace => "matching bracket",
+            Self::RepeatFind { reverse: false } => "repeat find",
+            Self::RepeatFind { reverse: true } => "repeat find backward",
+            Self::Prompt { forward: true } => "next prompt",
+            Self::Prompt { forward: false } => "previous prompt",
+            Self::Link { forward: true } => "next link",
+            Self::Link { forward: false } => "previous link",
+            Self::StyleChange { forward: true } => "next style change",
+            Self::StyleChange { forward: false } => "previous style change",
+            Self::Find { .. } | Self::Mark { .. } => return None,
+        })
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -76,10 +183,55 @@ pub(crate) enum Command {
     MoveVisual(Motion, usize),
     YankVisual(Option<ClipboardRegister>),
     StartSearch(SearchDirection),
+    /// `g/` and `g?`: search for text by its color and attributes.
+    StartStyleSearch(SearchDirection),
     RepeatSearch {
         reverse: bool,
         count: usize,
     },
+    SetMark(char),
+    Jump {
+        newer: bool,
+        count: usize,
+    },
+}
+
+impl Command {
+    /// What the command does, as the bindings list describes it. Commands
+    /// that name a character or register, and those only visual mode or a
+    /// pending operator produce, have none.
+    fn help(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Exit => "close review",
+            Self::Move(motion, _) => return motion.help(),
+            Self::ScrollPage { forward: true, .. } => "page down",
+            Self::ScrollPage { forward: false, .. } => "page up",
+            Self::RepositionViewport {
+                placement,
+                first_nonblank,
+                ..
+            } => match (placement, first_nonblank) {
+                (ViewportPlacement::Top, false) => "line to top of screen",
+                (ViewportPlacement::Top, true) => "line to top of screen, first nonblank",
+                (ViewportPlacement::Center, false) => "line to middle of screen",
+                (ViewportPlacement::Center, true) => "line to middle of screen, first nonblank",
+                (ViewportPlacement::Bottom, false) => "line to bottom of screen",
+                (ViewportPlacement::Bottom, true) => "line to bottom of screen, first nonblank",
+            },
+            Self::StartVisual(VisualKind::Character) => "visual selection",
+            Self::StartVisual(VisualKind::Line) => "visual line selection",
+            Self::StartSearch(SearchDirection::Forward) => "search forward",
+            Self::StartSearch(SearchDirection::Backward) => "search backward",
+            Self::StartStyleSearch(SearchDirection::Forward) => "search style forward",
+            Self::StartStyleSearch(SearchDirection::Backward) => "search style backward",
+            Self::RepeatSearch { reverse: false, .. } => "next match",
+            Self::RepeatSearch { reverse: true, .. } => "previous match",
+            Self::Jump { newer: true, .. } => "newer jump",
+            Self::Jump { newer: false, .. } => "older jump",
+            Self::YankLine(..) => "yank line",
+            _ => return None,
+        })
+    }
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -108,6 +260,10 @@ enum Prefix {
         direction: FindDirection,
         till: bool,
     },
+    SetMark,
+    Mark {
+        exact: bool,
+    },
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -122,6 +278,7 @@ enum OperatorState {
     },
 }
 
+#[derive(Clone)]
 pub(crate) struct Parser {
     count: Option<usize>,
     prefix: Prefix,
@@ -145,6 +302,38 @@ impl Default for Parser {
 }
 
 impl Parser {
+    /// What the keys fed so far wait to do, for keys that need one more.
+    fn pending_help(&self) -> Option<&'static str> {
+        if self.awaiting_register {
+            return Some("choose register");
+        }
+        if matches!(self.operator, OperatorState::Yank { .. }) {
+            return Some("yank");
+        }
+        Some(match self.prefix {
+            Prefix::Find {
+                direction: FindDirection::Forward,
+                till: false,
+            } => "find character forward",
+            Prefix::Find {
+                direction: FindDirection::Backward,
+                till: false,
+            } => "find character backward",
+            Prefix::Find {
+                direction: FindDirection::Forward,
+                till: true,
+            } => "till character forward",
+            Prefix::Find {
+                direction: FindDirection::Backward,
+                till: true,
+            } => "till character backward",
+            Prefix::SetMark => "set mark",
+            Prefix::Mark { exact: false } => "line of mark",
+            Prefix::Mark { exact: true } => "position of mark",
+            Prefix::None | Prefix::G | Prefix::Z | Prefix::Bracket { .. } => return None,
+        })
+    }
+
     pub(crate) fn feed(&mut self, key: Key) -> Command {
         if key == Key::Escape {
             return self.escape();
@@ -234,6 +423,20 @@ impl Parser {
             Key::Char('F') => self.start_find(FindDirection::Backward, false),
             Key::Char('t') => self.start_find(FindDirection::Forward, true),
             Key::Char('T') => self.start_find(FindDirection::Backward, true),
+            Key::Char('m') => {
+                self.prefix = Prefix::SetMark;
+                Command::None
+            }
+            Key::Char('\'') => {
+                self.prefix = Prefix::Mark { exact: false };
+                Command::None
+            }
+            Key::Char('`') => {
+                self.prefix = Prefix::Mark { exact: true };
+                Command::None
+            }
+            Key::Ctrl('o') => self.jump(false),
+            Key::Ctrl('i') => self.jump(true),
             Key::Char('/') => {
                 let direction = SearchDirection::Forward;
                 self.finish(Command::StartSearch(direction))
@@ -310,6 +513,18 @@ impl Parser {
             Key::Char('F') => self.start_find(FindDirection::Backward, false),
             Key::Char('t') => self.start_find(FindDirection::Forward, true),
             Key::Char('T') => self.start_find(FindDirection::Backward, true),
+            Key::Char('m') => {
+                self.prefix = Prefix::SetMark;
+                Command::None
+            }
+            Key::Char('\'') => {
+                self.prefix = Prefix::Mark { exact: false };
+                Command::None
+            }
+            Key::Char('`') => {
+                self.prefix = Prefix::Mark { exact: true };
+                Command::None
+            }
             key => match motion_for_key(key) {
                 Some(motion) => {
                     let count = self.take_count();
@@ -342,19 +557,26 @@ impl Parser {
                 first_nonblank,
             });
         }
+        if prefix == Prefix::SetMark {
+            let Key::Char(name @ 'a'..='z') = key else {
+                self.reset_pending();
+                return Command::Bell;
+            };
+            return self.finish(Command::SetMark(name));
+        }
+        if prefix == Prefix::G
+            && self.visual.is_none()
+            && let Key::Char(marker @ ('/' | '?')) = key
+        {
+            let direction = if marker == '/' {
+                SearchDirection::Forward
+            } else {
+                SearchDirection::Backward
+            };
+            return self.finish(Command::StartStyleSearch(direction));
+        }
         let count = pending_count.unwrap_or(1).max(1);
-        let motion = match (prefix, key) {
-            (Prefix::G, Key::Char('g')) => Some(Motion::DocumentStart),
-            (Prefix::Bracket { forward }, Key::Char('p')) => Some(Motion::Prompt { forward }),
-            (Prefix::Find { direction, till }, Key::Char(target)) => Some(Motion::Find {
-                direction,
-                till,
-                target,
-            }),
-            (Prefix::Z, _) => None,
-            _ => None,
-        };
-        let Some(motion) = motion else {
+        let Some(motion) = prefix_motion(prefix, key) else {
             self.reset_pending();
             return Command::Bell;
         };
@@ -417,18 +639,7 @@ impl Parser {
         }
 
         if prefix != Prefix::None {
-            let motion = match (prefix, key) {
-                (Prefix::G, Key::Char('g')) => Some(Motion::DocumentStart),
-                (Prefix::Bracket { forward }, Key::Char('p')) => Some(Motion::Prompt { forward }),
-                (Prefix::Find { direction, till }, Key::Char(target)) => Some(Motion::Find {
-                    direction,
-                    till,
-                    target,
-                }),
-                (Prefix::Z, _) => None,
-                _ => None,
-            };
-            let Some(motion) = motion else {
+            let Some(motion) = prefix_motion(prefix, key) else {
                 self.reset_pending();
                 return Command::Bell;
             };
@@ -496,6 +707,19 @@ impl Parser {
                 };
                 Command::None
             }
+            Key::Char('\'') | Key::Char('`') => {
+                prefix = Prefix::Mark {
+                    exact: key == Key::Char('`'),
+                };
+                self.operator = OperatorState::Yank {
+                    count,
+                    register,
+                    prefix,
+                    motion_count,
+                    text_object_around,
+                };
+                Command::None
+            }
             Key::Char('f') | Key::Char('F') | Key::Char('t') | Key::Char('T') => {
                 let (direction, till) = match key {
                     Key::Char('f') => (FindDirection::Forward, false),
@@ -529,6 +753,7 @@ impl Parser {
         self.register = match key {
             Key::Char('"') => Some(ClipboardRegister::Internal),
             Key::Char('+') => Some(ClipboardRegister::System),
+            Key::Char(name @ 'a'..='z') => Some(ClipboardRegister::Named(name)),
             _ => {
                 self.reset_pending();
                 return Command::Bell;
@@ -542,6 +767,11 @@ impl Parser {
         Command::None
     }
 
+    fn jump(&mut self, newer: bool) -> Command {
+        let count = self.take_count();
+        self.finish(Command::Jump { newer, count })
+    }
+
     fn page(&mut self, forward: bool) -> Command {
         let count = self.take_count();
         self.finish(Command::ScrollPage { forward, count })
@@ -584,6 +814,27 @@ fn multiply_counts(left: usize, right: usize) -> usize {
     left.saturating_mul(right).min(MAX_COUNT)
 }
 
+fn prefix_motion(prefix: Prefix, key: Key) -> Option<Motion> {
+    match (prefix, key) {
+        (Prefix::G, Key::Char('g')) => Some(Motion::DocumentStart),
+        (Prefix::Bracket { forward }, Key::Char('p')) => Some(Motion::Prompt { forward }),
+        (Prefix::Bracket { forward }, Key::Char('l')) => Some(Motion::Link { forward }),
+        (Prefix::Bracket { forward }, Key::Char('a')) => Some(Motion::StyleChange { forward }),
+        (Prefix::Find { direction, till }, Key::Char(target)) => Some(Motion::Find {
+            direction,
+            till,
+            target,
+        }),
+        (Prefix::Mark { exact }, Key::Char(name @ ('a'..='z' | '\'' | '`'))) => {
+            Some(Motion::Mark {
+                name: if name == '`' { PREVIOUS_CONTEXT } else { name },
+                exact,
+            })
+        }
+        _ => None,
+    }
+}
+
 fn motion_for_key(key: Key) -> Option<Motion> {
     match key {
         Key::Char('h') | Key::Left => Some(Motion::Left),
@@ -611,6 +862,7 @@ fn motion_for_key(key: Key) -> Option<Motion> {
 mod tests {
     use super::{
         Command, FindDirection, Key, Motion, Parser, TextObject, ViewportPlacement, VisualKind,
+        review_key_bytes, review_keys,
     };
     use crate::clipboard::ClipboardRegister;
     use crate::review::document::{SearchDirection, WordMove, WordStyle};
@@ -668,6 +920,24 @@ mod tests {
                 Command::Move(Motion::Prompt { forward: false }, 1)
             ]
         );
+        assert_eq!(
+            feed(
+                &mut parser,
+                &[Key::Char('2'), Key::Char(']'), Key::Char('l')]
+            ),
+            vec![
+                Command::None,
+                Command::None,
+                Command::Move(Motion::Link { forward: true }, 2)
+            ]
+        );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('['), Key::Char('a')]),
+            vec![
+                Command::None,
+                Command::Move(Motion::StyleChange { forward: false }, 1)
+            ]
+        );
         assert_eq!(
             parser.feed(Key::Char('%')),
             Command::Move(Motion::MatchingBrace, 1)
@@ -801,6 +1071,88 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_marks_jumps_and_named_registers() {
+        let mut parser = Parser::default();
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('m'), Key::Char('a')]),
+            vec![Command::None, Command::SetMark('a')]
+        );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('\''), Key::Char('a')]),
+            vec![
+                Command::None,
+                Command::Move(
+                    Motion::Mark {
+                        name: 'a',
+                        exact: false
+                    },
+                    1
+                )
+            ]
+        );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('`'), Key::Char('`')]),
+            vec![
+                Command::None,
+                Command::Move(
+                    Motion::Mark {
+                        name: '\'',
+                        exact: true
+                    },
+                    1
+                )
+            ]
+        );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('2'), Key::Ctrl('o')]),
+            vec![
+                Command::None,
+                Command::Jump {
+                    newer: false,
+                    count: 2
+                }
+            ]
+        );
+        assert_eq!(
+            parser.feed(Key::Ctrl('i')),
+            Command::Jump {
+                newer: true,
+                count: 1
+            }
+        );
+        assert_eq!(
+            feed(
+                &mut parser,
+                &[
+                    Key::Char('"'),
+                    Key::Char('q'),
+                    Key::Char('y'),
+                    Key::Char('`'),
+                    Key::Char('b')
+                ]
+            ),
+            vec![
+                Command::None,
+                Command::None,
+                Command::None,
+                Command::None,
+                Command::YankMotion(
+                    Motion::Mark {
+                        name: 'b',
+                        exact: true
+                    },
+                    1,
+                    Some(ClipboardRegister::Named('q'))
+                )
+            ]
+        );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('m'), Key::Char('A')]),
+            vec![Command::None, Command::Bell]
+        );
+    }
+
     #[test]
     fn parses_search_and_repetition() {
         let mut parser = Parser::default();
@@ -812,6 +1164,13 @@ mod tests {
             parser.feed(Key::Char('?')),
             Command::StartSearch(SearchDirection::Backward)
         );
+        assert_eq!(
+            feed(&mut parser, &[Key::Char('g'), Key::Char('/')]),
+            vec![
+                Command::None,
+                Command::StartStyleSearch(SearchDirection::Forward)
+            ]
+        );
         parser.feed(Key::Char('3'));
         assert_eq!(
             parser.feed(Key::Char('N')),
@@ -897,6 +1256,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn listed_review_keys_are_all_accepted() {
+        let listed = review_keys();
+        for (keys, help) in [
+            ("j", "down"),
+            ("gg", "top of document"),
+            ("f", "find character forward"),
+            ("]l", "next link"),
+            ("yy", "yank line"),
+            ("\"", "choose register"),
+            ("C-o", "older jump"),
+            ("zz", "line to middle of screen"),
+            ("q", "close review"),
+        ] {
+            assert!(listed.contains(&(keys.to_owned(), help)), "{keys} {help}");
+        }
+        for (keys, _) in &listed {
+            let mut parser = Parser::default();
+            let keys = review_key_bytes(keys)
+                .into_iter()
+                .map(|byte| match byte {
+                    0x01..=0x1F => Key::Ctrl(char::from(byte | 0x60)),
+                    byte => Key::Char(char::from(byte)),
+                })
+                .collect::<Vec<_>>();
+            assert!(
+                !feed(&mut parser, &keys).contains(&Command::Bell),
+                "{keys:?}"
+            );
+        }
+        assert_eq!(review_key_bytes("C-o"), b"\x0f");
+    }
+
     #[test]
     fn invalid_chords_bell_and_reset() {
         let mut parser = Parser::default();
@@ -0,0 +1,140 @@
+//! Style queries for attribute search in Review.
+//!
+//! `g/` and `g?` find styled text rather than a pattern: `g/red` finds the
+//! next red text, `g/bold on blue` bold text on a blue background, and
+//! `g/red:error` red text where `error` starts.
+
+use crate::{
+    attributes::ColorSet,
+    terminal::{Color, Style, UnderlineStyle},
+};
+use anyhow::{Result, bail};
+use regex::{Regex, RegexBuilder};
+
+/// What `[a` and `]a` compare between neighbouring cells.
+pub(crate) fn style_key(style: &Style) -> (Color, Color, bool, bool, bool) {
+    (
+        style.foreground,
+        style.background,
+        style.bold,
+        style.inverse,
+        style.underline != UnderlineStyle::None,
+    )
+}
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StyleQuery {
+    foreground: Option<ColorSet>,
+    background: Option<ColorSet>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    inverse: bool,
+    strikethrough: bool,
+    text: Option<Regex>,
+}
+
+impl StyleQuery {
+    /// Parses a style search: attribute and color words,
+    /// `on` before a background color, and an optional `:` and pattern.
+    pub(crate) fn parse(spec: &str) -> Result<Self> {
+        let (words, pattern) = match spec.split_once(':') {
+            Some((words, pattern)) => (words, Some(pattern)),
+            None => (spec, None),
+        };
+        let mut query = Self::default();
+        let mut words = words.split_whitespace();
+        while let Some(word) = words.next() {
+            match word.to_ascii_lowercase().as_str() {
+                "bold" => query.bold = true,
+                "dim" => query.dim = true,
+                "italic" => query.italic = true,
+                "underline" | "underlined" => query.underline = true,
+                "inverse" | "reverse" => query.inverse = true,
+                "strikethrough" => query.strikethrough = true,
+                "on" => {
+                    let Some(color) = words.next() else {
+                        bail!("on needs a background color");
+                    };
+                    query.background = Some(color_word(color)?);
+                }
+                _ if query.foreground.is_none() => query.foreground = Some(color_word(word)?),
+                _ => bail!("unknown style word {word}"),
+            }
+        }
+        if let Some(pattern) = pattern.filter(|pattern| !pattern.is_empty()) {
+            query.text = Some(RegexBuilder::new(pattern).multi_line(true).build()?);
+        }
+        if query.is_empty() {
+            bail!("style search needs a color or attribute");
+        }
+        Ok(query)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.foreground.is_none()
+            && self.background.is_none()
+            && !(self.bold
+                || self.dim
+                || self.italic
+                || self.underline
+                || self.inverse
+                || self.strikethrough)
+    }
+
+    pub(crate) fn text(&self) -> Option<&Regex> {
+        self.text.as_ref()
+    }
+
+    pub(crate) fn matches(&self, style: &Style) -> bool {
+        let color = |wanted: &Option<ColorSet>, color: Color| {
+            wanted.as_ref().is_none_or(|set| set.contains(color))
+        };
+        color(&self.foreground, style.foreground)
+            && color(&self.background, style.background)
+            && (!self.bold || style.bold)
+            && (!self.dim || style.dim)
+            && (!self.italic || style.italic)
+            && (!self.underline || style.underline != UnderlineStyle::None)
+            && (!self.inverse || style.inverse)
+            && (!self.strikethrough || style.strikethrough)
+    }
+}
+
+fn color_word(word: &str) -> Result<ColorSet> {
+    match ColorSet::parse(word) {
+        Some(set) => Ok(set),
+        None => bail!("unknown color {word}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StyleQuery;
+    use crate::terminal::{Color, Style};
+
+    #[test]
+    fn queries_combine_colors_attributes_and_patterns() {
+        let query = StyleQuery::parse("Red bold on blue:err.r").unwrap();
+        let mut style = Style {
+            foreground: Color::Indexed(1),
+            background: Color::Indexed(4),
+            bold: true,
+            ..Style::default()
+        };
+        assert!(query.matches(&style));
+        assert!(query.text().unwrap().is_match("error"));
+        style.bold = false;
+        assert!(!query.matches(&style));
+
+        assert!(StyleQuery::parse("inverse").unwrap().matches(&Style {
+            inverse: true,
+            ..Style::default()
+        }));
+        assert!(StyleQuery::parse("").is_err());
+        assert!(StyleQuery::parse("red green").is_err());
+        assert!(StyleQuery::parse("on").is_err());
+        assert!(StyleQuery::parse("bold:(").is_err());
+    }
+}
@@ -1,20 +1,45 @@
 use super::{
     clipboard::{Clipboard, ClipboardRegister, SystemClipboard, SystemClipboardProvider},
     keymap::{InputMode, KeyBindings},
+    regions::Regions,
+    selection::{SelectionHints, SelectionTracker},
     speech::{self, Speech, SpeechServerSpec},
-    table::Session as TableSession,
+    table::{ExportFormat, Session as TableSession, TableLayouts},
 };
 use mlua::{Lua, WeakLua};
-use std::{collections::VecDeque, fmt, rc::Rc, str::FromStr};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    rc::Rc,
+    str::FromStr,
+};
 
 mod auto_read;
 mod hooks;
+mod images;
+mod key_echo;
+mod links;
+mod notifications;
 mod options;
+mod pane_speech;
+mod secret_entry;
+mod terminal_status;
 mod tracking;
 
+pub(crate) use hooks::HOOK_NAMES;
+pub(crate) use images::image_description;
+pub use key_echo::KeyEchoMode;
+pub(crate) use key_echo::TypedKey;
+pub use links::LinkOpener;
+pub(crate) use notifications::NoticeSource;
+pub use pane_speech::PaneSpeechProfile;
+pub(crate) use terminal_status::directory_from_osc7;
+
 use auto_read::AutoReadBuffers;
 use hooks::LuaHooks;
+use key_echo::KeyEcho;
 use options::Options;
+use secret_entry::SecretEntry;
 use tracking::{CursorTrackingMode, PendingDelete};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -76,6 +101,8 @@ pub enum Error {
     UnknownHook(String),
     #[error("hook value must be a function or nil")]
     InvalidHookValue,
+    #[error("unknown mode {0}")]
+    UnknownMode(String),
     #[error("Lua bindings are only available in init.lua")]
     InvalidLuaBindingContext,
     #[error("Lua hooks are only available in init.lua")]
@@ -98,22 +125,39 @@ pub struct ScreenReader {
     speech: Speech,
     speech_server_spec: SpeechServerSpec,
     pending_speech_reconfiguration: Option<SpeechServerSpec>,
+    pending_tmux_attach: Option<crate::tmux_launch::TmuxAttachRequest>,
     lua_configuration_open: bool,
     options: Options,
     last_key: Vec<u8>,
     pending_key_echo: VecDeque<PendingKeyEcho>,
     key_echo_stream_active: bool,
+    key_echo: KeyEcho,
+    secret_entry: SecretEntry,
+    line_discipline_probe: Option<crate::pty::LineDisciplineProbe>,
     cursor_tracking_mode: CursorTrackingMode,
     clipboard: Clipboard,
     system_clipboard: SystemClipboard,
+    named_registers: BTreeMap<char, String>,
     pass_through: bool,
     key_bindings: KeyBindings,
+    user_mode: Option<InputMode>,
     table_session: TableSession,
+    table_layouts: TableLayouts,
+    selection: SelectionTracker,
+    selection_hints: SelectionHints,
+    regions: Regions,
     terminal_focused: bool,
     lua_ctx: Option<Rc<Lua>>,
     lua_ctx_weak: Option<WeakLua>,
     lua_hooks: LuaHooks,
     auto_read_buffers: AutoReadBuffers,
+    pane_speech: BTreeMap<(u64, crate::tmux_model::PaneId), PaneSpeechProfile>,
+    active_tmux_pane: Option<(u64, crate::tmux_model::PaneId)>,
+    /// The global voice, saved while the active pane's profile selects
+    /// another one.
+    global_voice: Option<Option<String>>,
+    notices: BTreeMap<notifications::NoticeSource, notifications::NoticeState>,
+    terminal_status: BTreeMap<notifications::NoticeSource, terminal_status::TerminalStatus>,
     pending_deletes: VecDeque<PendingDelete>,
     input_sequence: u64,
     pending_history_navigation: bool,
@@ -132,22 +176,37 @@ impl ScreenReader {
             speech,
             speech_server_spec: SpeechServerSpec::default(),
             pending_speech_reconfiguration: None,
+            pending_tmux_attach: None,
             lua_configuration_open: false,
             options: Options::default(),
             last_key: Vec::new(),
             pending_key_echo: VecDeque::new(),
             key_echo_stream_active: false,
+            key_echo: KeyEcho::default(),
+            secret_entry: SecretEntry::default(),
+            line_discipline_probe: None,
             cursor_tracking_mode: CursorTrackingMode::On,
             clipboard: Default::default(),
             system_clipboard: Default::default(),
+            named_registers: BTreeMap::new(),
             pass_through: false,
             key_bindings: KeyBindings::new(),
+            user_mode: None,
             table_session: TableSession::default(),
+            table_layouts: TableLayouts::default(),
+            selection: SelectionTracker::default(),
+            selection_hints: SelectionHints::default(),
+            regions: Regions::default(),
             terminal_focused: true,
             lua_ctx: None,
             lua_ctx_weak: None,
             lua_hooks: LuaHooks::default(),
             auto_read_buffers: AutoReadBuffers::default(),
+            pane_speech: BTreeMap::new(),
+            active_tmux_pane: None,
+            global_voice: None,
+            notices: BTreeMap::new(),
+            terminal_status: BTreeMap::new(),
             pending_deletes: VecDeque::new(),
             input_sequence: 0,
             pending_history_navigation: false,
@@ -198,6 +257,17 @@ impl ScreenReader {
         self.speech_server_spec = spec;
     }
 
+    /// Queue a `tmux -CC` launch for the app to type into the focused shell.
+    ///
+    /// Only the latest unconsumed request is retained.
+    pub fn request_tmux_attach(&mut self, request: crate::tmux_launch::TmuxAttachRequest) {
+        self.pending_tmux_attach = Some(request);
+    }
+
+    pub fn take_tmux_attach_request(&mut self) -> Option<crate::tmux_launch::TmuxAttachRequest> {
+        self.pending_tmux_attach.take()
+    }
+
     /// Select the process backend that the speech worker should start or
     /// transactionally replace.
     pub fn configure_speech_server(&mut self, spec: SpeechServerSpec) -> Result<()> {
@@ -216,8 +286,58 @@ impl ScreenReader {
         self.speech.shutdown();
     }
 
+    /// Table modes take over from a user mode until they end.
     pub fn input_mode(&self) -> InputMode {
-        self.table_session.mode()
+        match self.table_session.mode() {
+            InputMode::Normal => self.user_mode.unwrap_or(InputMode::Normal),
+            mode => mode,
+        }
+    }
+
+    /// The configuration name of the current input mode, such as `table` or
+    /// a mode declared in `lector.modes`.
+    pub fn input_mode_name(&self) -> &str {
+        self.key_bindings.mode_name(self.input_mode())
+    }
+
+    /// Enters the user mode `name`, leaving any other user mode. In help
+    /// mode, its keys are listed.
+    pub fn enter_user_mode(&mut self, name: &str) -> Result<()> {
+        let Some(mode) = self
+            .key_bindings
+            .mode(name)
+            .filter(|mode| matches!(mode, InputMode::User(_)))
+        else {
+            return Err(Error::UnknownMode(name.to_owned()));
+        };
+        let old = self.input_mode();
+        self.user_mode = Some(mode);
+        self.hook_on_mode_change(old, self.input_mode())?;
+        let announcement = format!("{name} mode on");
+        self.speak(&announcement, false)?;
+        if self.help_mode() {
+            let help = self.key_bindings.mode_help(mode);
+            self.speak(&help, false)?;
+        }
+        Ok(())
+    }
+
+    /// Leaves the current user mode. Returns whether one was active.
+    pub fn exit_user_mode(&mut self) -> Result<bool> {
+        let Some(mode) = self.user_mode else {
+            return Ok(false);
+        };
+        let old = self.input_mode();
+        self.user_mode = None;
+        self.hook_on_mode_change(old, self.input_mode())?;
+        let announcement = format!("{} mode off", self.key_bindings.mode_name(mode));
+        self.speak(&announcement, false)?;
+        Ok(true)
+    }
+
+    /// The active user mode, which may be covered by a table mode.
+    pub(crate) fn user_mode(&self) -> Option<InputMode> {
+        self.user_mode
     }
 
     pub(crate) fn speech(&self) -> &Speech {
@@ -240,12 +360,14 @@ impl ScreenReader {
     pub(crate) fn record_last_key(&mut self, raw: &[u8]) {
         self.input_sequence = self.input_sequence.wrapping_add(1);
         self.last_key.clear();
-        self.last_key.extend_from_slice(raw);
+        if !self.secret_entry_active() {
+            self.last_key.extend_from_slice(raw);
+        }
     }
 
     #[cfg(test)]
     pub(crate) fn record_forwarded_character(&mut self, character: char) {
-        if !self.suppress_key_echo() {
+        if !self.suppresses_screen_key_echo() || self.secret_entry_active() {
             return;
         }
         if self.pending_key_echo.len() == MAX_PENDING_KEY_ECHO_CHARS {
@@ -265,7 +387,11 @@ impl ScreenReader {
         if text.is_none_or(str::is_empty) {
             self.key_echo_stream_active = false;
         }
-        let Some(text) = text.filter(|_| self.suppress_key_echo()) else {
+        // Secrets are never echoed, so there is nothing to acknowledge, and
+        // they are not kept.
+        let Some(text) =
+            text.filter(|_| self.suppresses_screen_key_echo() && !self.secret_entry_active())
+        else {
             return;
         };
         for character in text.chars() {
@@ -278,7 +404,56 @@ impl ScreenReader {
     }
 
     fn has_pending_key_echo(&self) -> bool {
-        self.suppress_key_echo() && !self.pending_key_echo.is_empty()
+        self.suppresses_screen_key_echo() && !self.pending_key_echo.is_empty()
+    }
+
+    /// Speaks typing echo for a key sent to the terminal, following
+    /// `key_echo`.
+    pub(crate) fn echo_forwarded_key(&mut self, key: TypedKey<'_>) -> Result<()> {
+        if self.secret_entry_active() {
+            self.key_echo.clear();
+            return Ok(());
+        }
+        let mode = self.key_echo_mode();
+        let speech = &self.speech;
+        let utterances = self.key_echo.typed(mode, key, |text| {
+            speech.symbol(text).map(|symbol| symbol.replacement.clone())
+        });
+        for (index, utterance) in utterances.iter().enumerate() {
+            self.speak(utterance, index == 0)?;
+        }
+        Ok(())
+    }
+
+    /// Records the child PTY's echo flags for secret entry detection.
+    pub fn set_line_discipline(&mut self, line_discipline: Option<crate::pty::LineDiscipline>) {
+        self.secret_entry.set_line_discipline(line_discipline);
+    }
+
+    /// Where the child PTY's echo flags are read from when input arrives or
+    /// output is about to be spoken.
+    pub fn set_line_discipline_probe(&mut self, probe: Option<crate::pty::LineDisciplineProbe>) {
+        self.line_discipline_probe = probe;
+    }
+
+    /// Rereads the echo flags through the probe, if there is one. Echo flags
+    /// change without output, as when a program starts reading a password.
+    pub(crate) fn refresh_line_discipline(&mut self) {
+        if let Some(probe) = &self.line_discipline_probe {
+            self.secret_entry.set_line_discipline(probe.read());
+        }
+    }
+
+    /// Whether the application is reading a password, so typed keys must not
+    /// be spoken, remembered, or logged.
+    pub fn secret_entry_active(&self) -> bool {
+        self.secret_entry.active()
+    }
+
+    /// Screen echo of typed keys goes unspoken when asked for, or when the
+    /// keys are already echoed as they are sent.
+    fn suppresses_screen_key_echo(&self) -> bool {
+        self.suppress_key_echo() || self.key_echo_mode() != KeyEchoMode::None
     }
 
     pub(crate) fn set_pending_history_navigation(&mut self) {
@@ -302,7 +477,7 @@ impl ScreenReader {
         // row delimiter which did not come from a printable key. Preserve
         // spaces exactly, but exclude those record delimiters from matching.
         let text = text.trim_end_matches(['\r', '\n']);
-        if !self.suppress_key_echo() || text.is_empty() {
+        if !self.suppresses_screen_key_echo() || text.is_empty() {
             return false;
         }
 
@@ -410,6 +585,7 @@ impl ScreenReader {
                 .system_clipboard
                 .read(self.options.system_clipboard_provider())
                 .map_err(|error| Error::Clipboard(error.to_string())),
+            ClipboardRegister::Named(name) => Ok(self.named_registers.get(&name).cloned()),
         }
     }
 
@@ -424,6 +600,10 @@ impl ScreenReader {
                 .system_clipboard
                 .write(self.options.system_clipboard_provider(), text)
                 .map_err(|error| Error::Clipboard(error.to_string())),
+            ClipboardRegister::Named(name) => {
+                self.named_registers.insert(name, text);
+                Ok(())
+            }
         }
     }
 
@@ -437,6 +617,10 @@ impl ScreenReader {
                 .system_clipboard
                 .clear(self.options.system_clipboard_provider())
                 .map_err(|error| Error::Clipboard(error.to_string())),
+            ClipboardRegister::Named(name) => {
+                self.named_registers.remove(&name);
+                Ok(())
+            }
         }
     }
 
@@ -546,7 +730,20 @@ impl ScreenReader {
 
     pub fn set_suppress_key_echo(&mut self, value: bool) {
         self.options.set_suppress_key_echo(value);
-        if !value {
+        if !self.suppresses_screen_key_echo() {
+            self.pending_key_echo.clear();
+            self.key_echo_stream_active = false;
+        }
+    }
+
+    pub fn key_echo_mode(&self) -> KeyEchoMode {
+        self.options.key_echo()
+    }
+
+    pub fn set_key_echo_mode(&mut self, value: KeyEchoMode) {
+        self.options.set_key_echo(value);
+        self.key_echo.clear();
+        if !self.suppresses_screen_key_echo() {
             self.pending_key_echo.clear();
             self.key_echo_stream_active = false;
         }
@@ -580,6 +777,14 @@ impl ScreenReader {
         self.options.set_highlight_tracking(value);
     }
 
+    pub fn selection_tracking_enabled(&self) -> bool {
+        self.options.selection_tracking()
+    }
+
+    pub fn set_selection_tracking_enabled(&mut self, value: bool) {
+        self.options.set_selection_tracking(value);
+    }
+
     pub fn table_header_auto(&self) -> bool {
         self.options.table_header_auto()
     }
@@ -588,6 +793,14 @@ impl ScreenReader {
         self.options.toggle_table_header_auto()
     }
 
+    pub(crate) fn table_export_format(&self) -> ExportFormat {
+        self.options.table_export_format()
+    }
+
+    pub(crate) fn set_table_export_format(&mut self, value: ExportFormat) {
+        self.options.set_table_export_format(value);
+    }
+
     pub fn stop_speech_on_focus_loss(&self) -> bool {
         self.options.stop_speech_on_focus_loss()
     }
@@ -596,6 +809,23 @@ impl ScreenReader {
         self.options.set_stop_speech_on_focus_loss(value);
     }
 
+    pub fn mouse_hover_enabled(&self) -> bool {
+        self.options.mouse_hover()
+    }
+
+    pub fn set_mouse_hover_enabled(&mut self, value: bool) {
+        self.options.set_mouse_hover(value);
+    }
+
+    /// The Lua file evaluated when the REPL first opens.
+    pub fn repl_scratch_file(&self) -> Option<&str> {
+        self.options.repl_scratch_file()
+    }
+
+    pub fn set_repl_scratch_file(&mut self, value: Option<String>) {
+        self.options.set_repl_scratch_file(value);
+    }
+
     pub fn tmux_bell_mode(&self) -> TmuxBellMode {
         self.options.tmux_bell_mode()
     }
@@ -616,6 +846,34 @@ impl ScreenReader {
         &mut self.table_session
     }
 
+    pub(crate) fn table_layouts_mut(&mut self) -> &mut TableLayouts {
+        &mut self.table_layouts
+    }
+
+    pub(crate) fn selection_hints(&self) -> &SelectionHints {
+        &self.selection_hints
+    }
+
+    pub(crate) fn selection_hints_mut(&mut self) -> &mut SelectionHints {
+        &mut self.selection_hints
+    }
+
+    pub(crate) fn regions(&self) -> &Regions {
+        &self.regions
+    }
+
+    pub(crate) fn regions_mut(&mut self) -> &mut Regions {
+        &mut self.regions
+    }
+
+    /// Keep saved table layouts in the user's data directory. Until this is
+    /// called, layouts can only be defined from Lua.
+    pub fn use_default_table_layouts_path(&mut self) {
+        if let Some(path) = crate::table::default_layouts_path() {
+            self.table_layouts.set_path(path);
+        }
+    }
+
     pub fn speak(&mut self, text: &str, interrupt: bool) -> Result<()> {
         if text.is_empty() || !self.terminal_focused {
             return Ok(());
@@ -661,6 +919,13 @@ mod tests {
         fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
             Ok(())
         }
+
+        fn set_voice(&mut self, voice: Option<&str>) -> anyhow::Result<()> {
+            self.speaks
+                .borrow_mut()
+                .push(format!("[voice {}]", voice.unwrap_or("default")));
+            Ok(())
+        }
     }
 
     fn make_sr() -> (ScreenReader, Rc<RefCell<Vec<String>>>) {
@@ -673,6 +938,74 @@ mod tests {
         (sr, speaks)
     }
 
+    #[test]
+    fn pane_speech_applies_only_while_reading_the_active_pane() {
+        let (mut sr, speaks) = make_sr();
+        let pane = (1, crate::tmux_model::PaneId(20));
+        sr.set_tmux_pane_speech(
+            pane.0,
+            pane.1,
+            super::PaneSpeechProfile {
+                auto_read: None,
+                symbol_level: Some(speech::symbols::Level::None),
+                voice: Some("quiet".to_owned()),
+            },
+        )
+        .unwrap();
+        let mut view = View::new(4, 10);
+        view.process_changes(b"hi");
+        assert!(sr.auto_read(&mut view).unwrap());
+        assert_eq!(speaks.borrow().as_slice(), ["hi"]);
+
+        speaks.borrow_mut().clear();
+        sr.set_active_tmux_pane(Some(pane)).unwrap();
+        let mut view = View::new(4, 10);
+        view.process_changes(b"hi");
+        assert!(sr.auto_read(&mut view).unwrap());
+        let mut view = View::new(4, 10);
+        view.process_changes(b"ho");
+        assert!(sr.auto_read(&mut view).unwrap());
+        assert_eq!(speaks.borrow().as_slice(), ["[voice quiet]", "hi", "ho"]);
+        assert!(sr.speech().symbol_level() == speech::symbols::Level::Some);
+        assert_eq!(sr.speech().voice(), Some("quiet"));
+
+        speaks.borrow_mut().clear();
+        sr.set_active_tmux_pane(None).unwrap();
+        assert_eq!(speaks.borrow().as_slice(), ["[voice default]"]);
+        assert_eq!(sr.speech().voice(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn link_opener_receives_the_uri_and_failures_are_spoken() {
+        let (mut sr, speaks) = make_sr();
+        sr.open_link("https://x.example").unwrap();
+        sr.set_link_opener(Some(super::LinkOpener {
+            program: "sh".to_owned(),
+            args: vec![
+                "-c".to_owned(),
+                "test \"$0\" = https://x.example".to_owned(),
+            ],
+        }));
+        sr.open_link("https://x.example").unwrap();
+        sr.set_link_opener(Some(super::LinkOpener {
+            program: "sh".to_owned(),
+            args: vec!["-c".to_owned(), "echo broken >&2; exit 3".to_owned()],
+        }));
+        sr.open_link("https://x.example").unwrap();
+        sr.copy_link("https://x.example").unwrap();
+        assert_eq!(
+            speaks.borrow().as_slice(),
+            [
+                "no link opener configured",
+                "opened link",
+                "link opener failed: broken",
+                "copied link",
+            ]
+        );
+        assert_eq!(sr.clipboard_text(), Some("https://x.example"));
+    }
+
     #[test]
     fn auto_read_returns_false_when_unchanged() {
         let (mut sr, speaks) = make_sr();
@@ -23,11 +23,11 @@ enum DiffState {
 
 impl ScreenReader {
     pub fn auto_read(&mut self, view: &mut View) -> Result<bool> {
-        self.auto_read_impl(view, false)
+        self.with_active_pane_speech(|sr| sr.auto_read_impl(view, false))
     }
 
     pub(crate) fn auto_read_after_input(&mut self, view: &mut View) -> Result<bool> {
-        self.auto_read_impl(view, true)
+        self.with_active_pane_speech(|sr| sr.auto_read_impl(view, true))
     }
 
     fn auto_read_impl(&mut self, view: &mut View, prefer_cursor: bool) -> Result<bool> {
@@ -121,7 +121,7 @@ impl ScreenReader {
                             &format!("speaking live text bytes={}", text.len()),
                         );
                     }
-                    self.speak(&text, false)?;
+                    self.speak_live_text(&text)?;
                     crate::diagnostics::event(
                         "screen-reader",
                         "auto-read-progress",
@@ -436,11 +436,20 @@ impl ScreenReader {
         if let Some(text) = self.hook_on_live_read(&diff_text, cursor_moves, scrolled)?
             && !text.is_empty()
         {
-            self.speak(&text, false)?;
+            self.speak_live_text(&text)?;
         }
         self.auto_read_buffers.diff_text = diff_text;
         Ok(original_nonempty)
     }
+
+    /// Speaks auto-read text, saying "star" rather than the mask characters
+    /// a program echoes for a secret.
+    fn speak_live_text(&mut self, text: &str) -> Result<()> {
+        if self.secret_entry_active() && super::secret_entry::is_masked_echo(text) {
+            return self.speak("star", false);
+        }
+        self.speak(text, false)
+    }
 }
 
 const MAX_COMPACT_INTERFACE_STATE_CHARS: usize = 32;
@@ -1,6 +1,15 @@
-use super::{Error, Result, ScreenReader};
-use crate::{keymap::InputMode, table::TableState, view::View};
-use mlua::{Function, Lua, RegistryKey, Value};
+use super::{
+    Error, Result, ScreenReader,
+    images::pixel_format_key,
+    notifications::{NoticeSource, progress_state_name},
+};
+use crate::{
+    keymap::InputMode,
+    table::TableState,
+    terminal::ProgressState,
+    view::{ReviewImage, View},
+};
+use mlua::{Function, Lua, RegistryKey, Table, Value};
 
 impl ScreenReader {
     pub(crate) fn lua_binding_context(&self, lua: &Lua) -> Result<&std::rc::Rc<Lua>> {
@@ -165,8 +174,11 @@ impl ScreenReader {
             return Ok(());
         };
         let func: Function = lua.registry_value(key).map_err(Error::lua)?;
-        func.call::<()>((old.as_str().to_string(), new.as_str().to_string()))
-            .map_err(Error::lua)
+        func.call::<()>((
+            self.key_bindings.mode_name(old).to_string(),
+            self.key_bindings.mode_name(new).to_string(),
+        ))
+        .map_err(Error::lua)
     }
 
     pub(crate) fn hook_on_table_mode_enter(&mut self, table_state: &TableState) -> Result<()> {
@@ -189,6 +201,12 @@ impl ScreenReader {
         }
         tbl.set("current_col", table_state.current_col())
             .map_err(Error::lua)?;
+        tbl.set("format", model.format().name())
+            .map_err(Error::lua)?;
+        let alignments = lua
+            .create_sequence_from(model.alignments().iter().map(|alignment| alignment.name()))
+            .map_err(Error::lua)?;
+        tbl.set("alignments", alignments).map_err(Error::lua)?;
         let func: Function = lua.registry_value(key).map_err(Error::lua)?;
         func.call::<()>(tbl).map_err(Error::lua)
     }
@@ -238,7 +256,7 @@ impl ScreenReader {
             None => Value::Nil,
         };
         let result: Value = func
-            .call((key_value, mode.as_str().to_string()))
+            .call((key_value, self.key_bindings.mode_name(mode).to_string()))
             .map_err(Error::lua)?;
         Ok(matches!(result, Value::Boolean(true)))
     }
@@ -301,6 +319,113 @@ impl ScreenReader {
             .map_err(Error::lua)
     }
 
+    /// Returns true when the hook consumed the notification.
+    pub(super) fn hook_on_notification(
+        &mut self,
+        source: NoticeSource,
+        title: &str,
+        body: &str,
+    ) -> Result<bool> {
+        let Some(key) = &self.lua_hooks.on_notification else {
+            return Ok(false);
+        };
+        let Some(lua) = self.lua_ctx.as_ref() else {
+            return Ok(false);
+        };
+        let meta = notice_meta(lua, source)?;
+        meta.set("title", title).map_err(Error::lua)?;
+        meta.set("body", body).map_err(Error::lua)?;
+        let func: Function = lua.registry_value(key).map_err(Error::lua)?;
+        let result: Value = func.call(meta).map_err(Error::lua)?;
+        Ok(matches!(result, Value::Boolean(true)))
+    }
+
+    /// Returns true when the hook consumed the progress report.
+    pub(super) fn hook_on_progress(
+        &mut self,
+        source: NoticeSource,
+        state: ProgressState,
+        progress: Option<u8>,
+    ) -> Result<bool> {
+        let Some(key) = &self.lua_hooks.on_progress else {
+            return Ok(false);
+        };
+        let Some(lua) = self.lua_ctx.as_ref() else {
+            return Ok(false);
+        };
+        let meta = notice_meta(lua, source)?;
+        meta.set("state", progress_state_name(state))
+            .map_err(Error::lua)?;
+        meta.set("progress", progress).map_err(Error::lua)?;
+        let func: Function = lua.registry_value(key).map_err(Error::lua)?;
+        let result: Value = func.call(meta).map_err(Error::lua)?;
+        Ok(matches!(result, Value::Boolean(true)))
+    }
+
+    pub(super) fn hook_on_title_change(&mut self, source: NoticeSource, title: &str) -> Result<()> {
+        let Some(key) = &self.lua_hooks.on_title_change else {
+            return Ok(());
+        };
+        let Some(lua) = self.lua_ctx.as_ref() else {
+            return Ok(());
+        };
+        let meta = notice_meta(lua, source)?;
+        meta.set("title", title).map_err(Error::lua)?;
+        let func: Function = lua.registry_value(key).map_err(Error::lua)?;
+        func.call::<()>(meta).map_err(Error::lua)
+    }
+
+    /// Returns true when the hook consumed the directory change.
+    pub(super) fn hook_on_cwd_change(
+        &mut self,
+        source: NoticeSource,
+        directory: &str,
+        uri: &str,
+    ) -> Result<bool> {
+        let Some(key) = &self.lua_hooks.on_cwd_change else {
+            return Ok(false);
+        };
+        let Some(lua) = self.lua_ctx.as_ref() else {
+            return Ok(false);
+        };
+        let meta = notice_meta(lua, source)?;
+        meta.set("directory", directory).map_err(Error::lua)?;
+        meta.set("uri", uri).map_err(Error::lua)?;
+        let func: Function = lua.registry_value(key).map_err(Error::lua)?;
+        let result: Value = func.call(meta).map_err(Error::lua)?;
+        Ok(matches!(result, Value::Boolean(true)))
+    }
+
+    /// Returns the description the hook gave for `image`, if any.
+    pub(super) fn hook_on_image(&mut self, image: &ReviewImage) -> Result<Option<String>> {
+        let Some(key) = &self.lua_hooks.on_image else {
+            return Ok(None);
+        };
+        let Some(lua) = self.lua_ctx.as_ref() else {
+            return Ok(None);
+        };
+        let tbl = lua.create_table().map_err(Error::lua)?;
+        tbl.set("id", image.image.image_id).map_err(Error::lua)?;
+        tbl.set("row", image.row).map_err(Error::lua)?;
+        tbl.set("col", image.col).map_err(Error::lua)?;
+        tbl.set("rows", image.rows).map_err(Error::lua)?;
+        tbl.set("cols", image.cols).map_err(Error::lua)?;
+        tbl.set("width", image.image.pixel_width)
+            .map_err(Error::lua)?;
+        tbl.set("height", image.image.pixel_height)
+            .map_err(Error::lua)?;
+        tbl.set("format", pixel_format_key(image.image.format))
+            .map_err(Error::lua)?;
+        tbl.set("data", lua.create_string(&*image.data).map_err(Error::lua)?)
+            .map_err(Error::lua)?;
+        let func: Function = lua.registry_value(key).map_err(Error::lua)?;
+        let result: Value = func.call(tbl).map_err(Error::lua)?;
+        match result {
+            Value::String(text) => Ok(Some(text.to_str().map_err(Error::lua)?.to_string())),
+            _ => Ok(None),
+        }
+    }
+
     fn ensure_lua_hook_context(&self, lua: &Lua) -> Result<()> {
         let Some(weak_ctx) = self.lua_ctx_weak.as_ref() else {
             return Err(Error::InvalidLuaHookContext);
@@ -312,6 +437,42 @@ impl ScreenReader {
     }
 }
 
+/// `{ source = "root" }` or `{ source = "tmux", connection, pane }`.
+fn notice_meta(lua: &Lua, source: NoticeSource) -> Result<Table> {
+    let meta = lua.create_table().map_err(Error::lua)?;
+    match source {
+        NoticeSource::Root => meta.set("source", "root").map_err(Error::lua)?,
+        NoticeSource::TmuxPane(connection_id, pane_id) => {
+            meta.set("source", "tmux").map_err(Error::lua)?;
+            meta.set("connection", connection_id).map_err(Error::lua)?;
+            meta.set("pane", pane_id.0).map_err(Error::lua)?;
+        }
+    }
+    Ok(meta)
+}
+
+/// Every hook `LuaHooks` has a slot for.
+pub(crate) const HOOK_NAMES: &[&str] = &[
+    "on_startup",
+    "on_shutdown",
+    "on_screen_update",
+    "on_live_read",
+    "on_review_cursor_move",
+    "on_mode_change",
+    "on_table_mode_enter",
+    "on_table_mode_exit",
+    "on_clipboard_change",
+    "on_speech_start",
+    "on_speech_end",
+    "on_key_unhandled",
+    "on_notification",
+    "on_progress",
+    "on_title_change",
+    "on_cwd_change",
+    "on_image",
+    "on_error",
+];
+
 #[derive(Default)]
 pub(super) struct LuaHooks {
     on_startup: Option<RegistryKey>,
@@ -326,6 +487,11 @@ pub(super) struct LuaHooks {
     on_speech_start: Option<RegistryKey>,
     on_speech_end: Option<RegistryKey>,
     on_key_unhandled: Option<RegistryKey>,
+    on_notification: Option<RegistryKey>,
+    on_progress: Option<RegistryKey>,
+    on_title_change: Option<RegistryKey>,
+    on_cwd_change: Option<RegistryKey>,
+    on_image: Option<RegistryKey>,
     on_error: Option<RegistryKey>,
 }
 
@@ -344,6 +510,11 @@ impl LuaHooks {
             "on_speech_start" => Some(&mut self.on_speech_start),
             "on_speech_end" => Some(&mut self.on_speech_end),
             "on_key_unhandled" => Some(&mut self.on_key_unhandled),
+            "on_notification" => Some(&mut self.on_notification),
+            "on_progress" => Some(&mut self.on_progress),
+            "on_title_change" => Some(&mut self.on_title_change),
+            "on_cwd_change" => Some(&mut self.on_cwd_change),
+            "on_image" => Some(&mut self.on_image),
             "on_error" => Some(&mut self.on_error),
             _ => None,
         }
@@ -363,6 +534,11 @@ impl LuaHooks {
             "on_speech_start" => Some(&self.on_speech_start),
             "on_speech_end" => Some(&self.on_speech_end),
             "on_key_unhandled" => Some(&self.on_key_unhandled),
+            "on_notification" => Some(&self.on_notification),
+            "on_progress" => Some(&self.on_progress),
+            "on_title_change" => Some(&self.on_title_change),
+            "on_cwd_change" => Some(&self.on_cwd_change),
+            "on_image" => Some(&self.on_image),
             "on_error" => Some(&self.on_error),
             _ => None,
         }
@@ -0,0 +1,137 @@
+//! Spoken descriptions of Kitty graphics images found by review.
+//!
+//! Ghostty hands Lector decoded pixels, so an image sent as PNG is described
+//! by the pixel format it was decoded to. The `on_image` hook receives those
+//! pixels and can replace the description, for example with text from a
+//! local OCR or captioning program.
+
+use super::{Result, ScreenReader};
+use crate::{presentation::PixelFormat, view::ReviewImage};
+
+fn pixel_format_name(format: PixelFormat) -> &'static str {
+    match format {
+        PixelFormat::Rgb => "RGB",
+        PixelFormat::Rgba => "RGBA",
+        PixelFormat::GrayAlpha => "gray with alpha",
+        PixelFormat::Gray => "gray",
+    }
+}
+
+/// How the `on_image` hook names the layout of its pixel data.
+pub(super) fn pixel_format_key(format: PixelFormat) -> &'static str {
+    match format {
+        PixelFormat::Rgb => "rgb",
+        PixelFormat::Rgba => "rgba",
+        PixelFormat::GrayAlpha => "gray_alpha",
+        PixelFormat::Gray => "gray",
+    }
+}
+
+/// "image, 640 by 480, RGBA"
+pub(crate) fn image_description(image: &ReviewImage) -> String {
+    format!(
+        "image, {} by {}, {}",
+        image.image.pixel_width,
+        image.image.pixel_height,
+        pixel_format_name(image.image.format)
+    )
+}
+
+impl ScreenReader {
+    /// Speaks `image`, or what the `on_image` hook returns for it.
+    pub(crate) fn announce_image(&mut self, image: &ReviewImage) -> Result<()> {
+        let description = self
+            .hook_on_image(image)?
+            .unwrap_or_else(|| image_description(image));
+        self.speak(&description, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::image_description;
+    use crate::{
+        screen_reader::ScreenReader,
+        speech,
+        terminal::TerminalGeometry,
+        view::{ReviewImage, View},
+    };
+    use mlua::{Lua, Value};
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingDriver(Rc<RefCell<Vec<String>>>);
+
+    impl speech::Driver for RecordingDriver {
+        fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(text.to_owned());
+            Ok(())
+        }
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn view_with_image() -> View {
+        let mut view = View::new(3, 12);
+        view.set_size_with_geometry(TerminalGeometry::new(3, 12, 10, 20));
+        view.process_changes(b"ab\x1b_Ga=T,f=32,s=2,v=1,i=3,p=4,c=2,r=1,q=2;/wAA/wD/AP8=\x1b\\");
+        view
+    }
+
+    #[test]
+    fn review_finds_and_describes_images_under_the_cursor() {
+        let mut view = view_with_image();
+        let images = view.review_images();
+        assert_eq!(images.len(), 1);
+        let image = &images[0];
+        assert_eq!((image.row, image.col, image.rows, image.cols), (0, 2, 1, 2));
+        assert_eq!(image.data.len(), 8);
+        assert_eq!(image_description(image), "image, 2 by 1, RGBA");
+
+        assert!(ReviewImage::topmost_at(images, (0, 1)).is_none());
+        assert!(
+            ReviewImage::topmost_at(images, (0, 3))
+                .is_some_and(|found| found.same_placement(image))
+        );
+
+        view.process_changes(b"\x1b_Ga=d,q=2\x1b\\");
+        assert!(view.review_images().is_empty());
+    }
+
+    #[test]
+    fn the_image_hook_sees_the_pixels_and_can_replace_the_description() {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        let mut view = view_with_image();
+        let image = ReviewImage::topmost_at(view.review_images(), (0, 2)).unwrap();
+        sr.announce_image(image).unwrap();
+
+        let lua = Rc::new(Lua::new());
+        sr.set_lua_context(Rc::clone(&lua));
+        let hook: Value = lua
+            .load(
+                r#"
+                    return function(image)
+                        return image.format .. " " .. #image.data .. " bytes at " .. image.col
+                    end
+                "#,
+            )
+            .eval()
+            .unwrap();
+        sr.set_hook(&lua, "on_image", hook).unwrap();
+        sr.announce_image(image).unwrap();
+
+        assert_eq!(
+            output.borrow().as_slice(),
+            ["image, 2 by 1, RGBA", "rgba 8 bytes at 2"]
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Typing echo spoken from the keys Lector forwards, not from the screen.
+//!
+//! The terminal's own echo can be hidden (password prompts) or arrive late
+//! (slow SSH links), so these modes speak what was typed as it is sent. While
+//! one is active the matching screen echo is left unspoken, as with
+//! `suppress_key_echo`.
+
+use std::{fmt, str::FromStr};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum KeyEchoMode {
+    /// Typed keys are only heard through the screen, if the terminal echoes
+    /// them.
+    #[default]
+    None,
+    /// Each character as typed, with space and punctuation by name.
+    Characters,
+    /// The word just typed, once a space, punctuation, or Enter ends it.
+    Words,
+    Both,
+}
+
+impl KeyEchoMode {
+    fn characters(self) -> bool {
+        matches!(self, Self::Characters | Self::Both)
+    }
+
+    fn words(self) -> bool {
+        matches!(self, Self::Words | Self::Both)
+    }
+}
+
+impl fmt::Display for KeyEchoMode {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::None => "none",
+            Self::Characters => "characters",
+            Self::Words => "words",
+            Self::Both => "both",
+        })
+    }
+}
+
+impl FromStr for KeyEchoMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Self::None),
+            "characters" => Ok(Self::Characters),
+            "words" => Ok(Self::Words),
+            "both" => Ok(Self::Both),
+            _ => anyhow::bail!("key echo must be none, characters, words, or both"),
+        }
+    }
+}
+
+/// A forwarded key as typing echo sees it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TypedKey<'a> {
+    Text(&'a str),
+    Enter,
+    Backspace,
+    /// Any other key, such as an arrow, which may move away from the word
+    /// being typed.
+    Other,
+}
+
+/// The word being typed, kept between keys.
+#[derive(Debug, Default)]
+pub(super) struct KeyEcho {
+    word: String,
+}
+
+impl KeyEcho {
+    pub(super) fn clear(&mut self) {
+        self.word.clear();
+    }
+
+    /// What to speak for `key` under `mode`, in order. `symbol_name` names
+    /// a typed space or punctuation character, as the symbol map does.
+    pub(super) fn typed(
+        &mut self,
+        mode: KeyEchoMode,
+        key: TypedKey<'_>,
+        symbol_name: impl Fn(&str) -> Option<String>,
+    ) -> Vec<String> {
+        let mut utterances = Vec::new();
+        match key {
+            TypedKey::Text(text) => {
+                // Pasted or composed text arrives as one key; read it whole
+                // rather than as a burst of interrupted characters.
+                if mode.characters() && !text.is_empty() {
+                    let mut characters = text.chars();
+                    let name = match (characters.next(), characters.next()) {
+                        (Some(character), None) if !is_word_character(character) => {
+                            symbol_name(text)
+                        }
+                        _ => None,
+                    };
+                    utterances.push(name.unwrap_or_else(|| text.to_owned()));
+                }
+                for character in text.chars() {
+                    if is_word_character(character) {
+                        self.word.push(character);
+                    } else {
+                        self.finish_word(mode, &mut utterances);
+                    }
+                }
+            }
+            TypedKey::Enter => self.finish_word(mode, &mut utterances),
+            TypedKey::Backspace => {
+                self.word.pop();
+            }
+            TypedKey::Other => self.word.clear(),
+        }
+        if mode == KeyEchoMode::None {
+            utterances.clear();
+        }
+        utterances
+    }
+
+    fn finish_word(&mut self, mode: KeyEchoMode, utterances: &mut Vec<String>) {
+        let word = std::mem::take(&mut self.word);
+        if mode.words() && !word.is_empty() {
+            utterances.push(word);
+        }
+    }
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '\''
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyEcho, KeyEchoMode, TypedKey};
+    use crate::speech::symbols::SymbolMap;
+
+    fn type_keys(mode: KeyEchoMode, keys: &[TypedKey<'_>]) -> Vec<String> {
+        let symbols = SymbolMap::default_map();
+        let mut echo = KeyEcho::default();
+        keys.iter()
+            .flat_map(|key| {
+                echo.typed(mode, *key, |text| {
+                    symbols.get(text).map(|symbol| symbol.replacement.clone())
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn words_end_at_spaces_punctuation_and_enter() {
+        let keys = [
+            TypedKey::Text("l"),
+            TypedKey::Text("s"),
+            TypedKey::Text(" "),
+            TypedKey::Text("don't"),
+            TypedKey::Text(","),
+            TypedKey::Text("x"),
+            TypedKey::Text("y"),
+            TypedKey::Backspace,
+            TypedKey::Text("z"),
+            TypedKey::Enter,
+        ];
+        assert_eq!(type_keys(KeyEchoMode::Words, &keys), ["ls", "don't", "xz"]);
+        assert_eq!(
+            type_keys(KeyEchoMode::Both, &keys[..3]),
+            ["l", "s", "space", "ls"]
+        );
+        assert_eq!(
+            type_keys(KeyEchoMode::Characters, &keys[3..6]),
+            ["don't", "comma", "x"]
+        );
+        assert!(type_keys(KeyEchoMode::None, &keys).is_empty());
+    }
+
+    #[test]
+    fn other_keys_abandon_the_word_and_modes_parse() {
+        let keys = [
+            TypedKey::Text("a"),
+            TypedKey::Other,
+            TypedKey::Text("b"),
+            TypedKey::Text("."),
+        ];
+        assert_eq!(type_keys(KeyEchoMode::Words, &keys), ["b"]);
+        assert_eq!("both".parse::<KeyEchoMode>().unwrap(), KeyEchoMode::Both);
+        assert_eq!(KeyEchoMode::Characters.to_string(), "characters");
+        assert!("all".parse::<KeyEchoMode>().is_err());
+    }
+}
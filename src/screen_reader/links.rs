@@ -0,0 +1,55 @@
+//! Activating OSC 8 hyperlinks found by review commands and the links list.
+
+use super::{Result, ScreenReader};
+use crate::host_command::run_bounded_output;
+use std::process::Command;
+
+/// A host program that opens link targets. The URI is passed as the final
+/// argument after `args`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkOpener {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl ScreenReader {
+    #[must_use]
+    pub fn link_opener(&self) -> Option<&LinkOpener> {
+        self.options.link_opener()
+    }
+
+    pub fn set_link_opener(&mut self, opener: Option<LinkOpener>) {
+        self.options.set_link_opener(opener);
+    }
+
+    /// Copies a link target into the default clipboard register.
+    pub(crate) fn copy_link(&mut self, uri: &str) -> Result<()> {
+        let register = self.clipboard_default_register();
+        match self.write_clipboard(register, uri.to_owned()) {
+            Ok(()) => self.speak("copied link", false),
+            Err(error) => self.speak(&error.to_string(), false),
+        }
+    }
+
+    /// Runs the configured opener on `uri` through the bounded host-tool
+    /// runner, so a misbehaving opener cannot stall input handling.
+    pub(crate) fn open_link(&mut self, uri: &str) -> Result<()> {
+        let Some(opener) = self.link_opener().cloned() else {
+            return self.speak("no link opener configured", false);
+        };
+        let mut command = Command::new(&opener.program);
+        command.args(&opener.args).arg(uri);
+        let message = match run_bounded_output(&mut command, &std::env::temp_dir(), "link-opener") {
+            Ok(output) if output.status.success() => "opened link".to_owned(),
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                match stderr.lines().find(|line| !line.trim().is_empty()) {
+                    Some(line) => format!("link opener failed: {}", line.trim()),
+                    None => format!("link opener failed: {}", output.status),
+                }
+            }
+            Err(error) => format!("link opener failed: {error}"),
+        };
+        self.speak(&message, false)
+    }
+}
@@ -0,0 +1,333 @@
+//! Spoken desktop notifications (OSC 9 and OSC 777) and progress reports
+//! (OSC 9;4).
+//!
+//! Both are opt-in. State is kept per source, so the root PTY and every tmux
+//! pane have their own rate limit and their own last announced progress.
+
+use super::{Result, ScreenReader};
+use crate::{
+    terminal::{ProgressState, TerminalEvent},
+    tmux_model::PaneId,
+};
+
+/// Minimum time between two announcements from the same source.
+const NOTICE_INTERVAL_MS: u128 = 1_000;
+
+/// A terminal that reports notifications, progress, titles, and working
+/// directories: the root PTY or one tmux pane.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(crate) enum NoticeSource {
+    Root,
+    TmuxPane(u64, PaneId),
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct NoticeState {
+    last_spoken_ms: Option<u128>,
+    /// The last progress value announced, rounded down to the step.
+    progress_bucket: Option<u8>,
+    progress_state: Option<ProgressState>,
+}
+
+pub(crate) fn progress_state_name(state: ProgressState) -> &'static str {
+    match state {
+        ProgressState::Remove => "remove",
+        ProgressState::Set => "set",
+        ProgressState::Error => "error",
+        ProgressState::Indeterminate => "indeterminate",
+        ProgressState::Pause => "pause",
+    }
+}
+
+impl ScreenReader {
+    #[must_use]
+    pub fn notifications_enabled(&self) -> bool {
+        self.options.notifications()
+    }
+
+    pub fn set_notifications_enabled(&mut self, value: bool) {
+        self.options.set_notifications(value);
+    }
+
+    /// The percentage step between spoken progress reports; 0 disables them.
+    #[must_use]
+    pub fn progress_step(&self) -> u8 {
+        self.options.progress_step()
+    }
+
+    pub fn set_progress_step(&mut self, value: u8) {
+        self.options.set_progress_step(value.min(100));
+    }
+
+    /// Passes a notification, progress report, title, or working directory
+    /// to its Lua hook and, unless the hook consumed it, announces it
+    /// according to the options. Other events are ignored.
+    pub(crate) fn handle_terminal_notice(
+        &mut self,
+        source: NoticeSource,
+        event: &TerminalEvent,
+        now_ms: u128,
+    ) -> Result<()> {
+        match event {
+            TerminalEvent::DesktopNotification { title, body } => {
+                if self.hook_on_notification(source, title, body)? || !self.notifications_enabled()
+                {
+                    return Ok(());
+                }
+                let text = match (title.trim(), body.trim()) {
+                    ("", "") => return Ok(()),
+                    (title, "") => title.to_owned(),
+                    ("", body) => body.to_owned(),
+                    (title, body) => format!("{title}: {body}"),
+                };
+                self.speak_notice(source, &text, now_ms, true)?;
+            }
+            TerminalEvent::ProgressReport { state, progress } => {
+                if self.hook_on_progress(source, *state, *progress)? {
+                    return Ok(());
+                }
+                self.announce_progress(source, *state, *progress, now_ms)?;
+            }
+            TerminalEvent::TitleChanged(title) => self.note_title_change(source, title)?,
+            TerminalEvent::WorkingDirectoryChanged(uri) => {
+                self.note_working_directory_change(source, uri)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn announce_progress(
+        &mut self,
+        source: NoticeSource,
+        state: ProgressState,
+        progress: Option<u8>,
+        now_ms: u128,
+    ) -> Result<()> {
+        let step = self.progress_step();
+        if state == ProgressState::Remove {
+            self.notices.remove(&source);
+            return Ok(());
+        }
+        if step == 0 {
+            return Ok(());
+        }
+        let previous = self.notices.get(&source).copied().unwrap_or_default();
+        let percent = progress.map(|value| value.min(100));
+        // A step is superseded by the next report, but an error or pause
+        // may be reported only once, so it is never rate limited.
+        let (text, bucket) = match state {
+            ProgressState::Set => {
+                let Some(percent) = percent else {
+                    return Ok(());
+                };
+                let bucket = percent - percent % step;
+                if previous.progress_state == Some(ProgressState::Set)
+                    && previous.progress_bucket == Some(bucket)
+                {
+                    return Ok(());
+                }
+                (format!("{percent} percent"), Some(bucket))
+            }
+            ProgressState::Error | ProgressState::Pause => {
+                if previous.progress_state == Some(state) {
+                    return Ok(());
+                }
+                let label = if state == ProgressState::Error {
+                    "progress error"
+                } else {
+                    "progress paused"
+                };
+                let text = match percent {
+                    Some(percent) => format!("{label} at {percent} percent"),
+                    None => label.to_owned(),
+                };
+                (text, previous.progress_bucket)
+            }
+            ProgressState::Indeterminate | ProgressState::Remove => return Ok(()),
+        };
+        let rate_limited = state == ProgressState::Set;
+        if self.speak_notice(source, &text, now_ms, rate_limited)? {
+            let notice = self.notices.entry(source).or_default();
+            notice.progress_state = Some(state);
+            notice.progress_bucket = bucket;
+        }
+        Ok(())
+    }
+
+    /// Speaks `text` unless it is `rate_limited` and the source spoke too
+    /// recently. Returns whether anything was spoken; a dropped report is
+    /// gone, and only a later report from the source is heard.
+    fn speak_notice(
+        &mut self,
+        source: NoticeSource,
+        text: &str,
+        now_ms: u128,
+        rate_limited: bool,
+    ) -> Result<bool> {
+        let notice = self.notices.entry(source).or_default();
+        if rate_limited
+            && notice
+                .last_spoken_ms
+                .is_some_and(|last| now_ms.saturating_sub(last) < NOTICE_INTERVAL_MS)
+        {
+            return Ok(false);
+        }
+        notice.last_spoken_ms = Some(now_ms);
+        let text = match source {
+            NoticeSource::TmuxPane(connection_id, pane_id)
+                if self.active_tmux_pane() != Some((connection_id, pane_id)) =>
+            {
+                format!("pane {}, {text}", pane_id.0)
+            }
+            _ => text.to_owned(),
+        };
+        self.speak(&text, false)?;
+        Ok(true)
+    }
+
+    /// Drops notice, title, and directory state for tmux panes that `keep`
+    /// no longer recognizes.
+    pub(crate) fn retain_tmux_notices(&mut self, mut keep: impl FnMut(u64, PaneId) -> bool) {
+        self.notices.retain(|source, _| match source {
+            NoticeSource::Root => true,
+            NoticeSource::TmuxPane(connection_id, pane_id) => keep(*connection_id, *pane_id),
+        });
+        self.retain_tmux_terminal_status(keep);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NoticeSource;
+    use crate::{
+        screen_reader::ScreenReader,
+        speech,
+        terminal::{ProgressState, TerminalEvent},
+        tmux_model::PaneId,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingDriver(Rc<RefCell<Vec<String>>>);
+
+    impl speech::Driver for RecordingDriver {
+        fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(text.to_owned());
+            Ok(())
+        }
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn recording_screen_reader() -> (ScreenReader, Rc<RefCell<Vec<String>>>) {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        (sr, output)
+    }
+
+    fn progress(state: ProgressState, progress: Option<u8>) -> TerminalEvent {
+        TerminalEvent::ProgressReport { state, progress }
+    }
+
+    #[test]
+    fn notifications_are_opt_in_and_rate_limited() {
+        let (mut sr, output) = recording_screen_reader();
+        let event = TerminalEvent::DesktopNotification {
+            title: "build".to_owned(),
+            body: "done".to_owned(),
+        };
+        sr.handle_terminal_notice(NoticeSource::Root, &event, 0)
+            .unwrap();
+        assert!(output.borrow().is_empty());
+
+        sr.set_notifications_enabled(true);
+        sr.handle_terminal_notice(NoticeSource::Root, &event, 0)
+            .unwrap();
+        sr.handle_terminal_notice(NoticeSource::Root, &event, 500)
+            .unwrap();
+        sr.handle_terminal_notice(NoticeSource::Root, &event, 1_000)
+            .unwrap();
+        assert_eq!(output.borrow().as_slice(), ["build: done", "build: done"]);
+    }
+
+    #[test]
+    fn progress_is_announced_at_steps_and_on_error_or_pause() {
+        let (mut sr, output) = recording_screen_reader();
+        sr.set_progress_step(25);
+        let root = NoticeSource::Root;
+        for (value, now_ms) in [(3, 0), (20, 2_000), (26, 4_000), (40, 6_000), (51, 8_000)] {
+            sr.handle_terminal_notice(root, &progress(ProgressState::Set, Some(value)), now_ms)
+                .unwrap();
+        }
+        sr.handle_terminal_notice(root, &progress(ProgressState::Pause, Some(51)), 10_000)
+            .unwrap();
+        sr.handle_terminal_notice(root, &progress(ProgressState::Pause, Some(51)), 12_000)
+            .unwrap();
+        sr.handle_terminal_notice(root, &progress(ProgressState::Error, None), 14_000)
+            .unwrap();
+        assert_eq!(
+            output.borrow().as_slice(),
+            [
+                "3 percent",
+                "26 percent",
+                "51 percent",
+                "progress paused at 51 percent",
+                "progress error",
+            ]
+        );
+    }
+
+    #[test]
+    fn progress_errors_and_pauses_are_not_rate_limited() {
+        let (mut sr, output) = recording_screen_reader();
+        sr.set_progress_step(25);
+        let root = NoticeSource::Root;
+        sr.handle_terminal_notice(root, &progress(ProgressState::Set, Some(30)), 0)
+            .unwrap();
+        sr.handle_terminal_notice(root, &progress(ProgressState::Error, Some(30)), 100)
+            .unwrap();
+        sr.handle_terminal_notice(root, &progress(ProgressState::Set, Some(60)), 200)
+            .unwrap();
+        sr.handle_terminal_notice(root, &progress(ProgressState::Pause, None), 300)
+            .unwrap();
+        assert_eq!(
+            output.borrow().as_slice(),
+            [
+                "30 percent",
+                "progress error at 30 percent",
+                "progress paused"
+            ]
+        );
+    }
+
+    #[test]
+    fn tmux_panes_keep_separate_progress_and_name_background_panes() {
+        let (mut sr, output) = recording_screen_reader();
+        sr.set_progress_step(50);
+        let active = NoticeSource::TmuxPane(1, PaneId(1));
+        let background = NoticeSource::TmuxPane(1, PaneId(2));
+        sr.set_active_tmux_pane(Some((1, PaneId(1)))).unwrap();
+        sr.handle_terminal_notice(active, &progress(ProgressState::Set, Some(10)), 0)
+            .unwrap();
+        sr.handle_terminal_notice(background, &progress(ProgressState::Set, Some(10)), 0)
+            .unwrap();
+        sr.retain_tmux_notices(|_, pane_id| pane_id == PaneId(1));
+        sr.handle_terminal_notice(background, &progress(ProgressState::Set, Some(20)), 2_000)
+            .unwrap();
+        sr.handle_terminal_notice(active, &progress(ProgressState::Set, Some(20)), 2_000)
+            .unwrap();
+        assert_eq!(
+            output.borrow().as_slice(),
+            ["10 percent", "pane 2, 10 percent", "pane 2, 20 percent"]
+        );
+    }
+}
@@ -1,18 +1,30 @@
-use super::TmuxBellMode;
-use crate::clipboard::{ClipboardRegister, SystemClipboardProvider};
+use super::{KeyEchoMode, LinkOpener, TmuxBellMode};
+use crate::{
+    clipboard::{ClipboardRegister, SystemClipboardProvider},
+    table::ExportFormat,
+};
 
 pub(super) struct Options {
     help_mode: bool,
     auto_read: bool,
     suppress_key_echo: bool,
+    key_echo: KeyEchoMode,
     report_indentation: bool,
     review_follows_screen_cursor: bool,
     highlight_tracking: bool,
+    selection_tracking: bool,
     table_header_auto: bool,
+    table_export_format: ExportFormat,
     stop_speech_on_focus_loss: bool,
     tmux_bell_mode: TmuxBellMode,
     clipboard_default_register: ClipboardRegister,
     system_clipboard_provider: SystemClipboardProvider,
+    link_opener: Option<LinkOpener>,
+    notifications: bool,
+    progress_step: u8,
+    announce_cwd: bool,
+    mouse_hover: bool,
+    repl_scratch_file: Option<String>,
 }
 
 impl Default for Options {
@@ -21,14 +33,23 @@ impl Default for Options {
             help_mode: false,
             auto_read: true,
             suppress_key_echo: false,
+            key_echo: KeyEchoMode::None,
             report_indentation: true,
             review_follows_screen_cursor: true,
             highlight_tracking: false,
+            selection_tracking: false,
             table_header_auto: true,
+            table_export_format: ExportFormat::Tsv,
             stop_speech_on_focus_loss: true,
             tmux_bell_mode: TmuxBellMode::Audible,
             clipboard_default_register: ClipboardRegister::Internal,
             system_clipboard_provider: SystemClipboardProvider::Native,
+            link_opener: None,
+            notifications: false,
+            progress_step: 0,
+            announce_cwd: false,
+            mouse_hover: false,
+            repl_scratch_file: None,
         }
     }
 }
@@ -68,6 +89,14 @@ impl Options {
         self.suppress_key_echo = value;
     }
 
+    pub(super) fn key_echo(&self) -> KeyEchoMode {
+        self.key_echo
+    }
+
+    pub(super) fn set_key_echo(&mut self, value: KeyEchoMode) {
+        self.key_echo = value;
+    }
+
     pub(super) fn report_indentation(&self) -> bool {
         self.report_indentation
     }
@@ -97,6 +126,14 @@ impl Options {
         self.highlight_tracking = value;
     }
 
+    pub(super) fn selection_tracking(&self) -> bool {
+        self.selection_tracking
+    }
+
+    pub(super) fn set_selection_tracking(&mut self, value: bool) {
+        self.selection_tracking = value;
+    }
+
     pub(super) fn table_header_auto(&self) -> bool {
         self.table_header_auto
     }
@@ -106,6 +143,14 @@ impl Options {
         self.table_header_auto
     }
 
+    pub(super) fn table_export_format(&self) -> ExportFormat {
+        self.table_export_format
+    }
+
+    pub(super) fn set_table_export_format(&mut self, value: ExportFormat) {
+        self.table_export_format = value;
+    }
+
     pub(super) fn stop_speech_on_focus_loss(&self) -> bool {
         self.stop_speech_on_focus_loss
     }
@@ -142,12 +187,63 @@ impl Options {
     pub(super) fn set_system_clipboard_provider(&mut self, value: SystemClipboardProvider) {
         self.system_clipboard_provider = value;
     }
+
+    pub(super) fn link_opener(&self) -> Option<&LinkOpener> {
+        self.link_opener.as_ref()
+    }
+
+    pub(super) fn set_link_opener(&mut self, value: Option<LinkOpener>) {
+        self.link_opener = value;
+    }
+
+    pub(super) fn notifications(&self) -> bool {
+        self.notifications
+    }
+
+    pub(super) fn set_notifications(&mut self, value: bool) {
+        self.notifications = value;
+    }
+
+    pub(super) fn progress_step(&self) -> u8 {
+        self.progress_step
+    }
+
+    pub(super) fn set_progress_step(&mut self, value: u8) {
+        self.progress_step = value;
+    }
+
+    pub(super) fn announce_cwd(&self) -> bool {
+        self.announce_cwd
+    }
+
+    pub(super) fn set_announce_cwd(&mut self, value: bool) {
+        self.announce_cwd = value;
+    }
+
+    pub(super) fn mouse_hover(&self) -> bool {
+        self.mouse_hover
+    }
+
+    pub(super) fn set_mouse_hover(&mut self, value: bool) {
+        self.mouse_hover = value;
+    }
+
+    pub(super) fn repl_scratch_file(&self) -> Option<&str> {
+        self.repl_scratch_file.as_deref()
+    }
+
+    pub(super) fn set_repl_scratch_file(&mut self, value: Option<String>) {
+        self.repl_scratch_file = value;
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Options, TmuxBellMode};
-    use crate::clipboard::{ClipboardRegister, SystemClipboardProvider};
+    use super::{KeyEchoMode, Options, TmuxBellMode};
+    use crate::{
+        clipboard::{ClipboardRegister, SystemClipboardProvider},
+        table::ExportFormat,
+    };
 
     #[test]
     fn defaults_match_the_user_facing_configuration() {
@@ -155,10 +251,13 @@ mod tests {
         assert!(!options.help_mode());
         assert!(options.auto_read());
         assert!(!options.suppress_key_echo());
+        assert_eq!(options.key_echo(), KeyEchoMode::None);
         assert!(options.report_indentation());
         assert!(options.review_follows_screen_cursor());
         assert!(!options.highlight_tracking());
+        assert!(!options.selection_tracking());
         assert!(options.table_header_auto());
+        assert_eq!(options.table_export_format(), ExportFormat::Tsv);
         assert!(options.stop_speech_on_focus_loss());
         assert_eq!(options.tmux_bell_mode(), TmuxBellMode::Audible);
         assert_eq!(
@@ -169,6 +268,12 @@ mod tests {
             options.system_clipboard_provider(),
             SystemClipboardProvider::Native
         );
+        assert_eq!(options.link_opener(), None);
+        assert!(!options.notifications());
+        assert_eq!(options.progress_step(), 0);
+        assert!(!options.announce_cwd());
+        assert!(!options.mouse_hover());
+        assert_eq!(options.repl_scratch_file(), None);
     }
 
     #[test]
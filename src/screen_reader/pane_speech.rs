@@ -0,0 +1,150 @@
+//! Speech overrides for individual tmux panes.
+//!
+//! Profiles are keyed by connection ID and the stable tmux `PaneId`, so they
+//! follow a pane across window and pane switches and are only dropped when
+//! the pane or its connection disappears.
+
+use super::{Result, ScreenReader};
+use crate::{speech::symbols::Level, tmux_model::PaneId};
+
+/// Per-pane overrides. `None` fields fall back to the global setting.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PaneSpeechProfile {
+    pub auto_read: Option<bool>,
+    pub symbol_level: Option<Level>,
+    /// Backend voice ID or name.
+    pub voice: Option<String>,
+}
+
+impl PaneSpeechProfile {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.auto_read.is_none() && self.symbol_level.is_none() && self.voice.is_none()
+    }
+
+    /// A short description such as `muted, symbols none, voice Alex`, or an
+    /// empty string when nothing is overridden.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        match self.auto_read {
+            Some(false) => parts.push("muted".to_owned()),
+            Some(true) => parts.push("auto read".to_owned()),
+            None => {}
+        }
+        if let Some(level) = self.symbol_level {
+            parts.push(format!("symbols {level}"));
+        }
+        if let Some(voice) = &self.voice {
+            parts.push(format!("voice {voice}"));
+        }
+        parts.join(", ")
+    }
+}
+
+impl ScreenReader {
+    #[must_use]
+    pub fn tmux_pane_speech(
+        &self,
+        connection_id: u64,
+        pane_id: PaneId,
+    ) -> Option<&PaneSpeechProfile> {
+        self.pane_speech.get(&(connection_id, pane_id))
+    }
+
+    pub fn tmux_pane_speech_profiles(
+        &self,
+    ) -> impl Iterator<Item = ((u64, PaneId), &PaneSpeechProfile)> {
+        self.pane_speech
+            .iter()
+            .map(|(&key, profile)| (key, profile))
+    }
+
+    /// Replaces the overrides for one pane. An empty profile removes them.
+    pub fn set_tmux_pane_speech(
+        &mut self,
+        connection_id: u64,
+        pane_id: PaneId,
+        profile: PaneSpeechProfile,
+    ) -> Result<()> {
+        if profile.is_empty() {
+            self.pane_speech.remove(&(connection_id, pane_id));
+        } else {
+            self.pane_speech.insert((connection_id, pane_id), profile);
+        }
+        if self.active_tmux_pane == Some((connection_id, pane_id)) {
+            self.select_pane_voice()?;
+        }
+        Ok(())
+    }
+
+    /// Drops overrides for panes that `keep` no longer recognizes.
+    pub(crate) fn retain_tmux_pane_speech(&mut self, mut keep: impl FnMut(u64, PaneId) -> bool) {
+        self.pane_speech
+            .retain(|&(connection_id, pane_id), _| keep(connection_id, pane_id));
+    }
+
+    /// The tmux pane the active view reads from, as last reported by the app.
+    #[must_use]
+    pub fn active_tmux_pane(&self) -> Option<(u64, PaneId)> {
+        self.active_tmux_pane
+    }
+
+    /// Records the active pane and switches to its voice, or back to the
+    /// global voice when it has none.
+    pub(crate) fn set_active_tmux_pane(&mut self, pane: Option<(u64, PaneId)>) -> Result<()> {
+        self.active_tmux_pane = pane;
+        self.select_pane_voice()
+    }
+
+    fn select_pane_voice(&mut self) -> Result<()> {
+        let global = self
+            .global_voice
+            .take()
+            .unwrap_or_else(|| self.speech.voice().map(str::to_owned));
+        let pane_voice = self
+            .active_pane_speech()
+            .and_then(|profile| profile.voice.clone());
+        let selected = self
+            .speech
+            .set_voice(pane_voice.as_deref().or(global.as_deref()));
+        if pane_voice.is_some() || selected.is_err() {
+            self.global_voice = Some(global);
+        }
+        Ok(selected?)
+    }
+
+    fn active_pane_speech(&self) -> Option<&PaneSpeechProfile> {
+        self.active_tmux_pane
+            .and_then(|(connection_id, pane_id)| self.tmux_pane_speech(connection_id, pane_id))
+    }
+
+    /// Whether live output of the active view is read, honoring a pane
+    /// override of the global `auto_read` option.
+    #[must_use]
+    pub fn live_auto_read_enabled(&self) -> bool {
+        self.active_pane_speech()
+            .and_then(|profile| profile.auto_read)
+            .unwrap_or_else(|| self.auto_read_enabled())
+    }
+
+    /// Runs `read` with the active pane's symbol level, restoring the global
+    /// level afterwards. The pane's voice is selected when the pane becomes
+    /// active instead.
+    pub(super) fn with_active_pane_speech<T>(
+        &mut self,
+        read: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let Some(level) = self
+            .active_pane_speech()
+            .and_then(|profile| profile.symbol_level)
+        else {
+            return read(self);
+        };
+        let symbol_level = self.speech.symbol_level();
+        self.speech.set_symbol_level(level);
+        let result = read(self);
+        self.speech.set_symbol_level(symbol_level);
+        result
+    }
+}
@@ -0,0 +1,138 @@
+//! Password and other secret entry at the application cursor.
+//!
+//! A program reading a secret turns the PTY's echo off while keeping
+//! canonical line input, which line editors and full-screen programs never
+//! do. Programs that read a password in raw mode, often echoing `*`, are
+//! recognized by a prompt ending the cursor line, while echo is off. While
+//! secret entry holds, typed keys are neither spoken, remembered, nor
+//! logged.
+
+use crate::{pty::LineDiscipline, view::View};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A prompt such as `Password:`, `[sudo] password for ann:` or `Enter
+/// passphrase for key '~/.ssh/id':` that makes up the whole line before the
+/// cursor, followed only by masked echo.
+static SECRET_PROMPT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^\s*(?:\S+\s+){0,3}(password|passphrase|passcode|pin)\b[^:]*:\s*[*•●]*\s*$")
+        .expect("secret prompt regex")
+});
+
+/// The cue spoken when secret entry starts.
+pub(super) const SECRET_ENTRY_CUE: &str = "password field";
+
+#[derive(Debug, Default)]
+pub(super) struct SecretEntry {
+    line_discipline: Option<LineDiscipline>,
+    prompt: bool,
+    announced: bool,
+}
+
+impl SecretEntry {
+    pub(super) fn set_line_discipline(&mut self, line_discipline: Option<LineDiscipline>) {
+        self.line_discipline = line_discipline;
+    }
+
+    pub(super) fn active(&self) -> bool {
+        self.line_discipline
+            .is_some_and(|line| !line.echo && (line.canonical || self.prompt))
+    }
+
+    /// Looks for a secret prompt ending the cursor line of `view`. Returns
+    /// whether secret entry has just started and the cue should be spoken.
+    pub(super) fn update(&mut self, view: &View) -> bool {
+        let screen = view.screen();
+        let (row, col) = screen.cursor_position();
+        let (_, cols) = screen.size();
+        self.prompt = screen
+            .contents_between(row, col, row, cols)
+            .trim()
+            .is_empty()
+            && SECRET_PROMPT_RE.is_match(&screen.contents_between(row, 0, row, col));
+        let active = self.active();
+        let started = active && !self.announced;
+        self.announced = active;
+        started
+    }
+}
+
+/// Whether `text` is only the mask characters a program echoes for a
+/// secret, such as `***`.
+pub(super) fn is_masked_echo(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|character| matches!(character, '*' | '•' | '●'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SecretEntry, is_masked_echo};
+    use crate::{pty::LineDiscipline, view::View};
+
+    #[test]
+    fn echo_off_canonical_input_and_password_prompts_are_secret() {
+        let mut entry = SecretEntry::default();
+        let mut view = View::new(4, 40);
+        view.process_changes(b"$ ls\r\nfile\r\n$ ");
+        assert!(!entry.update(&view));
+
+        // Line editors turn off both echo and canonical input.
+        entry.set_line_discipline(Some(LineDiscipline {
+            echo: false,
+            canonical: false,
+        }));
+        assert!(!entry.update(&view));
+        entry.set_line_discipline(Some(LineDiscipline {
+            echo: false,
+            canonical: true,
+        }));
+        assert!(entry.update(&view));
+        assert!(!entry.update(&view));
+
+        entry.set_line_discipline(None);
+        assert!(!entry.update(&view));
+        assert!(!entry.active());
+        view.process_changes(b"sudo true\r\n[sudo] password for ann: ***");
+        // A prompt alone is not enough while the child still echoes input.
+        assert!(!entry.update(&view));
+        entry.set_line_discipline(Some(LineDiscipline {
+            echo: false,
+            canonical: false,
+        }));
+        assert!(entry.update(&view));
+        assert!(entry.active());
+    }
+
+    #[test]
+    fn prompts_must_end_the_cursor_line() {
+        let raw = Some(LineDiscipline {
+            echo: false,
+            canonical: false,
+        });
+        let mut entry = SecretEntry::default();
+        entry.set_line_discipline(raw);
+        let mut view = View::new(4, 60);
+        view.process_changes(b"$ echo the wifi password is: ");
+        assert!(!entry.update(&view));
+
+        let mut view = View::new(4, 60);
+        view.process_changes(b"Enter passphrase for key '/home/ann/.ssh/id': ");
+        assert!(entry.update(&view));
+
+        let mut view = View::new(4, 60);
+        view.process_changes(b"Password: hunter2\x1b[8D");
+        assert!(!entry.update(&view));
+        assert!(!entry.active());
+    }
+
+    #[test]
+    fn only_mask_characters_are_masked_echo() {
+        assert!(is_masked_echo("*"));
+        assert!(is_masked_echo("•••"));
+        assert!(!is_masked_echo("a*"));
+        assert!(!is_masked_echo(" "));
+    }
+}
@@ -0,0 +1,264 @@
+//! Window titles (OSC 0 and 2) and working directories (OSC 7) reported by
+//! the root terminal and each tmux pane.
+//!
+//! Title changes are never announced on their own; they are available to
+//! commands and the `on_title_change` hook. Directory changes can also be
+//! announced for the source being read when `announce_cwd` is enabled. A
+//! shell that marks its prompts with OSC 133 usually reports the directory
+//! just before drawing the prompt, so the announcement waits for the prompt
+//! instead of landing amid the command's last output.
+
+use super::{NoticeSource, Result, ScreenReader};
+use crate::tmux_model::PaneId;
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct TerminalStatus {
+    title: Option<String>,
+    /// The raw OSC 7 value, usually a `file://host/path` URI.
+    working_directory: Option<String>,
+    /// Whether the source has drawn an OSC 133 prompt mark.
+    marks_prompts: bool,
+    /// A directory change waiting for the next prompt to be announced.
+    pending_directory: Option<String>,
+}
+
+/// The local path named by an OSC 7 `file://` URI, percent-decoded. Values
+/// that are not `file://` URIs are returned unchanged.
+pub(crate) fn directory_from_osc7(value: &str) -> String {
+    let Some(rest) = value.strip_prefix("file://") else {
+        return value.to_owned();
+    };
+    let path = rest.find('/').map_or("/", |start| &rest[start..]);
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Abbreviates `directory` below `home` with `~`.
+fn spoken_directory(directory: &str, home: Option<&str>) -> String {
+    let Some(home) = home.map(|home| home.trim_end_matches('/')) else {
+        return directory.to_owned();
+    };
+    if home.is_empty() {
+        return directory.to_owned();
+    }
+    match directory.strip_prefix(home) {
+        Some("") => "~".to_owned(),
+        Some(rest) if rest.starts_with('/') => format!("~{rest}"),
+        _ => directory.to_owned(),
+    }
+}
+
+impl ScreenReader {
+    #[must_use]
+    pub fn announce_cwd_enabled(&self) -> bool {
+        self.options.announce_cwd()
+    }
+
+    pub fn set_announce_cwd_enabled(&mut self, value: bool) {
+        self.options.set_announce_cwd(value);
+    }
+
+    /// The source the active view reads: the active tmux pane, or the root
+    /// terminal.
+    fn active_notice_source(&self) -> NoticeSource {
+        self.active_tmux_pane()
+            .map_or(NoticeSource::Root, |(connection_id, pane_id)| {
+                NoticeSource::TmuxPane(connection_id, pane_id)
+            })
+    }
+
+    /// The last title reported by the source the active view reads.
+    #[must_use]
+    pub fn active_terminal_title(&self) -> Option<&str> {
+        self.terminal_status
+            .get(&self.active_notice_source())?
+            .title
+            .as_deref()
+    }
+
+    /// The last working directory reported by the source the active view
+    /// reads, decoded to a local path.
+    #[must_use]
+    pub fn active_working_directory(&self) -> Option<String> {
+        self.terminal_status
+            .get(&self.active_notice_source())?
+            .working_directory
+            .as_deref()
+            .map(directory_from_osc7)
+    }
+
+    pub(super) fn note_title_change(&mut self, source: NoticeSource, title: &str) -> Result<()> {
+        let status = self.terminal_status.entry(source).or_default();
+        if status.title.as_deref() == Some(title) {
+            return Ok(());
+        }
+        status.title = Some(title.to_owned());
+        self.hook_on_title_change(source, title)
+    }
+
+    /// Records an OSC 7 report. The first report from a source only sets
+    /// the baseline; later changes are announced when enabled, unless the
+    /// hook consumed them. Sources that mark prompts hold the announcement
+    /// until [`Self::note_prompt_start`].
+    pub(super) fn note_working_directory_change(
+        &mut self,
+        source: NoticeSource,
+        uri: &str,
+    ) -> Result<()> {
+        let status = self.terminal_status.entry(source).or_default();
+        if status.working_directory.as_deref() == Some(uri) {
+            return Ok(());
+        }
+        let is_first_report = status.working_directory.is_none();
+        status.working_directory = Some(uri.to_owned());
+        status.pending_directory = None;
+        let directory = directory_from_osc7(uri);
+        if self.hook_on_cwd_change(source, &directory, uri)?
+            || is_first_report
+            || !self.announce_cwd_enabled()
+        {
+            return Ok(());
+        }
+        let status = self.terminal_status.entry(source).or_default();
+        if status.marks_prompts {
+            status.pending_directory = Some(directory);
+            return Ok(());
+        }
+        self.announce_directory(source, &directory)
+    }
+
+    /// Records an OSC 133 prompt-start mark and announces a directory change
+    /// that was waiting for it.
+    pub(crate) fn note_prompt_start(&mut self, source: NoticeSource) -> Result<()> {
+        let status = self.terminal_status.entry(source).or_default();
+        status.marks_prompts = true;
+        match status.pending_directory.take() {
+            Some(directory) => self.announce_directory(source, &directory),
+            None => Ok(()),
+        }
+    }
+
+    fn announce_directory(&mut self, source: NoticeSource, directory: &str) -> Result<()> {
+        if source != self.active_notice_source() {
+            return Ok(());
+        }
+        let home = std::env::var("HOME").ok();
+        self.speak(&spoken_directory(directory, home.as_deref()), false)
+    }
+
+    pub(super) fn retain_tmux_terminal_status(
+        &mut self,
+        mut keep: impl FnMut(u64, PaneId) -> bool,
+    ) {
+        self.terminal_status.retain(|source, _| match source {
+            NoticeSource::Root => true,
+            NoticeSource::TmuxPane(connection_id, pane_id) => keep(*connection_id, *pane_id),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{directory_from_osc7, spoken_directory};
+    use crate::{
+        screen_reader::{NoticeSource, ScreenReader},
+        speech,
+        terminal::TerminalEvent,
+        tmux_model::PaneId,
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingDriver(Rc<RefCell<Vec<String>>>);
+
+    impl speech::Driver for RecordingDriver {
+        fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(text.to_owned());
+            Ok(())
+        }
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn osc7_uris_decode_to_local_paths() {
+        assert_eq!(
+            directory_from_osc7("file://host/home/me/My%20Files"),
+            "/home/me/My Files"
+        );
+        assert_eq!(directory_from_osc7("file://host"), "/");
+        assert_eq!(directory_from_osc7("file:///tmp/100%"), "/tmp/100%");
+        assert_eq!(directory_from_osc7("/plain/path"), "/plain/path");
+        assert_eq!(spoken_directory("/home/me", Some("/home/me/")), "~");
+        assert_eq!(spoken_directory("/home/me/src", Some("/home/me")), "~/src");
+        assert_eq!(spoken_directory("/home/meg", Some("/home/me")), "/home/meg");
+        assert_eq!(spoken_directory("/srv", None), "/srv");
+    }
+
+    #[test]
+    fn directory_changes_are_announced_only_when_enabled_for_the_active_source() {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        let cwd = |path: &str| TerminalEvent::WorkingDirectoryChanged(format!("file://h{path}"));
+        let background = NoticeSource::TmuxPane(1, PaneId(2));
+        sr.handle_terminal_notice(NoticeSource::Root, &cwd("/srv"), 0)
+            .unwrap();
+        sr.handle_terminal_notice(NoticeSource::Root, &cwd("/srv/a"), 0)
+            .unwrap();
+        assert!(output.borrow().is_empty());
+        assert_eq!(sr.active_working_directory().as_deref(), Some("/srv/a"));
+
+        sr.set_announce_cwd_enabled(true);
+        sr.handle_terminal_notice(NoticeSource::Root, &cwd("/srv/a"), 0)
+            .unwrap();
+        sr.handle_terminal_notice(NoticeSource::Root, &cwd("/srv/b"), 0)
+            .unwrap();
+        sr.handle_terminal_notice(background, &cwd("/srv"), 0)
+            .unwrap();
+        sr.handle_terminal_notice(background, &cwd("/srv/c"), 0)
+            .unwrap();
+        sr.handle_terminal_notice(
+            NoticeSource::Root,
+            &TerminalEvent::TitleChanged("vim".to_owned()),
+            0,
+        )
+        .unwrap();
+        assert_eq!(output.borrow().as_slice(), ["/srv/b"]);
+        assert_eq!(sr.active_terminal_title(), Some("vim"));
+
+        sr.note_prompt_start(NoticeSource::Root).unwrap();
+        sr.handle_terminal_notice(NoticeSource::Root, &cwd("/srv/d"), 0)
+            .unwrap();
+        assert_eq!(output.borrow().len(), 1);
+        sr.note_prompt_start(NoticeSource::Root).unwrap();
+        assert_eq!(output.borrow().as_slice(), ["/srv/b", "/srv/d"]);
+
+        sr.set_active_tmux_pane(Some((1, PaneId(2)))).unwrap();
+        assert_eq!(sr.active_working_directory().as_deref(), Some("/srv/c"));
+        assert_eq!(sr.active_terminal_title(), None);
+    }
+}
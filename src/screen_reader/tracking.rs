@@ -2,7 +2,8 @@ use super::{Result, ScreenReader};
 use crate::{
     ext::ScreenExt,
     presentation::{ViewId, ViewRevision},
-    terminal::Row,
+    selection,
+    terminal::{Row, ScreenIdentity},
     view::View,
 };
 use std::collections::HashSet;
@@ -303,6 +304,61 @@ impl ScreenReader {
         Ok(())
     }
 
+    /// Speaks the selected item of a full-screen list when it moves. Returns
+    /// whether anything was spoken, so the redrawn list is not also read.
+    ///
+    /// Only the alternate screen is tracked. On the primary screen a marker
+    /// such as `git branch`'s `*` moves because new output scrolls it, and
+    /// that output must be read.
+    pub fn track_selection(&mut self, view: &View) -> Result<bool> {
+        let current = if view.screen().screen == ScreenIdentity::Alternate {
+            let hint = self
+                .selection_hints
+                .for_title(self.active_terminal_title())
+                .cloned();
+            selection::detect(view.screen(), hint.as_ref())
+        } else {
+            None
+        };
+        let Some(text) = self.selection.update(current) else {
+            return Ok(false);
+        };
+        self.speak(&text, true)?;
+        Ok(true)
+    }
+
+    /// Announces the start of secret entry after its prompt is read.
+    /// Returns whether the cue was spoken.
+    pub fn track_secret_entry(&mut self, view: &View) -> Result<bool> {
+        if !self.secret_entry.update(view) {
+            return Ok(false);
+        }
+        self.key_echo.clear();
+        self.speak(super::secret_entry::SECRET_ENTRY_CUE, false)?;
+        Ok(true)
+    }
+
+    /// Leaves silenced and watched regions out of the auto-read diff.
+    pub(crate) fn exclude_regions_from_auto_read(&self, view: &mut View) {
+        let excluded = self
+            .regions
+            .excluded(view.screen(), self.active_terminal_title());
+        view.set_auto_read_exclusions(excluded);
+    }
+
+    /// Speaks the new text of each watched region that changed, whether or
+    /// not auto-read is on.
+    pub fn track_watched_regions(&mut self, view: &View) -> Result<()> {
+        let title = self.active_terminal_title().map(str::to_owned);
+        for text in self
+            .regions
+            .watched_changes(view.screen(), title.as_deref())
+        {
+            self.speak(&text, false)?;
+        }
+        Ok(())
+    }
+
     pub fn report_application_cursor_indentation_changes(&mut self, view: &mut View) -> Result<()> {
         if !self.indentation_reporting_enabled() {
             return Ok(());
@@ -0,0 +1,421 @@
+//! Finds the selected item of a list drawn by a full-screen application.
+//!
+//! Menus such as fzf, mc, dialog and ncurses lists mark their selection in
+//! one of three ways: a background color or inverse video that no other list
+//! row uses, a pointer such as `>` in front of one row, or bold text on one
+//! row of an otherwise plain list. Detection looks for a row that stands out
+//! from the rows around it in one of those ways; tracking speaks the selected
+//! item only when it moves, instead of the whole redrawn list.
+
+use crate::terminal::{Color, TerminalSnapshot};
+use anyhow::{Result, bail};
+use regex::Regex;
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+/// Pointers that lists commonly draw in front of the selected row.
+const MARKERS: [&str; 8] = [">", "❯", "▶", "►", "→", "➜", "*", "•"];
+
+/// A list needs this many rows, counting the selection, before one of them
+/// is treated as selected.
+const MIN_LIST_ROWS: usize = 3;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum SelectionStyle {
+    /// A background color or inverse video.
+    Background,
+    Marker,
+    Bold,
+}
+
+impl fmt::Display for SelectionStyle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Background => "background",
+            Self::Marker => "marker",
+            Self::Bold => "bold",
+        })
+    }
+}
+
+impl FromStr for SelectionStyle {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "background" => Ok(Self::Background),
+            "marker" => Ok(Self::Marker),
+            "bold" => Ok(Self::Bold),
+            _ => bail!("selection style must be background, marker, bold, or none"),
+        }
+    }
+}
+
+/// How one application marks its selection, for windows whose title
+/// matches `title`. A `style` of `None` turns detection off there.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SelectionHint {
+    pub(crate) title: String,
+    pub(crate) style: Option<SelectionStyle>,
+    pub(crate) marker: Option<String>,
+}
+
+impl SelectionHint {
+    fn validated(self) -> Result<DefinedHint> {
+        let title = Regex::new(&self.title)
+            .map_err(|error| anyhow::anyhow!("invalid selection hint title: {error}"))?;
+        if self.marker.as_deref().is_some_and(str::is_empty) {
+            bail!("selection hint marker must not be empty");
+        }
+        Ok(DefinedHint { hint: self, title })
+    }
+}
+
+/// A defined hint with its title pattern compiled.
+#[derive(Debug)]
+struct DefinedHint {
+    hint: SelectionHint,
+    title: Regex,
+}
+
+/// Hints defined from Lua, by name.
+#[derive(Debug, Default)]
+pub(crate) struct SelectionHints {
+    defined: BTreeMap<String, DefinedHint>,
+}
+
+impl SelectionHints {
+    pub(crate) fn get(&self, name: &str) -> Option<&SelectionHint> {
+        self.defined.get(name).map(|defined| &defined.hint)
+    }
+
+    pub(crate) fn define(&mut self, name: &str, hint: SelectionHint) -> Result<()> {
+        self.defined.insert(name.to_owned(), hint.validated()?);
+        Ok(())
+    }
+
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.defined.remove(name);
+    }
+
+    /// The first hint, by name, whose pattern matches `title`.
+    pub(crate) fn for_title(&self, title: Option<&str>) -> Option<&SelectionHint> {
+        let title = title?;
+        self.defined
+            .values()
+            .find(|defined| defined.title.is_match(title))
+            .map(|defined| &defined.hint)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Selection {
+    pub(crate) row: u16,
+    pub(crate) text: String,
+    pub(crate) style: SelectionStyle,
+}
+
+/// Remembers the last selection so only a moved one is announced.
+#[derive(Debug, Default)]
+pub(crate) struct SelectionTracker {
+    last: Option<Selection>,
+}
+
+impl SelectionTracker {
+    /// Returns the text to speak when `current` is the same kind of
+    /// selection as before but on another row or item. A selection that
+    /// first appears is left to ordinary screen reading.
+    pub(crate) fn update(&mut self, current: Option<Selection>) -> Option<String> {
+        let previous = std::mem::replace(&mut self.last, current);
+        let (previous, current) = (previous?, self.last.as_ref()?);
+        let moved = previous.style == current.style
+            && (previous.row != current.row || previous.text != current.text);
+        moved.then(|| current.text.clone())
+    }
+}
+
+/// The selected row of the list on `screen`, trying background, marker and
+/// bold selections in turn unless `hint` names one.
+pub(crate) fn detect(screen: &TerminalSnapshot, hint: Option<&SelectionHint>) -> Option<Selection> {
+    let rows = RowInfo::collect(screen);
+    let marker = hint.and_then(|hint| hint.marker.as_deref());
+    let styles = match hint {
+        Some(hint) => match hint.style {
+            Some(style) => vec![style],
+            None if hint.marker.is_some() => vec![SelectionStyle::Marker],
+            None => return None,
+        },
+        None => vec![
+            SelectionStyle::Background,
+            SelectionStyle::Marker,
+            SelectionStyle::Bold,
+        ],
+    };
+    styles.into_iter().find_map(|style| {
+        let (row, text) = match style {
+            SelectionStyle::Background => detect_background(screen, &rows),
+            SelectionStyle::Marker => detect_marker(&rows, marker),
+            SelectionStyle::Bold => detect_bold(screen, &rows),
+        }?;
+        let text = strip_marker(&text, marker);
+        (!text.is_empty()).then_some(Selection {
+            row,
+            text: text.to_owned(),
+            style,
+        })
+    })
+}
+
+struct RowInfo {
+    row: u16,
+    text: String,
+    first_col: u16,
+    /// The first row and length of the run of nonblank rows holding this
+    /// row.
+    block_start: usize,
+    block_len: usize,
+}
+
+impl RowInfo {
+    fn collect(screen: &TerminalSnapshot) -> Vec<Option<Self>> {
+        let (rows, cols) = screen.size();
+        let mut infos: Vec<Option<Self>> = (0..rows)
+            .map(|row| {
+                let text = screen.contents_between(row, 0, row, cols);
+                let first_col = (0..cols).find(|&col| {
+                    screen
+                        .cell(row, col)
+                        .is_some_and(|cell| !cell.contents().trim().is_empty())
+                })?;
+                Some(Self {
+                    row,
+                    text,
+                    first_col,
+                    block_start: 0,
+                    block_len: 0,
+                })
+            })
+            .collect();
+        let mut start = 0;
+        while start < infos.len() {
+            let end = (start..infos.len())
+                .find(|&index| infos[index].is_none())
+                .unwrap_or(infos.len());
+            for info in infos[start..end].iter_mut().flatten() {
+                info.block_start = start;
+                info.block_len = end - start;
+            }
+            start = end + 1;
+        }
+        infos
+    }
+}
+
+/// Rows other than the first and last, which usually hold menu bars and
+/// status lines rather than list items.
+fn inner_rows(rows: &[Option<RowInfo>]) -> impl Iterator<Item = &RowInfo> {
+    let last = rows.len().saturating_sub(1);
+    rows.iter()
+        .enumerate()
+        .filter(move |(index, _)| *index != 0 && *index != last)
+        .filter_map(|(_, info)| info.as_ref())
+        .filter(|info| info.block_len >= MIN_LIST_ROWS)
+}
+
+/// Whether enough other list rows surround `info` for it to be one item of
+/// a list.
+fn in_list(rows: &[Option<RowInfo>], info: &RowInfo) -> bool {
+    let others = inner_rows(rows)
+        .filter(|other| other.block_start == info.block_start && other.row != info.row)
+        .count();
+    others + 1 >= MIN_LIST_ROWS
+}
+
+fn detect_background(screen: &TerminalSnapshot, rows: &[Option<RowInfo>]) -> Option<(u16, String)> {
+    let (_, cols) = screen.size();
+    let mut runs = Vec::new();
+    for info in inner_rows(rows) {
+        let mut col = 0;
+        while col < cols {
+            let key = background_key(screen, info.row, col);
+            let start = col;
+            while col < cols && background_key(screen, info.row, col) == key {
+                col += 1;
+            }
+            let text = screen.contents_between(info.row, start, info.row, col);
+            if key != (Color::Default, false) && col - start >= 2 && !text.trim().is_empty() {
+                runs.push((info.row, key, text));
+            }
+        }
+    }
+    // Colors are not hashable, and a screen has only a handful of styles.
+    let row_count = |key: (Color, bool)| {
+        let mut rows: Vec<u16> = runs
+            .iter()
+            .filter(|(_, other, _)| *other == key)
+            .map(|(row, _, _)| *row)
+            .collect();
+        rows.dedup();
+        rows.len()
+    };
+    let mut unique = runs
+        .iter()
+        .filter(|(_, key, _)| row_count(*key) == 1)
+        .map(|(row, _, text)| (*row, text.clone()));
+    let (row, text) = unique.next()?;
+    // Two rows that each stand out are a layout, not a selection.
+    if unique.any(|(other, _)| other != row) {
+        return None;
+    }
+    let info = rows.get(usize::from(row))?.as_ref()?;
+    in_list(rows, info).then_some((row, text))
+}
+
+fn background_key(screen: &TerminalSnapshot, row: u16, col: u16) -> (Color, bool) {
+    screen
+        .cell(row, col)
+        .map_or((Color::Default, false), |cell| {
+            (cell.bgcolor(), cell.inverse())
+        })
+}
+
+fn detect_marker(rows: &[Option<RowInfo>], marker: Option<&str>) -> Option<(u16, String)> {
+    let markers: Vec<&str> = match marker {
+        Some(marker) => vec![marker],
+        None => MARKERS.to_vec(),
+    };
+    let marked = |info: &RowInfo| {
+        let text = info.text.trim_start();
+        markers.iter().any(|marker| text.starts_with(marker))
+    };
+    let mut candidates = inner_rows(rows).filter(|info| marked(info));
+    let selected = candidates.next()?;
+    if candidates.any(|info| info.first_col == selected.first_col) {
+        return None;
+    }
+    // The other items line up just after the pointer's column.
+    let items = inner_rows(rows)
+        .filter(|info| {
+            info.row != selected.row
+                && info.first_col > selected.first_col
+                && info.first_col <= selected.first_col.saturating_add(4)
+        })
+        .count();
+    (items + 1 >= MIN_LIST_ROWS).then(|| (selected.row, selected.text.clone()))
+}
+
+fn detect_bold(screen: &TerminalSnapshot, rows: &[Option<RowInfo>]) -> Option<(u16, String)> {
+    let (_, cols) = screen.size();
+    let bold = |row: u16| {
+        let cells = (0..cols)
+            .filter_map(|col| screen.cell(row, col))
+            .filter(|cell| !cell.contents().trim().is_empty());
+        let (mut any, mut all) = (false, true);
+        for cell in cells {
+            any |= cell.bold();
+            all &= cell.bold();
+        }
+        (any, all)
+    };
+    let mut selected = None;
+    for info in inner_rows(rows) {
+        match bold(info.row) {
+            (true, true) if selected.is_none() => selected = Some(info),
+            (true, _) => return None,
+            _ => {}
+        }
+    }
+    selected
+        .filter(|info| in_list(rows, info))
+        .map(|info| (info.row, info.text.clone()))
+}
+
+fn strip_marker<'a>(text: &'a str, marker: Option<&str>) -> &'a str {
+    let text = text.trim();
+    let stripped = match marker {
+        Some(marker) => text.strip_prefix(marker),
+        None => MARKERS.iter().find_map(|marker| text.strip_prefix(marker)),
+    };
+    stripped.map_or(text, str::trim_start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Selection, SelectionHint, SelectionStyle, SelectionTracker, detect};
+    use crate::terminal::{GhosttyEngine, TerminalEngine};
+
+    fn screen(rows: u16, cols: u16, contents: &str) -> GhosttyEngine {
+        let mut engine = GhosttyEngine::new(rows, cols).expect("create Ghostty engine");
+        engine
+            .advance(contents.as_bytes())
+            .expect("draw list fixture");
+        engine
+    }
+
+    fn selected(engine: &GhosttyEngine, hint: Option<&SelectionHint>) -> Option<(u16, String)> {
+        detect(engine.snapshot(), hint).map(|selection| (selection.row, selection.text))
+    }
+
+    #[test]
+    fn dialog_and_mc_selections_use_a_unique_background() {
+        let dialog = screen(
+            6,
+            20,
+            "title\r\n\x1b[47m 1 apples   \x1b[0m\r\n\x1b[47m \x1b[44m2 pears   \x1b[47m \x1b[0m\r\n\x1b[47m 3 plums    \x1b[0m\r\n\x1b[47m 4 figs     \x1b[0m\r\n< OK >",
+        );
+        assert_eq!(selected(&dialog, None), Some((2, "2 pears".into())));
+
+        let inverse = screen(5, 20, "menu\r\none\r\n\x1b[7mtwo\x1b[0m\r\nthree\r\nstatus");
+        assert_eq!(selected(&inverse, None), Some((2, "two".into())));
+    }
+
+    #[test]
+    fn pointer_and_bold_selections_need_a_surrounding_list() {
+        let fzf = screen(6, 20, "\r\n  alpha\r\n> beta\r\n  gamma\r\n  3/3\r\n> be");
+        assert_eq!(selected(&fzf, None), Some((2, "beta".into())));
+
+        let bold = screen(5, 20, "\r\nred\r\n\x1b[1mgreen\x1b[0m\r\nblue\r\n");
+        assert_eq!(selected(&bold, None), Some((2, "green".into())));
+
+        let prose = screen(4, 20, "$ ls\r\n\x1b[1mREADME\x1b[0m\r\n$ ");
+        assert_eq!(selected(&prose, None), None);
+    }
+
+    #[test]
+    fn hints_choose_or_disable_detection() {
+        let custom = screen(5, 20, "\r\n  one\r\n▌ two\r\n  three\r\n");
+        assert_eq!(selected(&custom, None), None);
+        let hint = SelectionHint {
+            title: "^picker".into(),
+            style: None,
+            marker: Some("▌".into()),
+        };
+        assert_eq!(selected(&custom, Some(&hint)), Some((2, "two".into())));
+
+        let inverse = screen(5, 20, "\r\none\r\n\x1b[7mtwo\x1b[0m\r\nthree\r\n");
+        let off = SelectionHint {
+            title: "vim".into(),
+            style: None,
+            marker: None,
+        };
+        assert_eq!(selected(&inverse, Some(&off)), None);
+    }
+
+    #[test]
+    fn tracker_announces_only_moved_selections() {
+        let at = |row, text: &str| Selection {
+            row,
+            text: text.into(),
+            style: SelectionStyle::Background,
+        };
+        let mut tracker = SelectionTracker::default();
+        assert_eq!(tracker.update(Some(at(2, "one"))), None);
+        assert_eq!(tracker.update(Some(at(2, "one"))), None);
+        assert_eq!(tracker.update(Some(at(3, "two"))), Some("two".into()));
+        assert_eq!(
+            tracker.update(Some(at(3, "scrolled"))),
+            Some("scrolled".into())
+        );
+        assert_eq!(tracker.update(None), None);
+        assert_eq!(tracker.update(Some(at(4, "three"))), None);
+    }
+}
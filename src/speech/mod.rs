@@ -52,6 +52,14 @@ pub trait Driver {
         ))
     }
 
+    /// Select a voice by ID or name for later speech, or restore the
+    /// backend's initial voice with `None`.
+    fn set_voice(&mut self, _voice: Option<&str>) -> DriverResult<()> {
+        Err(anyhow::anyhow!(
+            "this speech backend does not support voice selection"
+        ))
+    }
+
     /// Interrupt backend work during explicit lifecycle teardown.
     fn shutdown(&mut self) {}
 }
@@ -59,6 +67,7 @@ pub trait Driver {
 pub struct Speech {
     driver: Box<dyn Driver>,
     symbol_level: symbols::Level,
+    voice: Option<String>,
     symbols_map: symbols::SymbolMap,
     processed: String,
     run: String,
@@ -69,6 +78,7 @@ impl Speech {
         Speech {
             driver,
             symbol_level: symbols::Level::Some,
+            voice: None,
             symbols_map: symbols::SymbolMap::default_map(),
             processed: String::new(),
             run: String::new(),
@@ -207,6 +217,21 @@ impl Speech {
         self.driver.set_rate(rate).map_err(Error::Driver)
     }
 
+    /// The voice most recently selected with [`Self::set_voice`], or `None`
+    /// for the backend's initial voice.
+    pub fn voice(&self) -> Option<&str> {
+        self.voice.as_deref()
+    }
+
+    pub fn set_voice(&mut self, voice: Option<&str>) -> Result<()> {
+        if self.voice.as_deref() == voice {
+            return Ok(());
+        }
+        self.driver.set_voice(voice).map_err(Error::Driver)?;
+        self.voice = voice.map(str::to_owned);
+        Ok(())
+    }
+
     pub fn start(&mut self) -> Result<()> {
         self.driver.start().map_err(Error::Driver)
     }
@@ -228,13 +253,7 @@ impl Speech {
     }
 
     pub fn cycle_symbol_level(&mut self) -> symbols::Level {
-        use symbols::Level;
-        self.symbol_level = match self.symbol_level {
-            Level::None => Level::Some,
-            Level::Some => Level::Most,
-            Level::Most => Level::All,
-            Level::All | Level::Character => Level::None,
-        };
+        self.symbol_level = self.symbol_level.next();
         self.symbol_level
     }
 
@@ -65,6 +65,8 @@ pub enum Error {
     },
     #[error("speech server did not advertise required capability {0:?}")]
     MissingCapability(&'static str),
+    #[error("speech server does not support optional method {0:?}")]
+    UnsupportedMethod(&'static str),
     #[error("proc driver transport is no longer usable")]
     Unavailable,
     #[error("proc driver RPC error {code}: {message}{data}")]
@@ -138,6 +140,7 @@ pub struct ProcDriver {
     rate: f32,
     timeouts: RpcTimeouts,
     legacy_protocol: bool,
+    voice_selection: bool,
     unavailable: bool,
 }
 
@@ -287,6 +290,7 @@ impl ProcDriver {
             rate: 1.0,
             timeouts,
             legacy_protocol: false,
+            voice_selection: false,
             unavailable: false,
         };
         driver.initialize()?;
@@ -349,6 +353,7 @@ impl ProcDriver {
                 return Err(Error::MissingCapability(name));
             }
         }
+        self.voice_selection = initialized.capabilities.set_voice;
         Ok(())
     }
 
@@ -593,6 +598,18 @@ impl Driver for ProcDriver {
         self.rate = actual;
         Ok(())
     }
+
+    fn set_voice(&mut self, voice: Option<&str>) -> DriverResult<()> {
+        if !self.voice_selection {
+            return Err(Error::UnsupportedMethod("set_voice").into());
+        }
+        let result = self.call("set_voice", Some(json!({ "voice": voice })))?;
+        if let Err(error) = expect_null_result("set_voice", result) {
+            self.fail_transport();
+            return Err(error.into());
+        }
+        Ok(())
+    }
 }
 
 impl ProcDriver {
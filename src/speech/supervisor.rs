@@ -271,6 +271,7 @@ pub struct Supervisor {
     spec: SpeechServerSpec,
     active: Option<ManagedProcess>,
     desired_rate: f32,
+    desired_voice: Option<String>,
     pending_speech: VecDeque<PendingSpeech>,
     pending_speech_bytes: usize,
     started: bool,
@@ -311,6 +312,7 @@ impl Supervisor {
             spec,
             active: None,
             desired_rate: 1.0,
+            desired_voice: None,
             pending_speech: VecDeque::new(),
             pending_speech_bytes: 0,
             started: false,
@@ -343,6 +345,11 @@ impl Supervisor {
             .driver
             .set_rate(self.desired_rate)
             .context("restore speech rate")?;
+        if self.desired_voice.is_some() {
+            // A replacement server without voice selection still speaks, so
+            // failing to restore the voice is not a reason to reject it.
+            let _ = candidate.driver.set_voice(self.desired_voice.as_deref());
+        }
         Ok(candidate)
     }
 
@@ -576,6 +583,14 @@ impl Driver for Supervisor {
         self.call_active("set_rate", |driver| driver.set_rate(rate))
     }
 
+    fn set_voice(&mut self, voice: Option<&str>) -> DriverResult<()> {
+        self.desired_voice = voice.map(str::to_owned);
+        if !self.started {
+            return Ok(());
+        }
+        self.call_active("set_voice", |driver| driver.set_voice(voice))
+    }
+
     fn start(&mut self) -> DriverResult<()> {
         self.startup()
     }
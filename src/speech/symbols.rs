@@ -972,7 +972,7 @@ impl SymbolDesc {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
 pub enum Level {
     None,
     Some,
@@ -981,6 +981,20 @@ pub enum Level {
     Character,
 }
 
+impl Level {
+    /// The next level in the user-facing cycle. `Character` is only set
+    /// explicitly, so cycling from it starts over at `None`.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Level::None => Level::Some,
+            Level::Some => Level::Most,
+            Level::Most => Level::All,
+            Level::All | Level::Character => Level::None,
+        }
+    }
+}
+
 impl std::fmt::Display for Level {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(
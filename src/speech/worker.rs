@@ -24,6 +24,7 @@ enum Request {
     Speak { text: String, interrupt: bool },
     Stop,
     SetRate(f32),
+    SetVoice(Option<String>),
     ConfigureServer(SpeechServerSpec),
     Start(mpsc::SyncSender<std::result::Result<(), String>>),
 }
@@ -32,7 +33,11 @@ impl Request {
     fn speech_bytes(&self) -> usize {
         match self {
             Self::Speak { text, .. } => text.len(),
-            Self::Stop | Self::SetRate(_) | Self::ConfigureServer(_) | Self::Start(_) => 0,
+            Self::Stop
+            | Self::SetRate(_)
+            | Self::SetVoice(_)
+            | Self::ConfigureServer(_)
+            | Self::Start(_) => 0,
         }
     }
 
@@ -161,6 +166,24 @@ impl Mailbox {
         Ok(())
     }
 
+    fn enqueue_voice(&self, voice: Option<String>) -> DriverResult<()> {
+        let mut state = self.lock()?;
+        if state.shutdown {
+            return Ok(());
+        }
+        // Unlike the rate, a voice applies to the speech queued after it, so
+        // it keeps its place in line. Consecutive changes collapse into the
+        // last one, which keeps the queue bounded by the speech around it.
+        if let Some(Request::SetVoice(queued)) = state.requests.back_mut() {
+            *queued = voice;
+        } else {
+            state.requests.push_back(Request::SetVoice(voice));
+        }
+        drop(state);
+        self.available.notify_one();
+        Ok(())
+    }
+
     fn enqueue_server(&self, spec: SpeechServerSpec) -> DriverResult<()> {
         let mut state = self.lock()?;
         if state.shutdown {
@@ -314,6 +337,10 @@ impl Driver for BoundedAsyncDriver {
         self.mailbox.enqueue_server(spec)
     }
 
+    fn set_voice(&mut self, voice: Option<&str>) -> DriverResult<()> {
+        self.mailbox.enqueue_voice(voice.map(str::to_owned))
+    }
+
     fn shutdown(&mut self) {
         self.shutdown_inner();
     }
@@ -356,6 +383,7 @@ fn run_worker(mut driver: impl Driver, mailbox: &Mailbox) {
             Request::Speak { text, interrupt } => driver.speak(&text, interrupt),
             Request::Stop => driver.stop(),
             Request::SetRate(rate) => driver.set_rate(rate),
+            Request::SetVoice(voice) => driver.set_voice(voice.as_deref()),
             Request::ConfigureServer(spec) => driver.configure_server(spec),
             Request::Start(completed) => {
                 let result = driver.start();
@@ -1,9 +1,15 @@
 use crate::{keymap::InputMode, terminal::TerminalSnapshot, view::View};
 
+mod cells;
 mod detection;
+mod export;
+mod layouts;
 
+use cells::{CellSpan, delimited_cells, pipe_cells, tab_stop_cells};
 pub(crate) use detection::{detect, detect_manual_from_header};
-use detection::{is_separator_row, pipe_delimited_cell_text, row_has_fixed_width_columns};
+use detection::{is_separator_row, is_vertical_border, row_has_fixed_width_columns};
+pub(crate) use export::{ExportFormat, aligned_text, render as render_export, sort_rows};
+pub(crate) use layouts::{TableLayout, TableLayouts, default_layouts_path};
 
 pub(crate) trait TerminalSurface {
     fn screen(&self) -> &TerminalSnapshot;
@@ -33,6 +39,8 @@ impl TerminalSurface for TerminalSnapshot {
 #[derive(Clone, Debug)]
 pub(crate) struct SetupState {
     header_row: u16,
+    // Kept so the tabstops can be saved as a layout matching this header.
+    header_text: String,
     tabstops: Vec<u16>,
 }
 
@@ -47,6 +55,10 @@ impl SetupState {
         self.header_row
     }
 
+    pub(crate) fn header_text(&self) -> &str {
+        &self.header_text
+    }
+
     pub(crate) fn tabstops(&self) -> &[u16] {
         &self.tabstops
     }
@@ -72,6 +84,9 @@ pub(crate) struct Session {
     // while table mode remains active.
     navigation: Option<TableState>,
     setup: Option<SetupState>,
+    // Kept across table mode sessions so a search can be repeated on the
+    // next table.
+    last_search: Option<String>,
 }
 
 impl Default for Session {
@@ -80,6 +95,7 @@ impl Default for Session {
             mode: InputMode::Normal,
             navigation: None,
             setup: None,
+            last_search: None,
         }
     }
 }
@@ -109,12 +125,21 @@ impl Session {
         self.setup.as_mut()
     }
 
-    pub(crate) fn enter_setup(&mut self, header_row: u16) -> InputMode {
+    pub(crate) fn last_search(&self) -> Option<&str> {
+        self.last_search.as_deref()
+    }
+
+    pub(crate) fn set_last_search(&mut self, query: String) {
+        self.last_search = Some(query);
+    }
+
+    pub(crate) fn enter_setup(&mut self, header_row: u16, header_text: String) -> InputMode {
         let previous = self.mode;
         self.mode = InputMode::TableSetup;
         self.navigation = None;
         self.setup = Some(SetupState {
             header_row,
+            header_text,
             tabstops: Vec::new(),
         });
         previous
@@ -143,13 +168,67 @@ pub(crate) struct Column {
     end: u16,
 }
 
+/// How a table was drawn, which decides how its rows split into cells.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TableFormat {
+    /// Columns are fixed screen ranges separated by blank gutters, or set
+    /// manually with tabstops.
+    FixedWidth,
+    /// Rows split on `|`, which need not line up between rows.
+    Pipe,
+    /// A pipe table with a markdown alignment row below its header.
+    Markdown,
+    /// A grid drawn with box-drawing characters. With `multiline`, the
+    /// lines between two horizontal rules form one row.
+    Box { multiline: bool },
+    /// CSV-style data split on the delimiter, honoring double quotes.
+    Delimited(char),
+    /// Tab-separated values, split where text resumes on a tab stop.
+    TabSeparated,
+}
+
+impl TableFormat {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::FixedWidth => "fixed_width",
+            Self::Pipe => "pipe",
+            Self::Markdown => "markdown",
+            Self::Box { .. } => "box",
+            Self::Delimited(_) => "csv",
+            Self::TabSeparated => "tsv",
+        }
+    }
+}
+
+/// A column's alignment from a markdown alignment row.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ColumnAlignment {
+    Default,
+    Left,
+    Center,
+    Right,
+}
+
+impl ColumnAlignment {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Left => "left",
+            Self::Center => "center",
+            Self::Right => "right",
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct TableModel {
     top: u16,
     bottom: u16,
     columns: Vec<Column>,
     header_row: Option<u16>,
-    delimiter: Option<char>,
+    format: TableFormat,
+    /// One entry per column for markdown tables; empty otherwise.
+    alignments: Vec<ColumnAlignment>,
 }
 
 #[derive(Clone, Debug)]
@@ -163,14 +242,6 @@ impl Column {
     pub(crate) fn new(start: u16, end: u16) -> Self {
         Self { start, end }
     }
-
-    pub(crate) fn start(&self) -> u16 {
-        self.start
-    }
-
-    pub(crate) fn end(&self) -> u16 {
-        self.end
-    }
 }
 
 impl TableState {
@@ -189,10 +260,6 @@ impl TableState {
     pub(crate) fn set_current_col(&mut self, current_col: usize) {
         self.current_col = current_col;
     }
-
-    pub(crate) fn current_column(&self) -> Option<&Column> {
-        self.model.columns.get(self.current_col)
-    }
 }
 
 impl TableModel {
@@ -202,14 +269,25 @@ impl TableModel {
         bottom: u16,
         columns: Vec<Column>,
         header_row: Option<u16>,
-        delimiter: Option<char>,
+        format: TableFormat,
+    ) -> Self {
+        Self::detected(top, bottom, columns, header_row, format)
+    }
+
+    fn detected(
+        top: u16,
+        bottom: u16,
+        columns: Vec<Column>,
+        header_row: Option<u16>,
+        format: TableFormat,
     ) -> Self {
         Self {
             top,
             bottom,
             columns,
             header_row,
-            delimiter,
+            format,
+            alignments: Vec::new(),
         }
     }
 
@@ -229,6 +307,14 @@ impl TableModel {
         self.header_row
     }
 
+    pub(crate) fn format(&self) -> TableFormat {
+        self.format
+    }
+
+    pub(crate) fn alignments(&self) -> &[ColumnAlignment] {
+        &self.alignments
+    }
+
     pub fn column_for_col(&self, col: u16) -> usize {
         for (idx, column) in self.columns.iter().enumerate() {
             if col >= column.start && col <= column.end {
@@ -248,26 +334,165 @@ impl TableModel {
         }
     }
 
+    /// The cells of `row` for formats that split each row on its own, or
+    /// `None` when columns are fixed screen ranges.
+    fn row_cells<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> Option<Vec<CellSpan>> {
+        let screen = view.screen();
+        match self.format {
+            TableFormat::Pipe | TableFormat::Markdown => pipe_cells(screen, row),
+            TableFormat::Delimited(delimiter) => delimited_cells(screen, row, delimiter),
+            TableFormat::TabSeparated => tab_stop_cells(screen, row),
+            TableFormat::FixedWidth | TableFormat::Box { .. } => None,
+        }
+    }
+
     pub fn cell_text<V: TerminalSurface + ?Sized>(
         &self,
         view: &V,
         row: u16,
         col_idx: usize,
     ) -> String {
-        if self.delimiter == Some('|')
-            && let Some(text) = pipe_delimited_cell_text(&view.line(row), col_idx)
-        {
-            return text.to_string();
+        if let Some(cells) = self.row_cells(view, row) {
+            return cells
+                .into_iter()
+                .nth(col_idx)
+                .map(|cell| cell.text)
+                .unwrap_or_default();
         }
 
         let Some(column) = self.columns.get(col_idx) else {
             return String::new();
         };
         let end = column.end.min(view.size().1.saturating_sub(1));
-        let text = view
-            .screen()
-            .contents_between(row, column.start, row, end + 1);
-        text.trim().to_string()
+        let (first, last) = self.record_rows(view, row);
+        let mut text = String::new();
+        for line in first..=last {
+            let part = view
+                .screen()
+                .contents_between(line, column.start, line, end + 1);
+            let part = part.trim();
+            if !part.is_empty() {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(part);
+            }
+        }
+        text
+    }
+
+    /// The screen columns of cell `col_idx` in `row`. Rows that split on
+    /// their own report their own cells; the detected column is the
+    /// fallback.
+    pub(crate) fn cell_bounds<V: TerminalSurface + ?Sized>(
+        &self,
+        view: &V,
+        row: u16,
+        col_idx: usize,
+    ) -> Option<(u16, u16)> {
+        let column = self.columns.get(col_idx)?;
+        Some(
+            self.row_cells(view, row)
+                .and_then(|cells| cells.into_iter().nth(col_idx))
+                .map_or((column.start, column.end), |cell| (cell.start, cell.end)),
+        )
+    }
+
+    /// The column index under screen column `col` of `row`.
+    pub(crate) fn column_at<V: TerminalSurface + ?Sized>(
+        &self,
+        view: &V,
+        row: u16,
+        col: u16,
+    ) -> usize {
+        let Some(cells) = self.row_cells(view, row) else {
+            return self.column_for_col(col);
+        };
+        cells
+            .iter()
+            .rposition(|cell| cell.start <= col)
+            .unwrap_or(0)
+            .min(self.columns.len().saturating_sub(1))
+    }
+
+    /// The first and last line of the row that `row` belongs to. Only
+    /// multiline grids have rows longer than one line.
+    fn record_rows<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> (u16, u16) {
+        if self.format != (TableFormat::Box { multiline: true })
+            || row < self.top
+            || row > self.bottom
+        {
+            return (row, row);
+        }
+        let mut first = row;
+        while first > self.top && !self.is_rule_row(view, first - 1) {
+            first -= 1;
+        }
+        let mut last = row;
+        while last < self.bottom && !self.is_rule_row(view, last + 1) {
+            last += 1;
+        }
+        (first, last)
+    }
+
+    /// Whether `row` is a rule between rows. In a grid, a rule crosses the
+    /// outer border, so the edges of a box nested in a cell are content.
+    pub(crate) fn is_rule_row<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> bool {
+        let screen = view.screen();
+        if !is_separator_row(screen, row) {
+            return false;
+        }
+        if !matches!(self.format, TableFormat::Box { .. }) {
+            return true;
+        }
+        self.columns
+            .first()
+            .and_then(|column| column.start.checked_sub(1))
+            .and_then(|border| screen.cell(row, border))
+            .is_none_or(|cell| !is_vertical_border(cell.contents()))
+    }
+
+    /// The text of every cell in `row`.
+    pub(crate) fn row_text<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> Vec<String> {
+        (0..self.columns.len())
+            .map(|col_idx| self.cell_text(view, row, col_idx))
+            .collect()
+    }
+
+    /// The rows below the header that hold data, skipping rules, banners
+    /// and the continuation lines of multiline rows.
+    pub(crate) fn data_rows<V: TerminalSurface + ?Sized>(&self, view: &V) -> Vec<u16> {
+        (self.top..=self.bottom)
+            .filter(|&row| {
+                self.header_row.is_none_or(|header_row| row > header_row)
+                    && !self.is_skippable_row(view, row)
+            })
+            .collect()
+    }
+
+    /// The next data row after `row` whose cell in `col_idx` contains
+    /// `query`, ignoring case. The search wraps past the last row; the flag
+    /// reports whether it did.
+    pub(crate) fn find_in_column<V: TerminalSurface + ?Sized>(
+        &self,
+        view: &V,
+        col_idx: usize,
+        row: u16,
+        query: &str,
+    ) -> Option<(u16, bool)> {
+        let query = query.to_lowercase();
+        let rows = self.data_rows(view);
+        let split = rows.partition_point(|&candidate| candidate <= row);
+        let (before, after) = rows.split_at(split);
+        after
+            .iter()
+            .map(|&candidate| (candidate, false))
+            .chain(before.iter().map(|&candidate| (candidate, true)))
+            .find(|&(candidate, _)| {
+                self.cell_text(view, candidate, col_idx)
+                    .to_lowercase()
+                    .contains(&query)
+            })
     }
 
     pub fn header_text<V: TerminalSurface + ?Sized>(
@@ -347,10 +572,9 @@ impl TableModel {
         row: u16,
         preferred: usize,
     ) -> usize {
-        if self.delimiter == Some('|') {
-            let line = view.line(row);
+        if let Some(cells) = self.row_cells(view, row) {
             return nearest_matching_column(self.columns.len(), preferred, |col| {
-                pipe_delimited_cell_text(&line, col).is_some_and(|text| !text.is_empty())
+                cells.get(col).is_some_and(|cell| !cell.text.is_empty())
             });
         }
         nearest_matching_column(self.columns.len(), preferred, |col| {
@@ -359,31 +583,48 @@ impl TableModel {
     }
 
     pub fn is_skippable_row<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> bool {
-        is_separator_row(view.screen(), row) || self.is_banner_row(view, row)
+        self.is_rule_row(view, row)
+            || self.record_rows(view, row).0 != row
+            || self.is_banner_row(view, row)
     }
 
     pub fn is_banner_row<V: TerminalSurface + ?Sized>(&self, view: &V, row: u16) -> bool {
-        if row < self.top || row > self.bottom || is_separator_row(view.screen(), row) {
+        if row < self.top || row > self.bottom || self.is_rule_row(view, row) {
             return false;
         }
 
-        if self.delimiter.is_none() {
-            return !row_has_fixed_width_columns(view.screen(), row);
-        }
-
-        let line = view.line(row);
-        let trimmed = line.trim();
-        if !(trimmed.starts_with('|') && trimmed.ends_with('|')) {
-            return false;
+        match self.format {
+            TableFormat::FixedWidth => !row_has_fixed_width_columns(view.screen(), row),
+            TableFormat::Pipe | TableFormat::Markdown => {
+                let line = view.line(row);
+                let trimmed = line.trim();
+                if !(trimmed.starts_with('|') && trimmed.ends_with('|')) {
+                    return false;
+                }
+                self.row_cells(view, row).is_none_or(|cells| {
+                    cells
+                        .iter()
+                        .take(self.columns.len())
+                        .filter(|cell| !cell.text.is_empty())
+                        .take(2)
+                        .count()
+                        <= 1
+                })
+            }
+            // A title or note drawn across the grid crosses every inner
+            // border.
+            TableFormat::Box { .. } => {
+                let screen = view.screen();
+                self.columns.len() > 1
+                    && self.columns.iter().skip(1).all(|column| {
+                        column.start == 0
+                            || screen
+                                .cell(row, column.start - 1)
+                                .is_none_or(|cell| !is_vertical_border(cell.contents()))
+                    })
+            }
+            TableFormat::Delimited(_) | TableFormat::TabSeparated => false,
         }
-
-        (0..self.columns.len())
-            .filter(|&col| {
-                pipe_delimited_cell_text(trimmed, col).is_some_and(|text| !text.is_empty())
-            })
-            .take(2)
-            .count()
-            <= 1
     }
 }
 
@@ -493,7 +734,10 @@ mod tests {
         assert!(session.navigation().is_none());
         assert!(session.setup().is_none());
 
-        assert_eq!(session.enter_setup(4), InputMode::Normal);
+        assert_eq!(
+            session.enter_setup(4, "NAME  AGE".to_owned()),
+            InputMode::Normal
+        );
         assert_eq!(session.mode(), InputMode::TableSetup);
         assert_eq!(session.setup().unwrap().header_row(), 4);
         assert_eq!(
@@ -512,7 +756,8 @@ mod tests {
                 bottom: 8,
                 columns: vec![Column { start: 0, end: 2 }, Column { start: 3, end: 7 }],
                 header_row: Some(4),
-                delimiter: None,
+                format: TableFormat::FixedWidth,
+                alignments: Vec::new(),
             },
             1,
         );
@@ -536,7 +781,8 @@ mod tests {
                 bottom: 2,
                 columns: vec![Column { start: 0, end: 2 }, Column { start: 3, end: 5 }],
                 header_row: Some(0),
-                delimiter: None,
+                format: TableFormat::FixedWidth,
+                alignments: Vec::new(),
             },
             0,
         );
@@ -561,6 +807,7 @@ mod tests {
     fn toggling_tabstops_keeps_them_sorted_and_unique() {
         let mut setup = SetupState {
             header_row: 0,
+            header_text: String::new(),
             tabstops: Vec::new(),
         };
         assert_eq!(setup.toggle_tabstop(8), TabstopChange::Added);
@@ -573,12 +820,15 @@ mod tests {
 
     #[test]
     fn pipe_cell_text_handles_optional_outer_delimiters_and_missing_cells() {
-        assert_eq!(pipe_delimited_cell_text("A | B | C", 0), Some("A"));
-        assert_eq!(pipe_delimited_cell_text("A | B | C", 2), Some("C"));
-        assert_eq!(pipe_delimited_cell_text("| A | B |", 0), Some("A"));
-        assert_eq!(pipe_delimited_cell_text("| A | B |", 1), Some("B"));
-        assert_eq!(pipe_delimited_cell_text("| A | B |", 9), Some(""));
-        assert_eq!(pipe_delimited_cell_text("no delimiters", 0), None);
+        use cells::pipe_delimited_cell_text;
+
+        let cell = |line, col| pipe_delimited_cell_text(line, col);
+        assert_eq!(cell("A | B | C", 0).as_deref(), Some("A"));
+        assert_eq!(cell("A | B | C", 2).as_deref(), Some("C"));
+        assert_eq!(cell("| A | B |", 0).as_deref(), Some("A"));
+        assert_eq!(cell("| A | B |", 1).as_deref(), Some("B"));
+        assert_eq!(cell("| A | B |", 9).as_deref(), Some(""));
+        assert_eq!(cell("no delimiters", 0), None);
     }
 
     #[test]
@@ -590,7 +840,7 @@ mod tests {
         assert_eq!(model.top, 1);
         assert_eq!(model.bottom, 3);
         assert_eq!(model.header_row, Some(1));
-        assert_eq!(model.delimiter, None);
+        assert_eq!(model.format, TableFormat::FixedWidth);
         assert_eq!(model.columns.len(), 3);
         assert_eq!(model.cell_text(&view, 2, 0), "1");
         assert_eq!(model.cell_text(&view, 2, 1), "Ada");
@@ -609,7 +859,7 @@ mod tests {
         assert_eq!(model.top, 0);
         assert_eq!(model.bottom, 1);
         assert_eq!(model.header_row, None);
-        assert_eq!(model.delimiter, None);
+        assert_eq!(model.format, TableFormat::FixedWidth);
         assert_eq!(model.columns.len(), 3);
         assert_eq!(model.cell_text(&view, 0, 0), "1");
         assert_eq!(model.cell_text(&view, 1, 2), "6");
@@ -629,6 +879,195 @@ mod tests {
         assert!(!is_separator_row(&view, 3));
     }
 
+    #[test]
+    fn box_drawing_grids_skip_banners_and_ignore_nested_borders() {
+        let view = view_with_lines(
+            10,
+            30,
+            &[
+                "╭──────────────────────╮",
+                "│ Deployments          │",
+                "├────────┬─────────────┤",
+                "│ Name   │ Status      │",
+                "├────────┼─────────────┤",
+                "│ api    │ running     │",
+                "│ web    │ │ ok │ 2/2  │",
+                "│        │ └────┘      │",
+                "│ db     │ stopped     │",
+                "╰────────┴─────────────╯",
+            ],
+        );
+
+        let model = detect(&view, 5).expect("detect box table");
+        assert_eq!(model.format(), TableFormat::Box { multiline: false });
+        assert_eq!((model.top(), model.bottom()), (0, 9));
+        assert_eq!(model.column_count(), 2);
+        assert_eq!(model.header_row(), Some(3));
+        assert!(model.is_banner_row(&view, 1));
+        assert!(!model.is_rule_row(&view, 7));
+        assert_eq!(model.next_data_row(&view, 3), Some(5));
+        assert_eq!(model.cell_text(&view, 5, 1), "running");
+        assert_eq!(model.cell_text(&view, 6, 1), "│ ok │ 2/2");
+        assert_eq!(model.cell_text(&view, 8, 0), "db");
+    }
+
+    #[test]
+    fn multiline_box_cells_join_the_lines_between_rules() {
+        let view = view_with_lines(
+            10,
+            24,
+            &[
+                "┌──────┬──────────┐",
+                "│ Key  │ Value    │",
+                "├──────┼──────────┤",
+                "│ name │ New      │",
+                "│      │ York     │",
+                "├──────┼──────────┤",
+                "│ zip  │ 10001    │",
+                "├──────┼──────────┤",
+                "│ tz   │ EST      │",
+                "└──────┴──────────┘",
+            ],
+        );
+
+        let model = detect(&view, 4).expect("detect multiline grid");
+        assert_eq!(model.format(), TableFormat::Box { multiline: true });
+        assert_eq!(model.header_row(), Some(1));
+        assert_eq!(model.cell_text(&view, 3, 1), "New York");
+        assert_eq!(model.cell_text(&view, 4, 1), "New York");
+        assert!(model.is_skippable_row(&view, 4));
+        assert_eq!(model.next_data_row(&view, 3), Some(6));
+        assert_eq!(model.prev_data_row(&view, 6), Some(3));
+    }
+
+    #[test]
+    fn markdown_alignment_rows_set_column_alignments() {
+        let view = view_with_lines(
+            4,
+            40,
+            &[
+                "| Item | Qty | Note \\| tag |",
+                "| :--- | --: | :---: |",
+                "| pen  | 2   | a \\| b |",
+                "",
+            ],
+        );
+
+        let model = detect(&view, 2).expect("detect markdown table");
+        assert_eq!(model.format(), TableFormat::Markdown);
+        assert_eq!(model.header_row(), Some(0));
+        assert_eq!(model.column_count(), 3);
+        assert_eq!(
+            model.alignments(),
+            [
+                ColumnAlignment::Left,
+                ColumnAlignment::Right,
+                ColumnAlignment::Center,
+            ]
+        );
+        assert_eq!(model.header_text(&view, 2).as_deref(), Some("Note | tag"));
+        assert_eq!(model.cell_text(&view, 2, 2), "a | b");
+        assert!(model.is_skippable_row(&view, 1));
+
+        let plain = view_with_lines(3, 20, &["| A | B |", "| 1 | 2 |", ""]);
+        let model = detect(&plain, 1).expect("detect pipe table");
+        assert_eq!(model.format(), TableFormat::Pipe);
+        assert!(model.alignments().is_empty());
+    }
+
+    #[test]
+    fn csv_rows_split_on_unquoted_delimiters() {
+        let view = view_with_lines(
+            5,
+            40,
+            &[
+                "id,name,city",
+                "1,\"Smith, J\",Oslo",
+                "2,Ada,\"New York\"",
+                "",
+                "not,a,table,row",
+            ],
+        );
+
+        let model = detect(&view, 1).expect("detect csv");
+        assert_eq!(model.format(), TableFormat::Delimited(','));
+        assert_eq!((model.top(), model.bottom()), (0, 2));
+        assert_eq!(model.header_row(), Some(0));
+        assert_eq!(model.cell_text(&view, 1, 1), "Smith, J");
+        assert_eq!(model.cell_text(&view, 2, 2), "New York");
+        assert_eq!(model.column_at(&view, 1, 13), 2);
+        assert_eq!(model.cell_bounds(&view, 1, 1), Some((2, 11)));
+    }
+
+    #[test]
+    fn tab_separated_rows_split_on_tab_stops() {
+        let view = view_with_lines(
+            4,
+            40,
+            &[
+                "name\tcity\tzip",
+                "Ada\tNew York\t10001",
+                "Bob\tOslo\t0150",
+                "",
+            ],
+        );
+
+        let model = detect(&view, 1).expect("detect tsv");
+        assert_eq!(model.format(), TableFormat::TabSeparated);
+        assert_eq!(model.header_row(), Some(0));
+        assert_eq!(model.column_count(), 3);
+        assert_eq!(model.cell_text(&view, 1, 1), "New York");
+        assert_eq!(model.cell_text(&view, 2, 2), "0150");
+        assert_eq!(model.header_text(&view, 2).as_deref(), Some("zip"));
+    }
+
+    #[test]
+    fn prose_is_not_split_on_tab_stops() {
+        let view = view_with_lines(
+            6,
+            40,
+            &[
+                "Running it over README.md splits text",
+                "Without a fixed layout the words wrap",
+                "",
+                "NAME    STATUS   AGE",
+                "web     Running  3d",
+                "db      Pending  10m",
+            ],
+        );
+
+        assert!(detect(&view, 0).is_none());
+
+        let model = detect(&view, 4).expect("detect fixed-width table");
+        assert_eq!(model.format(), TableFormat::FixedWidth);
+        assert_eq!((model.top(), model.bottom()), (3, 5));
+        assert_eq!(model.column_count(), 3);
+        assert_eq!(model.cell_text(&view, 4, 1), "Running");
+        assert_eq!(model.cell_text(&view, 5, 2), "10m");
+    }
+
+    #[test]
+    fn column_search_skips_the_header_and_wraps_past_the_last_row() {
+        let view = view_with_lines(
+            5,
+            30,
+            &[
+                "NAME   STATUS",
+                "------ -------",
+                "api    Running",
+                "web    Failed",
+                "db     running",
+            ],
+        );
+        let model = detect(&view, 2).expect("detect table");
+
+        assert_eq!(model.data_rows(&view), [2, 3, 4]);
+        assert_eq!(model.find_in_column(&view, 1, 2, "RUN"), Some((4, false)));
+        assert_eq!(model.find_in_column(&view, 1, 4, "run"), Some((2, true)));
+        assert_eq!(model.find_in_column(&view, 1, 2, "status"), None);
+        assert_eq!(model.row_text(&view, 3), ["web", "Failed"]);
+    }
+
     #[test]
     fn ghostty_backed_snapshot_supports_table_detection_and_cell_analysis() {
         use crate::terminal::GhosttyEngine;
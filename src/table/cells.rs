@@ -0,0 +1,300 @@
+//! Per-row cell splitting for tables whose columns do not line up on screen:
+//! pipe and markdown tables, CSV-style delimited data, and tab-separated
+//! values expanded by the terminal's tab stops.
+
+use super::TerminalSurface;
+
+/// Terminal tab stops are every eight columns unless a program changes them.
+const TAB_WIDTH: u16 = 8;
+
+/// Blanks needed before a tab stop to start a new field.
+const MIN_FIELD_GAP: u16 = 2;
+
+/// One cell of a row: the screen columns it covers and its text, trimmed and
+/// unescaped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct CellSpan {
+    pub(crate) start: u16,
+    pub(crate) end: u16,
+    pub(crate) text: String,
+}
+
+/// One screen cell. Blank cells hold a space and the trailing halves of wide
+/// characters are dropped, so `col` is the only reliable position.
+struct Glyph {
+    col: u16,
+    text: String,
+}
+
+impl Glyph {
+    fn is(&self, ch: char) -> bool {
+        let mut chars = self.text.chars();
+        chars.next() == Some(ch) && chars.next().is_none()
+    }
+
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+}
+
+fn row_glyphs(view: &(dyn TerminalSurface + '_), row: u16) -> Vec<Glyph> {
+    let (_, cols) = view.size();
+    (0..cols)
+        .filter_map(|col| {
+            let cell = view.screen().cell(row, col)?;
+            if cell.is_wide_continuation() {
+                return None;
+            }
+            let text = if cell.has_contents() {
+                cell.contents().to_owned()
+            } else {
+                " ".to_owned()
+            };
+            Some(Glyph { col, text })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn str_glyphs(line: &str) -> Vec<Glyph> {
+    line.chars()
+        .enumerate()
+        .map(|(col, ch)| Glyph {
+            col: u16::try_from(col).unwrap_or(u16::MAX),
+            text: ch.to_string(),
+        })
+        .collect()
+}
+
+/// Builds a cell from `glyphs[range]`, covering the columns from `start` to
+/// the last glyph of the range.
+fn span_of(glyphs: &[Glyph], range: std::ops::Range<usize>, start: u16, text: &str) -> CellSpan {
+    let end = range
+        .clone()
+        .next_back()
+        .map_or(start, |index| glyphs[index].col)
+        .max(start);
+    CellSpan {
+        start,
+        end,
+        text: text.trim().to_owned(),
+    }
+}
+
+pub(super) fn pipe_cells(view: &(dyn TerminalSurface + '_), row: u16) -> Option<Vec<CellSpan>> {
+    split_pipe(&row_glyphs(view, row))
+}
+
+pub(super) fn delimited_cells(
+    view: &(dyn TerminalSurface + '_),
+    row: u16,
+    delimiter: char,
+) -> Option<Vec<CellSpan>> {
+    split_delimited(&row_glyphs(view, row), delimiter)
+}
+
+pub(super) fn tab_stop_cells(view: &(dyn TerminalSurface + '_), row: u16) -> Option<Vec<CellSpan>> {
+    split_tab_stops(&row_glyphs(view, row))
+}
+
+/// The text of cell `col_idx` of a `|`-separated line. Missing cells are
+/// empty; lines without delimiters are not pipe rows.
+#[cfg(test)]
+pub(super) fn pipe_delimited_cell_text(line: &str, col_idx: usize) -> Option<String> {
+    let cells = split_pipe(&str_glyphs(line))?;
+    Some(
+        cells
+            .into_iter()
+            .nth(col_idx)
+            .map(|cell| cell.text)
+            .unwrap_or_default(),
+    )
+}
+
+/// Splits on `|`, dropping the empty cells outside leading and trailing
+/// delimiters. Markdown's `\|` is a literal pipe inside a cell.
+fn split_pipe(glyphs: &[Glyph]) -> Option<Vec<CellSpan>> {
+    let first = glyphs.iter().position(|glyph| !glyph.is_blank())?;
+    let last = glyphs.iter().rposition(|glyph| !glyph.is_blank())?;
+    let delimiters: Vec<usize> = (first..=last)
+        .filter(|&index| glyphs[index].is('|') && (index == 0 || !glyphs[index - 1].is('\\')))
+        .collect();
+    if delimiters.is_empty() {
+        return None;
+    }
+
+    let leading = delimiters.first() == Some(&first);
+    let trailing = delimiters.last() == Some(&last);
+    let mut bounds = Vec::with_capacity(delimiters.len() + 2);
+    if !leading {
+        bounds.push(None);
+    }
+    bounds.extend(delimiters.iter().copied().map(Some));
+    if !trailing {
+        bounds.push(None);
+    }
+
+    let cells: Vec<CellSpan> = bounds
+        .windows(2)
+        .map(|pair| {
+            let from = pair[0].map_or(0, |index| index + 1);
+            let to = pair[1].unwrap_or(last + 1);
+            let start = pair[0].map_or(glyphs[first].col, |index| glyphs[index].col + 1);
+            let text: String = glyphs[from..to]
+                .iter()
+                .map(|glyph| glyph.text.as_str())
+                .collect();
+            span_of(glyphs, from..to, start, &text.replace("\\|", "|"))
+        })
+        .collect();
+    (!cells.is_empty()).then_some(cells)
+}
+
+/// Splits CSV-style data on `delimiter`. Fields may be wrapped in double
+/// quotes, which protect delimiters, and `""` inside quotes is one quote.
+/// Lines that end inside quotes are not split.
+fn split_delimited(glyphs: &[Glyph], delimiter: char) -> Option<Vec<CellSpan>> {
+    let last = glyphs.iter().rposition(|glyph| !glyph.is_blank())?;
+    let mut cells = Vec::new();
+    let mut text = String::new();
+    let mut from = 0;
+    let mut in_quotes = false;
+    let mut index = 0;
+    while index <= last {
+        let glyph = &glyphs[index];
+        if glyph.is('"') {
+            if in_quotes && glyphs.get(index + 1).is_some_and(|next| next.is('"')) {
+                text.push('"');
+                index += 1;
+            } else {
+                in_quotes = !in_quotes;
+            }
+        } else if !in_quotes && glyph.is(delimiter) {
+            cells.push(span_of(glyphs, from..index, glyphs[from].col, &text));
+            text.clear();
+            from = index + 1;
+        } else {
+            text.push_str(&glyph.text);
+        }
+        index += 1;
+    }
+    if in_quotes || cells.is_empty() {
+        return None;
+    }
+    let start = glyphs.get(from).map_or(glyphs[last].col, |glyph| glyph.col);
+    cells.push(span_of(glyphs, from..last + 1, start, &text));
+    Some(cells)
+}
+
+/// Splits a line printed with tabs: a field starts wherever text resumes on a
+/// tab stop after at least two blanks. A single blank is how prose separates
+/// words, whichever column the next word lands on. Blanks spanning several
+/// tab stops hold empty fields, and text that does not start on a tab stop
+/// is not tab-separated.
+fn split_tab_stops(glyphs: &[Glyph]) -> Option<Vec<CellSpan>> {
+    let mut cells = Vec::new();
+    let mut current: Option<(u16, u16, String)> = None;
+    // The column where the blanks before the next text began.
+    let mut blank_from = 0u16;
+    let mut pending_blanks = String::new();
+    for glyph in glyphs {
+        if glyph.is_blank() {
+            if current.is_some() && pending_blanks.is_empty() {
+                blank_from = glyph.col;
+            }
+            pending_blanks.push_str(&glyph.text);
+            continue;
+        }
+        let resumes_on_stop = glyph.col % TAB_WIDTH == 0;
+        match current.as_mut() {
+            Some((_, end, text))
+                if pending_blanks.is_empty()
+                    || !resumes_on_stop
+                    || glyph.col - blank_from < MIN_FIELD_GAP =>
+            {
+                text.push_str(&pending_blanks);
+                text.push_str(&glyph.text);
+                *end = glyph.col;
+            }
+            _ => {
+                if current.is_none() && !resumes_on_stop {
+                    return None;
+                }
+                let tabs = if let Some((start, end, text)) = current.take() {
+                    cells.push(CellSpan { start, end, text });
+                    glyph.col / TAB_WIDTH - blank_from / TAB_WIDTH
+                } else {
+                    glyph.col / TAB_WIDTH + 1
+                };
+                for empty in 1..tabs {
+                    let stop = glyph.col - (tabs - empty) * TAB_WIDTH;
+                    cells.push(CellSpan {
+                        start: stop,
+                        end: stop,
+                        text: String::new(),
+                    });
+                }
+                current = Some((glyph.col, glyph.col, glyph.text.clone()));
+            }
+        }
+        pending_blanks.clear();
+    }
+    let (start, end, text) = current?;
+    cells.push(CellSpan { start, end, text });
+    (cells.len() >= 2).then_some(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pipe_delimited_cell_text, split_delimited, split_tab_stops, str_glyphs};
+
+    fn texts(cells: Option<Vec<super::CellSpan>>) -> Option<Vec<String>> {
+        cells.map(|cells| cells.into_iter().map(|cell| cell.text).collect())
+    }
+
+    #[test]
+    fn pipe_cells_honor_escaped_delimiters() {
+        assert_eq!(
+            pipe_delimited_cell_text(r"| a \| b | c |", 0).as_deref(),
+            Some("a | b")
+        );
+        assert_eq!(
+            pipe_delimited_cell_text(r"| a \| b | c |", 1).as_deref(),
+            Some("c")
+        );
+        assert_eq!(pipe_delimited_cell_text("|", 0), None);
+    }
+
+    #[test]
+    fn delimited_cells_unquote_fields_and_keep_protected_delimiters() {
+        assert_eq!(
+            texts(split_delimited(&str_glyphs(r#"1,"Smith, ""J""",,x "#), ',')),
+            Some(vec![
+                "1".to_owned(),
+                r#"Smith, "J""#.to_owned(),
+                String::new(),
+                "x".to_owned(),
+            ])
+        );
+        assert_eq!(split_delimited(&str_glyphs("no delimiter"), ','), None);
+        assert_eq!(split_delimited(&str_glyphs(r#"a,"open"#), ','), None);
+    }
+
+    #[test]
+    fn tab_stop_cells_follow_tab_stops_and_keep_empty_fields() {
+        let cells = split_tab_stops(&str_glyphs("name    New York                x")).unwrap();
+        assert_eq!(
+            cells
+                .iter()
+                .map(|cell| (cell.start, cell.text.as_str()))
+                .collect::<Vec<_>>(),
+            [(0, "name"), (8, "New York"), (24, ""), (32, "x")]
+        );
+        assert_eq!(split_tab_stops(&str_glyphs("  indented  text")), None);
+        assert_eq!(
+            split_tab_stops(&str_glyphs("Running it over README.md")),
+            None
+        );
+        assert_eq!(split_tab_stops(&str_glyphs("single")), None);
+    }
+}
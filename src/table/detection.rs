@@ -1,31 +1,48 @@
-use super::{Column, TableModel, TerminalSurface};
+use super::{
+    CellSpan, Column, ColumnAlignment, TableFormat, TableModel, TerminalSurface,
+    cells::{delimited_cells, pipe_cells, tab_stop_cells},
+};
 
 type View = dyn TerminalSurface;
 
-pub(super) fn pipe_delimited_cell_text(line: &str, col_idx: usize) -> Option<&str> {
-    let trimmed = line.trim();
-    let (leading_delimiter, _) = pipe_cell_layout(trimmed)?;
+/// Delimiters tried for CSV-style data, in order.
+const DATA_DELIMITERS: [char; 2] = [',', ';'];
 
-    let mut cells = trimmed.split('|');
-    if leading_delimiter == 1 {
-        cells.next();
-    }
-    Some(cells.nth(col_idx).unwrap_or_default().trim())
+pub(crate) fn detect(view: &View, row: u16) -> Option<TableModel> {
+    detect_box_table(view, row)
+        .or_else(|| detect_pipe_table(view, row))
+        .or_else(|| match detect_fixed_width_table(view, row) {
+            Some(fixed) => Some(
+                detect_tab_separated_table(view, row)
+                    .filter(|tsv| refines_fixed_width(tsv, &fixed))
+                    .unwrap_or(fixed),
+            ),
+            None => detect_tab_separated_table(view, row),
+        })
+        .or_else(|| {
+            DATA_DELIMITERS.iter().find_map(|&delimiter| {
+                detect_split_table(view, row, TableFormat::Delimited(delimiter), |view, row| {
+                    delimited_cells(view, row, delimiter)
+                })
+            })
+        })
 }
 
-fn pipe_cell_layout(line: &str) -> Option<(usize, usize)> {
-    let delimiters = line.matches('|').count();
-    if delimiters == 0 {
-        return None;
-    }
-    let leading = usize::from(line.starts_with('|'));
-    let trailing = usize::from(line.ends_with('|'));
-    let cells = delimiters + 1 - leading - trailing;
-    (cells > 0).then_some((leading, cells))
+fn detect_tab_separated_table(view: &View, row: u16) -> Option<TableModel> {
+    detect_split_table(view, row, TableFormat::TabSeparated, |view, row| {
+        tab_stop_cells(view, row)
+    })
 }
 
-pub(crate) fn detect(view: &View, row: u16) -> Option<TableModel> {
-    detect_pipe_table(view, row).or_else(|| detect_fixed_width_table(view, row))
+/// Tab-separated fields vary in width from row to row, so fixed-width
+/// detection finds columns wherever gaps happen to line up. When every row
+/// of the same table also splits on tab stops into at least as many fields,
+/// the tab stops are the better reading; a table found on other rows, or
+/// with fewer fields, never replaces the fixed-width one.
+fn refines_fixed_width(tsv: &TableModel, fixed: &TableModel) -> bool {
+    tsv.top() == fixed.top()
+        && tsv.bottom() == fixed.bottom()
+        && tsv.column_count() >= fixed.column_count()
 }
 
 pub(crate) fn detect_manual_from_header(
@@ -68,18 +85,18 @@ pub(crate) fn detect_manual_from_header(
         return None;
     }
 
-    Some(TableModel {
+    Some(TableModel::detected(
         top,
         bottom,
         columns,
-        header_row: Some(header_row),
-        delimiter: None,
-    })
+        Some(header_row),
+        TableFormat::FixedWidth,
+    ))
 }
 
 fn detect_pipe_table(view: &View, row: u16) -> Option<TableModel> {
     let rows = view.size().0;
-    let anchor = nearest_pipe_row(view, row)?;
+    let anchor = nearest_matching_row(view, row, 6, row_is_pipe_table_row)?;
 
     let mut top = anchor;
     while top > 0 && row_is_pipe_table_row(view, top - 1) {
@@ -98,37 +115,75 @@ fn detect_pipe_table(view: &View, row: u16) -> Option<TableModel> {
         return None;
     }
 
-    let header_row = detect_header_row(view, top, bottom, &columns, Some('|')).or(Some(header_row));
+    let alignments = (header_row < bottom)
+        .then(|| markdown_alignments(view, header_row + 1))
+        .flatten();
+    let format = if alignments.is_some() {
+        TableFormat::Markdown
+    } else {
+        TableFormat::Pipe
+    };
+    let header_row = detect_header_row(view, top, bottom, &columns, format).or(Some(header_row));
 
-    Some(TableModel {
-        top,
-        bottom,
-        columns,
-        header_row,
-        delimiter: Some('|'),
-    })
+    let mut model = TableModel::detected(top, bottom, columns, header_row, format);
+    if let Some(mut alignments) = alignments {
+        alignments.resize(model.columns.len(), ColumnAlignment::Default);
+        model.alignments = alignments;
+    }
+    Some(model)
 }
 
-fn nearest_pipe_row(view: &View, row: u16) -> Option<u16> {
+/// Reads a markdown alignment row such as `| :--- | :---: | ---: |`, or
+/// `None` when `row` is not one.
+fn markdown_alignments(view: &View, row: u16) -> Option<Vec<ColumnAlignment>> {
+    let cells = pipe_cells(view, row)?;
+    cells
+        .iter()
+        .map(|cell| {
+            let text = cell.text.as_str();
+            let left = text.starts_with(':');
+            let right = text.ends_with(':');
+            let dashes = text.trim_start_matches(':').trim_end_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|ch| ch == '-') {
+                return None;
+            }
+            Some(match (left, right) {
+                (true, true) => ColumnAlignment::Center,
+                (true, false) => ColumnAlignment::Left,
+                (false, true) => ColumnAlignment::Right,
+                (false, false) => ColumnAlignment::Default,
+            })
+        })
+        .collect()
+}
+
+/// The row nearest to `row`, at most `reach` rows away, that satisfies
+/// `matches`. Rows above win ties.
+fn nearest_matching_row(
+    view: &View,
+    row: u16,
+    reach: u16,
+    matches: impl Fn(&View, u16) -> bool,
+) -> Option<u16> {
     let rows = view.size().0;
     if rows == 0 {
         return None;
     }
 
-    if row_is_pipe_table_row(view, row) {
+    if row < rows && matches(view, row) {
         return Some(row);
     }
 
-    for offset in 1..=6u16 {
+    for offset in 1..=reach {
         if row >= offset {
             let up = row - offset;
-            if row_is_pipe_table_row(view, up) {
+            if up < rows && matches(view, up) {
                 return Some(up);
             }
         }
 
         let down = row + offset;
-        if down < rows && row_is_pipe_table_row(view, down) {
+        if down < rows && matches(view, down) {
             return Some(down);
         }
     }
@@ -141,27 +196,18 @@ fn find_pipe_header_row_in_bounds(view: &View, top: u16, bottom: u16) -> Option<
 }
 
 fn row_looks_like_pipe_header(view: &View, row: u16) -> bool {
-    let line = view.line(row);
-    let trimmed = line.trim();
-    if trimmed.is_empty() || is_separator_row(view, row) {
-        return false;
-    }
-    if trimmed.matches('|').count() < 1 {
+    if row_is_blank(view, row) || is_separator_row(view, row) {
         return false;
     }
 
-    let Some((leading_delimiter, cell_count)) = pipe_cell_layout(trimmed) else {
-        return false;
-    };
-
-    trimmed
-        .split('|')
-        .skip(leading_delimiter)
-        .take(cell_count)
-        .filter(|cell| !cell.trim().is_empty())
-        .take(2)
-        .count()
-        >= 2
+    pipe_cells(view, row).is_some_and(|cells| {
+        cells
+            .iter()
+            .filter(|cell| !cell.text.is_empty())
+            .take(2)
+            .count()
+            >= 2
+    })
 }
 
 fn row_is_pipe_table_row(view: &View, row: u16) -> bool {
@@ -169,7 +215,16 @@ fn row_is_pipe_table_row(view: &View, row: u16) -> bool {
 }
 
 fn detect_pipe_columns(view: &View, top: u16, bottom: u16, header_row: u16) -> Option<Vec<Column>> {
-    let positions = delimiter_positions(view, header_row, '|');
+    let positions: Vec<u16> = delimiter_positions(view, header_row, '|')
+        .into_iter()
+        .filter(|&col| {
+            col == 0
+                || view
+                    .screen()
+                    .cell(header_row, col - 1)
+                    .is_none_or(|cell| cell.contents() != "\\")
+        })
+        .collect();
     if positions.is_empty() {
         return None;
     }
@@ -238,11 +293,7 @@ fn detect_fixed_width_table(view: &View, row: u16) -> Option<TableModel> {
         return None;
     }
 
-    let anchor = if row_is_fixed_width_candidate(view, row) {
-        row
-    } else {
-        nearest_fixed_width_candidate(view, row)?
-    };
+    let anchor = nearest_matching_row(view, row, 2, row_is_fixed_width_candidate)?;
 
     let mut top = anchor;
     while top > 0 && row_is_fixed_width_candidate(view, top - 1) {
@@ -278,15 +329,15 @@ fn detect_fixed_width_table(view: &View, row: u16) -> Option<TableModel> {
         return None;
     }
 
-    let header_row = detect_header_row(view, top, bottom, &columns, None);
+    let header_row = detect_header_row(view, top, bottom, &columns, TableFormat::FixedWidth);
 
-    Some(TableModel {
+    Some(TableModel::detected(
         top,
         bottom,
         columns,
         header_row,
-        delimiter: None,
-    })
+        TableFormat::FixedWidth,
+    ))
 }
 
 fn row_is_fixed_width_candidate(view: &View, row: u16) -> bool {
@@ -309,29 +360,6 @@ fn row_is_manual_table_row(view: &View, row: u16) -> bool {
         || view.line(row).contains('|')
 }
 
-fn nearest_fixed_width_candidate(view: &View, row: u16) -> Option<u16> {
-    let rows = view.size().0;
-    if rows == 0 {
-        return None;
-    }
-
-    for offset in 1..=2u16 {
-        if row >= offset {
-            let up = row - offset;
-            if row_is_fixed_width_candidate(view, up) {
-                return Some(up);
-            }
-        }
-
-        let down = row + offset;
-        if down < rows && row_is_fixed_width_candidate(view, down) {
-            return Some(down);
-        }
-    }
-
-    None
-}
-
 fn detect_fixed_width_columns(
     view: &View,
     top: u16,
@@ -628,22 +656,16 @@ fn detect_header_row(
     top: u16,
     bottom: u16,
     columns: &[Column],
-    delimiter: Option<char>,
+    format: TableFormat,
 ) -> Option<u16> {
     if top >= bottom {
         return None;
     }
 
-    let model = TableModel {
-        top,
-        bottom,
-        columns: columns.to_vec(),
-        header_row: None,
-        delimiter,
-    };
+    let model = TableModel::detected(top, bottom, columns.to_vec(), None, format);
 
     for row in top..=bottom {
-        if is_separator_row(view, row) && row > top {
+        if model.is_rule_row(view, row) && row > top {
             let mut candidate = row - 1;
             loop {
                 if !model.is_skippable_row(view, candidate) {
@@ -705,9 +727,176 @@ pub(super) fn is_separator_row(view: &(dyn TerminalSurface + '_), row: u16) -> b
         return false;
     }
 
+    // A grid row whose cells are all blank is still a row, not a rule.
+    if trimmed
+        .chars()
+        .all(|ch| ch.is_whitespace() || is_vertical_border_char(ch))
+    {
+        return false;
+    }
+
     trimmed.chars().all(|ch| match ch {
         '-' | '=' | '+' | '|' | '_' | ':' => true,
-        _ if ch.is_whitespace() => true,
+        _ if ch.is_whitespace() || is_box_drawing(ch) => true,
         _ => false,
     })
 }
+
+fn is_box_drawing(ch: char) -> bool {
+    ('\u{2500}'..='\u{257f}').contains(&ch)
+}
+
+fn is_vertical_border_char(ch: char) -> bool {
+    matches!(ch, '│' | '┃' | '║' | '┆' | '┇' | '┊' | '┋' | '╎' | '╏')
+}
+
+/// Whether a screen cell holds a vertical box-drawing border.
+pub(super) fn is_vertical_border(contents: &str) -> bool {
+    let mut chars = contents.chars();
+    chars.next().is_some_and(is_vertical_border_char) && chars.next().is_none()
+}
+
+fn row_is_box_row(view: &View, row: u16) -> bool {
+    !row_is_blank(view, row) && view.line(row).chars().any(is_box_drawing)
+}
+
+/// Detects a grid drawn with box-drawing characters. Column borders are the
+/// vertical borders shared by most rows, so banner rows spanning the grid
+/// and boxes nested inside a cell do not add or remove columns.
+fn detect_box_table(view: &View, row: u16) -> Option<TableModel> {
+    let (rows, cols) = view.size();
+    let anchor = nearest_matching_row(view, row, 6, row_is_box_row)?;
+
+    let mut top = anchor;
+    while top > 0 && row_is_box_row(view, top - 1) {
+        top -= 1;
+    }
+
+    let mut bottom = anchor;
+    while bottom + 1 < rows && row_is_box_row(view, bottom + 1) {
+        bottom += 1;
+    }
+
+    let content_rows: Vec<u16> = (top..=bottom)
+        .filter(|&candidate| !is_separator_row(view, candidate))
+        .collect();
+    if content_rows.is_empty() {
+        return None;
+    }
+
+    let positions: Vec<u16> = (0..cols)
+        .filter(|&col| {
+            let borders = content_rows
+                .iter()
+                .filter(|&&candidate| {
+                    view.screen()
+                        .cell(candidate, col)
+                        .is_some_and(|cell| is_vertical_border(cell.contents()))
+                })
+                .count();
+            borders * 2 > content_rows.len()
+        })
+        .collect();
+    if positions.is_empty() {
+        return None;
+    }
+
+    let mut columns = columns_from_delimiter_positions(view, &positions);
+    columns.retain(|col| column_has_content(view, top, bottom, col.start, col.end));
+    if columns.len() < 2 {
+        return None;
+    }
+
+    let single_line = TableFormat::Box { multiline: false };
+    let header_row = detect_header_row(view, top, bottom, &columns, single_line);
+    let model = TableModel::detected(top, bottom, columns.clone(), header_row, single_line);
+    let format = TableFormat::Box {
+        multiline: rules_separate_records(view, &model),
+    };
+    let header_row = detect_header_row(view, top, bottom, &columns, format);
+    Some(TableModel::detected(
+        top, bottom, columns, header_row, format,
+    ))
+}
+
+/// Whether the rules below a grid's header separate records rather than
+/// sections, which makes the lines between two rules one row. A lone rule
+/// above a totals row does not count, so a grid needs three ruled records, or
+/// two of the same height.
+fn rules_separate_records(view: &View, model: &TableModel) -> bool {
+    let after = model.header_row.unwrap_or(model.top);
+    let Some(first) = (after + 1..model.bottom).find(|&row| model.is_rule_row(view, row)) else {
+        return false;
+    };
+
+    let mut heights = Vec::new();
+    let mut height = 0u16;
+    for row in first + 1..=model.bottom {
+        if model.is_rule_row(view, row) {
+            if height > 0 {
+                heights.push(height);
+            }
+            height = 0;
+        } else {
+            height += 1;
+        }
+    }
+    if height > 0 {
+        heights.push(height);
+    }
+
+    match heights.as_slice() {
+        [] | [_] => false,
+        [first, second] => first == second,
+        _ => true,
+    }
+}
+
+/// Detects data whose rows split on their own, such as CSV or tab-separated
+/// output. Every row of the table must split into the same number of cells.
+fn detect_split_table(
+    view: &View,
+    row: u16,
+    format: TableFormat,
+    split: impl Fn(&View, u16) -> Option<Vec<CellSpan>>,
+) -> Option<TableModel> {
+    let (rows, cols) = view.size();
+    let cell_count = |candidate: u16| {
+        split(view, candidate)
+            .map(|cells| cells.len())
+            .filter(|&count| count >= 2)
+    };
+    let anchor =
+        nearest_matching_row(view, row, 2, |_, candidate| cell_count(candidate).is_some())?;
+    let count = cell_count(anchor)?;
+
+    let mut top = anchor;
+    while top > 0 && cell_count(top - 1) == Some(count) {
+        top -= 1;
+    }
+
+    let mut bottom = anchor;
+    while bottom + 1 < rows && cell_count(bottom + 1) == Some(count) {
+        bottom += 1;
+    }
+    if top == bottom {
+        return None;
+    }
+
+    let cells = split(view, top)?;
+    let columns: Vec<Column> = cells
+        .iter()
+        .enumerate()
+        .map(|(idx, cell)| Column {
+            start: cell.start,
+            end: cells
+                .get(idx + 1)
+                .map_or(cols.saturating_sub(1), |next| next.start.saturating_sub(1)),
+        })
+        .collect();
+
+    let header_row = detect_header_row(view, top, bottom, &columns, format);
+    Some(TableModel::detected(
+        top, bottom, columns, header_row, format,
+    ))
+}
@@ -0,0 +1,261 @@
+//! Writes table cells out as text: the formats for copying rows, columns or
+//! whole tables, and the aligned text of a sorted copy.
+
+use super::ColumnAlignment;
+use std::{cmp::Ordering, fmt, str::FromStr};
+use unicode_width::UnicodeWidthStr;
+
+/// How copied table data is written.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum ExportFormat {
+    #[default]
+    Tsv,
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    pub(crate) fn next(self) -> Self {
+        match self {
+            Self::Tsv => Self::Csv,
+            Self::Csv => Self::Markdown,
+            Self::Markdown => Self::Tsv,
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Tsv => "tsv",
+            Self::Csv => "csv",
+            Self::Markdown => "markdown",
+        })
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+        match value {
+            "tsv" => Ok(Self::Tsv),
+            "csv" => Ok(Self::Csv),
+            "markdown" => Ok(Self::Markdown),
+            _ => anyhow::bail!("table export format must be tsv, csv, or markdown"),
+        }
+    }
+}
+
+/// Writes `rows` in `format`, after `header` when there is one. Markdown
+/// tables get an alignment row below the header, from `alignments` where the
+/// source table had one.
+pub(crate) fn render(
+    format: ExportFormat,
+    header: Option<&[String]>,
+    rows: &[Vec<String>],
+    alignments: &[ColumnAlignment],
+) -> String {
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    if let Some(header) = header {
+        lines.push(render_row(format, header));
+        if format == ExportFormat::Markdown {
+            let rule: Vec<String> = (0..header.len())
+                .map(|col| {
+                    match alignments
+                        .get(col)
+                        .copied()
+                        .unwrap_or(ColumnAlignment::Default)
+                    {
+                        ColumnAlignment::Default => "---",
+                        ColumnAlignment::Left => ":---",
+                        ColumnAlignment::Center => ":---:",
+                        ColumnAlignment::Right => "---:",
+                    }
+                    .to_owned()
+                })
+                .collect();
+            lines.push(format!("| {} |", rule.join(" | ")));
+        }
+    }
+    lines.extend(rows.iter().map(|row| render_row(format, row)));
+    lines.join("\n")
+}
+
+fn render_row(format: ExportFormat, cells: &[String]) -> String {
+    match format {
+        ExportFormat::Tsv => cells
+            .iter()
+            .map(|cell| cell.replace(['\t', '\n'], " "))
+            .collect::<Vec<_>>()
+            .join("\t"),
+        ExportFormat::Csv => cells
+            .iter()
+            .map(|cell| csv_field(cell))
+            .collect::<Vec<_>>()
+            .join(","),
+        ExportFormat::Markdown => {
+            let cells: Vec<String> = cells
+                .iter()
+                .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+                .collect();
+            format!("| {} |", cells.join(" | "))
+        }
+    }
+}
+
+fn csv_field(cell: &str) -> String {
+    if cell.contains([',', '"', '\n']) || cell.trim() != cell {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_owned()
+    }
+}
+
+/// Sorts rows by the cell in `col`. Numbers, including percentages and
+/// numbers with thousands separators, sort by value; other text sorts
+/// without regard to case, and empty cells sort last.
+pub(crate) fn sort_rows(rows: &mut [Vec<String>], col: usize) {
+    rows.sort_by(|a, b| {
+        let a = a.get(col).map_or("", String::as_str);
+        let b = b.get(col).map_or("", String::as_str);
+        compare_cells(a, b)
+    });
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+    match (cell_number(a), cell_number(b)) {
+        (Some(a), Some(b)) => a.total_cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+fn cell_number(text: &str) -> Option<f64> {
+    let text = text.strip_suffix('%').unwrap_or(text);
+    text.replace(',', "").parse().ok()
+}
+
+/// Lays rows out as plain text with columns padded to a common width, so a
+/// frozen copy can be read line by line or navigated again in table mode.
+pub(crate) fn aligned_text(header: Option<&[String]>, rows: &[Vec<String>]) -> String {
+    let column_count = header
+        .map(<[String]>::len)
+        .into_iter()
+        .chain(rows.iter().map(Vec::len))
+        .max()
+        .unwrap_or(0);
+    let mut widths = vec![0; column_count];
+    for row in header.into_iter().chain(rows.iter().map(Vec::as_slice)) {
+        for (col, cell) in row.iter().enumerate() {
+            widths[col] = widths[col].max(UnicodeWidthStr::width(cell.as_str()));
+        }
+    }
+    // A blank cell would leave a wider gutter that splits nothing, so it
+    // reads as a dash.
+    let widths: Vec<usize> = widths.into_iter().map(|width| width.max(1)).collect();
+
+    let line = |cells: &[String]| {
+        let mut line = String::new();
+        for (col, width) in widths.iter().enumerate() {
+            let cell = cells.get(col).map_or("", String::as_str);
+            let cell = if cell.is_empty() { "-" } else { cell };
+            if col > 0 {
+                line.push_str("  ");
+            }
+            line.push_str(cell);
+            let padding = width.saturating_sub(UnicodeWidthStr::width(cell));
+            line.extend(std::iter::repeat_n(' ', padding));
+        }
+        line.trim_end().to_owned()
+    };
+
+    let mut lines = Vec::with_capacity(rows.len() + 2);
+    if let Some(header) = header {
+        lines.push(line(header));
+        lines.push(
+            widths
+                .iter()
+                .map(|&width| "-".repeat(width))
+                .collect::<Vec<_>>()
+                .join("  "),
+        );
+    }
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExportFormat, aligned_text, render, sort_rows};
+    use crate::table::ColumnAlignment;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| (*cell).to_owned()).collect()
+    }
+
+    #[test]
+    fn export_formats_quote_and_escape_cells() {
+        let header = row(&["name", "note"]);
+        let rows = vec![row(&["Ada", "says \"hi\", twice"]), row(&["Bob", "a|b"])];
+
+        assert_eq!(
+            render(ExportFormat::Tsv, Some(&header), &rows, &[]),
+            "name\tnote\nAda\tsays \"hi\", twice\nBob\ta|b"
+        );
+        assert_eq!(
+            render(ExportFormat::Csv, None, &rows, &[]),
+            "Ada,\"says \"\"hi\"\", twice\"\nBob,a|b"
+        );
+        assert_eq!(
+            render(
+                ExportFormat::Markdown,
+                Some(&header),
+                &rows,
+                &[ColumnAlignment::Left, ColumnAlignment::Right],
+            ),
+            "| name | note |\n| :--- | ---: |\n| Ada | says \"hi\", twice |\n| Bob | a\\|b |"
+        );
+        assert_eq!(
+            "markdown".parse::<ExportFormat>().unwrap(),
+            ExportFormat::Markdown
+        );
+        assert!("xml".parse::<ExportFormat>().is_err());
+        assert_eq!(ExportFormat::Markdown.next(), ExportFormat::Tsv);
+    }
+
+    #[test]
+    fn sorting_compares_numbers_by_value_and_puts_empty_cells_last() {
+        let mut rows = vec![
+            row(&["b", "10%"]),
+            row(&["c", ""]),
+            row(&["a", "9%"]),
+            row(&["d", "1,200"]),
+        ];
+        sort_rows(&mut rows, 1);
+        assert_eq!(
+            rows.iter().map(|row| row[0].as_str()).collect::<Vec<_>>(),
+            ["a", "b", "d", "c"]
+        );
+
+        sort_rows(&mut rows, 0);
+        assert_eq!(rows[0][0], "a");
+    }
+
+    #[test]
+    fn aligned_text_pads_columns_and_marks_blank_cells() {
+        let header = row(&["NAME", "AGE"]);
+        let rows = vec![row(&["Ada", "37"]), row(&["Bartholomew", ""])];
+        assert_eq!(
+            aligned_text(Some(&header), &rows),
+            "NAME         AGE\n-----------  ---\nAda          37\nBartholomew  -"
+        );
+    }
+}
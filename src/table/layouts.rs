@@ -0,0 +1,384 @@
+//! Named tabstop layouts for tables that automatic detection splits wrongly.
+//!
+//! Layouts saved from tabstop setup persist in a JSON file shared by every
+//! Lector instance; layouts defined from Lua last for the session. Either
+//! kind is matched by a regex on the table's header line, on the window
+//! title, or both. Patterns are compiled once, and the saved file is read
+//! again only after it changes.
+
+use super::{TableModel, detect_manual_from_header};
+use crate::view::View;
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Version written to new layout files.
+const LAYOUTS_VERSION: u32 = 1;
+const MAX_LAYOUTS_BYTES: u64 = 1024 * 1024;
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub(crate) struct TableLayout {
+    /// Regex matched against a whole header line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) header: Option<String>,
+    /// Regex matched against the window title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) title: Option<String>,
+    /// Screen columns where the second and later columns start.
+    pub(crate) tabstops: Vec<u16>,
+}
+
+impl TableLayout {
+    /// The layout saved from tabstop setup: it matches headers with the same
+    /// words, however far apart they are.
+    pub(crate) fn for_header(header_line: &str, tabstops: &[u16]) -> Self {
+        let words: Vec<String> = header_line.split_whitespace().map(regex::escape).collect();
+        Self {
+            header: Some(format!("^ *{} *$", words.join(" +"))),
+            title: None,
+            tabstops: tabstops.to_vec(),
+        }
+    }
+
+    /// Checks and compiles the patterns, checks the tabstops, and sorts the
+    /// tabstops.
+    fn validated(mut self) -> Result<DefinedLayout> {
+        if self.header.is_none() && self.title.is_none() {
+            bail!("table layout needs a header or title pattern");
+        }
+        let compile = |pattern: &Option<String>| {
+            pattern
+                .as_deref()
+                .map(|pattern| {
+                    Regex::new(pattern)
+                        .with_context(|| format!("invalid table layout pattern {pattern}"))
+                })
+                .transpose()
+        };
+        let (header, title) = (compile(&self.header)?, compile(&self.title)?);
+        if self.tabstops.contains(&0) {
+            bail!("table layout tabstops must be after the first column");
+        }
+        self.tabstops.sort_unstable();
+        self.tabstops.dedup();
+        if self.tabstops.is_empty() {
+            bail!("table layout needs at least one tabstop");
+        }
+        Ok(DefinedLayout {
+            layout: self,
+            header,
+            title,
+        })
+    }
+}
+
+/// A layout with its patterns compiled.
+#[derive(Debug)]
+struct DefinedLayout {
+    layout: TableLayout,
+    header: Option<Regex>,
+    title: Option<Regex>,
+}
+
+impl DefinedLayout {
+    /// Builds the table this layout describes around `row`, if the layout
+    /// applies there.
+    fn model_at(&self, view: &View, row: u16, title: Option<&str>) -> Option<TableModel> {
+        if let Some(pattern) = &self.title
+            && !pattern.is_match(title?)
+        {
+            return None;
+        }
+        let header_row = match &self.header {
+            Some(header) => (0..=row)
+                .rev()
+                .find(|&candidate| header.is_match(&view.line(candidate)))?,
+            // Without a header pattern the table starts at the top of the
+            // block of text the cursor is in.
+            None => {
+                if view.line(row).trim().is_empty() {
+                    return None;
+                }
+                let mut top = row;
+                while top > 0 && !view.line(top - 1).trim().is_empty() {
+                    top -= 1;
+                }
+                top
+            }
+        };
+        let model = detect_manual_from_header(view, header_row, &self.layout.tabstops)?;
+        (model.top() <= row && row <= model.bottom()).then_some(model)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct LayoutsFile {
+    version: u32,
+    layouts: BTreeMap<String, TableLayout>,
+}
+
+/// The saved layouts as last read, with the file's modification time and
+/// size at that point, or `None` when there was no file.
+#[derive(Debug)]
+struct SavedLayouts {
+    stamp: Option<(SystemTime, u64)>,
+    layouts: BTreeMap<String, DefinedLayout>,
+}
+
+/// Layouts defined from Lua, plus where saved layouts are kept.
+#[derive(Debug, Default)]
+pub(crate) struct TableLayouts {
+    path: Option<PathBuf>,
+    defined: BTreeMap<String, DefinedLayout>,
+    saved: Option<SavedLayouts>,
+}
+
+impl TableLayouts {
+    pub(crate) fn set_path(&mut self, path: PathBuf) {
+        self.path = Some(path);
+        self.saved = None;
+    }
+
+    /// Saved layouts, read again only when the file has changed since the
+    /// last read, so layouts saved by another instance are still seen.
+    fn saved(&mut self) -> Result<Option<&BTreeMap<String, DefinedLayout>>> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+        let stamp = file_stamp(path)?;
+        if self.saved.as_ref().is_none_or(|saved| saved.stamp != stamp) {
+            // A hand-edited entry that no longer validates is skipped
+            // rather than hiding every other saved layout.
+            let layouts = load(path)?
+                .into_iter()
+                .filter_map(|(name, layout)| Some((name, layout.validated().ok()?)))
+                .collect();
+            self.saved = Some(SavedLayouts { stamp, layouts });
+        }
+        Ok(self.saved.as_ref().map(|saved| &saved.layouts))
+    }
+
+    pub(crate) fn get(&mut self, name: &str) -> Result<Option<TableLayout>> {
+        if let Some(defined) = self.defined.get(name) {
+            return Ok(Some(defined.layout.clone()));
+        }
+        Ok(self
+            .saved()?
+            .and_then(|saved| saved.get(name))
+            .map(|defined| defined.layout.clone()))
+    }
+
+    pub(crate) fn define(&mut self, name: &str, layout: TableLayout) -> Result<()> {
+        validate_name(name)?;
+        self.defined.insert(name.to_owned(), layout.validated()?);
+        Ok(())
+    }
+
+    pub(crate) fn save(&mut self, name: &str, layout: TableLayout) -> Result<()> {
+        validate_name(name)?;
+        let layout = layout.validated()?.layout;
+        let Some(path) = &self.path else {
+            bail!("no table layout file is available");
+        };
+        let mut layouts = load(path)?;
+        layouts.insert(name.to_owned(), layout);
+        store(path, layouts)?;
+        self.saved = None;
+        // A saved layout replaces a session one of the same name, so the
+        // tabstops just set up are the ones used.
+        self.defined.remove(name);
+        Ok(())
+    }
+
+    /// Forgets `name`, both for this session and in the saved file.
+    pub(crate) fn remove(&mut self, name: &str) -> Result<()> {
+        self.defined.remove(name);
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let mut layouts = load(path)?;
+        if layouts.remove(name).is_some() {
+            store(path, layouts)?;
+            self.saved = None;
+        }
+        Ok(())
+    }
+
+    /// The first layout, by name, that describes a table around `row`. Lua
+    /// layouts are tried before saved ones.
+    pub(crate) fn find(
+        &mut self,
+        view: &View,
+        row: u16,
+        title: Option<&str>,
+    ) -> Result<Option<(String, TableModel)>> {
+        self.saved()?;
+        let saved = self.saved.as_ref().map(|saved| &saved.layouts);
+        let found = self
+            .defined
+            .iter()
+            .chain(
+                saved
+                    .into_iter()
+                    .flatten()
+                    .filter(|(name, _)| !self.defined.contains_key(*name)),
+            )
+            .find_map(|(name, layout)| {
+                layout
+                    .model_at(view, row, title)
+                    .map(|model| (name.clone(), model))
+            });
+        Ok(found)
+    }
+}
+
+/// `$XDG_DATA_HOME/lector/table-layouts.json`, or the platform data
+/// directory.
+#[must_use]
+pub(crate) fn default_layouts_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|path| path.join("lector").join("table-layouts.json"))
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if name.trim().is_empty() || name.chars().any(char::is_control) {
+        bail!("table layout names must be non-empty text");
+    }
+    Ok(())
+}
+
+/// The modification time and size of the layout file, or `None` when there
+/// is none.
+fn file_stamp(path: &Path) -> Result<Option<(SystemTime, u64)>> {
+    match fs::metadata(path) {
+        Ok(metadata) => Ok(Some((metadata.modified()?, metadata.len()))),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error).context("read table layouts"),
+    }
+}
+
+fn load(path: &Path) -> Result<BTreeMap<String, TableLayout>> {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(error) => return Err(error).context("read table layouts"),
+    };
+    if metadata.len() > MAX_LAYOUTS_BYTES {
+        bail!("table layout file is larger than 1 MiB");
+    }
+    let file: LayoutsFile =
+        serde_json::from_slice(&fs::read(path)?).context("parse table layouts")?;
+    if file.version != LAYOUTS_VERSION {
+        bail!("unsupported table layout version {}", file.version);
+    }
+    Ok(file.layouts)
+}
+
+fn store(path: &Path, layouts: BTreeMap<String, TableLayout>) -> Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let file = LayoutsFile {
+        version: LAYOUTS_VERSION,
+        layouts,
+    };
+    // Write beside the destination first so an interrupted save never
+    // leaves a truncated layout file.
+    let partial = path.with_extension("json.partial");
+    fs::write(&partial, serde_json::to_vec_pretty(&file)?)?;
+    fs::rename(&partial, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TableLayout, TableLayouts};
+    use crate::view::View;
+
+    fn view_from_lines(lines: &[&str]) -> View {
+        let cols = lines.iter().map(|line| line.len()).max().unwrap_or(0) as u16 + 1;
+        let mut view = View::new(lines.len() as u16, cols);
+        view.process_changes(lines.join("\r\n").as_bytes());
+        view
+    }
+
+    #[test]
+    fn saved_layouts_persist_and_match_headers_with_different_spacing() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("table-layouts.json");
+        let mut layouts = TableLayouts::default();
+        layouts.set_path(path.clone());
+        layouts
+            .save(
+                "ps",
+                TableLayout::for_header("PID  USER   COMMAND", &[5, 12]),
+            )
+            .unwrap();
+
+        let mut reopened = TableLayouts::default();
+        reopened.set_path(path);
+        let view = view_from_lines(&[
+            "$ ps",
+            "  PID  USER   COMMAND",
+            "    1  root   init",
+            "  812  ada    vim notes",
+        ]);
+        let (name, model) = reopened.find(&view, 3, None).unwrap().unwrap();
+        assert_eq!(name, "ps");
+        assert_eq!(model.header_row(), Some(1));
+        assert!(reopened.find(&view, 0, None).unwrap().is_none());
+
+        reopened.remove("ps").unwrap();
+        assert!(reopened.get("ps").unwrap().is_none());
+    }
+
+    #[test]
+    fn saved_layouts_are_read_again_after_another_instance_saves() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("table-layouts.json");
+        let mut first = TableLayouts::default();
+        first.set_path(path.clone());
+        let view = view_from_lines(&["NAME  SIZE", "a     1"]);
+        assert!(first.find(&view, 1, None).unwrap().is_none());
+
+        let mut second = TableLayouts::default();
+        second.set_path(path);
+        second
+            .save("sizes", TableLayout::for_header("NAME  SIZE", &[6]))
+            .unwrap();
+        let (name, _) = first.find(&view, 1, None).unwrap().unwrap();
+        assert_eq!(name, "sizes");
+    }
+
+    #[test]
+    fn title_layouts_apply_only_to_matching_windows() {
+        let mut layouts = TableLayouts::default();
+        let layout = TableLayout {
+            header: None,
+            title: Some("^htop".to_owned()),
+            tabstops: vec![4, 9],
+        };
+        layouts.define("htop", layout).unwrap();
+        let view = view_from_lines(&["", "ab  cde  fg", "hi  jkl  mn"]);
+
+        assert!(layouts.find(&view, 2, Some("vim")).unwrap().is_none());
+        let (_, model) = layouts
+            .find(&view, 2, Some("htop - host"))
+            .unwrap()
+            .unwrap();
+        assert_eq!((model.top(), model.bottom()), (1, 2));
+
+        let invalid = TableLayout {
+            header: None,
+            title: None,
+            tabstops: vec![4],
+        };
+        assert!(layouts.define("bad", invalid).is_err());
+    }
+}
@@ -128,6 +128,15 @@ pub struct HistoryPosition {
     pub col: u16,
 }
 
+/// An inclusive rectangle of screen cells.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ScreenRect {
+    pub top: u16,
+    pub left: u16,
+    pub bottom: u16,
+    pub right: u16,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum SemanticKind {
     PromptStart,
@@ -291,6 +300,8 @@ pub struct UpdateSummary {
     /// This batch ended at an OSC 133 input-start boundary with no subsequent
     /// visible, structural, semantic, or incomplete parser output.
     pub semantic_input_boundary: bool,
+    /// This batch added an OSC 133 prompt-start mark.
+    pub prompt_started: bool,
     pub cursor_visibility_restored: bool,
     pub batch_count: usize,
 }
@@ -319,6 +330,7 @@ impl Default for UpdateSummary {
             synchronized_output_opened: false,
             synchronized_output_closed: false,
             semantic_input_boundary: false,
+            prompt_started: false,
             cursor_visibility_restored: false,
             batch_count: 0,
         }
@@ -340,6 +352,7 @@ impl UpdateSummary {
         self.synchronized_output_opened |= next.synchronized_output_opened;
         self.synchronized_output_closed = next.synchronized_output_closed;
         self.semantic_input_boundary = next.semantic_input_boundary;
+        self.prompt_started |= next.prompt_started;
         self.cursor_visibility_restored = next.cursor_visibility_restored;
         self.batch_count = self.batch_count.saturating_add(next.batch_count);
         self.effects.bells = self.effects.bells.saturating_add(next.effects.bells);
@@ -834,11 +847,40 @@ impl TerminalSnapshot {
     }
 
     pub fn contents_full_into(&self, out: &mut String) {
+        self.contents_full_excluding_into(out, &[]);
+    }
+
+    /// Like [`Self::contents_full_into`], with the cells of `excluded` read
+    /// as blanks.
+    pub fn contents_full_excluding_into(&self, out: &mut String, excluded: &[ScreenRect]) {
         out.clear();
         let (_, cols) = self.size();
-        for row in self.rows.iter() {
+        let mut blanked = Vec::new();
+        for (index, row) in self.rows.iter().enumerate() {
             let row_start = out.len();
-            append_row_contents(row, 0, cols, out);
+            blanked.clear();
+            blanked.extend(
+                excluded
+                    .iter()
+                    .filter(|rect| {
+                        (usize::from(rect.top)..=usize::from(rect.bottom)).contains(&index)
+                    })
+                    .map(|rect| (rect.left, rect.right.min(cols.saturating_sub(1)))),
+            );
+            blanked.sort_unstable();
+            let mut col = 0;
+            for &(left, right) in &blanked {
+                if right < col || left >= cols {
+                    continue;
+                }
+                let left = left.max(col);
+                append_row_contents(row, col, left - col, out);
+                out.extend(std::iter::repeat_n(' ', usize::from(right - left) + 1));
+                col = right + 1;
+            }
+            if col < cols {
+                append_row_contents(row, col, cols - col, out);
+            }
             let trimmed_row_len = out[row_start..].trim_end().len();
             out.truncate(row_start + trimmed_row_len);
             out.push('\n');
@@ -1013,6 +1055,7 @@ impl GhosttyEngine {
     }
 
     pub fn try_advance(&mut self, bytes: &[u8]) -> Result<UpdateSummary, lector_ghostty::Error> {
+        let prompt_before = latest_prompt_start(&self.snapshot);
         let mut update = self.terminal.advance(bytes)?;
         let synchronized_output_opened = update.synchronized_output_open_snapshot.is_some();
         self.synchronized_output_open_snapshot = update
@@ -1022,6 +1065,8 @@ impl GhosttyEngine {
         self.refresh_snapshot_after_update(&update.damage)?;
         let mut update = normalize_ghostty_update(update);
         update.synchronized_output_opened = synchronized_output_opened;
+        update.prompt_started =
+            latest_prompt_start(&self.snapshot).is_some_and(|prompt| Some(prompt) != prompt_before);
         Ok(update)
     }
 
@@ -1462,11 +1507,21 @@ fn normalize_ghostty_update(update: GhosttyUpdate) -> UpdateSummary {
         synchronized_output_opened: false,
         synchronized_output_closed: update.synchronized_output_closed,
         semantic_input_boundary: update.semantic_input_boundary,
+        prompt_started: false,
         cursor_visibility_restored: update.cursor_visibility_restored,
         batch_count: 1,
     }
 }
 
+/// The newest OSC 133 prompt-start mark on the primary screen.
+fn latest_prompt_start(snapshot: &TerminalSnapshot) -> Option<HistoryPosition> {
+    snapshot
+        .semantic_marks
+        .iter()
+        .rfind(|mark| !mark.alternate_screen && mark.kind == SemanticKind::PromptStart)
+        .map(|mark| mark.position)
+}
+
 fn normalize_ghostty_operation(operation: GhosttyOperation) -> TerminalOperation {
     match operation {
         GhosttyOperation::ScrollUp { top, bottom, count } => {
@@ -0,0 +1,267 @@
+//! Summary of attention-worthy state in one connection's attached session.
+//!
+//! Nothing here queries tmux. The summary is assembled from the topology and
+//! from latches Lector already maintains while processing pane streams:
+//! unseen background output, pending background bells, and panes left stale
+//! by `%pause`.
+
+use crate::tmux_model::{PaneId, TmuxTopology, WindowId};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TmuxActivityKind {
+    /// A background window produced output since it was last visited.
+    UnseenOutput,
+    /// A background window rang a bell that has not been acknowledged.
+    Bell,
+    /// tmux paused the pane stream and it has not been recaptured yet.
+    Stale,
+    /// The pane's process exited (`pane_dead`).
+    Exited,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TmuxActivityItem {
+    pub kind: TmuxActivityKind,
+    pub window_id: WindowId,
+    pub window_index: u32,
+    pub window_name: String,
+    /// Pane ID, index, and title for pane-scoped activity.
+    pub pane: Option<(PaneId, u32, String)>,
+}
+
+impl TmuxActivityItem {
+    fn target(&self) -> String {
+        match &self.pane {
+            Some((_, index, _)) => format!("{}.{index}", self.window_index),
+            None => self.window_index.to_string(),
+        }
+    }
+
+    fn detail(&self) -> String {
+        let location = match &self.pane {
+            Some((pane_id, index, title)) => {
+                format!("pane {}.{index} %{} {title}", self.window_index, pane_id.0)
+            }
+            None => format!(
+                "window {} @{} {}",
+                self.window_index, self.window_id.0, self.window_name
+            ),
+        };
+        let state = match self.kind {
+            TmuxActivityKind::UnseenOutput => "unseen output",
+            TmuxActivityKind::Bell => "bell",
+            TmuxActivityKind::Stale => "stale after pause",
+            TmuxActivityKind::Exited => "exited",
+        };
+        format!("{}: {state}", location.trim_end())
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TmuxActivitySummary {
+    items: Vec<TmuxActivityItem>,
+}
+
+impl TmuxActivitySummary {
+    /// Collects activity for the attached session, ordered by window index
+    /// and then pane index. Windows outside that session are ignored because
+    /// a control client does not receive their pane streams.
+    #[must_use]
+    pub fn collect(
+        topology: &TmuxTopology,
+        unseen_windows: &BTreeSet<WindowId>,
+        bell_windows: &BTreeSet<WindowId>,
+        stale_panes: &BTreeSet<PaneId>,
+    ) -> Self {
+        let mut items = Vec::new();
+        let Some(session) = topology
+            .attached_session()
+            .and_then(|session_id| topology.session(session_id))
+        else {
+            return Self { items };
+        };
+        for (&window_index, &window_id) in &session.windows {
+            let Some(window) = topology.window(window_id) else {
+                continue;
+            };
+            let window_item = |kind| TmuxActivityItem {
+                kind,
+                window_id,
+                window_index,
+                window_name: window.name.clone(),
+                pane: None,
+            };
+            if unseen_windows.contains(&window_id) {
+                items.push(window_item(TmuxActivityKind::UnseenOutput));
+            }
+            if bell_windows.contains(&window_id) {
+                items.push(window_item(TmuxActivityKind::Bell));
+            }
+            let mut panes = topology
+                .panes()
+                .values()
+                .filter(|pane| pane.window_id == window_id)
+                .collect::<Vec<_>>();
+            panes.sort_by_key(|pane| (pane.index, pane.id));
+            for pane in panes {
+                let pane_item = |kind| TmuxActivityItem {
+                    pane: Some((pane.id, pane.index, pane.title.clone())),
+                    ..window_item(kind)
+                };
+                if stale_panes.contains(&pane.id) {
+                    items.push(pane_item(TmuxActivityKind::Stale));
+                }
+                if pane.dead {
+                    items.push(pane_item(TmuxActivityKind::Exited));
+                }
+            }
+        }
+        Self { items }
+    }
+
+    #[must_use]
+    pub fn items(&self) -> &[TmuxActivityItem] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// One compact announcement, for example
+    /// `output in windows 2, 3; bell in window 2; exited pane 1.2`.
+    #[must_use]
+    pub fn spoken(&self) -> String {
+        if self.items.is_empty() {
+            return "no tmux activity".to_owned();
+        }
+        let groups = [
+            (
+                TmuxActivityKind::UnseenOutput,
+                "output in window",
+                "output in windows",
+            ),
+            (TmuxActivityKind::Bell, "bell in window", "bells in windows"),
+            (TmuxActivityKind::Stale, "stale pane", "stale panes"),
+            (TmuxActivityKind::Exited, "exited pane", "exited panes"),
+        ];
+        groups
+            .into_iter()
+            .filter_map(|(kind, singular, plural)| {
+                let targets = self
+                    .items
+                    .iter()
+                    .filter(|item| item.kind == kind)
+                    .map(TmuxActivityItem::target)
+                    .collect::<Vec<_>>();
+                match targets.len() {
+                    0 => None,
+                    1 => Some(format!("{singular} {}", targets[0])),
+                    _ => Some(format!("{plural} {}", targets.join(", "))),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    /// One line per item for the reviewable popup.
+    #[must_use]
+    pub fn detail(&self) -> String {
+        if self.items.is_empty() {
+            return "No unseen output, bells, stale panes, or exited panes.".to_owned();
+        }
+        self.items
+            .iter()
+            .map(TmuxActivityItem::detail)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TmuxActivityKind, TmuxActivitySummary};
+    use crate::tmux_model::{PaneId, TmuxTopology, WindowId};
+    use std::collections::BTreeSet;
+
+    const SPLIT: &str = "abcd,20x4,0,0{10x4,0,0,20,9x4,11,0,21}";
+    const SINGLE: &str = "dcba,20x4,0,0,22";
+    const OTHER: &str = "dcbb,20x4,0,0,23";
+
+    fn topology() -> TmuxTopology {
+        let lines = [
+            b"S\t$1\twork".to_vec(),
+            b"S\t$2\tother".to_vec(),
+            format!("W\t$1\t@10\t1\t1\t{SPLIT}\t{SPLIT}\t*\teditor").into_bytes(),
+            format!("W\t$1\t@11\t2\t0\t{SINGLE}\t{SINGLE}\t-\tlogs").into_bytes(),
+            format!("W\t$2\t@12\t1\t1\t{OTHER}\t{OTHER}\t*\telsewhere").into_bytes(),
+            b"P\t@10\t%20\t1\t1\t0\t0\t10\t4\t0\t0\t0\t1\t0\t0\t0\t0\tleft".to_vec(),
+            b"P\t@10\t%21\t2\t0\t11\t0\t9\t4\t1\t0\t0\t1\t0\t0\t0\t0\tright".to_vec(),
+            b"P\t@11\t%22\t1\t1\t0\t0\t20\t4\t0\t0\t0\t1\t0\t0\t0\t0\ttail".to_vec(),
+            b"P\t@12\t%23\t1\t1\t0\t0\t20\t4\t1\t0\t0\t1\t0\t0\t0\t0\tremote".to_vec(),
+            b"A\t$1".to_vec(),
+        ];
+        let mut topology = TmuxTopology::new(1);
+        topology.replace_inventory(&lines).expect("topology");
+        topology
+    }
+
+    #[test]
+    fn summary_orders_windows_then_panes_and_speaks_compactly() {
+        let summary = TmuxActivitySummary::collect(
+            &topology(),
+            &BTreeSet::from([WindowId(11), WindowId(12)]),
+            &BTreeSet::from([WindowId(11)]),
+            &BTreeSet::from([PaneId(20)]),
+        );
+        let kinds = summary
+            .items()
+            .iter()
+            .map(|item| item.kind)
+            .collect::<Vec<_>>();
+        // Window @12 and its dead pane belong to an unattached session.
+        assert_eq!(
+            kinds,
+            [
+                TmuxActivityKind::Stale,
+                TmuxActivityKind::Exited,
+                TmuxActivityKind::UnseenOutput,
+                TmuxActivityKind::Bell,
+            ]
+        );
+        assert_eq!(
+            summary.spoken(),
+            "output in window 2; bell in window 2; stale pane 1.1; exited pane 1.2"
+        );
+        assert_eq!(
+            summary.detail(),
+            "pane 1.1 %20 left: stale after pause\n\
+             pane 1.2 %21 right: exited\n\
+             window 2 @11 logs: unseen output\n\
+             window 2 @11 logs: bell"
+        );
+    }
+
+    #[test]
+    fn empty_summary_is_still_announced() {
+        let mut topology = TmuxTopology::new(1);
+        topology
+            .replace_inventory(&[
+                b"S\t$1\twork".to_vec(),
+                format!("W\t$1\t@11\t2\t1\t{SINGLE}\t{SINGLE}\t*\tlogs").into_bytes(),
+                b"P\t@11\t%22\t1\t1\t0\t0\t20\t4\t0\t0\t0\t1\t0\t0\t0\t0\ttail".to_vec(),
+                b"A\t$1".to_vec(),
+            ])
+            .expect("topology");
+        let summary = TmuxActivitySummary::collect(
+            &topology,
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+        );
+        assert!(summary.is_empty());
+        assert_eq!(summary.spoken(), "no tmux activity");
+    }
+}
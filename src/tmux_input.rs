@@ -146,7 +146,8 @@ pub fn translate_mouse(
     encode_mouse(event, row, col, encoding)
 }
 
-fn protocol_reports(protocol: MouseProtocol, kind: MouseEventKind) -> bool {
+/// Whether an application that requested `protocol` wants events of `kind`.
+pub(crate) fn protocol_reports(protocol: MouseProtocol, kind: MouseEventKind) -> bool {
     match kind {
         MouseEventKind::Down(_) | MouseEventKind::Scroll(_) => protocol != MouseProtocol::None,
         MouseEventKind::Up(_) => !matches!(protocol, MouseProtocol::None | MouseProtocol::Press),
@@ -160,7 +161,14 @@ fn protocol_reports(protocol: MouseProtocol, kind: MouseEventKind) -> bool {
     }
 }
 
-fn encode_mouse(event: MouseEvent, row: u32, col: u32, encoding: MouseEncoding) -> Option<Vec<u8>> {
+/// Encodes `event` at the zero-based application cell `row`, `col`. `None`
+/// means `encoding` cannot represent the position.
+pub(crate) fn encode_mouse(
+    event: MouseEvent,
+    row: u32,
+    col: u32,
+    encoding: MouseEncoding,
+) -> Option<Vec<u8>> {
     let mut code: u32 = match event.kind {
         MouseEventKind::Down(MouseButton::Left | MouseButton::Unknown)
         | MouseEventKind::Up(MouseButton::Left | MouseButton::Unknown) => 0,
@@ -0,0 +1,173 @@
+//! Explicit `tmux -CC` launch commands for starting or attaching a control
+//! client from Lector.
+//!
+//! Lector never talks to a tmux server out of band. A request is only turned
+//! into an argument vector for the root child, or into one shell command line
+//! typed into the current pane; the resulting control-mode marker is then
+//! detected by the ordinary [`crate::tmux_gateway::TmuxGatewayRouter`] path.
+
+use std::{ffi::OsString, fmt, path::PathBuf};
+use thiserror::Error;
+
+/// Program name resolved through `PATH` for every launch.
+pub const TMUX_PROGRAM: &str = "tmux";
+
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum LaunchError {
+    #[error("tmux session name must not be empty")]
+    EmptySession,
+    #[error("tmux session name must not contain ':' or '.'")]
+    InvalidSessionSeparator,
+    #[error("tmux socket must not be empty")]
+    EmptySocket,
+    #[error("tmux {0} must not contain control characters")]
+    ControlCharacter(&'static str),
+}
+
+/// The server selected by tmux's `-L` or `-S` option.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum TmuxSocket {
+    /// tmux's default server for the current user.
+    #[default]
+    Default,
+    /// A named socket in tmux's socket directory (`-L`).
+    Name(String),
+    /// An explicit socket path (`-S`).
+    Path(PathBuf),
+}
+
+impl TmuxSocket {
+    /// Parses a user-supplied socket. A value containing `/` is a path passed
+    /// with `-S`; anything else is a socket name passed with `-L`.
+    pub fn parse(value: &str) -> Result<Self, LaunchError> {
+        if value.is_empty() {
+            return Err(LaunchError::EmptySocket);
+        }
+        if value.chars().any(char::is_control) {
+            return Err(LaunchError::ControlCharacter("socket"));
+        }
+        Ok(if value.contains('/') {
+            Self::Path(PathBuf::from(value))
+        } else {
+            Self::Name(value.to_owned())
+        })
+    }
+}
+
+impl fmt::Display for TmuxSocket {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => formatter.write_str("default"),
+            Self::Name(name) => formatter.write_str(name),
+            Self::Path(path) => write!(formatter, "{}", path.display()),
+        }
+    }
+}
+
+/// Starts the named session, or attaches to it when it already exists, as a
+/// control-mode client of one explicitly selected server.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TmuxAttachRequest {
+    socket: TmuxSocket,
+    session: String,
+}
+
+impl TmuxAttachRequest {
+    pub fn new(socket: TmuxSocket, session: &str) -> Result<Self, LaunchError> {
+        if session.is_empty() {
+            return Err(LaunchError::EmptySession);
+        }
+        // tmux rejects these separators in session names because they
+        // delimit window and pane targets.
+        if session.contains([':', '.']) {
+            return Err(LaunchError::InvalidSessionSeparator);
+        }
+        if session.chars().any(char::is_control) {
+            return Err(LaunchError::ControlCharacter("session name"));
+        }
+        Ok(Self {
+            socket,
+            session: session.to_owned(),
+        })
+    }
+
+    /// Parses prompt input of the form `SESSION` or `SOCKET:SESSION`.
+    ///
+    /// Session names cannot contain `:`, so the final separator always ends
+    /// the socket, even when a socket path itself contains one.
+    pub fn parse_target(value: &str) -> Result<Self, LaunchError> {
+        let value = value.trim();
+        match value.rsplit_once(':') {
+            Some((socket, session)) => Self::new(TmuxSocket::parse(socket)?, session),
+            None => Self::new(TmuxSocket::Default, value),
+        }
+    }
+
+    #[must_use]
+    pub fn socket(&self) -> &TmuxSocket {
+        &self.socket
+    }
+
+    #[must_use]
+    pub fn session(&self) -> &str {
+        &self.session
+    }
+
+    /// Arguments following [`TMUX_PROGRAM`] for a root-child launch.
+    #[must_use]
+    pub fn args(&self) -> Vec<OsString> {
+        let mut args = Vec::with_capacity(7);
+        match &self.socket {
+            TmuxSocket::Default => {}
+            TmuxSocket::Name(name) => {
+                args.push("-L".into());
+                args.push(name.into());
+            }
+            TmuxSocket::Path(path) => {
+                args.push("-S".into());
+                args.push(path.into());
+            }
+        }
+        args.extend(
+            ["-CC", "new-session", "-A", "-s", self.session.as_str()]
+                .into_iter()
+                .map(OsString::from),
+        );
+        args
+    }
+
+    /// A POSIX shell command line, terminated by carriage return, suitable
+    /// for typing into the shell of the current pane. `TMUX` is cleared for
+    /// the command because a pane inside tmux has it set, and tmux refuses
+    /// to start a client there.
+    #[must_use]
+    pub fn shell_input(&self) -> Vec<u8> {
+        let mut command = format!("TMUX= {TMUX_PROGRAM}");
+        for arg in self.args() {
+            command.push(' ');
+            command.push_str(&shell_quote(&arg.to_string_lossy()));
+        }
+        command.push('\r');
+        command.into_bytes()
+    }
+
+    /// Short spoken description of the requested target.
+    #[must_use]
+    pub fn description(&self) -> String {
+        match &self.socket {
+            TmuxSocket::Default => format!("tmux session {}", self.session),
+            socket => format!("tmux session {} on socket {socket}", self.session),
+        }
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    if !value.is_empty()
+        && value
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || b"-_./=@%+,".contains(&byte))
+    {
+        return value.to_owned();
+    }
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
@@ -347,6 +347,16 @@ impl TmuxTopology {
         self.key_tables.get(table)?.get(key)
     }
 
+    /// The bindings of one key table, sorted by key.
+    pub fn table_bindings(&self, table: &str) -> impl Iterator<Item = &TmuxBinding> {
+        let bindings = if table == "prefix" {
+            Some(&self.bindings)
+        } else {
+            self.key_tables.get(table)
+        };
+        bindings.into_iter().flat_map(BTreeMap::values)
+    }
+
     #[must_use]
     pub fn has_key_table(&self, table: &str) -> bool {
         table == "prefix" && !self.bindings.is_empty() || self.key_tables.contains_key(table)
@@ -173,6 +173,16 @@ impl TmuxLayout {
         self.panes.iter().find(|pane| pane.pane_id == pane_id)
     }
 
+    /// The pane containing the window cell `point`, or `None` on a divider.
+    #[must_use]
+    pub fn pane_at(&self, point: GridPoint) -> Option<&LayoutPane> {
+        self.panes.iter().find(|pane| {
+            let row = point.row - pane.origin.row;
+            let col = point.col - pane.origin.col;
+            (0..i32::from(pane.rows)).contains(&row) && (0..i32::from(pane.cols)).contains(&col)
+        })
+    }
+
     #[must_use]
     pub fn border_snapshot(&self, geometry: TerminalGeometry) -> TerminalSnapshot {
         const NORTH: u8 = 1;
@@ -1323,6 +1333,17 @@ fn capture_command_for_state(
     command
 }
 
+/// Captures server-side history rows `first_line..=last_line`, counted in
+/// tmux's negative offsets above the live screen, for Review.
+#[must_use]
+pub fn history_capture_command(pane_id: PaneId, first_line: i64, last_line: i64) -> Vec<u8> {
+    format!(
+        "capture-pane -p -e -J -S {first_line} -E {last_line} -t %{}\n",
+        pane_id.0
+    )
+    .into_bytes()
+}
+
 #[must_use]
 pub fn pending_escape_capture_command(pane_id: PaneId) -> Vec<u8> {
     format!("capture-pane -p -P -t %{}\n", pane_id.0).into_bytes()
@@ -1402,7 +1423,7 @@ fn capture_line_flags(line: &[u8]) -> (&[u8], &[u8]) {
 #[cfg(test)]
 mod synchronization_tests {
     use super::{
-        PaneState, TmuxLayout, TmuxPaneSet, capture_command_for_metadata,
+        PaneState, TmuxLayout, TmuxPaneSet, capture_command_for_metadata, history_capture_command,
         pending_escape_capture_command, portable_capture_command_for_metadata,
     };
     use crate::{
@@ -1615,6 +1636,10 @@ mod synchronization_tests {
             pending_escape_capture_command(PaneId(20)),
             b"capture-pane -p -P -t %20\n"
         );
+        assert_eq!(
+            history_capture_command(PaneId(20), -1500, -501),
+            b"capture-pane -p -e -J -S -1500 -E -501 -t %20\n"
+        );
     }
 
     #[test]
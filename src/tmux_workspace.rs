@@ -0,0 +1,717 @@
+//! Named tmux workspaces: saved sessions, windows, layouts, working
+//! directories, and labels, plus the command sequence that recreates them.
+//!
+//! A workspace is restored through the owning connection's ordinary control
+//! FIFO, one command at a time. Each creating command prints the stable IDs it
+//! produced, so later splits and layouts never depend on indexes that another
+//! client could change. A failed command rolls back everything the restore
+//! created without touching pre-existing sessions or windows.
+
+use crate::tmux_model::{PaneId, TmuxTopology, WindowId};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Version written to new workspace files.
+pub const WORKSPACE_VERSION: u32 = 1;
+
+/// `list-panes` query supplying the working directory that inventory omits.
+pub const WORKSPACE_DIRECTORY_QUERY: &str =
+    "list-panes -a -F 'D\t#{pane_id}\t#{pane_current_path}'\n";
+
+const MAX_WORKSPACE_BYTES: u64 = 1024 * 1024;
+const MAX_NAME_LEN: usize = 64;
+const CREATED_FORMAT: &str = "'#{session_id} #{window_id} #{pane_id}'";
+
+#[derive(Debug, Error)]
+pub enum WorkspaceError {
+    #[error("workspace names may contain only letters, digits, '.', '-', and '_'")]
+    InvalidName,
+    #[error("no workspace directory is available")]
+    NoDirectory,
+    #[error("workspace {0} does not exist")]
+    NotFound(String),
+    #[error("workspace file is larger than 1 MiB")]
+    TooLarge,
+    #[error("unsupported workspace version {0}")]
+    UnsupportedVersion(u32),
+    #[error("workspace has no windows")]
+    Empty,
+    #[error("workspace {0} is invalid")]
+    Invalid(&'static str),
+    #[error("tmux returned an unexpected reply to {0}")]
+    UnexpectedReply(&'static str),
+    #[error("{command}: {detail}")]
+    CommandFailed { command: String, detail: String },
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WorkspacePane {
+    pub title: String,
+    /// Absolute working directory, when tmux reported one.
+    pub cwd: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WorkspaceWindow {
+    pub name: String,
+    pub layout: String,
+    /// Panes in tmux pane-index order, which is the order `select-layout`
+    /// assigns them to layout cells.
+    pub panes: Vec<WorkspacePane>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct WorkspaceSession {
+    pub name: String,
+    pub windows: Vec<WorkspaceWindow>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TmuxWorkspace {
+    pub version: u32,
+    /// A user-assigned connection label, if the connection had one.
+    pub label: Option<String>,
+    pub sessions: Vec<WorkspaceSession>,
+}
+
+impl TmuxWorkspace {
+    /// Captures every session of one connection. `directories` comes from
+    /// [`WORKSPACE_DIRECTORY_QUERY`]; panes missing from it are saved without
+    /// a working directory.
+    #[must_use]
+    pub fn capture(topology: &TmuxTopology, directories: &BTreeMap<PaneId, String>) -> Self {
+        let default_label = format!("tmux {}", topology.connection_id());
+        let sessions = topology
+            .sessions()
+            .values()
+            .map(|session| WorkspaceSession {
+                name: session.name.clone(),
+                windows: session
+                    .windows
+                    .values()
+                    .filter_map(|window_id| topology.window(*window_id))
+                    .map(|window| {
+                        let mut panes = topology
+                            .panes()
+                            .values()
+                            .filter(|pane| pane.window_id == window.id)
+                            .collect::<Vec<_>>();
+                        panes.sort_by_key(|pane| (pane.index, pane.id));
+                        WorkspaceWindow {
+                            name: window.name.clone(),
+                            layout: window.layout.clone(),
+                            panes: panes
+                                .into_iter()
+                                .map(|pane| WorkspacePane {
+                                    title: pane.title.clone(),
+                                    cwd: directories.get(&pane.id).cloned(),
+                                })
+                                .collect(),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            version: WORKSPACE_VERSION,
+            label: (topology.label() != default_label).then(|| topology.label().to_owned()),
+            sessions,
+        }
+    }
+
+    /// Rejects files that could not be replayed safely or meaningfully.
+    pub fn validate(&self) -> Result<(), WorkspaceError> {
+        if self.version != WORKSPACE_VERSION {
+            return Err(WorkspaceError::UnsupportedVersion(self.version));
+        }
+        if self
+            .sessions
+            .iter()
+            .all(|session| session.windows.is_empty())
+        {
+            return Err(WorkspaceError::Empty);
+        }
+        let mut names = BTreeSet::new();
+        for session in &self.sessions {
+            if session.name.is_empty()
+                || session.name.contains([':', '.'])
+                || !names.insert(session.name.as_str())
+            {
+                return Err(WorkspaceError::Invalid("session name"));
+            }
+            for window in &session.windows {
+                if window.panes.is_empty() {
+                    return Err(WorkspaceError::Invalid("window without panes"));
+                }
+                if layout_size(&window.layout).is_none() {
+                    return Err(WorkspaceError::Invalid("layout"));
+                }
+                if window.panes.iter().any(|pane| {
+                    pane.cwd
+                        .as_deref()
+                        .is_some_and(|cwd| !Path::new(cwd).is_absolute())
+                }) {
+                    return Err(WorkspaceError::Invalid("working directory"));
+                }
+            }
+        }
+        let has_control = |text: &str| text.chars().any(char::is_control);
+        if self.label.as_deref().is_some_and(has_control)
+            || self.sessions.iter().any(|session| {
+                has_control(&session.name)
+                    || session.windows.iter().any(|window| {
+                        has_control(&window.name)
+                            || has_control(&window.layout)
+                            || window.panes.iter().any(|pane| {
+                                has_control(&pane.title)
+                                    || pane.cwd.as_deref().is_some_and(has_control)
+                            })
+                    })
+            })
+        {
+            return Err(WorkspaceError::Invalid(
+                "text containing control characters",
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn save(&self, directory: &Path, name: &str) -> Result<PathBuf, WorkspaceError> {
+        let path = workspace_path(directory, name)?;
+        fs::create_dir_all(directory)?;
+        // Write beside the destination first so an interrupted save never
+        // leaves a truncated workspace under the requested name.
+        let partial = path.with_extension("json.partial");
+        fs::write(&partial, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(&partial, &path)?;
+        Ok(path)
+    }
+
+    pub fn load(directory: &Path, name: &str) -> Result<Self, WorkspaceError> {
+        let path = workspace_path(directory, name)?;
+        let metadata = match fs::metadata(&path) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                return Err(WorkspaceError::NotFound(name.to_owned()));
+            }
+            Err(error) => return Err(error.into()),
+        };
+        if metadata.len() > MAX_WORKSPACE_BYTES {
+            return Err(WorkspaceError::TooLarge);
+        }
+        let workspace: Self = serde_json::from_slice(&fs::read(&path)?)?;
+        workspace.validate()?;
+        Ok(workspace)
+    }
+
+    #[must_use]
+    pub fn window_count(&self) -> usize {
+        self.sessions
+            .iter()
+            .map(|session| session.windows.len())
+            .sum()
+    }
+}
+
+/// `$XDG_DATA_HOME/lector/tmux-workspaces`, or the platform data directory.
+#[must_use]
+pub fn default_workspace_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|path| path.join("lector").join("tmux-workspaces"))
+}
+
+pub fn workspace_path(directory: &Path, name: &str) -> Result<PathBuf, WorkspaceError> {
+    validate_workspace_name(name)?;
+    Ok(directory.join(format!("{name}.json")))
+}
+
+pub fn validate_workspace_name(name: &str) -> Result<(), WorkspaceError> {
+    if name.is_empty()
+        || name.len() > MAX_NAME_LEN
+        || name.starts_with('.')
+        || !name
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'-' | b'_'))
+    {
+        return Err(WorkspaceError::InvalidName);
+    }
+    Ok(())
+}
+
+/// Parses one `D` record from [`WORKSPACE_DIRECTORY_QUERY`].
+#[must_use]
+pub fn parse_directory_record(line: &[u8]) -> Option<(PaneId, String)> {
+    let line = std::str::from_utf8(line).ok()?;
+    let mut fields = line.splitn(3, '\t');
+    if fields.next()? != "D" {
+        return None;
+    }
+    let pane_id = PaneId(fields.next()?.strip_prefix('%')?.parse().ok()?);
+    let path = fields.next()?;
+    (Path::new(path).is_absolute() && !path.chars().any(char::is_control))
+        .then(|| (pane_id, path.to_owned()))
+}
+
+fn layout_size(layout: &str) -> Option<(u32, u32)> {
+    let (_, rest) = layout.split_once(',')?;
+    let size = rest.split([',', '{', '[']).next()?;
+    let (width, height) = size.split_once('x')?;
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+/// Quotes one argument for tmux's command parser.
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum RestoreStep {
+    NewSession {
+        session: String,
+        window: String,
+        cwd: Option<String>,
+        size: (u32, u32),
+    },
+    NewWindow {
+        session: String,
+        window: String,
+        cwd: Option<String>,
+    },
+    SplitPane {
+        cwd: Option<String>,
+    },
+    TitlePane {
+        title: String,
+    },
+    SelectLayout {
+        layout: String,
+        window: String,
+    },
+}
+
+impl RestoreStep {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::NewSession { .. } => "new-session",
+            Self::NewWindow { .. } => "new-window",
+            Self::SplitPane { .. } => "split-window",
+            Self::TitlePane { .. } => "select-pane",
+            Self::SelectLayout { .. } => "select-layout",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Created {
+    Session(crate::tmux_model::SessionId),
+    Window(WindowId),
+}
+
+/// Progress reported after one successful restore command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RestoreProgress {
+    /// More commands remain within the current window.
+    Continue,
+    /// A window received its layout.
+    WindowRestored {
+        name: String,
+        done: usize,
+        total: usize,
+    },
+}
+
+/// Replays one workspace as a sequence of dependent control commands.
+#[derive(Clone, Debug)]
+pub struct TmuxWorkspaceRestore {
+    name: String,
+    label: Option<String>,
+    steps: VecDeque<RestoreStep>,
+    in_flight: Option<RestoreStep>,
+    window: Option<WindowId>,
+    pane: Option<PaneId>,
+    created: Vec<Created>,
+    windows_done: usize,
+    windows_total: usize,
+}
+
+impl TmuxWorkspaceRestore {
+    /// Plans a restore. Sessions already present on the connection receive
+    /// new windows; missing sessions are created detached.
+    #[must_use]
+    pub fn new(name: &str, workspace: &TmuxWorkspace, topology: &TmuxTopology) -> Self {
+        let existing = topology
+            .sessions()
+            .values()
+            .map(|session| session.name.as_str())
+            .collect::<BTreeSet<_>>();
+        let mut steps = VecDeque::new();
+        for session in &workspace.sessions {
+            for (position, window) in session.windows.iter().enumerate() {
+                let Some(first) = window.panes.first() else {
+                    continue;
+                };
+                steps.push_back(
+                    if position == 0 && !existing.contains(session.name.as_str()) {
+                        RestoreStep::NewSession {
+                            session: session.name.clone(),
+                            window: window.name.clone(),
+                            cwd: first.cwd.clone(),
+                            size: layout_size(&window.layout).unwrap_or((80, 24)),
+                        }
+                    } else {
+                        RestoreStep::NewWindow {
+                            session: session.name.clone(),
+                            window: window.name.clone(),
+                            cwd: first.cwd.clone(),
+                        }
+                    },
+                );
+                for (index, pane) in window.panes.iter().enumerate() {
+                    if index > 0 {
+                        steps.push_back(RestoreStep::SplitPane {
+                            cwd: pane.cwd.clone(),
+                        });
+                    }
+                    if !pane.title.is_empty() {
+                        steps.push_back(RestoreStep::TitlePane {
+                            title: pane.title.clone(),
+                        });
+                    }
+                }
+                steps.push_back(RestoreStep::SelectLayout {
+                    layout: window.layout.clone(),
+                    window: window.name.clone(),
+                });
+            }
+        }
+        Self {
+            name: name.to_owned(),
+            label: workspace.label.clone(),
+            steps,
+            in_flight: None,
+            window: None,
+            pane: None,
+            created: Vec::new(),
+            windows_done: 0,
+            windows_total: workspace.window_count(),
+        }
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The connection label saved with the workspace, applied on success.
+    #[must_use]
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.steps.is_empty() && self.in_flight.is_none()
+    }
+
+    /// Returns the next newline-terminated command. Only one command is in
+    /// flight at a time because later targets depend on earlier replies.
+    pub fn next_command(&mut self) -> Option<Vec<u8>> {
+        if self.in_flight.is_some() {
+            return None;
+        }
+        let step = self.steps.pop_front()?;
+        let directory = |cwd: &Option<String>| {
+            cwd.as_deref()
+                .map(|cwd| format!(" -c {}", quote(cwd)))
+                .unwrap_or_default()
+        };
+        let command = match &step {
+            RestoreStep::NewSession {
+                session,
+                window,
+                cwd,
+                size: (width, height),
+            } => format!(
+                "new-session -d -P -F {CREATED_FORMAT} -s {} -n {} -x {width} -y {height}{}",
+                quote(session),
+                quote(window),
+                directory(cwd),
+            ),
+            RestoreStep::NewWindow {
+                session,
+                window,
+                cwd,
+            } => format!(
+                "new-window -d -P -F {CREATED_FORMAT} -t {} -n {}{}",
+                quote(&format!("={session}:")),
+                quote(window),
+                directory(cwd),
+            ),
+            RestoreStep::SplitPane { cwd } => format!(
+                "split-window -d -P -F {CREATED_FORMAT} -t %{}{}",
+                self.pane?.0,
+                directory(cwd),
+            ),
+            RestoreStep::TitlePane { title } => {
+                format!("select-pane -t %{} -T {}", self.pane?.0, quote(title))
+            }
+            RestoreStep::SelectLayout { layout, .. } => {
+                format!("select-layout -t @{} {}", self.window?.0, quote(layout))
+            }
+        };
+        self.in_flight = Some(step);
+        let mut bytes = command.into_bytes();
+        bytes.push(b'\n');
+        Some(bytes)
+    }
+
+    /// Applies the reply to the command returned by [`Self::next_command`].
+    pub fn apply_reply(
+        &mut self,
+        success: bool,
+        output: &[Vec<u8>],
+    ) -> Result<RestoreProgress, WorkspaceError> {
+        let Some(step) = self.in_flight.take() else {
+            return Err(WorkspaceError::UnexpectedReply("workspace restore"));
+        };
+        if !success {
+            return Err(WorkspaceError::CommandFailed {
+                command: step.label().to_owned(),
+                detail: output
+                    .first()
+                    .map(|line| String::from_utf8_lossy(line).into_owned())
+                    .unwrap_or_else(|| "tmux rejected the command".to_owned()),
+            });
+        }
+        match step {
+            RestoreStep::NewSession { .. }
+            | RestoreStep::NewWindow { .. }
+            | RestoreStep::SplitPane { .. } => {
+                let (session_id, window_id, pane_id) = output
+                    .first()
+                    .and_then(|line| parse_created(line))
+                    .ok_or(WorkspaceError::UnexpectedReply(step.label()))?;
+                match step {
+                    RestoreStep::NewSession { .. } => {
+                        self.created.push(Created::Session(session_id));
+                    }
+                    RestoreStep::NewWindow { .. } => {
+                        self.created.push(Created::Window(window_id));
+                    }
+                    _ => {}
+                }
+                self.window = Some(window_id);
+                self.pane = Some(pane_id);
+                Ok(RestoreProgress::Continue)
+            }
+            RestoreStep::TitlePane { .. } => Ok(RestoreProgress::Continue),
+            RestoreStep::SelectLayout { window, .. } => {
+                self.windows_done += 1;
+                Ok(RestoreProgress::WindowRestored {
+                    name: window,
+                    done: self.windows_done,
+                    total: self.windows_total,
+                })
+            }
+        }
+    }
+
+    /// Commands that remove everything this restore created, newest first.
+    #[must_use]
+    pub fn rollback_commands(&self) -> Vec<Vec<u8>> {
+        self.created
+            .iter()
+            .rev()
+            .map(|created| match created {
+                Created::Session(session_id) => format!("kill-session -t ${}\n", session_id.0),
+                Created::Window(window_id) => format!("kill-window -t @{}\n", window_id.0),
+            })
+            .map(String::into_bytes)
+            .collect()
+    }
+}
+
+fn parse_created(line: &[u8]) -> Option<(crate::tmux_model::SessionId, WindowId, PaneId)> {
+    let line = std::str::from_utf8(line).ok()?;
+    let mut fields = line.split(' ');
+    let session = fields.next()?.strip_prefix('$')?.parse().ok()?;
+    let window = fields.next()?.strip_prefix('@')?.parse().ok()?;
+    let pane = fields.next()?.strip_prefix('%')?.parse().ok()?;
+    fields.next().is_none().then_some((
+        crate::tmux_model::SessionId(session),
+        WindowId(window),
+        PaneId(pane),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        RestoreProgress, TmuxWorkspace, TmuxWorkspaceRestore, WorkspaceError, WorkspacePane,
+        WorkspaceSession, WorkspaceWindow, parse_directory_record, validate_workspace_name,
+    };
+    use crate::tmux_model::{PaneId, TmuxTopology};
+    use std::collections::BTreeMap;
+
+    const SPLIT: &str = "abcd,20x4,0,0{10x4,0,0,20,9x4,11,0,21}";
+
+    fn topology() -> TmuxTopology {
+        let lines = [
+            b"S\t$1\twork".to_vec(),
+            format!("W\t$1\t@10\t1\t1\t{SPLIT}\t{SPLIT}\t*\teditor").into_bytes(),
+            b"P\t@10\t%21\t2\t0\t11\t0\t9\t4\t0\t0\t0\t1\t0\t0\t0\t0\tright".to_vec(),
+            b"P\t@10\t%20\t1\t1\t0\t0\t10\t4\t0\t0\t0\t1\t0\t0\t0\t0\tleft".to_vec(),
+            b"A\t$1".to_vec(),
+        ];
+        let mut topology = TmuxTopology::new(1);
+        topology.replace_inventory(&lines).expect("topology");
+        topology
+    }
+
+    fn pane(title: &str, cwd: &str) -> WorkspacePane {
+        WorkspacePane {
+            title: title.to_owned(),
+            cwd: Some(cwd.to_owned()),
+        }
+    }
+
+    #[test]
+    fn capture_round_trips_through_a_named_file() {
+        let directories = BTreeMap::from([
+            parse_directory_record(b"D\t%20\t/src/it's here").unwrap(),
+            parse_directory_record(b"D\t%21\t/tmp").unwrap(),
+        ]);
+        assert_eq!(parse_directory_record(b"D\t%22\trelative"), None);
+        let mut topology = topology();
+        topology.set_label("morning").unwrap();
+        let workspace = TmuxWorkspace::capture(&topology, &directories);
+        assert_eq!(workspace.label.as_deref(), Some("morning"));
+        assert_eq!(
+            workspace.sessions[0].windows[0].panes,
+            [pane("left", "/src/it's here"), pane("right", "/tmp")]
+        );
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = workspace.save(directory.path(), "daily").unwrap();
+        assert_eq!(path, directory.path().join("daily.json"));
+        assert_eq!(
+            TmuxWorkspace::load(directory.path(), "daily").unwrap(),
+            workspace
+        );
+        assert!(matches!(
+            TmuxWorkspace::load(directory.path(), "missing"),
+            Err(WorkspaceError::NotFound(_))
+        ));
+        for name in ["", "../escape", ".hidden", "a b"] {
+            assert!(matches!(
+                validate_workspace_name(name),
+                Err(WorkspaceError::InvalidName)
+            ));
+        }
+    }
+
+    #[test]
+    fn restore_targets_printed_ids_and_rolls_back_only_what_it_created() {
+        let workspace = TmuxWorkspace {
+            version: 1,
+            label: None,
+            sessions: vec![
+                WorkspaceSession {
+                    name: "work".to_owned(),
+                    windows: vec![WorkspaceWindow {
+                        name: "editor".to_owned(),
+                        layout: SPLIT.to_owned(),
+                        panes: vec![pane("", "/src"), pane("logs", "/var/log")],
+                    }],
+                },
+                WorkspaceSession {
+                    name: "build".to_owned(),
+                    windows: vec![WorkspaceWindow {
+                        name: "make".to_owned(),
+                        layout: "dcba,80x24,0,0,30".to_owned(),
+                        panes: vec![WorkspacePane {
+                            title: String::new(),
+                            cwd: None,
+                        }],
+                    }],
+                },
+            ],
+        };
+        workspace.validate().unwrap();
+        let mut restore = TmuxWorkspaceRestore::new("daily", &workspace, &topology());
+        let mut step = |reply: Option<&str>| {
+            let command = String::from_utf8(restore.next_command().unwrap()).unwrap();
+            assert!(restore.next_command().is_none(), "one command in flight");
+            let progress = restore.apply_reply(
+                true,
+                &reply
+                    .map(|line| line.as_bytes().to_vec())
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+            );
+            (command, progress.unwrap())
+        };
+
+        // The existing session receives a new window instead of a new session.
+        let (command, _) = step(Some("$1 @40 %50"));
+        assert_eq!(
+            command,
+            "new-window -d -P -F '#{session_id} #{window_id} #{pane_id}' -t '=work:' \
+             -n 'editor' -c '/src'\n"
+        );
+        let (command, _) = step(Some("$1 @40 %51"));
+        assert_eq!(
+            command,
+            "split-window -d -P -F '#{session_id} #{window_id} #{pane_id}' -t %50 -c '/var/log'\n"
+        );
+        let (command, _) = step(None);
+        assert_eq!(command, "select-pane -t %51 -T 'logs'\n");
+        let (command, progress) = step(None);
+        assert_eq!(command, format!("select-layout -t @40 '{SPLIT}'\n"));
+        assert_eq!(
+            progress,
+            RestoreProgress::WindowRestored {
+                name: "editor".to_owned(),
+                done: 1,
+                total: 2,
+            }
+        );
+        let (command, _) = step(Some("$7 @41 %52"));
+        assert_eq!(
+            command,
+            "new-session -d -P -F '#{session_id} #{window_id} #{pane_id}' -s 'build' \
+             -n 'make' -x 80 -y 24\n"
+        );
+
+        assert!(
+            restore
+                .next_command()
+                .unwrap()
+                .starts_with(b"select-layout -t @41 ")
+        );
+        assert!(matches!(
+            restore.apply_reply(false, &[b"invalid layout".to_vec()]),
+            Err(WorkspaceError::CommandFailed { .. })
+        ));
+        assert_eq!(
+            restore.rollback_commands(),
+            [
+                b"kill-session -t $7\n".to_vec(),
+                b"kill-window -t @40\n".to_vec()
+            ]
+        );
+        assert_eq!(
+            parse_directory_record(b"D\t%1\t/"),
+            Some((PaneId(1), "/".to_owned()))
+        );
+    }
+}
@@ -2,10 +2,10 @@ use super::{
     ext::{CellExt, ScreenExt},
     presentation::{
         AccessibilityEpoch, PaneMediaStore, PresentationError, PresentedHistoryBasis,
-        PresentedHistoryDelta, PresentedViewFrame, SurfaceId, ViewId, ViewRevision,
+        PresentedHistoryDelta, PresentedImage, PresentedViewFrame, SurfaceId, ViewId, ViewRevision,
     },
     terminal::{
-        GhosttyEngine, GhosttyReviewMark, HistoryPosition, SemanticKind as Osc133Kind,
+        GhosttyEngine, GhosttyReviewMark, HistoryPosition, ScreenRect, SemanticKind as Osc133Kind,
         SemanticMark as Osc133Mark, TerminalEngine, TerminalGeometry, TerminalSnapshot,
         UpdateSummary, Viewport,
     },
@@ -19,6 +19,42 @@ use std::{
     },
 };
 
+/// An image placement as review sees it: its cells on the review screen and
+/// its decoded pixels.
+#[derive(Clone, Debug)]
+pub struct ReviewImage {
+    pub image: PresentedImage,
+    pub row: u16,
+    pub col: u16,
+    pub rows: u16,
+    pub cols: u16,
+    pub data: Arc<[u8]>,
+}
+
+impl ReviewImage {
+    #[must_use]
+    pub fn contains(&self, (row, col): (u16, u16)) -> bool {
+        (self.row..self.row.saturating_add(self.rows)).contains(&row)
+            && (self.col..self.col.saturating_add(self.cols)).contains(&col)
+    }
+
+    /// Whether `other` is the same placement of the same image.
+    #[must_use]
+    pub fn same_placement(&self, other: &Self) -> bool {
+        self.image.image_id == other.image.image_id
+            && self.image.placement_id == other.image.placement_id
+    }
+
+    /// The topmost of `images` covering the review screen cell `position`.
+    #[must_use]
+    pub fn topmost_at(images: &[Self], position: (u16, u16)) -> Option<&Self> {
+        images
+            .iter()
+            .filter(|image| image.contains(position))
+            .max_by_key(|image| image.image.z_index)
+    }
+}
+
 /// A bounded history avoids unbounded memory growth while retaining enough
 /// output for extended review and semantic-prompt navigation.
 pub const SCROLLBACK_LINES: usize = 10_000;
@@ -282,6 +318,10 @@ pub struct View {
     committed_snapshot: TerminalSnapshot,
     accessibility_read_state: AccessibilityReadState,
     media: PaneMediaStore,
+    /// The review images last found, with the review scrollback they were
+    /// clipped to. Parsing output and resizing clear it, so moving the review
+    /// cursor does not query the image placements again.
+    review_images_cache: Option<(usize, Vec<ReviewImage>)>,
     /// Cumulative parser metadata for standalone Views, where parsing is also
     /// the accessibility publication boundary. Presentation-tracked Views use
     /// the bounded revision journal and `presented_update` exclusively; keeping
@@ -311,6 +351,9 @@ pub struct View {
     cached_prev_full_valid: bool,
     cached_full_row_hashes: Vec<u64>,
     cached_prev_full_row_hashes: Vec<u64>,
+    /// Cells the full-screen contents above read as blanks, so auto-read
+    /// ignores changes there.
+    auto_read_exclusions: Vec<ScreenRect>,
 }
 
 impl View {
@@ -367,6 +410,7 @@ impl View {
             committed_snapshot,
             accessibility_read_state: AccessibilityReadState::Live,
             media: PaneMediaStore::new(Default::default()),
+            review_images_cache: None,
             standalone_update: UpdateSummary::default(),
             presented_update: UpdateSummary::default(),
             prev_screen,
@@ -388,6 +432,7 @@ impl View {
             cached_prev_full_valid: false,
             cached_full_row_hashes: Vec::new(),
             cached_prev_full_row_hashes: Vec::new(),
+            auto_read_exclusions: Vec::new(),
         }
     }
 
@@ -444,6 +489,7 @@ impl View {
         // selected review viewport is restored afterward.
         self.engine.select_viewport(Viewport::Live);
         let mut update = TerminalEngine::advance(&mut self.engine, buf);
+        self.review_images_cache = None;
         let synchronized_output_open_snapshot =
             self.engine.take_synchronized_output_open_snapshot();
         let synchronized = update.synchronized_output;
@@ -1616,6 +1662,63 @@ impl View {
         Ok(&self.media)
     }
 
+    /// The visible image placements on the screen the review cursor is
+    /// reading, in reading order, clipped to that screen. Images whose media
+    /// cannot be read are left out rather than failing review.
+    pub(crate) fn review_images(&mut self) -> &[ReviewImage] {
+        let scrollback = self.scrollback();
+        if self
+            .review_images_cache
+            .as_ref()
+            .is_none_or(|(cached, _)| *cached != scrollback)
+        {
+            let images = self.find_review_images(scrollback);
+            self.review_images_cache = Some((scrollback, images));
+        }
+        self.review_images_cache
+            .as_ref()
+            .map_or(&[], |(_, images)| images)
+    }
+
+    fn find_review_images(&mut self, scrollback: usize) -> Vec<ReviewImage> {
+        let offset = i64::try_from(scrollback).unwrap_or(i64::MAX);
+        let (rows, cols) = self.size();
+        let Ok(media) = self.presentation_media() else {
+            return Vec::new();
+        };
+        let clip = |start: i32, len: u16, offset: i64, limit: u16| {
+            let start = i64::from(start).saturating_add(offset);
+            let end = start.saturating_add(i64::from(len)).min(i64::from(limit));
+            let start = start.max(0);
+            (start < end).then(|| {
+                (
+                    u16::try_from(start).unwrap_or(u16::MAX),
+                    u16::try_from(end - start).unwrap_or(u16::MAX),
+                )
+            })
+        };
+        let mut images: Vec<ReviewImage> = media
+            .placements()
+            .iter()
+            .filter(|placement| placement.visible)
+            .filter_map(|placement| {
+                let rect = placement.grid_rect;
+                let (row, rows) = clip(rect.origin.row, rect.rows, offset, rows)?;
+                let (col, cols) = clip(rect.origin.col, rect.cols, 0, cols)?;
+                Some(ReviewImage {
+                    image: placement.clone(),
+                    row,
+                    col,
+                    rows,
+                    cols,
+                    data: media.image_data(placement.image_id)?.clone(),
+                })
+            })
+            .collect();
+        images.sort_by_key(|image| (image.row, image.col, std::cmp::Reverse(image.image.z_index)));
+        images
+    }
+
     /// Runs work against the live drawing screen, then returns to the review
     /// viewport. Screen diffing and application-cursor tracking must not read
     /// whichever historical page the review cursor happens to be on.
@@ -2062,6 +2165,7 @@ impl View {
             self.freeze_current_accessibility();
         }
         TerminalEngine::resize_with_geometry(&mut self.engine, geometry);
+        self.review_images_cache = None;
         if self.presentation_tracking {
             self.advance_live_revision();
             self.live_history_revision = self
@@ -2478,6 +2582,18 @@ impl View {
         self.screen().contents_full_into(out);
     }
 
+    /// Sets the cells [`Self::full_contents_cached`] leaves out. Both cached
+    /// screens are rebuilt when the set changes, so they stay comparable.
+    pub(crate) fn set_auto_read_exclusions(&mut self, exclusions: Vec<ScreenRect>) {
+        if exclusions == self.auto_read_exclusions {
+            return;
+        }
+        self.auto_read_exclusions = exclusions;
+        self.invalidate_visible_cache();
+        self.cached_prev_full_valid = false;
+        self.cached_prev_full_row_hashes.clear();
+    }
+
     pub fn full_contents_cached(&mut self) -> (&str, &str, &[u64], &[u64]) {
         self.ensure_cached_full();
         self.ensure_cached_prev_full();
@@ -2494,7 +2610,8 @@ impl View {
             return;
         }
         let mut cached_full = std::mem::take(&mut self.cached_full);
-        self.screen().contents_full_into(&mut cached_full);
+        self.screen()
+            .contents_full_excluding_into(&mut cached_full, &self.auto_read_exclusions);
         compute_row_hashes(&cached_full, &mut self.cached_full_row_hashes);
         self.cached_full = cached_full;
         self.cached_full_valid = true;
@@ -2505,7 +2622,8 @@ impl View {
             return;
         }
         let mut cached_prev_full = std::mem::take(&mut self.cached_prev_full);
-        self.prev_screen.contents_full_into(&mut cached_prev_full);
+        self.prev_screen
+            .contents_full_excluding_into(&mut cached_prev_full, &self.auto_read_exclusions);
         compute_row_hashes(&cached_prev_full, &mut self.cached_prev_full_row_hashes);
         self.cached_prev_full = cached_prev_full;
         self.cached_prev_full_valid = true;
@@ -0,0 +1,233 @@
+use super::{
+    Result, ViewAction, ViewController, ViewKind,
+    filter_list::{FilterList, ListInput, ListItem},
+};
+use crate::{keymap::InputMode, screen_reader::ScreenReader, terminal_input::KeyInput, view::View};
+use std::{any::Any, io::Write};
+
+/// What choosing an entry of the bindings list runs once the list closes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BindingTarget {
+    /// A Lector binding, looked up again so a binding changed since the list
+    /// opened runs as it now is.
+    Lector { mode: InputMode, key: String },
+    /// Keys typed into the Review overlay under the list.
+    Review(String),
+    /// A binding discovered from the tmux server.
+    Tmux { connection_id: u64, command: String },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingItem {
+    pub key: String,
+    pub help: String,
+    pub target: BindingTarget,
+}
+
+/// Every binding in effect where the list was opened. Typing filters by key
+/// or description; Enter closes the list and runs the selected binding.
+pub struct BindingsView {
+    list: FilterList<BindingTarget>,
+}
+
+impl BindingsView {
+    #[must_use]
+    pub fn new(rows: u16, cols: u16, items: Vec<BindingItem>) -> Self {
+        let mut list = FilterList::new(
+            rows,
+            cols,
+            "no matching bindings",
+            "Up/Down select, Enter run, Escape close",
+        );
+        list.set_items(
+            items
+                .into_iter()
+                .map(|item| ListItem {
+                    label: format!("{}, {}", item.key, item.help),
+                    value: item.target,
+                })
+                .collect(),
+        );
+        Self { list }
+    }
+
+    fn finish(&self, input: ListInput) -> ViewAction {
+        match input {
+            ListInput::Choose => self.list.selected().map_or(ViewAction::Bell, |target| {
+                ViewAction::RunBinding(target.clone())
+            }),
+            ListInput::Cancel => ViewAction::Pop,
+            ListInput::Handled(action) => action,
+        }
+    }
+}
+
+impl ViewController for BindingsView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn model(&mut self) -> &mut View {
+        self.list.model()
+    }
+
+    fn title(&self) -> &str {
+        "key bindings"
+    }
+
+    fn kind(&self) -> ViewKind {
+        ViewKind::Bindings
+    }
+
+    fn handle_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        input: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        let input = self.list.handle_input(sr, input)?;
+        Ok(self.finish(input))
+    }
+
+    fn handle_key_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        key: &KeyInput,
+        _raw: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        let input = self.list.handle_key_input(sr, key)?;
+        Ok(self.finish(input))
+    }
+
+    fn handle_paste(
+        &mut self,
+        sr: &mut ScreenReader,
+        contents: &str,
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        let input = self.list.handle_paste(sr, contents)?;
+        Ok(self.finish(input))
+    }
+
+    fn on_resize(&mut self, rows: u16, cols: u16) {
+        self.list.on_resize(rows, cols);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BindingItem, BindingTarget, BindingsView};
+    use crate::{
+        keymap::InputMode,
+        screen_reader::ScreenReader,
+        speech,
+        views::{ViewAction, ViewController},
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingDriver(Rc<RefCell<Vec<String>>>);
+
+    impl speech::Driver for RecordingDriver {
+        fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(text.to_owned());
+            Ok(())
+        }
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn item(key: &str, help: &str, target: BindingTarget) -> BindingItem {
+        BindingItem {
+            key: key.to_owned(),
+            help: help.to_owned(),
+            target,
+        }
+    }
+
+    #[test]
+    fn filters_bindings_and_runs_the_selected_one() {
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        let mut bindings = BindingsView::new(
+            6,
+            40,
+            vec![
+                item(
+                    "F1",
+                    "toggle help",
+                    BindingTarget::Lector {
+                        mode: InputMode::Normal,
+                        key: "F1".to_owned(),
+                    },
+                ),
+                item(
+                    "gg",
+                    "top of document",
+                    BindingTarget::Review("gg".to_owned()),
+                ),
+                item(
+                    "tmux prefix c",
+                    "new-window",
+                    BindingTarget::Tmux {
+                        connection_id: 1,
+                        command: "new-window".to_owned(),
+                    },
+                ),
+            ],
+        );
+        let contents = bindings.model().contents_full();
+        assert!(contents.contains("F1, toggle help"));
+        assert!(contents.contains("tmux prefix c, new-window"));
+
+        bindings
+            .handle_input(&mut sr, b"WIND", &mut Vec::new())
+            .unwrap();
+        bindings
+            .handle_input(&mut sr, b"zz", &mut Vec::new())
+            .unwrap();
+        assert!(matches!(
+            bindings
+                .handle_input(&mut sr, b"\r", &mut Vec::new())
+                .unwrap(),
+            ViewAction::Bell
+        ));
+        bindings
+            .handle_input(&mut sr, b"\x7f\x7f\x7f\x7f\x7f\x7f", &mut Vec::new())
+            .unwrap();
+        bindings
+            .handle_input(&mut sr, b"\x1b[B", &mut Vec::new())
+            .unwrap();
+        let ViewAction::RunBinding(target) = bindings
+            .handle_input(&mut sr, b"\r", &mut Vec::new())
+            .unwrap()
+        else {
+            panic!("expected a binding to run");
+        };
+        assert_eq!(target, BindingTarget::Review("gg".to_owned()));
+        assert_eq!(
+            output.borrow().first().map(String::as_str),
+            Some("tmux prefix c, new-window")
+        );
+        assert!(
+            output
+                .borrow()
+                .iter()
+                .any(|text| text == "no matching bindings")
+        );
+        assert_eq!(output.borrow().last().unwrap(), "gg, top of document");
+    }
+}
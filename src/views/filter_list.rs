@@ -0,0 +1,290 @@
+use super::{
+    Result, ViewAction,
+    text_input::{truncate_display_width, visible_input_window},
+};
+use crate::{
+    line_editor::{EditorAction, LineEditor},
+    screen_reader::ScreenReader,
+    terminal_input::KeyInput,
+    view::View,
+};
+use terminput::KeyCode;
+
+/// An entry of a [`FilterList`]: the text shown, searched and spoken, and
+/// what choosing it refers to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct ListItem<T> {
+    pub label: String,
+    pub value: T,
+}
+
+/// What a key did to a [`FilterList`]. The list has already spoken and
+/// redrawn itself; choosing and cancelling are left to the view around it.
+pub(super) enum ListInput {
+    Choose,
+    Cancel,
+    Handled(ViewAction),
+}
+
+/// A search field over a list of entries, shared by the choosers. Typing
+/// filters entries by their labels, Up and Down move among the matches, and
+/// the rows scroll to keep the selection and the key hint visible.
+pub(super) struct FilterList<T> {
+    view: View,
+    items: Vec<ListItem<T>>,
+    editor: LineEditor,
+    selected: Option<usize>,
+    viewport_start: usize,
+    empty_text: &'static str,
+    hint: &'static str,
+}
+
+impl<T: PartialEq> FilterList<T> {
+    pub(super) fn new(rows: u16, cols: u16, empty_text: &'static str, hint: &'static str) -> Self {
+        let mut list = Self {
+            view: View::new(rows, cols),
+            items: Vec::new(),
+            editor: LineEditor::new(),
+            selected: None,
+            viewport_start: 0,
+            empty_text,
+            hint,
+        };
+        list.render();
+        list
+    }
+
+    pub(super) fn model(&mut self) -> &mut View {
+        &mut self.view
+    }
+
+    /// Replaces the entries, keeping the selected one selected while it is
+    /// still listed and matches the search.
+    pub(super) fn set_items(&mut self, items: Vec<ListItem<T>>) {
+        self.selected = self
+            .selected()
+            .and_then(|selected| items.iter().position(|item| item.value == *selected));
+        self.items = items;
+        self.reconcile_selection();
+        self.render();
+    }
+
+    /// Selects the entry for `value` if it matches the search.
+    pub(super) fn select(&mut self, value: &T) {
+        if let Some(index) = self
+            .matching_indices()
+            .into_iter()
+            .find(|index| self.items[*index].value == *value)
+        {
+            self.selected = Some(index);
+            self.render();
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub(super) fn selected(&self) -> Option<&T> {
+        self.selected_item().map(|item| &item.value)
+    }
+
+    fn selected_item(&self) -> Option<&ListItem<T>> {
+        self.items.get(self.selected?)
+    }
+
+    fn matching_indices(&self) -> Vec<usize> {
+        let query = self.editor.input().to_lowercase();
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| query.is_empty() || item.label.to_lowercase().contains(&query))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    fn reconcile_selection(&mut self) {
+        let matching = self.matching_indices();
+        self.selected = self
+            .selected
+            .filter(|selected| matching.contains(selected))
+            .or_else(|| matching.first().copied());
+    }
+
+    fn move_selection(&mut self, delta: isize) -> bool {
+        let matching = self.matching_indices();
+        let Some(index) = self
+            .selected
+            .and_then(|selected| matching.iter().position(|index| *index == selected))
+        else {
+            self.selected = matching.first().copied();
+            return self.selected.is_some();
+        };
+        let next = index.saturating_add_signed(delta);
+        if next >= matching.len() || next == index {
+            return false;
+        }
+        self.selected = Some(matching[next]);
+        true
+    }
+
+    fn speak_selected(&self, sr: &mut ScreenReader) -> Result<()> {
+        match self.selected_item() {
+            Some(item) => sr.speak(&item.label, false)?,
+            None => sr.speak(self.empty_text, false)?,
+        }
+        Ok(())
+    }
+
+    fn apply_editor_action(
+        &mut self,
+        sr: &mut ScreenReader,
+        action: EditorAction,
+    ) -> Result<ListInput> {
+        Ok(match action {
+            EditorAction::Changed => {
+                self.reconcile_selection();
+                self.render();
+                self.speak_selected(sr)?;
+                ListInput::Handled(ViewAction::Redraw)
+            }
+            EditorAction::Submit => ListInput::Choose,
+            EditorAction::Bell => ListInput::Handled(ViewAction::Bell),
+            EditorAction::None => ListInput::Handled(ViewAction::None),
+        })
+    }
+
+    fn move_and_announce(&mut self, sr: &mut ScreenReader, delta: isize) -> Result<ListInput> {
+        if !self.move_selection(delta) {
+            return Ok(ListInput::Handled(ViewAction::Bell));
+        }
+        self.render();
+        self.speak_selected(sr)?;
+        Ok(ListInput::Handled(ViewAction::Redraw))
+    }
+
+    pub(super) fn handle_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        input: &[u8],
+    ) -> Result<ListInput> {
+        match input {
+            b"\x1b" => Ok(ListInput::Cancel),
+            b"\r" | b"\n" => Ok(ListInput::Choose),
+            b"\x1b[A" => self.move_and_announce(sr, -1),
+            b"\x1b[B" => self.move_and_announce(sr, 1),
+            _ => {
+                let action = self.editor.handle_bytes(input);
+                self.apply_editor_action(sr, action)
+            }
+        }
+    }
+
+    pub(super) fn handle_key_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        key: &KeyInput,
+    ) -> Result<ListInput> {
+        if key.is_release() {
+            return Ok(ListInput::Handled(ViewAction::None));
+        }
+        match key.event().code {
+            KeyCode::Esc => Ok(ListInput::Cancel),
+            KeyCode::Enter => Ok(ListInput::Choose),
+            KeyCode::Up => self.move_and_announce(sr, -1),
+            KeyCode::Down => self.move_and_announce(sr, 1),
+            _ => {
+                let action = self.editor.handle_key_input(key);
+                self.apply_editor_action(sr, action)
+            }
+        }
+    }
+
+    pub(super) fn handle_paste(
+        &mut self,
+        sr: &mut ScreenReader,
+        contents: &str,
+    ) -> Result<ListInput> {
+        let action = self.editor.handle_text(contents);
+        self.apply_editor_action(sr, action)
+    }
+
+    pub(super) fn on_resize(&mut self, rows: u16, cols: u16) {
+        self.view.set_size(rows, cols);
+        self.render();
+    }
+
+    fn render(&mut self) {
+        let (rows, cols) = self.view.size();
+        let (visible_query, query_cursor_width) = visible_input_window(
+            self.editor.input(),
+            self.editor.cursor(),
+            usize::from(cols).saturating_sub(8),
+        );
+        let mut lines = vec![format!("search: {visible_query}")];
+        let item_capacity = usize::from(rows).saturating_sub(2);
+        let matching = self.matching_indices();
+        let selected_index = self
+            .selected
+            .and_then(|selected| matching.iter().position(|index| *index == selected));
+        if item_capacity == 0 {
+            self.viewport_start = 0;
+        } else {
+            let max_start = matching.len().saturating_sub(item_capacity);
+            self.viewport_start = self.viewport_start.min(max_start);
+            if let Some(selected_index) = selected_index {
+                if selected_index < self.viewport_start {
+                    self.viewport_start = selected_index;
+                } else if selected_index >= self.viewport_start.saturating_add(item_capacity) {
+                    self.viewport_start = selected_index
+                        .saturating_add(1)
+                        .saturating_sub(item_capacity)
+                        .min(max_start);
+                }
+            }
+        }
+        if matching.is_empty() && item_capacity > 0 {
+            lines.push(self.empty_text.to_owned());
+        } else {
+            lines.extend(
+                matching
+                    .iter()
+                    .skip(self.viewport_start)
+                    .take(item_capacity)
+                    .map(|index| self.items[*index].label.clone()),
+            );
+        }
+        if rows > 1 {
+            lines.push(self.hint.to_owned());
+        }
+        let mut bytes = b"\x1b[2J\x1b[H".to_vec();
+        for (index, line) in lines.into_iter().take(usize::from(rows)).enumerate() {
+            if index > 0 {
+                bytes.extend_from_slice(b"\r\n");
+            }
+            bytes.extend_from_slice(truncate_display_width(&line, usize::from(cols)).as_bytes());
+        }
+        let selected_cursor_row = selected_index
+            .filter(|index| {
+                *index >= self.viewport_start
+                    && *index < self.viewport_start.saturating_add(item_capacity)
+            })
+            .map(|index| index.saturating_sub(self.viewport_start).saturating_add(2));
+        let (cursor_row, cursor_col) = selected_cursor_row.map_or_else(
+            || {
+                (
+                    1,
+                    query_cursor_width
+                        .saturating_add(9)
+                        .min(usize::from(cols))
+                        .max(1),
+                )
+            },
+            |row| (row, 1),
+        );
+        bytes.extend_from_slice(format!("\x1b[{cursor_row};{cursor_col}H").as_bytes());
+        self.view.clear_update_summary();
+        self.view.process_changes(&bytes);
+        self.view.clear_update_summary();
+    }
+}
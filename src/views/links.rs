@@ -0,0 +1,219 @@
+use super::{
+    Result, ViewAction, ViewController, ViewKind,
+    filter_list::{FilterList, ListInput, ListItem},
+};
+use crate::{
+    review::document::ReviewDocument, screen_reader::ScreenReader, terminal_input::KeyInput,
+    view::View,
+};
+use std::{any::Any, io::Write};
+
+/// Every OSC 8 link in a frozen capture of a view and its scrollback.
+/// Typing filters by text or target; Enter runs the link opener and `C-y`
+/// copies the selected target.
+pub struct LinksView {
+    list: FilterList<String>,
+}
+
+impl LinksView {
+    pub fn new(source: &mut View) -> Self {
+        let (document, _, _) = ReviewDocument::capture(source);
+        let (rows, cols) = source.size();
+        let mut list = FilterList::new(
+            rows,
+            cols,
+            "no matching links",
+            "Up/Down select, Enter open, C-y copy, Escape close",
+        );
+        list.set_items(
+            document
+                .links()
+                .iter()
+                .map(|link| ListItem {
+                    label: if link.text.is_empty() || link.text == link.uri {
+                        link.uri.clone()
+                    } else {
+                        format!("{}, {}", link.text, link.uri)
+                    },
+                    value: link.uri.clone(),
+                })
+                .collect(),
+        );
+        Self { list }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    fn open(&self, sr: &mut ScreenReader) -> Result<ViewAction> {
+        let Some(uri) = self.list.selected() else {
+            return Ok(ViewAction::Bell);
+        };
+        sr.open_link(uri)?;
+        Ok(ViewAction::None)
+    }
+
+    fn copy(&self, sr: &mut ScreenReader) -> Result<ViewAction> {
+        let Some(uri) = self.list.selected() else {
+            return Ok(ViewAction::Bell);
+        };
+        sr.copy_link(uri)?;
+        Ok(ViewAction::None)
+    }
+
+    fn finish(&self, sr: &mut ScreenReader, input: ListInput) -> Result<ViewAction> {
+        match input {
+            ListInput::Choose => self.open(sr),
+            ListInput::Cancel => Ok(ViewAction::Pop),
+            ListInput::Handled(action) => Ok(action),
+        }
+    }
+}
+
+impl ViewController for LinksView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn model(&mut self) -> &mut View {
+        self.list.model()
+    }
+
+    fn title(&self) -> &str {
+        "links"
+    }
+
+    fn kind(&self) -> ViewKind {
+        ViewKind::Links
+    }
+
+    fn handle_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        input: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        if input == b"\x19" {
+            return self.copy(sr);
+        }
+        let input = self.list.handle_input(sr, input)?;
+        self.finish(sr, input)
+    }
+
+    fn handle_key_input(
+        &mut self,
+        sr: &mut ScreenReader,
+        key: &KeyInput,
+        _raw: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        if !key.is_release() && key.control_code() == Some(0x19) {
+            return self.copy(sr);
+        }
+        let input = self.list.handle_key_input(sr, key)?;
+        self.finish(sr, input)
+    }
+
+    fn handle_paste(
+        &mut self,
+        sr: &mut ScreenReader,
+        contents: &str,
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        let input = self.list.handle_paste(sr, contents)?;
+        self.finish(sr, input)
+    }
+
+    fn on_resize(&mut self, rows: u16, cols: u16) {
+        self.list.on_resize(rows, cols);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinksView;
+    use crate::{
+        screen_reader::ScreenReader,
+        speech,
+        view::View,
+        views::{ViewAction, ViewController},
+    };
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingDriver(Rc<RefCell<Vec<String>>>);
+
+    impl speech::Driver for RecordingDriver {
+        fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            self.0.borrow_mut().push(text.to_owned());
+            Ok(())
+        }
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn lists_filters_copies_and_opens_links() {
+        let mut source = View::new(5, 40);
+        source.process_changes(
+            b"\x1b]8;;https://a.example\x1b\\alpha\x1b]8;;\x1b\\\r\nmiddle\r\n\x1b]8;;https://b.example\x1b\\https://b.example\x1b]8;;\x1b\\",
+        );
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        let mut links = LinksView::new(&mut source);
+        assert!(!links.is_empty());
+        let contents = links.model().contents_full();
+        assert!(contents.contains("alpha, https://a.example"));
+        assert!(contents.contains("https://b.example"));
+
+        assert!(matches!(
+            links
+                .handle_input(&mut sr, b"\x1b[B", &mut Vec::new())
+                .unwrap(),
+            ViewAction::Redraw
+        ));
+        assert!(matches!(
+            links
+                .handle_input(&mut sr, b"\x1b[B", &mut Vec::new())
+                .unwrap(),
+            ViewAction::Bell
+        ));
+        links
+            .handle_input(&mut sr, b"alp", &mut Vec::new())
+            .unwrap();
+        links
+            .handle_input(&mut sr, b"\x19", &mut Vec::new())
+            .unwrap();
+        links.handle_input(&mut sr, b"\r", &mut Vec::new()).unwrap();
+        assert_eq!(sr.clipboard_text(), Some("https://a.example"));
+        assert_eq!(
+            output.borrow().as_slice(),
+            [
+                "https://b.example",
+                "alpha, https://a.example",
+                "copied link",
+                "no link opener configured",
+            ]
+        );
+        assert!(matches!(
+            links
+                .handle_input(&mut sr, b"\x1b", &mut Vec::new())
+                .unwrap(),
+            ViewAction::Pop
+        ));
+    }
+}
@@ -10,11 +10,26 @@ use mlua::{
     Error as LuaError, Function, HookTriggers, Lua, LuaOptions, MultiValue, StdLib, Table, Thread,
     ThreadStatus, Value, VmState,
 };
-use std::{any::Any, cell::RefCell, io::Write, rc::Rc};
+use std::{
+    any::Any,
+    cell::RefCell,
+    collections::BTreeSet,
+    ffi::c_void,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    rc::{Rc, Weak},
+};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
 const CLOSE_HINT: &str = "Esc to close";
+/// Entries kept in the history file.
+const HISTORY_LIMIT: usize = 1000;
+/// Nested tables deeper than this print as `{...}`.
+const TABLE_DEPTH_LIMIT: usize = 4;
+/// Entries printed from one table before the rest are counted.
+const TABLE_ENTRY_LIMIT: usize = 200;
 
 struct ReplOutput {
     lines: Vec<String>,
@@ -35,6 +50,7 @@ struct LuaReplState {
     thread: Option<Thread>,
     print_buffer: Rc<RefCell<ReplOutput>>,
     screen_reader_ptr: Rc<RefCell<*mut ScreenReader>>,
+    history_path: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -79,18 +95,118 @@ impl LuaReplSession {
 
         let mut editor = LineEditor::new();
         editor.set_history(history);
-        Ok(Self {
-            state: Rc::new(RefCell::new(LuaReplState {
-                transcript: initial_transcript(),
-                editor,
-                pending_lines: Vec::new(),
-                lua,
-                env,
-                thread: None,
-                print_buffer,
-                screen_reader_ptr,
-            })),
-        })
+        let state = Rc::new(RefCell::new(LuaReplState {
+            transcript: initial_transcript(),
+            editor,
+            pending_lines: Vec::new(),
+            lua: lua.clone(),
+            env,
+            thread: None,
+            print_buffer,
+            screen_reader_ptr,
+            history_path: None,
+        }));
+        install_transcript_api(&lua, Rc::downgrade(&state)).map_err(Error::lua)?;
+        Ok(Self { state })
+    }
+
+    /// `$XDG_STATE_HOME/lector/repl_history`, or the platform local data
+    /// directory.
+    pub fn default_history_path() -> Option<PathBuf> {
+        let directory = dirs::state_dir().or_else(dirs::data_local_dir)?;
+        Some(directory.join("lector").join("repl_history"))
+    }
+
+    /// A session whose history is read from `path` and written back after
+    /// every new entry. A file that can't be read is reported in the
+    /// transcript and left untouched.
+    pub fn with_history_file(path: PathBuf) -> Result<Self> {
+        match read_history(&path) {
+            Ok(history) => {
+                let session = Self::new(history)?;
+                session.state.borrow_mut().history_path = Some(path);
+                Ok(session)
+            }
+            Err(err) => {
+                let session = Self::new(Vec::new())?;
+                session
+                    .state
+                    .borrow_mut()
+                    .push_output(&format!("Error: read {}: {err}", path.display()));
+                Ok(session)
+            }
+        }
+    }
+
+    /// Evaluates the Lua file at `path` in the REPL environment. The file
+    /// runs on the next ticks, like submitted input; the returned lines are
+    /// what was added to the transcript before it starts.
+    pub fn load_file(&self, path: &str) -> Vec<String> {
+        let path = expand_home(path);
+        let mut state = self.state.borrow_mut();
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(err) => {
+                return state.push_output(&format!("Error: read {}: {err}", path.display()));
+            }
+        };
+        let func = state
+            .lua
+            .load(&source)
+            .set_name(path.display().to_string())
+            .set_environment(state.env.clone())
+            .into_function();
+        let started = func.and_then(|func| state.start_eval(func));
+        match started {
+            Ok(()) => state.push_output(&format!("loading {}", path.display())),
+            Err(err) => state.push_output(&format!("Error: {err}")),
+        }
+    }
+}
+
+impl LuaReplState {
+    fn push_output(&mut self, text: &str) -> Vec<String> {
+        let mut added = Vec::new();
+        for line in text.split('\n') {
+            let line = line.to_string();
+            self.transcript.push(TranscriptLine::Output(line.clone()));
+            added.push(line);
+        }
+        trim_transcript(&mut self.transcript);
+        added
+    }
+
+    fn push_output_lines(&mut self, lines: &[String]) {
+        self.transcript
+            .extend(lines.iter().cloned().map(TranscriptLine::Output));
+        trim_transcript(&mut self.transcript);
+    }
+
+    fn start_eval(&mut self, func: Function) -> mlua::Result<()> {
+        let thread = self.lua.create_thread(func)?;
+        thread.set_hook(
+            HookTriggers::new().every_nth_instruction(1000),
+            |_lua, _debug| Ok(VmState::Yield),
+        )?;
+        self.thread = Some(thread);
+        Ok(())
+    }
+
+    /// Writes the transcript as plain text to `path`, or to a new file in
+    /// the transcripts directory, returning where it went.
+    fn save_transcript(&self, path: Option<&str>) -> io::Result<(PathBuf, usize)> {
+        let path = match path {
+            Some(path) => expand_home(path),
+            None => crate::review::export::default_transcript_path()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no state directory"))?,
+        };
+        let mut text = String::new();
+        for line in &self.transcript {
+            text.push_str(&transcript_line_text(line));
+            text.push('\n');
+        }
+        crate::review::export::write_new_file(&path, text.as_bytes())?;
+        Ok((path, self.transcript.len()))
     }
 }
 
@@ -130,15 +246,12 @@ impl LuaReplView {
     }
 
     fn append_output(&mut self, text: &str) -> Vec<String> {
-        let mut added = Vec::new();
-        let mut state = self.session.state.borrow_mut();
-        for line in text.split('\n') {
-            let line = line.to_string();
-            state.transcript.push(TranscriptLine::Output(line.clone()));
-            added.push(line);
-        }
-        trim_transcript(&mut state.transcript);
-        added
+        self.session.state.borrow_mut().push_output(text)
+    }
+
+    fn append_output_lines(&mut self, lines: Vec<String>) -> Vec<String> {
+        self.session.state.borrow_mut().push_output_lines(&lines);
+        lines
     }
 
     fn drain_print_buffer(&mut self) -> Vec<String> {
@@ -149,11 +262,7 @@ impl LuaReplView {
             .drain(..)
             .collect::<Vec<_>>();
         if !added.is_empty() {
-            let mut state = self.session.state.borrow_mut();
-            state
-                .transcript
-                .extend(added.iter().cloned().map(TranscriptLine::Output));
-            trim_transcript(&mut state.transcript);
+            self.session.state.borrow_mut().push_output_lines(&added);
         }
         added
     }
@@ -273,42 +382,38 @@ impl LuaReplView {
     }
 
     fn start_eval(&mut self, func: Function) -> Result<()> {
-        let lua = self.session.state.borrow().lua.clone();
-        let thread = lua.create_thread(func).map_err(Error::lua)?;
-        thread
-            .set_hook(
-                HookTriggers::new().every_nth_instruction(1000),
-                |_lua, _debug| Ok(VmState::Yield),
-            )
-            .map_err(Error::lua)?;
-        self.session.state.borrow_mut().thread = Some(thread);
-        Ok(())
+        self.session
+            .state
+            .borrow_mut()
+            .start_eval(func)
+            .map_err(Error::lua)
     }
 
-    fn resume_eval(&mut self) -> Result<(bool, Vec<String>)> {
+    /// Resumes the running chunk. Once it finishes, its results are added
+    /// to the transcript, with a spoken summary when a table spans lines.
+    fn resume_eval(&mut self) -> Result<(bool, Vec<String>, Option<String>)> {
         let thread = self.session.state.borrow().thread.clone();
         let Some(thread) = thread else {
-            return Ok((false, Vec::new()));
+            return Ok((false, Vec::new(), None));
         };
         match thread.resume::<MultiValue>(()) {
             Ok(values) => {
                 let mut added = Vec::new();
+                let mut summary = None;
                 if thread.status() == ThreadStatus::Finished {
                     if !values.is_empty() {
-                        let mut pieces = Vec::new();
-                        for value in values {
-                            pieces.push(format_value(value));
-                        }
-                        added = self.append_output(&pieces.join("\t"));
+                        let (lines, result_summary) = format_results(values);
+                        added = self.append_output_lines(lines);
+                        summary = result_summary;
                     }
                     self.session.state.borrow_mut().thread = None;
                 }
-                Ok((true, added))
+                Ok((true, added, summary))
             }
             Err(err) => {
                 let added = self.append_output(&format!("Error: {}", err));
                 self.session.state.borrow_mut().thread = None;
-                Ok((true, added))
+                Ok((true, added, None))
             }
         }
     }
@@ -341,7 +446,7 @@ impl LuaReplView {
         ViewAction::Redraw
     }
 
-    fn submit_input(&mut self) -> Result<ViewAction> {
+    fn submit_input(&mut self, sr: &ScreenReader) -> Result<ViewAction> {
         let (line, source, pending_empty) = {
             let state = self.session.state.borrow();
             let line = state.editor.input().to_string();
@@ -355,6 +460,17 @@ impl LuaReplView {
         if pending_empty && line.trim().is_empty() {
             return Ok(ViewAction::Bell);
         }
+        if pending_empty && let Some((command, argument)) = repl_command(&line) {
+            let (command, argument) = (command.to_string(), argument.map(str::to_string));
+            self.write_bytes(b"\r\n");
+            self.commit_submission(&source, line);
+            let added = self.run_command(sr, &command, argument.as_deref());
+            self.write_output_lines(&added);
+            if self.session.state.borrow().thread.is_none() {
+                self.write_prompt();
+            }
+            return Ok(ViewAction::Redraw);
+        }
 
         match self.classify_input(&source)? {
             CompileOutcome::Incomplete => {
@@ -387,9 +503,45 @@ impl LuaReplView {
         }
     }
 
+    /// Runs a `:` command typed at the main prompt.
+    fn run_command(
+        &mut self,
+        sr: &ScreenReader,
+        command: &str,
+        argument: Option<&str>,
+    ) -> Vec<String> {
+        match command {
+            "save" => {
+                let saved = self.session.state.borrow().save_transcript(argument);
+                match saved {
+                    Ok((path, lines)) => self.append_output(&format!(
+                        "saved {lines} {} to {}",
+                        if lines == 1 { "line" } else { "lines" },
+                        path.display()
+                    )),
+                    Err(err) => self.append_output(&format!("Error: save transcript: {err}")),
+                }
+            }
+            "load" => match argument.or(sr.repl_scratch_file()) {
+                Some(path) => self.session.load_file(path),
+                None => self.append_output("Error: no file to load and no repl_scratch_file"),
+            },
+            _ => self.append_output(&format!(
+                "Error: unknown command :{command}; commands are :save and :load"
+            )),
+        }
+    }
+
     fn commit_submission(&mut self, source: &str, line: String) {
         let mut state = self.session.state.borrow_mut();
+        let history_len = state.editor.history().len();
         state.editor.commit_history_entry(source);
+        let saved = match &state.history_path {
+            Some(path) if state.editor.history().len() != history_len => {
+                write_history(path, state.editor.history())
+            }
+            _ => Ok(()),
+        };
         let pending = std::mem::take(&mut state.pending_lines);
         for (index, text) in pending.into_iter().chain(std::iter::once(line)).enumerate() {
             state.transcript.push(TranscriptLine::Input {
@@ -399,13 +551,80 @@ impl LuaReplView {
         }
         trim_transcript(&mut state.transcript);
         state.editor.clear();
+        drop(state);
         self.rendered_input.clear();
         self.rendered_cursor = 0;
+        if let Err(err) = saved {
+            let added = self.append_output(&format!("Error: save REPL history: {err}"));
+            self.write_output_lines(&added);
+        }
+    }
+
+    /// Completes the dotted Lua name before the cursor from the tables it
+    /// names. When several names share no longer prefix, they are listed
+    /// above the prompt.
+    fn complete(&mut self) -> ViewAction {
+        let (candidates, prefix_len) = {
+            let state = self.session.state.borrow();
+            let before_cursor = state
+                .editor
+                .input()
+                .graphemes(true)
+                .take(state.editor.cursor())
+                .collect::<String>();
+            let name = completion_name(&before_cursor);
+            if name.is_empty() {
+                return ViewAction::Bell;
+            }
+            let (table_path, prefix) = name.rsplit_once('.').unwrap_or(("", name));
+            let candidates = completion_candidates(&state.env, table_path, prefix);
+            (candidates, prefix.len())
+        };
+        let Some(first) = candidates.first() else {
+            return ViewAction::Bell;
+        };
+        // Candidates are ASCII identifiers, so byte prefixes are char prefixes.
+        let common = candidates.iter().fold(first.as_str(), |common, candidate| {
+            let shared = common
+                .bytes()
+                .zip(candidate.bytes())
+                .take_while(|(a, b)| a == b)
+                .count();
+            &common[..shared]
+        });
+        if common.len() > prefix_len {
+            let completion = common[prefix_len..].to_string();
+            let action = self
+                .session
+                .state
+                .borrow_mut()
+                .editor
+                .handle_text(&completion);
+            if matches!(action, EditorAction::Changed) && !self.try_append_input() {
+                self.apply_editor_update();
+            }
+            return ViewAction::Redraw;
+        }
+        if candidates.len() == 1 {
+            return ViewAction::Bell;
+        }
+        self.write_bytes(b"\r\n");
+        let added = self.append_output(&candidates.join("  "));
+        self.write_output_lines(&added);
+        self.write_prompt();
+        if !self.try_append_input() {
+            self.apply_editor_update();
+        }
+        ViewAction::Redraw
     }
 
-    fn apply_editor_action(&mut self, action: EditorAction) -> Result<ViewAction> {
+    fn apply_editor_action(
+        &mut self,
+        sr: &ScreenReader,
+        action: EditorAction,
+    ) -> Result<ViewAction> {
         match action {
-            EditorAction::Submit => self.submit_input(),
+            EditorAction::Submit => self.submit_input(sr),
             EditorAction::Changed => {
                 if !self.try_append_input() {
                     self.apply_editor_update();
@@ -467,8 +686,11 @@ impl ViewController for LuaReplView {
         if input == b"\x15" {
             return Ok(self.clear_current_line());
         }
+        if input == b"\t" {
+            return Ok(self.complete());
+        }
         let action = self.session.state.borrow_mut().editor.handle_bytes(input);
-        self.apply_editor_action(action)
+        self.apply_editor_action(sr, action)
     }
 
     fn handle_key_input(
@@ -501,8 +723,14 @@ impl ViewController for LuaReplView {
         if matches!(key.control_code(), Some(0x15)) {
             return Ok(self.clear_current_line());
         }
+        let event = key.normalized_event();
+        if matches!(key.control_code(), Some(0x09))
+            || (event.code == terminput::KeyCode::Tab && event.modifiers.is_empty())
+        {
+            return Ok(self.complete());
+        }
         let action = self.session.state.borrow_mut().editor.handle_key_input(key);
-        self.apply_editor_action(action)
+        self.apply_editor_action(sr, action)
     }
 
     fn handle_paste(
@@ -522,7 +750,7 @@ impl ViewController for LuaReplView {
             .borrow_mut()
             .editor
             .handle_text(&contents);
-        self.apply_editor_action(action)
+        self.apply_editor_action(sr, action)
     }
 
     fn tick(&mut self, sr: &mut ScreenReader, _pty_stream: &mut dyn Write) -> Result<ViewAction> {
@@ -530,7 +758,7 @@ impl ViewController for LuaReplView {
         if self.session.state.borrow().thread.is_none() {
             return Ok(ViewAction::None);
         }
-        let (progressed, added) = self.resume_eval()?;
+        let (progressed, added, summary) = self.resume_eval()?;
         let printed = self.drain_print_buffer();
         if !added.is_empty() {
             self.write_output_lines(&added);
@@ -538,10 +766,20 @@ impl ViewController for LuaReplView {
         if !printed.is_empty() {
             self.write_output_lines(&printed);
         }
-        if progressed {
-            if self.session.state.borrow().thread.is_none() {
-                self.write_prompt();
+        if progressed && self.session.state.borrow().thread.is_none() {
+            self.write_prompt();
+        }
+        if let Some(summary) = summary {
+            // A printed table can run for many lines. Speak its shape instead
+            // and leave the lines to review.
+            self.view.clear_update_summary();
+            if sr.auto_read_enabled() {
+                let mut spoken = printed;
+                spoken.push(summary);
+                sr.speak(&spoken.join("\n"), false)?;
             }
+        }
+        if progressed {
             return Ok(ViewAction::Redraw);
         }
         Ok(ViewAction::None)
@@ -619,6 +857,319 @@ fn trim_transcript(transcript: &mut Vec<TranscriptLine>) {
     }
 }
 
+/// Splits `:name argument` typed at the main prompt. A Lua `::label::`
+/// is not a command.
+fn repl_command(line: &str) -> Option<(&str, Option<&str>)> {
+    let rest = line.trim().strip_prefix(':')?;
+    if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let (command, argument) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let argument = argument.trim();
+    Some((command, (!argument.is_empty()).then_some(argument)))
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Reads one JSON string per line, so entries keep their line breaks. A
+/// missing file is an empty history.
+fn read_history(path: &Path) -> io::Result<Vec<String>> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    Ok(text
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn write_history(path: &Path, history: &[String]) -> io::Result<()> {
+    if let Some(directory) = path.parent()
+        && !directory.as_os_str().is_empty()
+    {
+        fs::create_dir_all(directory)?;
+    }
+    let mut text = String::new();
+    for entry in &history[history.len().saturating_sub(HISTORY_LIMIT)..] {
+        text.push_str(&serde_json::to_string(entry).map_err(io::Error::other)?);
+        text.push('\n');
+    }
+    // Write beside the history file first so an interrupted write never
+    // leaves it truncated.
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(".partial");
+    fs::write(&partial, text)?;
+    fs::rename(&partial, path)
+}
+
+/// `lector.repl.transcript()` and `lector.repl.save(path)` in the REPL.
+fn install_transcript_api(lua: &Lua, state: Weak<RefCell<LuaReplState>>) -> mlua::Result<()> {
+    let tbl_repl = lua.create_table()?;
+    let transcript_fn = lua.create_function({
+        let state = Weak::clone(&state);
+        move |_, ()| {
+            let state = state
+                .upgrade()
+                .ok_or_else(|| LuaError::external("REPL closed"))?;
+            let state = state.borrow();
+            Ok(state
+                .transcript
+                .iter()
+                .map(transcript_line_text)
+                .collect::<Vec<_>>())
+        }
+    })?;
+    let save_fn = lua.create_function(move |_, path: Option<String>| {
+        let state = state
+            .upgrade()
+            .ok_or_else(|| LuaError::external("REPL closed"))?;
+        let (path, _) = state
+            .borrow()
+            .save_transcript(path.as_deref())
+            .map_err(LuaError::external)?;
+        Ok(path.display().to_string())
+    })?;
+    tbl_repl.set("transcript", transcript_fn)?;
+    tbl_repl.set("save", save_fn)?;
+    lua.globals().get::<Table>("lector")?.set("repl", tbl_repl)
+}
+
+/// The dotted Lua name that ends `text`, such as `lector.api.sp`.
+fn completion_name(text: &str) -> &str {
+    let start = text
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+        .last()
+        .map_or(text.len(), |(index, _)| index);
+    let name = &text[start..];
+    if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        name
+    } else {
+        ""
+    }
+}
+
+/// Names in the table at `table_path` that start with `prefix`, including
+/// those an `__index` table supplies, as the globals behind the REPL
+/// environment do, and those the `lector` proxies answer. Lookups are raw, so
+/// completing never runs a metamethod that could loop or block Lector.
+fn completion_candidates(env: &Table, table_path: &str, prefix: &str) -> Vec<String> {
+    let mut next = Some(env.clone());
+    if !table_path.is_empty() {
+        for segment in table_path.split('.') {
+            if segment.is_empty() {
+                return Vec::new();
+            }
+            next = match next.and_then(|table| raw_lookup(table, segment)) {
+                Some(Value::Table(table)) => Some(table),
+                _ => None,
+            };
+        }
+    }
+    let mut names = lua::proxy_names(table_path)
+        .iter()
+        .filter(|name| name.starts_with(prefix))
+        .map(|name| name.to_string())
+        .collect::<BTreeSet<_>>();
+    for _ in 0..TABLE_DEPTH_LIMIT {
+        let Some(table) = next.take() else {
+            break;
+        };
+        for (key, _) in table.pairs::<Value, Value>().flatten() {
+            if let Value::String(key) = key
+                && let Ok(key) = key.to_str()
+                && key.starts_with(prefix)
+                && is_identifier(&key)
+            {
+                names.insert(key.to_string());
+            }
+        }
+        next = index_table(&table);
+    }
+    names.into_iter().collect()
+}
+
+/// `table[key]` looked up without metamethods, following `__index` tables
+/// but never calling an `__index` function.
+fn raw_lookup(table: Table, key: &str) -> Option<Value> {
+    let mut next = Some(table);
+    for _ in 0..TABLE_DEPTH_LIMIT {
+        let table = next.take()?;
+        match table.raw_get::<Value>(key) {
+            Ok(Value::Nil) | Err(_) => next = index_table(&table),
+            Ok(value) => return Some(value),
+        }
+    }
+    None
+}
+
+/// The table that `table`'s metatable sends missing keys to, if any.
+fn index_table(table: &Table) -> Option<Table> {
+    match table.metatable()?.raw_get::<Value>("__index") {
+        Ok(Value::Table(index)) => Some(index),
+        _ => None,
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Lines for a chunk's results, and what to speak instead of them when a
+/// table spreads over several lines.
+fn format_results(values: MultiValue) -> (Vec<String>, Option<String>) {
+    let multiline = values
+        .iter()
+        .any(|value| matches!(value, Value::Table(table) if !table_is_empty(table)));
+    if !multiline {
+        let pieces = values
+            .into_iter()
+            .map(|value| match value {
+                Value::Table(_) => "{}".to_string(),
+                value => format_value(value),
+            })
+            .collect::<Vec<_>>();
+        let lines = pieces.join("\t").split('\n').map(str::to_string).collect();
+        return (lines, None);
+    }
+    let mut lines = Vec::new();
+    let mut spoken = Vec::new();
+    for value in values {
+        match &value {
+            Value::Table(table) => {
+                spoken.push(table_summary(table));
+                write_table_value(&value, "", 0, "", &mut Vec::new(), &mut lines);
+            }
+            _ => {
+                let text = format_value(value);
+                spoken.push(text.clone());
+                lines.extend(text.split('\n').map(str::to_string));
+            }
+        }
+    }
+    (lines, Some(spoken.join(", ")))
+}
+
+fn table_is_empty(table: &Table) -> bool {
+    table.pairs::<Value, Value>().next().is_none()
+}
+
+/// "table, 3 entries"
+fn table_summary(table: &Table) -> String {
+    match table.pairs::<Value, Value>().count() {
+        1 => "table, 1 entry".to_string(),
+        count => format!("table, {count} entries"),
+    }
+}
+
+/// Writes `value` as Lua constructor syntax, one entry per line. `lead` is
+/// the entry's key, and `seen` holds the enclosing tables so that cycles
+/// print as `<cycle>`.
+fn write_table_value(
+    value: &Value,
+    lead: &str,
+    depth: usize,
+    trailer: &str,
+    seen: &mut Vec<*const c_void>,
+    lines: &mut Vec<String>,
+) {
+    let indent = "  ".repeat(depth);
+    let Value::Table(table) = value else {
+        lines.push(format!("{indent}{lead}{}{trailer}", literal(value)));
+        return;
+    };
+    if table_is_empty(table) {
+        lines.push(format!("{indent}{lead}{{}}{trailer}"));
+        return;
+    }
+    if seen.contains(&table.to_pointer()) {
+        lines.push(format!("{indent}{lead}<cycle>{trailer}"));
+        return;
+    }
+    if depth >= TABLE_DEPTH_LIMIT {
+        lines.push(format!("{indent}{lead}{{...}}{trailer}"));
+        return;
+    }
+
+    lines.push(format!("{indent}{lead}{{"));
+    seen.push(table.to_pointer());
+    let entries = table_entries(table);
+    let hidden = entries.len().saturating_sub(TABLE_ENTRY_LIMIT);
+    for (key, entry) in entries.into_iter().take(TABLE_ENTRY_LIMIT) {
+        let lead = match key {
+            Some(key) => format!("{key} = "),
+            None => String::new(),
+        };
+        write_table_value(&entry, &lead, depth + 1, ",", seen, lines);
+    }
+    if hidden > 0 {
+        lines.push(format!("{indent}  -- {hidden} more"));
+    }
+    seen.pop();
+    lines.push(format!("{indent}}}{trailer}"));
+}
+
+/// The sequence `1..n` first, without keys, then the other entries
+/// ordered by key.
+fn table_entries(table: &Table) -> Vec<(Option<String>, Value)> {
+    let sequence_len = table.raw_len() as i64;
+    let mut keyed = table
+        .pairs::<Value, Value>()
+        .flatten()
+        .filter(
+            |(key, _)| !matches!(key, Value::Integer(index) if (1..=sequence_len).contains(index)),
+        )
+        .collect::<Vec<_>>();
+    keyed.sort_by_cached_key(|(key, _)| match key {
+        Value::Integer(index) => (0, *index, String::new()),
+        Value::String(_) => (1, 0, format_value(key.clone())),
+        _ => (2, 0, literal(key)),
+    });
+    let mut entries = (1..=sequence_len)
+        .map(|index| (None, table.raw_get::<Value>(index).unwrap_or(Value::Nil)))
+        .collect::<Vec<_>>();
+    entries.extend(keyed.into_iter().map(|(key, value)| {
+        let key = match &key {
+            Value::String(name) if name.to_str().is_ok_and(|name| is_identifier(&name)) => {
+                format_value(key)
+            }
+            _ => format!("[{}]", literal(&key)),
+        };
+        (Some(key), value)
+    }));
+    entries
+}
+
+/// A value as it is written in Lua source, with strings quoted.
+fn literal(value: &Value) -> String {
+    match value {
+        Value::String(text) => match text.to_str() {
+            Ok(text) => format!("{:?}", &*text),
+            Err(_) => "<binary>".to_string(),
+        },
+        _ => format_value(value.clone()),
+    }
+}
+
+fn transcript_line_text(line: &TranscriptLine) -> String {
+    match line {
+        TranscriptLine::Input { continuation, text } => {
+            let prompt = if *continuation { "... " } else { "> " };
+            format!("{prompt}{text}")
+        }
+        TranscriptLine::Output(text) => text.clone(),
+    }
+}
+
 fn render_transcript_line(line: &TranscriptLine, cols: usize) -> String {
     match line {
         TranscriptLine::Input { continuation, text } => {
@@ -717,8 +1268,8 @@ fn display_grapheme(grapheme: &str) -> &str {
 #[cfg(test)]
 mod tests {
     use super::{
-        CLOSE_HINT, CompileOutcome, LuaReplSession, LuaReplView, classify_input, truncate_to_width,
-        visible_input_window,
+        CLOSE_HINT, CompileOutcome, LuaReplSession, LuaReplView, classify_input, format_results,
+        truncate_to_width, visible_input_window,
     };
     use crate::{screen_reader::ScreenReader, speech, views::ViewController};
     use std::{cell::RefCell, rc::Rc};
@@ -1010,6 +1561,200 @@ mod tests {
         assert_eq!(repl.history(), ["print(1)", "print(2)"]);
     }
 
+    #[test]
+    fn tables_print_over_several_lines_and_speak_a_summary() {
+        let mut repl = LuaReplView::new(16, 40, Vec::new()).expect("create lua repl");
+        let (mut sr, speaks) = make_screen_reader();
+
+        enter(&mut repl, &mut sr, b"{1, 'two', name = {x = true}}\r");
+        finish_eval(&mut repl, &mut sr);
+
+        let contents = repl.model().screen().contents();
+        let lines = contents.lines().map(str::trim_end).collect::<Vec<_>>();
+        let start = lines.iter().position(|line| *line == "{").expect("table");
+        assert_eq!(
+            lines[start..start + 7],
+            [
+                "{",
+                "  1,",
+                "  \"two\",",
+                "  name = {",
+                "    x = true,",
+                "  },",
+                "}"
+            ]
+        );
+        assert_eq!(speaks.borrow().as_slice(), ["table, 3 entries"]);
+    }
+
+    #[test]
+    fn table_printing_orders_keys_and_marks_cycles() {
+        let session = LuaReplSession::new(Vec::new()).expect("create Lua session");
+        let state = session.state.borrow();
+        let values = state
+            .lua
+            .load(
+                r#"
+                    local t = {b = 1, [3] = "x", ["not a name"] = false, a = {}}
+                    t.self = t
+                    return t, 5
+                "#,
+            )
+            .eval::<mlua::MultiValue>()
+            .unwrap();
+
+        let (lines, summary) = format_results(values);
+        assert_eq!(
+            lines,
+            [
+                "{",
+                "  [3] = \"x\",",
+                "  a = {},",
+                "  b = 1,",
+                "  [\"not a name\"] = false,",
+                "  self = <cycle>,",
+                "}",
+                "5"
+            ]
+        );
+        assert_eq!(summary.as_deref(), Some("table, 5 entries, 5"));
+
+        let values = state.lua.load("return {}, 'x'").eval().unwrap();
+        assert_eq!(format_results(values), (vec!["{}\tx".to_string()], None));
+    }
+
+    #[test]
+    fn tab_completes_lector_names_and_lists_ambiguous_ones() {
+        let mut repl = LuaReplView::new(10, 60, Vec::new()).expect("create lua repl");
+        let (mut sr, _speaks) = make_screen_reader();
+
+        for input in [&b"lec"[..], b"\t", b".api.se", b"\t"] {
+            enter(&mut repl, &mut sr, input);
+        }
+        assert_eq!(
+            repl.session.state.borrow().editor.input(),
+            "lector.api.set_"
+        );
+
+        for input in [&b"\x15"[..], b"x = lector.api.s", b"\t"] {
+            enter(&mut repl, &mut sr, input);
+        }
+        assert_eq!(
+            repl.session.state.borrow().editor.input(),
+            "x = lector.api.s"
+        );
+        let contents = repl.model().screen().contents();
+        assert!(contents.contains("set_speech  set_tmux_pane_speech  speak"));
+        assert!(contents.contains("> x = lector.api.s"));
+
+        for input in [&b"\x15"[..], b"lector.o.mouse_h", b"\t"] {
+            enter(&mut repl, &mut sr, input);
+        }
+        assert_eq!(
+            repl.session.state.borrow().editor.input(),
+            "lector.o.mouse_hover"
+        );
+
+        enter(&mut repl, &mut sr, b"\x15");
+        enter(&mut repl, &mut sr, b"lector.api.zz");
+        let action = repl
+            .handle_input(&mut sr, b"\t", &mut Vec::new())
+            .expect("complete unknown name");
+        assert!(matches!(action, crate::views::ViewAction::Bell));
+    }
+
+    #[test]
+    fn tab_completion_never_calls_index_functions() {
+        let mut repl = LuaReplView::new(10, 60, Vec::new()).expect("create lua repl");
+        let (mut sr, _speaks) = make_screen_reader();
+
+        enter(
+            &mut repl,
+            &mut sr,
+            b"obj = setmetatable({}, {__index = function() while true do end end})\r",
+        );
+        finish_eval(&mut repl, &mut sr);
+        enter(&mut repl, &mut sr, b"obj.inner.na");
+        let action = repl
+            .handle_input(&mut sr, b"\t", &mut Vec::new())
+            .expect("complete through an index function");
+        assert!(matches!(action, crate::views::ViewAction::Bell));
+        assert_eq!(repl.session.state.borrow().editor.input(), "obj.inner.na");
+    }
+
+    #[test]
+    fn history_persists_to_its_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("lector").join("repl_history");
+        let session = LuaReplSession::with_history_file(path.clone()).expect("create session");
+        let mut repl = LuaReplView::from_session(10, 40, session);
+        let (mut sr, _speaks) = make_screen_reader();
+
+        enter(&mut repl, &mut sr, b"function f()\r");
+        enter(&mut repl, &mut sr, b"end\r");
+        finish_eval(&mut repl, &mut sr);
+        enter(&mut repl, &mut sr, b"f()\r");
+        finish_eval(&mut repl, &mut sr);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "\"function f()\\nend\"\n\"f()\"\n"
+        );
+
+        let reopened = LuaReplSession::with_history_file(path).expect("reopen session");
+        let repl = LuaReplView::from_session(10, 40, reopened);
+        assert_eq!(repl.history(), ["function f()\nend", "f()"]);
+    }
+
+    #[test]
+    fn commands_save_the_transcript_and_load_files() {
+        let directory = tempfile::tempdir().unwrap();
+        let scratch = directory.path().join("scratch.lua");
+        std::fs::write(&scratch, "loaded = 'yes'\nprint('from scratch')\n").unwrap();
+        let mut repl = LuaReplView::new(16, 80, Vec::new()).expect("create lua repl");
+        let (mut sr, _speaks) = make_screen_reader();
+        sr.set_repl_scratch_file(Some(scratch.display().to_string()));
+
+        enter(&mut repl, &mut sr, b":load\r");
+        finish_eval(&mut repl, &mut sr);
+        enter(&mut repl, &mut sr, b"loaded\r");
+        finish_eval(&mut repl, &mut sr);
+        enter(&mut repl, &mut sr, b":nope\r");
+
+        let saved = directory.path().join("repl.txt");
+        let save = format!(":save {}\r", saved.display());
+        enter(&mut repl, &mut sr, save.as_bytes());
+        let transcript = std::fs::read_to_string(&saved).unwrap();
+        assert!(transcript.starts_with("Lua REPL ready.\n> :load\nloading "));
+        assert!(transcript.contains("from scratch\n> loaded\nyes\n> :nope\n"));
+        assert!(transcript.contains("Error: unknown command :nope"));
+        assert!(transcript.ends_with(&format!("> :save {}\n", saved.display())));
+        assert!(
+            repl.model()
+                .screen()
+                .contents()
+                .contains(&format!("saved 9 lines to {}", saved.display()))
+        );
+
+        enter(&mut repl, &mut sr, b"#lector.repl.transcript()\r");
+        finish_eval(&mut repl, &mut sr);
+        assert!(
+            repl.model()
+                .screen()
+                .contents()
+                .lines()
+                .any(|line| line.trim() == "11")
+        );
+
+        enter(&mut repl, &mut sr, save.as_bytes());
+        assert_eq!(std::fs::read_to_string(&saved).unwrap(), transcript);
+        assert!(
+            repl.model()
+                .screen()
+                .contents()
+                .contains("Error: save transcript: file already exists")
+        );
+    }
+
     #[test]
     fn unicode_windows_preserve_graphemes_and_terminal_cell_widths() {
         assert_eq!(truncate_to_width("a界b", 3), "a界");
@@ -1,3 +1,6 @@
+mod bindings;
+mod filter_list;
+mod links;
 mod lua_repl;
 mod message;
 mod popup;
@@ -11,6 +14,8 @@ mod tmux_connection;
 mod tmux_connections;
 mod tmux_portal;
 
+pub use bindings::{BindingItem, BindingTarget, BindingsView};
+pub use links::LinksView;
 pub use lua_repl::{LuaReplSession, LuaReplView};
 pub use message::MessageView;
 pub use popup::{PopupResponse, PopupView};
@@ -18,6 +23,7 @@ pub use pty::PtyView;
 pub use review::ReviewView;
 pub(crate) use stack::CompositorTransitionToken;
 pub use stack::ViewStack;
+pub use text_input::{TextInputPurpose, TextInputView};
 pub use tmux_chooser::{TmuxChooserTarget, TmuxChooserView};
 pub use tmux_command::TmuxCommandView;
 pub use tmux_connection::TmuxConnectionView;
@@ -80,6 +86,19 @@ pub enum ViewAction {
         pane_id: crate::tmux_model::PaneId,
         bytes: Vec<u8>,
     },
+    /// Review asks for older server-side history of a tmux pane.
+    TmuxHistoryRequest {
+        connection_id: u64,
+        pane_id: crate::tmux_model::PaneId,
+        first_line: i64,
+        last_line: i64,
+    },
+    TextInputSubmit {
+        purpose: TextInputPurpose,
+        text: String,
+    },
+    /// Close the bindings list and run the chosen binding.
+    RunBinding(BindingTarget),
     Redraw,
     /// Present and finalize a view that already announced its own interaction.
     RedrawSilently,
@@ -99,9 +118,30 @@ pub enum ViewKind {
     TmuxChooser,
     TmuxCommand,
     TmuxPortal,
+    TextInput,
+    Links,
+    Bindings,
     Other,
 }
 
+impl ViewKind {
+    /// Whether views of this kind edit typed text, so that mode bindings such
+    /// as table mode's `j` or Escape must leave their keys to the view.
+    #[must_use]
+    pub const fn takes_text(self) -> bool {
+        matches!(
+            self,
+            Self::LuaRepl
+                | Self::TmuxConnectionRename
+                | Self::TmuxChooser
+                | Self::TmuxCommand
+                | Self::TextInput
+                | Self::Links
+                | Self::Bindings
+        )
+    }
+}
+
 pub trait ViewController {
     fn as_any(&self) -> &dyn Any;
     fn as_any_mut(&mut self) -> &mut dyn Any;
@@ -120,6 +160,20 @@ pub trait ViewController {
     fn place_application_cursor_at_review_cursor(&mut self) -> Option<ViewAction> {
         None
     }
+    /// Lets overlays with a scrollable document, such as Review, run a table
+    /// command over rows outside the visible model. `None` means the command
+    /// should use the model.
+    fn run_table_command(
+        &mut self,
+        _sr: &mut ScreenReader,
+        _command: &mut dyn FnMut(
+            &mut ScreenReader,
+            &mut View,
+        )
+            -> crate::commands::Result<crate::commands::CommandResult>,
+    ) -> Option<crate::commands::Result<crate::commands::CommandResult>> {
+        None
+    }
     fn wants_tick(&self) -> bool {
         false
     }
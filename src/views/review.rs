@@ -1,7 +1,9 @@
 use super::{Result, ViewAction, ViewController, ViewKind};
 use crate::{
+    attributes::describe_style,
     review::{
         document::{ReviewDocument, SearchDirection},
+        marks::Marks,
         parser::{
             Command, FindDirection, Key, Motion, Parser, TextObject, ViewportPlacement, VisualKind,
         },
@@ -9,6 +11,7 @@ use crate::{
     screen_reader::ScreenReader,
     terminal::HistoryPosition,
     terminal_input::KeyInput,
+    tmux_model::PaneId,
     view::View,
 };
 use std::{any::Any, io::Write};
@@ -23,16 +26,83 @@ struct LastFind {
     matched: HistoryPosition,
 }
 
+/// A committed search. `style` marks a `g/` or `g?` search, whose query
+/// describes colors and attributes instead of a regular expression.
 #[derive(Clone, Debug)]
 struct LastSearch {
     query: String,
     direction: SearchDirection,
+    style: bool,
+}
+
+impl LastSearch {
+    fn is_valid(&self) -> bool {
+        if self.style {
+            ReviewDocument::valid_style_query(&self.query)
+        } else {
+            ReviewDocument::valid_search_query(&self.query)
+        }
+    }
+
+    fn find(
+        &self,
+        document: &ReviewDocument,
+        position: HistoryPosition,
+        count: usize,
+    ) -> Option<HistoryPosition> {
+        if self.style {
+            document.search_style(&self.query, position, self.direction, count)
+        } else {
+            document.search(&self.query, position, self.direction, count)
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
 struct SearchPrompt {
     query: String,
     direction: SearchDirection,
+    style: bool,
+}
+
+/// Rows fetched from the tmux server per history request.
+const TMUX_HISTORY_FETCH_LINES: u32 = 1_000;
+
+/// Document rows rendered around the cursor for table commands. Tables may
+/// be taller than the terminal, but detection stays bounded.
+const TABLE_SURFACE_ROWS: usize = 2_000;
+
+/// A slice of the document rendered into one tall model, so table detection
+/// and navigation reach rows above and below the viewport and a header stays
+/// readable after it scrolls away.
+struct TableSurface {
+    view: View,
+    first_row: usize,
+    rows: usize,
+}
+
+/// Server-side history of the reviewed tmux pane that is older than the
+/// pane's own retained scrollback. Offsets count rows above the live screen.
+#[derive(Clone, Debug)]
+struct TmuxHistory {
+    connection_id: u64,
+    pane_id: PaneId,
+    loaded_lines: u32,
+    remaining_lines: u32,
+    in_flight: Option<HistoryFetch>,
+}
+
+#[derive(Clone, Debug)]
+struct HistoryFetch {
+    lines: u32,
+    then: HistoryContinuation,
+}
+
+/// What the user was doing when Review ran out of rows.
+#[derive(Clone, Debug)]
+enum HistoryContinuation {
+    Reveal,
+    Search(LastSearch, usize),
 }
 
 pub struct ReviewView {
@@ -51,6 +121,9 @@ pub struct ReviewView {
     last_find: Option<LastFind>,
     last_search: Option<LastSearch>,
     search_prompt: Option<SearchPrompt>,
+    marks: Marks,
+    tmux_history: Option<TmuxHistory>,
+    table_surface: Option<TableSurface>,
 }
 
 impl ReviewView {
@@ -90,12 +163,164 @@ impl ReviewView {
             last_find: None,
             last_search: None,
             search_prompt: None,
+            marks: Marks::default(),
+            tmux_history: None,
+            table_surface: None,
         };
         review.ensure_cursor_visible();
         review.render();
         review
     }
 
+    /// Lets Review fetch up to `remaining_lines` of tmux history above the
+    /// `loaded_lines` rows the pane engine already retained.
+    pub(crate) fn with_tmux_history(
+        mut self,
+        connection_id: u64,
+        pane_id: PaneId,
+        loaded_lines: u32,
+        remaining_lines: u32,
+    ) -> Self {
+        self.tmux_history = (remaining_lines > 0).then_some(TmuxHistory {
+            connection_id,
+            pane_id,
+            loaded_lines,
+            remaining_lines,
+            in_flight: None,
+        });
+        self
+    }
+
+    pub(crate) fn document(&self) -> &ReviewDocument {
+        &self.document
+    }
+
+    /// The visual selection as `(anchor, cursor, linewise)`.
+    pub(crate) fn selection(&self) -> Option<(HistoryPosition, HistoryPosition, bool)> {
+        self.visual_anchor
+            .map(|anchor| (anchor, self.cursor, self.visual_kind == VisualKind::Line))
+    }
+
+    pub(crate) fn tmux_history_pane(&self) -> Option<(u64, PaneId)> {
+        self.tmux_history
+            .as_ref()
+            .map(|history| (history.connection_id, history.pane_id))
+    }
+
+    /// Requests the next bounded chunk of older tmux history, or returns
+    /// `None` when this Review has no more server-side rows to offer.
+    fn request_tmux_history(&mut self, then: HistoryContinuation) -> Option<ViewAction> {
+        let history = self.tmux_history.as_mut()?;
+        if history.in_flight.is_some() {
+            return Some(ViewAction::None);
+        }
+        if history.remaining_lines == 0 {
+            return None;
+        }
+        let lines = history.remaining_lines.min(TMUX_HISTORY_FETCH_LINES);
+        history.in_flight = Some(HistoryFetch { lines, then });
+        Some(ViewAction::TmuxHistoryRequest {
+            connection_id: history.connection_id,
+            pane_id: history.pane_id,
+            first_line: -i64::from(history.loaded_lines.saturating_add(lines)),
+            last_line: -i64::from(history.loaded_lines.saturating_add(1)),
+        })
+    }
+
+    /// Stitches a history reply above the document and resumes the motion or
+    /// search that requested it. `None` reports a failed capture.
+    pub(crate) fn apply_tmux_history(
+        &mut self,
+        sr: &mut ScreenReader,
+        lines: Option<&[Vec<u8>]>,
+    ) -> Result<ViewAction> {
+        let Some(history) = self.tmux_history.as_mut() else {
+            return Ok(ViewAction::None);
+        };
+        let Some(fetch) = history.in_flight.take() else {
+            return Ok(ViewAction::None);
+        };
+        let Some(lines) = lines.filter(|lines| !lines.is_empty()) else {
+            history.remaining_lines = 0;
+            sr.speak("no older tmux history", false)?;
+            return Ok(ViewAction::Bell);
+        };
+        history.loaded_lines = history.loaded_lines.saturating_add(fetch.lines);
+        history.remaining_lines = history.remaining_lines.saturating_sub(fetch.lines);
+        let older = ReviewDocument::from_capture_lines(lines, self.document.capture_cols());
+        let shift = self.document.prepend_history(older);
+        self.shift_positions(shift);
+        match fetch.then {
+            HistoryContinuation::Reveal => {
+                let target = HistoryPosition {
+                    row: shift.saturating_sub(1),
+                    col: self.cursor.col,
+                };
+                Ok(self.move_to(target))
+            }
+            HistoryContinuation::Search(search, count) => Ok(self.search(search, count)),
+        }
+    }
+
+    fn shift_positions(&mut self, shift: usize) {
+        let shifted = |position: HistoryPosition| HistoryPosition {
+            row: position.row.saturating_add(shift),
+            col: position.col,
+        };
+        self.cursor = shifted(self.cursor);
+        self.viewport_top = self.viewport_top.saturating_add(shift);
+        self.visual_anchor = self.visual_anchor.map(shifted);
+        if let Some(find) = &mut self.last_find {
+            find.matched = shifted(find.matched);
+        }
+        self.marks.shift(shift);
+        self.table_surface = None;
+        self.render();
+    }
+
+    fn build_table_surface(&self, first_row: usize, rows: usize) -> TableSurface {
+        let cols = self.document.capture_cols().max(1);
+        let mut view = View::new(u16::try_from(rows).unwrap_or(u16::MAX).max(1), cols);
+        let mut bytes = Vec::new();
+        for offset in 0..rows {
+            bytes.extend_from_slice(format!("\x1B[{};1H", offset.saturating_add(1)).as_bytes());
+            bytes.extend_from_slice(&self.document.formatted_row(
+                first_row.saturating_add(offset),
+                0,
+                cols,
+            ));
+            bytes.extend_from_slice(b"\x1B[0m");
+        }
+        view.process_changes(&bytes);
+        view.clear_update_summary();
+        TableSurface {
+            view,
+            first_row,
+            rows,
+        }
+    }
+
+    /// Searches the document, first fetching older tmux history when nothing
+    /// matches or a backward search would wrap past the oldest row.
+    fn search(&mut self, search: LastSearch, count: usize) -> ViewAction {
+        let target = search.find(&self.document, self.cursor, count);
+        let wrapped = search.direction == SearchDirection::Backward
+            && target.is_some_and(|target| target >= self.cursor);
+        if (target.is_none() || wrapped)
+            && let Some(action) =
+                self.request_tmux_history(HistoryContinuation::Search(search.clone(), count))
+        {
+            return action;
+        }
+        match target {
+            Some(target) => {
+                self.marks.record_jump(self.cursor);
+                self.move_to(target)
+            }
+            None => ViewAction::Bell,
+        }
+    }
+
     fn document_height(&self) -> usize {
         usize::from(self.rows).saturating_sub(usize::from(self.search_prompt.is_some()))
     }
@@ -142,14 +367,18 @@ impl ReviewView {
         }
 
         if let Some(prompt) = &self.search_prompt {
-            let marker = match prompt.direction {
-                SearchDirection::Forward => '/',
-                SearchDirection::Backward => '?',
+            let marker = match (prompt.style, prompt.direction) {
+                (false, SearchDirection::Forward) => "/",
+                (false, SearchDirection::Backward) => "?",
+                (true, SearchDirection::Forward) => "g/",
+                (true, SearchDirection::Backward) => "g?",
             };
             let row = self.rows.max(1);
             bytes.extend_from_slice(format!("\x1B[{row};1H\x1B[2K{marker}").as_bytes());
             bytes.extend_from_slice(prompt.query.as_bytes());
-            let col = 2usize
+            let col = marker
+                .len()
+                .saturating_add(1)
                 .saturating_add(prompt.query.graphemes(true).count())
                 .min(usize::from(self.cols).max(1));
             bytes.extend_from_slice(format!("\x1B[{row};{col}H\x1B[?25h").as_bytes());
@@ -174,7 +403,7 @@ impl ReviewView {
         self.view.clear_update_summary();
     }
 
-    fn handle_search_key(&mut self, key: Key) -> Result<ViewAction> {
+    fn handle_search_key(&mut self, sr: &mut ScreenReader, key: Key) -> Result<ViewAction> {
         match key {
             Key::Escape => {
                 self.search_prompt = None;
@@ -199,7 +428,7 @@ impl ReviewView {
                 self.render();
                 Ok(ViewAction::Redraw)
             }
-            Key::Enter => self.finish_search(),
+            Key::Enter => self.finish_search(sr),
             Key::Char(ch) if !ch.is_control() => {
                 self.search_prompt
                     .as_mut()
@@ -213,23 +442,58 @@ impl ReviewView {
         }
     }
 
-    fn finish_search(&mut self) -> Result<ViewAction> {
+    fn finish_search(&mut self, sr: &mut ScreenReader) -> Result<ViewAction> {
         let prompt = self.search_prompt.as_ref().expect("search prompt");
         let query = if prompt.query.is_empty() {
-            let Some(last) = &self.last_search else {
+            let Some(last) = self
+                .last_search
+                .as_ref()
+                .filter(|last| last.style == prompt.style)
+            else {
                 return Ok(ViewAction::Bell);
             };
             last.query.clone()
         } else {
             prompt.query.clone()
         };
-        let direction = prompt.direction;
-        let Some(target) = self.document.search(&query, self.cursor, direction, 1) else {
+        let search = LastSearch {
+            query,
+            direction: prompt.direction,
+            style: prompt.style,
+        };
+        if self.tmux_history.is_some() && search.is_valid() {
+            // The match may only exist in server-side history, so the prompt
+            // is committed before any fetch is awaited.
+            self.last_search = Some(search.clone());
+            self.search_prompt = None;
+            let styled = search.style;
+            let action = self.search(search, 1);
+            if styled && matches!(action, ViewAction::Redraw) {
+                self.speak_style(sr)?;
+            }
+            return Ok(action);
+        }
+        let Some(target) = search.find(&self.document, self.cursor, 1) else {
             return Ok(ViewAction::Bell);
         };
-        self.last_search = Some(LastSearch { query, direction });
+        let styled = search.style;
+        self.last_search = Some(search);
         self.search_prompt = None;
-        Ok(self.move_to(target))
+        self.marks.record_jump(self.cursor);
+        let action = self.move_to(target);
+        if styled {
+            self.speak_style(sr)?;
+        }
+        Ok(action)
+    }
+
+    /// Describes the style under the cursor after an attribute motion or
+    /// search lands on it.
+    fn speak_style(&self, sr: &mut ScreenReader) -> Result<()> {
+        if let Some(style) = self.document.style_at(self.cursor) {
+            sr.speak(&describe_style(style), false)?;
+        }
+        Ok(())
     }
 
     fn handle_command(&mut self, sr: &mut ScreenReader, command: Command) -> Result<ViewAction> {
@@ -256,11 +520,28 @@ impl ReviewView {
             }
             Command::Move(motion, count) | Command::MoveVisual(motion, count) => {
                 let Some(target) = self.motion_target(motion, count) else {
-                    return Ok(ViewAction::Bell);
+                    return Ok(self.reveal_tmux_history(matches!(
+                        motion,
+                        Motion::Up | Motion::DocumentStart
+                    )));
                 };
-                Ok(self.move_to(target))
+                if motion.is_jump() {
+                    self.marks.record_jump(self.cursor);
+                }
+                let action = self.move_to(target);
+                if matches!(motion, Motion::StyleChange { .. }) {
+                    self.speak_style(sr)?;
+                }
+                Ok(action)
+            }
+            Command::ScrollPage { forward, count } => {
+                let action = self.scroll_page(forward, count);
+                Ok(if matches!(action, ViewAction::Bell) {
+                    self.reveal_tmux_history(!forward)
+                } else {
+                    action
+                })
             }
-            Command::ScrollPage { forward, count } => Ok(self.scroll_page(forward, count)),
             Command::RepositionViewport {
                 placement,
                 line,
@@ -318,10 +599,11 @@ impl ReviewView {
                 };
                 self.yank(sr, register, text)
             }
-            Command::StartSearch(direction) => {
+            Command::StartSearch(direction) | Command::StartStyleSearch(direction) => {
                 self.search_prompt = Some(SearchPrompt {
                     query: String::new(),
                     direction,
+                    style: matches!(command, Command::StartStyleSearch(_)),
                 });
                 self.render();
                 Ok(ViewAction::Redraw)
@@ -335,17 +617,43 @@ impl ReviewView {
                 } else {
                     last.direction
                 };
-                let Some(target) = self
-                    .document
-                    .search(&last.query, self.cursor, direction, count)
-                else {
-                    return Ok(ViewAction::Bell);
+                let styled = last.style;
+                let action = self.search(LastSearch { direction, ..last }, count);
+                if styled && matches!(action, ViewAction::Redraw) {
+                    self.speak_style(sr)?;
+                }
+                Ok(action)
+            }
+            Command::SetMark(name) => {
+                self.marks.set(name, self.cursor);
+                sr.speak(&format!("mark {name}"), false)?;
+                Ok(ViewAction::None)
+            }
+            Command::Jump { newer, count } => {
+                let target = if newer {
+                    self.marks.newer(self.cursor, count)
+                } else {
+                    self.marks.older(self.cursor, count)
                 };
-                Ok(self.move_to(target))
+                Ok(match target {
+                    Some(target) => self.move_to(target),
+                    None => ViewAction::Bell,
+                })
             }
         }
     }
 
+    /// An upward motion that failed on the first row reveals older tmux
+    /// history instead of ringing the bell, while any remains.
+    fn reveal_tmux_history(&mut self, upward: bool) -> ViewAction {
+        if upward && self.cursor.row == 0 {
+            self.request_tmux_history(HistoryContinuation::Reveal)
+                .unwrap_or(ViewAction::Bell)
+        } else {
+            ViewAction::Bell
+        }
+    }
+
     fn motion_target(&mut self, motion: Motion, count: usize) -> Option<HistoryPosition> {
         let mut position = self.cursor;
         match motion {
@@ -437,6 +745,15 @@ impl ReviewView {
                 Some(destination)
             }
             Motion::Prompt { forward } => self.document.prompt(position, forward, count),
+            Motion::Link { forward } => self.document.link(position, forward, count),
+            Motion::StyleChange { forward } => self.document.style_change(position, forward, count),
+            Motion::Mark { name, exact } => {
+                let mut position = self.document.clamp(self.marks.get(name)?);
+                if !exact {
+                    position.col = self.document.line_first_nonblank(position.row);
+                }
+                Some(position)
+            }
         }
     }
 
@@ -489,7 +806,11 @@ impl ReviewView {
     fn yank_motion_text(&self, motion: Motion, target: HistoryPosition) -> Option<String> {
         if matches!(
             motion,
-            Motion::Up | Motion::Down | Motion::DocumentStart | Motion::DocumentEnd
+            Motion::Up
+                | Motion::Down
+                | Motion::DocumentStart
+                | Motion::DocumentEnd
+                | Motion::Mark { exact: false, .. }
         ) {
             return self.document.yank_range(self.cursor, target, true);
         }
@@ -503,6 +824,9 @@ impl ReviewView {
                 | Motion::Word(crate::review::document::WordMove::ForwardStart, _)
                 | Motion::Word(crate::review::document::WordMove::BackwardStart, _)
                 | Motion::Prompt { .. }
+                | Motion::Link { .. }
+                | Motion::StyleChange { .. }
+                | Motion::Mark { exact: true, .. }
         );
         if !exclusive {
             return self.document.yank_range(self.cursor, target, false);
@@ -540,7 +864,7 @@ impl ReviewView {
 
     fn handle_review_key(&mut self, sr: &mut ScreenReader, key: Key) -> Result<ViewAction> {
         if self.search_prompt.is_some() {
-            self.handle_search_key(key)
+            self.handle_search_key(sr, key)
         } else {
             let command = self.parser.feed(key);
             self.handle_command(sr, command)
@@ -556,7 +880,9 @@ impl ReviewView {
         for key in keys {
             let action = self.handle_review_key(sr, key)?;
             match action {
-                ViewAction::Pop | ViewAction::Bell => return Ok(action),
+                ViewAction::Pop | ViewAction::Bell | ViewAction::TmuxHistoryRequest { .. } => {
+                    return Ok(action);
+                }
                 ViewAction::Redraw => result = ViewAction::Redraw,
                 ViewAction::None => {}
                 ViewAction::PtyInput
@@ -568,6 +894,8 @@ impl ReviewView {
                 | ViewAction::TmuxChooserSelect { .. }
                 | ViewAction::TmuxCommandSubmit { .. }
                 | ViewAction::TmuxInput { .. }
+                | ViewAction::TextInputSubmit { .. }
+                | ViewAction::RunBinding(_)
                 | ViewAction::RedrawSilently => {
                     unreachable!()
                 }
@@ -598,6 +926,53 @@ impl ViewController for ReviewView {
         self.kind
     }
 
+    fn run_table_command(
+        &mut self,
+        sr: &mut ScreenReader,
+        command: &mut dyn FnMut(
+            &mut ScreenReader,
+            &mut View,
+        ) -> crate::commands::Result<crate::commands::CommandResult>,
+    ) -> Option<crate::commands::Result<crate::commands::CommandResult>> {
+        if self.kind != ViewKind::Review {
+            return None;
+        }
+        let covers_cursor = self.table_surface.as_ref().is_some_and(|surface| {
+            (surface.first_row..surface.first_row.saturating_add(surface.rows))
+                .contains(&self.cursor.row)
+        });
+        if !covers_cursor {
+            let row_count = self.document.row_count();
+            let rows = TABLE_SURFACE_ROWS.min(row_count);
+            let first_row = self
+                .cursor
+                .row
+                .saturating_sub(rows / 2)
+                .min(row_count.saturating_sub(rows));
+            self.table_surface = Some(self.build_table_surface(first_row, rows));
+            // Table rows detected on another surface no longer line up.
+            sr.table_session_mut().set_navigation(None);
+        }
+        let surface = self
+            .table_surface
+            .as_mut()
+            .expect("the table surface was just ensured");
+        let row =
+            u16::try_from(self.cursor.row.saturating_sub(surface.first_row)).unwrap_or(u16::MAX);
+        surface
+            .view
+            .set_review_cursor_position((row, self.cursor.col));
+        let result = command(sr, &mut surface.view);
+        let (row, col) = surface.view.review_cursor_position();
+        let target = HistoryPosition {
+            row: surface.first_row.saturating_add(usize::from(row)),
+            col,
+        };
+        self.cursor = self.document.clamp(target);
+        self.render();
+        Some(result)
+    }
+
     fn place_application_cursor_at_review_cursor(&mut self) -> Option<ViewAction> {
         let (row, col) = self.view.review_cursor_position();
         let target = HistoryPosition {
@@ -691,6 +1066,8 @@ fn semantic_key(key: &KeyInput) -> Key {
             0x02 => Key::Ctrl('b'),
             0x04 => Key::Ctrl('d'),
             0x06 => Key::Ctrl('f'),
+            0x09 => Key::Ctrl('i'),
+            0x0F => Key::Ctrl('o'),
             0x15 => Key::Ctrl('u'),
             _ => Key::Unknown,
         };
@@ -709,6 +1086,7 @@ fn semantic_key(key: &KeyInput) -> Key {
         KeyCode::Esc => Key::Escape,
         KeyCode::Enter => Key::Enter,
         KeyCode::Backspace => Key::Backspace,
+        KeyCode::Tab => Key::Ctrl('i'),
         KeyCode::Left => Key::Left,
         KeyCode::Down => Key::Down,
         KeyCode::Up => Key::Up,
@@ -725,6 +1103,8 @@ fn raw_key(byte: u8) -> Key {
         0x02 => Key::Ctrl('b'),
         0x04 => Key::Ctrl('d'),
         0x06 => Key::Ctrl('f'),
+        b'\t' => Key::Ctrl('i'),
+        0x0F => Key::Ctrl('o'),
         0x15 => Key::Ctrl('u'),
         value if value.is_ascii() && !value.is_ascii_control() => Key::Char(value as char),
         _ => Key::Unknown,
@@ -736,10 +1116,13 @@ mod tests {
     use super::ReviewView;
     use crate::{
         clipboard::{ClipboardRegister, SystemClipboardProvider},
+        commands::{self, Action},
+        keymap::InputMode,
         screen_reader::ScreenReader,
         speech,
         terminal::HistoryPosition,
         terminal_input::KeyInput,
+        tmux_model::PaneId,
         view::View,
         views::{ViewAction, ViewController, ViewKind},
     };
@@ -978,6 +1361,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn attribute_motions_and_style_searches_speak_the_style() {
+        let (mut view, mut sr, spoken) = setup(b"plain \x1b[1;31mred\x1b[0m \x1b[7msel\x1b[0m");
+        assert!(matches!(
+            input(&mut view, &mut sr, b"]a"),
+            ViewAction::Redraw
+        ));
+        assert_eq!(view.cursor.col, 6);
+        assert_eq!(spoken.borrow().last().unwrap(), "Maroon bold");
+
+        input(&mut view, &mut sr, b"g/inverse\r");
+        assert_eq!(view.cursor.col, 10);
+        assert_eq!(spoken.borrow().last().unwrap(), "default inverse");
+        assert!(matches!(
+            input(&mut view, &mut sr, b"[a"),
+            ViewAction::Redraw
+        ));
+        assert_eq!(view.cursor.col, 6);
+    }
+
+    #[test]
+    fn marks_jump_list_and_named_registers() {
+        let (mut view, mut sr, spoken) = setup(b"alpha beta\r\n  gamma delta\r\nend");
+        input(&mut view, &mut sr, b"wma");
+        assert!(spoken.borrow().iter().any(|text| text == "mark a"));
+        input(&mut view, &mut sr, b"G");
+        assert_eq!(view.cursor, HistoryPosition { row: 2, col: 0 });
+        assert!(matches!(
+            input(&mut view, &mut sr, b"`a"),
+            ViewAction::Redraw
+        ));
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 6 });
+        input(&mut view, &mut sr, b"''");
+        assert_eq!(view.cursor, HistoryPosition { row: 2, col: 0 });
+        assert!(matches!(input(&mut view, &mut sr, b"'b"), ViewAction::Bell));
+
+        input(&mut view, &mut sr, b"\x0f");
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 6 });
+        input(&mut view, &mut sr, b"\t");
+        assert_eq!(view.cursor, HistoryPosition { row: 2, col: 0 });
+
+        input(&mut view, &mut sr, b"\"qyiw");
+        assert_eq!(
+            sr.read_clipboard(ClipboardRegister::Named('q')).unwrap(),
+            Some("end".to_owned())
+        );
+        assert_eq!(sr.clipboard_text(), None);
+    }
+
     #[test]
     fn yank_motions_counts_lines_and_visual_ranges_use_vi_boundaries() {
         let (mut view, mut sr, _) = setup(b"alpha beta\r\ngamma");
@@ -1092,6 +1524,72 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn exhausted_rows_fetch_bounded_tmux_history_for_motions_and_search() {
+        let (view, mut sr, output) = setup(b"three\r\nfour");
+        let mut view = view.with_tmux_history(7, PaneId(20), 40, 1_500);
+        assert!(matches!(
+            input(&mut view, &mut sr, b"k"),
+            ViewAction::TmuxHistoryRequest {
+                connection_id: 7,
+                pane_id: PaneId(20),
+                first_line: -1_040,
+                last_line: -41,
+            }
+        ));
+        // A second request waits for the reply instead of ringing.
+        assert!(matches!(input(&mut view, &mut sr, b"k"), ViewAction::None));
+        assert!(matches!(
+            view.apply_tmux_history(&mut sr, Some(&[b"one".to_vec(), b"two".to_vec()]))
+                .unwrap(),
+            ViewAction::Redraw
+        ));
+        assert_eq!(view.cursor, HistoryPosition { row: 1, col: 0 });
+        assert_eq!(view.document.line_text(0), "one");
+        assert_eq!(view.document.line_text(2), "three");
+
+        input(&mut view, &mut sr, b"G");
+        assert!(matches!(
+            input(&mut view, &mut sr, b"?zero\r"),
+            ViewAction::TmuxHistoryRequest {
+                first_line: -1_540,
+                last_line: -1_041,
+                ..
+            }
+        ));
+        assert!(view.search_prompt.is_none());
+        assert!(matches!(
+            view.apply_tmux_history(&mut sr, Some(&[b"zero".to_vec()]))
+                .unwrap(),
+            ViewAction::Redraw
+        ));
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 0 });
+
+        // Server history is exhausted, so the top of the document rings.
+        assert!(matches!(input(&mut view, &mut sr, b"k"), ViewAction::Bell));
+        assert!(output.borrow().is_empty());
+    }
+
+    #[test]
+    fn failed_or_empty_tmux_history_ends_fetching() {
+        let (view, mut sr, output) = setup(b"alpha");
+        let mut view = view.with_tmux_history(7, PaneId(20), 0, 5_000);
+        assert!(matches!(
+            input(&mut view, &mut sr, b"/missing\r"),
+            ViewAction::TmuxHistoryRequest { .. }
+        ));
+        assert!(matches!(
+            view.apply_tmux_history(&mut sr, None).unwrap(),
+            ViewAction::Bell
+        ));
+        assert_eq!(output.borrow().as_slice(), ["no older tmux history"]);
+        assert!(matches!(input(&mut view, &mut sr, b"n"), ViewAction::Bell));
+        assert!(matches!(
+            input(&mut view, &mut sr, b"/[\r"),
+            ViewAction::Bell
+        ));
+    }
+
     #[test]
     fn missing_prompt_and_unmatched_brace_are_bell_errors() {
         let (mut view, mut sr, _) = setup(b"{broken");
@@ -1099,6 +1597,58 @@ mod tests {
         assert!(matches!(input(&mut view, &mut sr, b"%"), ViewAction::Bell));
     }
 
+    #[test]
+    fn bracket_l_moves_between_hyperlinks() {
+        let (mut view, mut sr, _) = setup(
+            b"go \x1b]8;;https://a.example\x1b\\here\x1b]8;;\x1b\\ or \x1b]8;;https://b.example\x1b\\there\x1b]8;;\x1b\\",
+        );
+        input(&mut view, &mut sr, b"]l");
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 3 });
+        input(&mut view, &mut sr, b"]l");
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 11 });
+        assert!(matches!(input(&mut view, &mut sr, b"]l"), ViewAction::Bell));
+        input(&mut view, &mut sr, b"[l");
+        assert_eq!(view.cursor, HistoryPosition { row: 0, col: 3 });
+        assert!(matches!(input(&mut view, &mut sr, b"[l"), ViewAction::Bell));
+    }
+
+    #[test]
+    fn table_commands_reach_scrolled_rows_and_keep_the_header() {
+        let mut source = View::new(3, 40);
+        let mut text = b"NAME   STATUS".to_vec();
+        for index in 0..10 {
+            text.extend_from_slice(format!("\r\npod{index}   Running").as_bytes());
+        }
+        source.process_changes(&text);
+        let output = Rc::new(RefCell::new(Vec::new()));
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(RecordingDriver(
+            output.clone(),
+        ))));
+        let mut review = ReviewView::new(&mut source);
+        review.cursor = HistoryPosition { row: 10, col: 0 };
+        let run = |review: &mut ReviewView, sr: &mut ScreenReader, action| {
+            review
+                .run_table_command(sr, &mut |sr, view| {
+                    commands::handle(sr, "Review", view, action)
+                })
+                .expect("Review runs table commands")
+                .unwrap();
+        };
+
+        run(&mut review, &mut sr, Action::ToggleTableMode);
+        assert_eq!(sr.input_mode(), InputMode::Table);
+        assert_eq!(review.viewport_top, 8);
+        run(&mut review, &mut sr, Action::TableColNext);
+        run(&mut review, &mut sr, Action::TableRowTop);
+        assert_eq!(review.cursor, HistoryPosition { row: 0, col: 7 });
+        assert_eq!(review.viewport_top, 0);
+        assert!(review.model().contents_full().contains("NAME"));
+        assert_eq!(
+            output.borrow().as_slice(),
+            ["table mode on", "pod9", "STATUS", "Running", "STATUS"]
+        );
+    }
+
     #[test]
     fn screen_snapshot_stays_frozen() {
         let mut source = View::new(2, 20);
@@ -245,6 +245,13 @@ impl ViewStack {
             .downcast_mut::<crate::views::TmuxConnectionView>()
     }
 
+    pub(crate) fn active_review_mut(&mut self) -> Option<&mut crate::views::ReviewView> {
+        self.views
+            .last_mut()?
+            .as_any_mut()
+            .downcast_mut::<crate::views::ReviewView>()
+    }
+
     pub(crate) fn active_tmux_chooser_mut(&mut self) -> Option<&mut crate::views::TmuxChooserView> {
         self.views
             .last_mut()?
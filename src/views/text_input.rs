@@ -1,6 +1,173 @@
+use super::{Result, ViewAction, ViewController, ViewKind};
+use crate::{
+    line_editor::{EditorAction, LineEditor},
+    screen_reader::ScreenReader,
+    terminal::ScreenRect,
+    terminal_input::KeyInput,
+    view::View,
+};
+use std::{any::Any, io::Write};
+use terminput::KeyCode;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+/// Identifies which App operation receives a submitted line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TextInputPurpose {
+    TmuxAttach,
+    SaveTmuxWorkspace { connection_id: u64 },
+    RestoreTmuxWorkspace { connection_id: u64 },
+    TableColumnSearch,
+    TableLayoutName,
+    SaveTranscript,
+    RegionName { rect: ScreenRect },
+}
+
+/// A single-line prompt whose submission is handled by the App.
+pub struct TextInputView {
+    view: View,
+    purpose: TextInputPurpose,
+    title: String,
+    prompt: String,
+    editor: LineEditor,
+}
+
+impl TextInputView {
+    #[must_use]
+    pub fn new(
+        rows: u16,
+        cols: u16,
+        purpose: TextInputPurpose,
+        title: &str,
+        prompt: &str,
+        initial: &str,
+    ) -> Self {
+        let mut editor = LineEditor::new();
+        editor.handle_text(initial);
+        let mut input = Self {
+            view: View::new(rows, cols),
+            purpose,
+            title: title.to_owned(),
+            prompt: prompt.to_owned(),
+            editor,
+        };
+        input.render();
+        input
+    }
+
+    fn apply_editor_action(&mut self, action: EditorAction) -> ViewAction {
+        match action {
+            EditorAction::Submit => ViewAction::TextInputSubmit {
+                purpose: self.purpose,
+                text: self.editor.input().to_owned(),
+            },
+            EditorAction::Changed => {
+                self.render();
+                ViewAction::Redraw
+            }
+            EditorAction::Bell => ViewAction::Bell,
+            EditorAction::None => ViewAction::None,
+        }
+    }
+
+    fn render(&mut self) {
+        let (rows, cols) = self.view.size();
+        let prefix = format!("{}: ", self.prompt);
+        let prefix_width = UnicodeWidthStr::width(prefix.as_str());
+        let (visible_input, input_cursor_width) = visible_input_window(
+            self.editor.input(),
+            self.editor.cursor(),
+            usize::from(cols).saturating_sub(prefix_width.saturating_add(1)),
+        );
+        let lines = [
+            format!("{prefix}{visible_input}"),
+            "Enter submits, Escape cancels".to_owned(),
+        ];
+        let mut bytes = b"\x1b[2J\x1b[H".to_vec();
+        for (index, line) in lines.into_iter().take(usize::from(rows)).enumerate() {
+            if index > 0 {
+                bytes.extend_from_slice(b"\r\n");
+            }
+            bytes.extend_from_slice(truncate_display_width(&line, usize::from(cols)).as_bytes());
+        }
+        let cursor_col = prefix_width
+            .saturating_add(input_cursor_width)
+            .saturating_add(1)
+            .min(usize::from(cols));
+        bytes.extend_from_slice(format!("\x1b[1;{}H", cursor_col.max(1)).as_bytes());
+        self.view.clear_update_summary();
+        self.view.process_changes(&bytes);
+        self.view.clear_update_summary();
+    }
+}
+
+impl ViewController for TextInputView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn model(&mut self) -> &mut View {
+        &mut self.view
+    }
+
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn kind(&self) -> ViewKind {
+        ViewKind::TextInput
+    }
+
+    fn handle_input(
+        &mut self,
+        _sr: &mut ScreenReader,
+        input: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        if input == b"\x1b" {
+            return Ok(ViewAction::Pop);
+        }
+        let action = self.editor.handle_bytes(input);
+        Ok(self.apply_editor_action(action))
+    }
+
+    fn handle_key_input(
+        &mut self,
+        _sr: &mut ScreenReader,
+        key: &KeyInput,
+        _raw: &[u8],
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        if key.is_release() {
+            return Ok(ViewAction::None);
+        }
+        if key.event().code == KeyCode::Esc {
+            return Ok(ViewAction::Pop);
+        }
+        let action = self.editor.handle_key_input(key);
+        Ok(self.apply_editor_action(action))
+    }
+
+    fn handle_paste(
+        &mut self,
+        _sr: &mut ScreenReader,
+        contents: &str,
+        _pty_stream: &mut dyn Write,
+    ) -> Result<ViewAction> {
+        let action = self.editor.handle_text(contents);
+        Ok(self.apply_editor_action(action))
+    }
+
+    fn on_resize(&mut self, rows: u16, cols: u16) {
+        self.view.set_size(rows, cols);
+        self.render();
+    }
+}
+
 pub(super) fn visible_input_window(
     input: &str,
     cursor: usize,
@@ -65,7 +232,31 @@ fn display_grapheme(grapheme: &str) -> &str {
 
 #[cfg(test)]
 mod tests {
-    use super::{truncate_display_width, visible_input_window};
+    use super::{
+        TextInputPurpose, TextInputView, ViewAction, ViewController, truncate_display_width,
+        visible_input_window,
+    };
+    use crate::{screen_reader::ScreenReader, speech};
+
+    struct SilentDriver;
+
+    impl speech::Driver for SilentDriver {
+        fn speak(&mut self, _text: &str, _interrupt: bool) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn stop(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn get_rate(&self) -> f32 {
+            1.0
+        }
+
+        fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
 
     #[test]
     fn input_windows_follow_the_cursor_without_splitting_unicode() {
@@ -84,4 +275,33 @@ mod tests {
         assert_eq!(truncate_display_width("a\tb\nc", 5), "a⇥b↵c");
         assert_eq!(truncate_display_width("a界b", 3), "a界");
     }
+
+    #[test]
+    fn submitted_text_carries_its_purpose_and_escape_cancels() {
+        let mut sr = ScreenReader::new(speech::Speech::new(Box::new(SilentDriver)));
+        let mut output = Vec::new();
+        let mut input = TextInputView::new(
+            3,
+            40,
+            TextInputPurpose::TmuxAttach,
+            "attach tmux session",
+            "session",
+            "work",
+        );
+        assert!(input.model().contents_full().contains("session: work"));
+
+        input.handle_input(&mut sr, b"2", &mut output).unwrap();
+        assert!(matches!(
+            input.handle_input(&mut sr, b"\r", &mut output).unwrap(),
+            ViewAction::TextInputSubmit {
+                purpose: TextInputPurpose::TmuxAttach,
+                text,
+            } if text == "work2"
+        ));
+        assert!(matches!(
+            input.handle_input(&mut sr, b"\x1b", &mut output).unwrap(),
+            ViewAction::Pop
+        ));
+        assert!(output.is_empty());
+    }
 }
@@ -1,16 +1,14 @@
 use super::{
     Result, ViewAction, ViewController, ViewKind,
-    text_input::{truncate_display_width, visible_input_window},
+    filter_list::{FilterList, ListInput, ListItem},
 };
 use crate::{
-    line_editor::{EditorAction, LineEditor},
     screen_reader::ScreenReader,
     terminal_input::KeyInput,
     tmux_model::{PaneId, SessionId, TmuxTopology, WindowId},
     view::View,
 };
-use std::{any::Any, io::Write};
-use terminput::KeyCode;
+use std::{any::Any, collections::BTreeMap, io::Write};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum TmuxChooserTarget {
@@ -26,21 +24,12 @@ enum TmuxChooserScope {
     Panes(WindowId),
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-struct ChooserItem {
-    target: TmuxChooserTarget,
-    label: String,
-}
-
 pub struct TmuxChooserView {
-    view: View,
+    list: FilterList<TmuxChooserTarget>,
     title: String,
     connection_id: u64,
     scope: TmuxChooserScope,
-    items: Vec<ChooserItem>,
-    editor: LineEditor,
-    selected: Option<TmuxChooserTarget>,
-    viewport_start: usize,
+    pane_notes: BTreeMap<PaneId, String>,
 }
 
 impl TmuxChooserView {
@@ -106,17 +95,27 @@ impl TmuxChooserView {
         selected: Option<TmuxChooserTarget>,
         topology: &TmuxTopology,
     ) -> Self {
+        let empty_text = match scope {
+            TmuxChooserScope::Sessions => "no matching sessions",
+            TmuxChooserScope::Windows(_) => "no matching windows",
+            TmuxChooserScope::Panes(_) => "no matching panes",
+        };
         let mut chooser = Self {
-            view: View::new(rows, cols),
+            list: FilterList::new(
+                rows,
+                cols,
+                empty_text,
+                "Up/Down select, Enter choose, Escape cancel",
+            ),
             title: title.to_owned(),
             connection_id,
             scope,
-            items: Vec::new(),
-            editor: LineEditor::new(),
-            selected,
-            viewport_start: 0,
+            pane_notes: BTreeMap::new(),
         };
         chooser.sync_topology(topology);
+        if let Some(selected) = selected {
+            chooser.list.select(&selected);
+        }
         chooser
     }
 
@@ -127,17 +126,24 @@ impl TmuxChooserView {
 
     #[must_use]
     pub fn selected_target(&self) -> Option<TmuxChooserTarget> {
-        self.selected
+        self.list.selected().copied()
+    }
+
+    /// Appends a note, such as a pane's speech overrides, to pane rows.
+    /// Notes are searchable and spoken with the row.
+    pub fn set_pane_notes(&mut self, notes: BTreeMap<PaneId, String>, topology: &TmuxTopology) {
+        self.pane_notes = notes;
+        self.sync_topology(topology);
     }
 
     pub fn sync_topology(&mut self, topology: &TmuxTopology) {
-        self.items = match self.scope {
+        let items = match self.scope {
             TmuxChooserScope::Sessions => topology
                 .sessions()
                 .values()
-                .map(|session| ChooserItem {
-                    target: TmuxChooserTarget::Session(session.id),
+                .map(|session| ListItem {
                     label: format!("{}{} {}", '$', session.id.0, session.name),
+                    value: TmuxChooserTarget::Session(session.id),
                 })
                 .collect(),
             TmuxChooserScope::Windows(session_id) => topology
@@ -146,9 +152,9 @@ impl TmuxChooserView {
                 .flat_map(|session| &session.windows)
                 .filter_map(|(index, window_id)| {
                     let window = topology.window(*window_id)?;
-                    Some(ChooserItem {
-                        target: TmuxChooserTarget::Window(*window_id),
+                    Some(ListItem {
                         label: format!("@{} {index} {}", window_id.0, window.name),
+                        value: TmuxChooserTarget::Window(*window_id),
                     })
                 })
                 .collect(),
@@ -157,12 +163,18 @@ impl TmuxChooserView {
                     .panes()
                     .values()
                     .filter(|pane| pane.window_id == window_id)
-                    .map(|pane| ChooserItem {
-                        target: TmuxChooserTarget::Pane(pane.id),
-                        label: format!("%{} {} {}", pane.id.0, pane.index, pane.title),
+                    .map(|pane| {
+                        let mut label = format!("%{} {} {}", pane.id.0, pane.index, pane.title);
+                        if let Some(note) = self.pane_notes.get(&pane.id) {
+                            label = format!("{}, {note}", label.trim_end());
+                        }
+                        ListItem {
+                            label,
+                            value: TmuxChooserTarget::Pane(pane.id),
+                        }
                     })
                     .collect::<Vec<_>>();
-                panes.sort_by_key(|item| match item.target {
+                panes.sort_by_key(|item| match item.value {
                     TmuxChooserTarget::Pane(pane_id) => topology
                         .pane(pane_id)
                         .map_or((u32::MAX, u64::MAX), |pane| (pane.index, pane.id.0)),
@@ -171,179 +183,24 @@ impl TmuxChooserView {
                 panes
             }
         };
-        self.reconcile_selection();
-        self.render();
-    }
-
-    fn matching_items(&self) -> Vec<&ChooserItem> {
-        let query = self.editor.input().to_lowercase();
-        self.items
-            .iter()
-            .filter(|item| query.is_empty() || item.label.to_lowercase().contains(&query))
-            .collect()
-    }
-
-    fn reconcile_selection(&mut self) {
-        let selected = self.selected;
-        let next = {
-            let matching = self.matching_items();
-            selected
-                .filter(|target| matching.iter().any(|item| item.target == *target))
-                .or_else(|| matching.first().map(|item| item.target))
-        };
-        self.selected = next;
-    }
-
-    fn move_selection(&mut self, delta: isize) -> bool {
-        let matching = self.matching_items();
-        let Some(current) = self.selected else {
-            self.selected = matching.first().map(|item| item.target);
-            return self.selected.is_some();
-        };
-        let Some(index) = matching.iter().position(|item| item.target == current) else {
-            self.selected = matching.first().map(|item| item.target);
-            return self.selected.is_some();
-        };
-        let next = index.saturating_add_signed(delta);
-        if next >= matching.len() || next == index {
-            return false;
-        }
-        self.selected = Some(matching[next].target);
-        true
-    }
-
-    fn selected_label(&self) -> Option<&str> {
-        let selected = self.selected?;
-        self.items
-            .iter()
-            .find(|item| item.target == selected)
-            .map(|item| item.label.as_str())
+        self.list.set_items(items);
     }
 
     fn choose(&self) -> ViewAction {
-        self.selected
+        self.list
+            .selected()
             .map_or(ViewAction::Bell, |target| ViewAction::TmuxChooserSelect {
                 connection_id: self.connection_id,
-                target,
+                target: *target,
             })
     }
 
-    fn apply_editor_action(
-        &mut self,
-        sr: &mut ScreenReader,
-        action: EditorAction,
-    ) -> Result<ViewAction> {
-        match action {
-            EditorAction::Changed => {
-                self.reconcile_selection();
-                self.render();
-                if let Some(label) = self.selected_label() {
-                    sr.speak(label, false)?;
-                }
-                Ok(ViewAction::Redraw)
-            }
-            EditorAction::Submit => Ok(self.choose()),
-            EditorAction::Bell => Ok(ViewAction::Bell),
-            EditorAction::None => Ok(ViewAction::None),
-        }
-    }
-
-    fn move_and_announce(&mut self, sr: &mut ScreenReader, delta: isize) -> Result<ViewAction> {
-        if !self.move_selection(delta) {
-            return Ok(ViewAction::Bell);
-        }
-        self.render();
-        if let Some(label) = self.selected_label() {
-            sr.speak(label, false)?;
-        }
-        Ok(ViewAction::Redraw)
-    }
-
-    fn empty_text(&self) -> &'static str {
-        match self.scope {
-            TmuxChooserScope::Sessions => "no matching sessions",
-            TmuxChooserScope::Windows(_) => "no matching windows",
-            TmuxChooserScope::Panes(_) => "no matching panes",
-        }
-    }
-
-    fn render(&mut self) {
-        let (rows, cols) = self.view.size();
-        let (visible_query, query_cursor_width) = visible_input_window(
-            self.editor.input(),
-            self.editor.cursor(),
-            usize::from(cols).saturating_sub(8),
-        );
-        let mut lines = vec![format!("search: {visible_query}")];
-        let item_capacity = usize::from(rows).saturating_sub(2);
-        let (matching_len, selected_index) = {
-            let matching = self.matching_items();
-            (
-                matching.len(),
-                self.selected
-                    .and_then(|selected| matching.iter().position(|item| item.target == selected)),
-            )
-        };
-        if item_capacity == 0 {
-            self.viewport_start = 0;
-        } else {
-            let max_start = matching_len.saturating_sub(item_capacity);
-            self.viewport_start = self.viewport_start.min(max_start);
-            if let Some(selected_index) = selected_index {
-                if selected_index < self.viewport_start {
-                    self.viewport_start = selected_index;
-                } else if selected_index >= self.viewport_start.saturating_add(item_capacity) {
-                    self.viewport_start = selected_index
-                        .saturating_add(1)
-                        .saturating_sub(item_capacity)
-                        .min(max_start);
-                }
-            }
-        }
-        let matching = self.matching_items();
-        if matching.is_empty() && item_capacity > 0 {
-            lines.push(self.empty_text().to_owned());
-        } else {
-            for item in matching
-                .into_iter()
-                .skip(self.viewport_start)
-                .take(item_capacity)
-            {
-                lines.push(item.label.clone());
-            }
-        }
-        if rows > 1 {
-            lines.push("Up/Down select, Enter choose, Escape cancel".to_owned());
-        }
-        let mut bytes = b"\x1b[2J\x1b[H".to_vec();
-        for (index, line) in lines.into_iter().take(usize::from(rows)).enumerate() {
-            if index > 0 {
-                bytes.extend_from_slice(b"\r\n");
-            }
-            bytes.extend_from_slice(truncate_display_width(&line, usize::from(cols)).as_bytes());
+    fn finish(&self, input: ListInput) -> ViewAction {
+        match input {
+            ListInput::Choose => self.choose(),
+            ListInput::Cancel => ViewAction::Pop,
+            ListInput::Handled(action) => action,
         }
-        let selected_cursor_row = selected_index
-            .filter(|index| {
-                *index >= self.viewport_start
-                    && *index < self.viewport_start.saturating_add(item_capacity)
-            })
-            .map(|index| index.saturating_sub(self.viewport_start).saturating_add(2));
-        let (cursor_row, cursor_col) = selected_cursor_row.map_or_else(
-            || {
-                (
-                    1,
-                    query_cursor_width
-                        .saturating_add(9)
-                        .min(usize::from(cols))
-                        .max(1),
-                )
-            },
-            |row| (row, 1),
-        );
-        bytes.extend_from_slice(format!("\x1b[{cursor_row};{cursor_col}H").as_bytes());
-        self.view.clear_update_summary();
-        self.view.process_changes(&bytes);
-        self.view.clear_update_summary();
     }
 }
 
@@ -357,7 +214,7 @@ impl ViewController for TmuxChooserView {
     }
 
     fn model(&mut self) -> &mut View {
-        &mut self.view
+        self.list.model()
     }
 
     fn title(&self) -> &str {
@@ -374,16 +231,8 @@ impl ViewController for TmuxChooserView {
         input: &[u8],
         _pty_stream: &mut dyn Write,
     ) -> Result<ViewAction> {
-        match input {
-            b"\x1b" => Ok(ViewAction::Pop),
-            b"\r" | b"\n" => Ok(self.choose()),
-            b"\x1b[A" => self.move_and_announce(sr, -1),
-            b"\x1b[B" => self.move_and_announce(sr, 1),
-            _ => {
-                let action = self.editor.handle_bytes(input);
-                self.apply_editor_action(sr, action)
-            }
-        }
+        let input = self.list.handle_input(sr, input)?;
+        Ok(self.finish(input))
     }
 
     fn handle_key_input(
@@ -393,19 +242,8 @@ impl ViewController for TmuxChooserView {
         _raw: &[u8],
         _pty_stream: &mut dyn Write,
     ) -> Result<ViewAction> {
-        if key.is_release() {
-            return Ok(ViewAction::None);
-        }
-        match key.event().code {
-            KeyCode::Esc => Ok(ViewAction::Pop),
-            KeyCode::Enter => Ok(self.choose()),
-            KeyCode::Up => self.move_and_announce(sr, -1),
-            KeyCode::Down => self.move_and_announce(sr, 1),
-            _ => {
-                let action = self.editor.handle_key_input(key);
-                self.apply_editor_action(sr, action)
-            }
-        }
+        let input = self.list.handle_key_input(sr, key)?;
+        Ok(self.finish(input))
     }
 
     fn handle_paste(
@@ -414,12 +252,11 @@ impl ViewController for TmuxChooserView {
         contents: &str,
         _pty_stream: &mut dyn Write,
     ) -> Result<ViewAction> {
-        let action = self.editor.handle_text(contents);
-        self.apply_editor_action(sr, action)
+        let input = self.list.handle_paste(sr, contents)?;
+        Ok(self.finish(input))
     }
 
     fn on_resize(&mut self, rows: u16, cols: u16) {
-        self.view.set_size(rows, cols);
-        self.render();
+        self.list.on_resize(rows, cols);
     }
 }
@@ -1,6 +1,6 @@
 use super::{Result, ViewAction, ViewController, ViewKind};
 use crate::{
-    presentation::{PresentedFrameIndex, PresentedViewFrame, Scene, SurfaceId, ViewId},
+    presentation::{GridPoint, PresentedFrameIndex, PresentedViewFrame, Scene, SurfaceId, ViewId},
     screen_reader::ScreenReader,
     terminal::{TerminalGeometry, UpdateSummary},
     terminal_input::KeyInput,
@@ -12,7 +12,7 @@ use crate::{
     view::View,
 };
 use std::{any::Any, io::Write};
-use terminput::MouseEvent;
+use terminput::{MouseEvent, MouseEventKind};
 
 const CONNECTION_TEXT: &str = "tmux connection is active.\r\n\
 Waiting for tmux session and pane inventory.";
@@ -28,6 +28,9 @@ pub struct TmuxConnectionView {
     active_window: ActiveWindowProjection,
     showing_portal: bool,
     inventory_error: Option<String>,
+    /// The pane a mouse button was pressed in, which receives the drags and
+    /// release that follow wherever they land, as tmux itself does.
+    mouse_capture: Option<PaneId>,
 }
 
 pub(crate) struct PaneOutput {
@@ -133,6 +136,7 @@ impl TmuxConnectionView {
             active_window: ActiveWindowProjection::MissingActiveWindow,
             showing_portal: false,
             inventory_error: None,
+            mouse_capture: None,
         }
     }
 
@@ -445,12 +449,35 @@ impl TmuxConnectionView {
             .is_some_and(|active| active.layout.pane(pane_id).is_some())
     }
 
+    /// Routes a physical mouse event to the pane under the pointer, in that
+    /// pane's coordinates, protocol, and encoding.
     #[must_use]
-    pub fn translate_mouse_input(&self, event: MouseEvent) -> Option<ViewAction> {
+    pub fn translate_mouse_input(&mut self, event: MouseEvent) -> Option<ViewAction> {
         if self.is_showing_portal() || !self.is_ready() {
             return None;
         }
-        let pane = self.active_layout_pane()?;
+        let layout = &self.active_window.ready()?.layout;
+        let pointer = GridPoint::new(i32::from(event.row), i32::from(event.column));
+        let captured = match event.kind {
+            MouseEventKind::Drag(_) | MouseEventKind::Up(_) => {
+                self.mouse_capture.and_then(|pane_id| layout.pane(pane_id))
+            }
+            _ => None,
+        };
+        // A drag out of the captured pane stops at its edge.
+        let (pane, event) = match captured.copied() {
+            Some(pane) => (Some(pane), clamp_to_pane(event, pane)),
+            None => (layout.pane_at(pointer).copied(), event),
+        };
+        match event.kind {
+            MouseEventKind::Down(_) => self.mouse_capture = pane.map(|pane| pane.pane_id),
+            MouseEventKind::Up(_) => self.mouse_capture = None,
+            _ => {}
+        }
+        let pane = pane?;
+        if self.panes.pane_portal_target(pane.pane_id).is_some() {
+            return None;
+        }
         let screen = self.panes.pane_view(pane.pane_id)?.live_screen();
         let bytes = crate::tmux_input::translate_mouse(
             event,
@@ -461,11 +488,6 @@ impl TmuxConnectionView {
         Some(self.input_action(pane.pane_id, bytes))
     }
 
-    fn active_layout_pane(&self) -> Option<LayoutPane> {
-        let active = self.active_window.ready()?;
-        active.layout.pane(active.active_pane?).copied()
-    }
-
     fn active_pane_portal_target(&self) -> Option<u64> {
         self.panes
             .pane_portal_target(self.active_window.active_pane()?)
@@ -666,6 +688,18 @@ fn render_text(view: &mut View, text: &str) {
     view.process_changes(&bytes);
 }
 
+fn clamp_to_pane(event: MouseEvent, pane: LayoutPane) -> MouseEvent {
+    let clamp = |value: u16, origin: i32, len: u16| {
+        let last = origin + i32::from(len.max(1)) - 1;
+        u16::try_from(i32::from(value).clamp(origin, last)).unwrap_or(value)
+    };
+    MouseEvent {
+        row: clamp(event.row, pane.origin.row, pane.rows),
+        column: clamp(event.column, pane.origin.col, pane.cols),
+        ..event
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::TmuxConnectionView;
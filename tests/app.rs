@@ -1492,6 +1492,31 @@ fn click_actions_write_mouse_events_at_review_cursor() {
     assert_eq!(pty_out, b"\x1B[<0;8;5M\x1B[<0;8;5m\x1B[<2;8;5M\x1B[<2;8;5m");
 }
 
+#[test]
+fn review_cursor_motion_reports_hover_to_applications_tracking_all_motion() {
+    let (mut app, mut sr, _recorder, clock) = make_app();
+    let mut pty_out = Vec::new();
+    let mut term_out = Vec::new();
+
+    app.handle_pty(
+        &mut sr,
+        b"abcdefghij\x1B[?1003h\x1B[?1006h\x1B[1;3H",
+        &mut term_out,
+    )
+    .expect("enable all-motion mouse reporting and position cursor");
+    clock.advance_ms(u128::from(DIFF_DELAY) + 1);
+    assert!(app.maybe_finalize_changes(&mut sr).expect("finalize"));
+
+    app.handle_stdin(&mut sr, b"\x1B.", &mut pty_out, &mut term_out)
+        .expect("move review cursor without hover");
+    assert!(pty_out.is_empty());
+
+    sr.set_mouse_hover_enabled(true);
+    app.handle_stdin(&mut sr, b"\x1B.", &mut pty_out, &mut term_out)
+        .expect("move review cursor with hover");
+    assert_eq!(pty_out, b"\x1B[<35;5;1M");
+}
+
 #[test]
 fn coordinate_clicks_use_the_live_application_protocol_during_a_held_frame() {
     let (mut app, mut sr, _recorder, _clock) = make_app();
@@ -1635,6 +1660,66 @@ fn table_navigation_preserves_direction_and_boundary_behavior() {
     assert!(pty_out.is_empty());
 }
 
+#[test]
+fn bindings_list_opened_in_table_mode_filters_and_runs_a_table_binding() {
+    let (mut app, mut sr, recorder, clock) = make_app();
+    let mut pty_out = Vec::new();
+    let mut term_out = Vec::new();
+
+    app.handle_pty(
+        &mut sr,
+        b"| A | B | C |\r\n|---|---|---|\r\n| 1 | 2 | 3 |\r\n| 4 | 5 | 6 |\x1B[1;1H",
+        &mut term_out,
+    )
+    .expect("draw table");
+    clock.advance_ms(u128::from(DIFF_DELAY) + 1);
+    assert!(app.maybe_finalize_changes(&mut sr).expect("finalize table"));
+    app.handle_stdin(&mut sr, b"\x1Bt", &mut pty_out, &mut term_out)
+        .expect("enter table mode");
+    app.handle_stdin(&mut sr, b"\x1B?", &mut pty_out, &mut term_out)
+        .expect("open bindings list");
+    recorder.inner.borrow_mut().speaks.clear();
+
+    for key in b"table row" {
+        app.handle_stdin(&mut sr, &[*key], &mut pty_out, &mut term_out)
+            .expect("filter bindings");
+    }
+    for key in [b"\x1B[B".as_slice(), b"\x1B[A"] {
+        app.handle_stdin(&mut sr, key, &mut pty_out, &mut term_out)
+            .expect("select binding");
+    }
+    assert_eq!(
+        recorder
+            .inner
+            .borrow()
+            .speaks
+            .last()
+            .map(|(text, _)| text.as_str()),
+        Some("G, bottom table row")
+    );
+    assert!(
+        recorder
+            .inner
+            .borrow()
+            .speaks
+            .iter()
+            .any(|(text, _)| text == "M-o, next table row")
+    );
+    app.handle_stdin(&mut sr, b"\r", &mut pty_out, &mut term_out)
+        .expect("run binding");
+
+    assert_eq!(
+        recorder
+            .inner
+            .borrow()
+            .speaks
+            .last()
+            .map(|(text, _)| text.as_str()),
+        Some("4")
+    );
+    assert!(pty_out.is_empty());
+}
+
 #[test]
 fn pty_output_writes_terminal_and_autoreads() {
     let (mut app, mut sr, recorder, clock) = make_app();
@@ -148,6 +148,20 @@ fn ghostty_update_facts_merge_across_every_fragment() {
     assert!(!merged.changed_rows.is_empty());
 }
 
+#[test]
+fn ghostty_update_reports_new_prompt_marks() {
+    let mut ghostty = GhosttyEngine::new(ROWS, COLS).expect("create Ghostty engine");
+    let advance = |ghostty: &mut GhosttyEngine, bytes: &[u8]| {
+        ghostty
+            .advance(bytes)
+            .expect("advance Ghostty engine")
+            .prompt_started
+    };
+    assert!(advance(&mut ghostty, b"\x1b]133;A\x07$ \x1b]133;B\x07"));
+    assert!(!advance(&mut ghostty, b"ls\r\n\x1b]133;C\x07out\r\n"));
+    assert!(advance(&mut ghostty, b"\x1b]133;D;0\x07\x1b]133;A\x07$ "));
+}
+
 #[test]
 fn ghostty_update_reports_actual_screen_transition_and_synchronized_output() {
     let mut ghostty = GhosttyEngine::new(3, 12).expect("create Ghostty engine");
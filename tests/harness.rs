@@ -76,3 +76,17 @@ fn harness_script_terminal_differential_corpus() {
     let mut harness = Harness::new(24, 80).expect("create harness");
     harness.run_script(script).expect("run script");
 }
+
+#[test]
+fn harness_script_selection_tracking() {
+    let script = include_str!("scripts/selection_tracking.txt");
+    let mut harness = Harness::new(24, 80).expect("create harness");
+    harness.run_script(script).expect("run script");
+}
+
+#[test]
+fn harness_script_screen_regions() {
+    let script = include_str!("scripts/screen_regions.txt");
+    let mut harness = Harness::new(24, 80).expect("create harness");
+    harness.run_script(script).expect("run script");
+}
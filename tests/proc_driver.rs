@@ -45,6 +45,26 @@ fn proc_driver_accepts_a_method_not_found_initialize_as_legacy() {
     assert!((driver.get_rate() - 1.5).abs() < f32::EPSILON);
 }
 
+#[test]
+fn proc_driver_selects_voices_only_when_the_server_advertises_them() {
+    let server_path = PathBuf::from(env!("CARGO_BIN_EXE_proc_stub_server"));
+    let mut driver = ProcDriver::new(&server_path).expect("spawn proc stub server");
+    driver.set_voice(Some("quiet")).expect("set_voice");
+    driver.set_voice(None).expect("restore default voice");
+    driver.speak("still usable", false).expect("speak");
+
+    let mut legacy = ProcDriver::new_with_args(&server_path, ["--legacy"])
+        .expect("spawn legacy proc stub server");
+    let error = legacy.set_voice(Some("quiet")).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<ProcError>(),
+        Some(ProcError::UnsupportedMethod("set_voice"))
+    ));
+    legacy
+        .speak("legacy speech continues", false)
+        .expect("unsupported voices do not poison the transport");
+}
+
 #[test]
 fn rpc_discover_is_available_before_initialize() {
     let mut child = Command::new(env!("CARGO_BIN_EXE_proc_stub_server"))
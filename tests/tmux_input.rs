@@ -380,6 +380,29 @@ fn application_harness_routes_keyboard_paste_focus_mouse_queries_and_latest_size
         "a mouse event on the split border leaked into the active pane"
     );
 
+    app.handle_pty(
+        &mut sr,
+        b"%output %20 \\033[?1000h\\033[?1006h\n",
+        &mut physical,
+    )
+    .unwrap();
+    app.handle_stdin(&mut sr, b"\x1b[<0;3;2M", &mut Vec::new(), &mut physical)
+        .unwrap();
+    assert_eq!(
+        decode_send_keys(&tick_commands(&mut app, &mut sr, &mut physical), PaneId(20)),
+        b"\x1b[<0;3;2M",
+        "a click over an inactive pane must reach that pane"
+    );
+    app.handle_stdin(&mut sr, b"\x1b[<0;46;3M", &mut Vec::new(), &mut physical)
+        .unwrap();
+    app.handle_stdin(&mut sr, b"\x1b[<0;3;2m", &mut Vec::new(), &mut physical)
+        .unwrap();
+    assert_eq!(
+        decode_send_keys(&tick_commands(&mut app, &mut sr, &mut physical), PaneId(21)),
+        b"\x1b[<0;5;3M\x1b[<0;1;2m",
+        "a release belongs to the pane its press started in"
+    );
+
     app.handle_pty(&mut sr, b"%output %21 \\033[6n\n", &mut physical)
         .unwrap();
     assert!(
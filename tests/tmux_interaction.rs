@@ -1,8 +1,8 @@
 use lector::{
     app::{App, Clock},
     output_scheduler::OutputSchedulerConfig,
-    screen_reader::ScreenReader,
-    speech,
+    screen_reader::{PaneSpeechProfile, ScreenReader},
+    speech::{self, symbols::Level},
     terminal::GhosttyEngine,
     tmux_model::{PaneId, SessionId, TmuxTopology, WindowId},
     views::{
@@ -709,6 +709,165 @@ fn tmux_m_w_announces_connection_and_window_while_terminal_wording_stays_separat
     );
 }
 
+#[test]
+fn activity_summary_reports_unseen_output_bells_and_stale_panes_until_visited() {
+    let (mut app, mut sr, recorder, mut physical) = ready_app();
+    app.handle_pty(&mut sr, b"%output %21 build done\\007\n", &mut physical)
+        .unwrap();
+    app.handle_pty(&mut sr, b"%pause %23\n", &mut physical)
+        .unwrap();
+    recorder.0.borrow_mut().clear();
+
+    assert!(
+        app.show_tmux_activity_summary(&mut sr, &mut physical)
+            .unwrap()
+    );
+    assert_eq!(
+        recorder.0.borrow().first().map(String::as_str),
+        Some("output in window 2; bell in window 2; stale pane 1.2")
+    );
+    let contents = app.debug_active_view_contents();
+    assert!(contents.contains("pane 1.2 %23 right: stale after pause"));
+    assert!(contents.contains("window 2 @11 second-window: unseen output"));
+    assert!(contents.contains("window 2 @11 second-window: bell"));
+    input(&mut app, &mut sr, &mut physical, b"\x1b");
+
+    // Visiting the window acknowledges both its output and its bell.
+    app.handle_pty(&mut sr, b"%continue %23\n", &mut physical)
+        .unwrap();
+    app.handle_pty(&mut sr, b"%session-window-changed $1 @11\n", &mut physical)
+        .unwrap();
+    recorder.0.borrow_mut().clear();
+    assert!(
+        app.show_tmux_activity_summary(&mut sr, &mut physical)
+            .unwrap()
+    );
+    assert!(
+        !recorder
+            .0
+            .borrow()
+            .iter()
+            .any(|spoken| spoken.contains("window 2"))
+    );
+}
+
+#[test]
+fn pane_speech_overrides_follow_the_pane_and_appear_in_the_pane_chooser() {
+    let (mut app, mut sr, _recorder, mut physical) = ready_app();
+    let connection_id = app.active_tmux_connection().unwrap();
+    sr.set_tmux_pane_speech(
+        connection_id,
+        PaneId(23),
+        PaneSpeechProfile {
+            auto_read: Some(false),
+            symbol_level: Some(Level::None),
+            voice: None,
+        },
+    )
+    .unwrap();
+
+    let later = PaneSpeechProfile {
+        auto_read: Some(false),
+        symbol_level: None,
+        voice: None,
+    };
+    sr.set_tmux_pane_speech(connection_id, PaneId(99), later.clone())
+        .unwrap();
+
+    input(&mut app, &mut sr, &mut physical, b"x");
+    assert_eq!(sr.active_tmux_pane(), Some((connection_id, PaneId(20))));
+    assert!(sr.live_auto_read_enabled());
+    // A pane the inventory has not reported yet keeps its profile.
+    assert_eq!(sr.tmux_pane_speech(connection_id, PaneId(99)), Some(&later));
+
+    app.handle_pty(&mut sr, b"%window-pane-changed @10 %23\n", &mut physical)
+        .unwrap();
+    input(&mut app, &mut sr, &mut physical, b"x");
+    assert_eq!(sr.active_tmux_pane(), Some((connection_id, PaneId(23))));
+    assert!(!sr.live_auto_read_enabled());
+
+    assert!(app.show_tmux_pane_chooser(&mut sr, &mut physical).unwrap());
+    let contents = app.debug_active_view_contents();
+    assert!(contents.contains("%23 2 right, muted, symbols none"));
+    assert!(!contents.contains("left,"));
+}
+
+#[test]
+fn workspaces_save_directories_and_roll_back_a_failed_restore() {
+    let (mut app, mut sr, recorder, mut physical) = ready_app();
+    let directory = tempfile::tempdir().unwrap();
+    app.set_tmux_workspace_dir(directory.path().to_owned());
+
+    assert!(
+        app.show_tmux_workspace_prompt(&mut sr, true, &mut physical)
+            .unwrap()
+    );
+    input(&mut app, &mut sr, &mut physical, b"daily\r");
+    assert_eq!(
+        tick(&mut app, &mut sr, &mut physical),
+        lector::tmux_workspace::WORKSPACE_DIRECTORY_QUERY.as_bytes()
+    );
+    app.handle_pty(
+        &mut sr,
+        &reply(40, &[b"D\t%20\t/src".to_vec()], true),
+        &mut physical,
+    )
+    .unwrap();
+    let saved: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(directory.path().join("daily.json")).unwrap())
+            .unwrap();
+    assert_eq!(
+        saved["sessions"][0]["windows"][0]["panes"][0]["cwd"],
+        "/src"
+    );
+    assert!(
+        recorder
+            .0
+            .borrow()
+            .iter()
+            .any(|spoken| spoken == "saved workspace daily")
+    );
+
+    std::fs::write(
+        directory.path().join("split.json"),
+        r#"{"version":1,"label":null,"sessions":[{"name":"work","windows":[
+            {"name":"logs","layout":"b25f,80x24,0,0{40x24,0,0,1,39x24,41,0,2}",
+             "panes":[{"title":"","cwd":"/var/log"},{"title":"","cwd":null}]}]}]}"#,
+    )
+    .unwrap();
+    assert!(
+        app.show_tmux_workspace_prompt(&mut sr, false, &mut physical)
+            .unwrap()
+    );
+    input(&mut app, &mut sr, &mut physical, b"split\r");
+    let command = String::from_utf8(tick(&mut app, &mut sr, &mut physical)).unwrap();
+    assert!(command.starts_with("new-window -d -P -F "));
+    assert!(command.ends_with("-t '=work:' -n 'logs' -c '/var/log'\n"));
+    app.handle_pty(
+        &mut sr,
+        &reply(41, &[b"$1 @40 %50".to_vec()], true),
+        &mut physical,
+    )
+    .unwrap();
+    let command = String::from_utf8(tick(&mut app, &mut sr, &mut physical)).unwrap();
+    assert!(command.contains("split-window -d"));
+    assert!(command.ends_with(" -t %50\n"));
+    app.handle_pty(
+        &mut sr,
+        &reply(42, &[b"no space for new pane".to_vec()], false),
+        &mut physical,
+    )
+    .unwrap();
+    assert_eq!(
+        tick(&mut app, &mut sr, &mut physical),
+        b"kill-window -t @40\n"
+    );
+    assert!(
+        app.debug_active_view_contents()
+            .contains("split-window: no space for new pane")
+    );
+}
+
 #[test]
 fn window_and_session_changes_announce_the_new_location_concisely() {
     let (mut app, mut sr, recorder, mut physical) = ready_app();
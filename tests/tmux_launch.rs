@@ -0,0 +1,140 @@
+use lector::{
+    app::{App, Clock},
+    screen_reader::ScreenReader,
+    speech,
+    tmux_launch::{LaunchError, TmuxAttachRequest, TmuxSocket},
+    views,
+};
+use std::{
+    cell::{Cell, RefCell},
+    ffi::OsString,
+    path::PathBuf,
+    rc::Rc,
+};
+
+#[derive(Clone, Default)]
+struct TestClock(Rc<Cell<u128>>);
+
+impl Clock for TestClock {
+    fn now_ms(&self) -> u128 {
+        self.0.get()
+    }
+}
+
+#[derive(Clone, Default)]
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+impl speech::Driver for Recorder {
+    fn speak(&mut self, text: &str, _interrupt: bool) -> anyhow::Result<()> {
+        self.0.borrow_mut().push(text.to_owned());
+        Ok(())
+    }
+
+    fn stop(&mut self) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    fn get_rate(&self) -> f32 {
+        1.0
+    }
+
+    fn set_rate(&mut self, _rate: f32) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+fn args(values: &[&str]) -> Vec<OsString> {
+    values.iter().map(OsString::from).collect()
+}
+
+#[test]
+fn sockets_select_named_or_path_servers_explicitly() {
+    let default = TmuxAttachRequest::parse_target("work").unwrap();
+    assert_eq!(default.socket(), &TmuxSocket::Default);
+    assert_eq!(
+        default.args(),
+        args(&["-CC", "new-session", "-A", "-s", "work"])
+    );
+
+    let named = TmuxAttachRequest::parse_target("alt:work").unwrap();
+    assert_eq!(named.socket(), &TmuxSocket::Name("alt".to_owned()));
+    assert_eq!(
+        named.args(),
+        args(&["-L", "alt", "-CC", "new-session", "-A", "-s", "work"])
+    );
+
+    // Only the final separator ends the socket, so paths may contain ':'.
+    let path = TmuxAttachRequest::parse_target("/tmp/a:b/sock:work").unwrap();
+    assert_eq!(
+        path.socket(),
+        &TmuxSocket::Path(PathBuf::from("/tmp/a:b/sock"))
+    );
+    assert_eq!(path.session(), "work");
+    assert_eq!(
+        path.description(),
+        "tmux session work on socket /tmp/a:b/sock"
+    );
+}
+
+#[test]
+fn invalid_targets_are_rejected_before_any_command_is_built() {
+    assert_eq!(
+        TmuxAttachRequest::parse_target("  "),
+        Err(LaunchError::EmptySession)
+    );
+    assert_eq!(
+        TmuxAttachRequest::parse_target("alt:"),
+        Err(LaunchError::EmptySession)
+    );
+    assert_eq!(
+        TmuxAttachRequest::parse_target(":work"),
+        Err(LaunchError::EmptySocket)
+    );
+    assert_eq!(
+        TmuxAttachRequest::new(TmuxSocket::Default, "a.b"),
+        Err(LaunchError::InvalidSessionSeparator)
+    );
+    assert_eq!(
+        TmuxAttachRequest::new(TmuxSocket::Default, "a\rb"),
+        Err(LaunchError::ControlCharacter("session name"))
+    );
+    assert_eq!(
+        TmuxSocket::parse("bad\nsocket"),
+        Err(LaunchError::ControlCharacter("socket"))
+    );
+}
+
+#[test]
+fn shell_input_quotes_every_user_supplied_argument() {
+    let request =
+        TmuxAttachRequest::new(TmuxSocket::parse("/tmp/my sock").unwrap(), "it's").unwrap();
+    assert_eq!(
+        request.shell_input(),
+        b"TMUX= tmux -S '/tmp/my sock' -CC new-session -A -s 'it'\\''s'\r"
+    );
+}
+
+#[test]
+fn queued_attach_is_typed_into_the_root_shell_on_the_next_tick() {
+    let recorder = Recorder::default();
+    let stack = views::ViewStack::new(Box::new(views::PtyView::new(24, 80)));
+    let mut app = App::new_with_clock(stack, Box::new(TestClock::default())).unwrap();
+    let mut sr = ScreenReader::new(speech::Speech::new(Box::new(recorder.clone())));
+    let mut physical = Vec::new();
+    let mut pty = Vec::new();
+
+    sr.request_tmux_attach(TmuxAttachRequest::parse_target("alt:work").unwrap());
+    app.handle_tick(&mut sr, &mut pty, &mut physical).unwrap();
+    assert_eq!(pty, b"TMUX= tmux -L alt -CC new-session -A -s work\r");
+    assert!(
+        recorder
+            .0
+            .borrow()
+            .iter()
+            .any(|spoken| spoken == "attaching tmux session work on socket alt")
+    );
+
+    pty.clear();
+    app.handle_tick(&mut sr, &mut pty, &mut physical).unwrap();
+    assert!(pty.is_empty());
+}